@@ -25,15 +25,23 @@
 
 extern crate alloc;
 
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+use alloc::rc::Rc;
 use alloc::string::String;
 use alloc::vec::Vec;
+use core::cell::RefCell;
 use core::cmp::Ordering;
 use core::convert::{self, Infallible, TryFrom};
 use core::f64;
 use core::fmt;
+use core::fmt::Write as _;
 use core::iter::{self, Product, Sum};
 use core::mem;
-use core::ops::{Add, BitAnd, BitOr, BitXor, Div, Mul, Neg, Not, Rem, Shl, Shr, Sub};
+use core::ops::{
+    Add, BitAnd, BitOr, BitXor, Bound, ControlFlow, Div, Mul, Neg, Not, Range, RangeBounds, Rem,
+    Shl, Shr, Sub,
+};
 use core::str;
 use core::str::FromStr;
 
@@ -226,6 +234,13 @@ extern "C" {
     /// The `decodeURI()` function decodes a Uniform Resource Identifier (URI)
     /// previously created by `encodeURI` or by a similar routine.
     ///
+    /// On malformed input, the error value is a [`UriError`] (upcast to
+    /// `JsValue`, as with every other `catch`-bound function in this crate);
+    /// it carries a message but not the offset of the bad escape sequence.
+    /// For input that must never throw, or for error messages that should
+    /// point at the failing offset, see [`uri::try_decode_component_lossy`]
+    /// and [`uri::find_invalid_sequence`].
+    ///
     /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/decodeURI)
     #[wasm_bindgen(catch, js_name = decodeURI)]
     pub fn decode_uri(encoded: &str) -> Result<JsString, JsValue>;
@@ -233,6 +248,13 @@ extern "C" {
     /// The `decodeURIComponent()` function decodes a Uniform Resource Identifier (URI) component
     /// previously created by `encodeURIComponent` or by a similar routine.
     ///
+    /// On malformed input, the error value is a [`UriError`] (upcast to
+    /// `JsValue`, as with every other `catch`-bound function in this crate);
+    /// it carries a message but not the offset of the bad escape sequence.
+    /// For input that must never throw, or for error messages that should
+    /// point at the failing offset, see [`uri::try_decode_component_lossy`]
+    /// and [`uri::find_invalid_sequence`].
+    ///
     /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/decodeURIComponent)
     #[wasm_bindgen(catch, js_name = decodeURIComponent)]
     pub fn decode_uri_component(encoded: &str) -> Result<JsString, JsValue>;
@@ -341,6 +363,15 @@ extern "C" {
     #[wasm_bindgen(static_method_of = Array)]
     pub fn from(val: &JsValue) -> Array;
 
+    /// The `Array.fromAsync()` method creates a new, shallow-copied `Array`
+    /// instance from an async iterable, an iterable, or an array-like
+    /// object, returning a [`Promise`] that resolves to the new array once
+    /// all of the source's elements have been awaited.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/fromAsync)
+    #[wasm_bindgen(static_method_of = Array, js_name = fromAsync, catch)]
+    pub fn from_async(items: &JsValue) -> Result<Promise, JsValue>;
+
     /// The `copyWithin()` method shallow copies part of an array to another
     /// location in the same array and returns it, without modifying its size.
     ///
@@ -429,6 +460,13 @@ extern "C" {
         callback: &mut dyn FnMut(JsValue, u32, Array) -> Vec<JsValue>,
     ) -> Array;
 
+    /// Like [`flat_map`](Self::flat_map), but `callback` returns an
+    /// [`Array`] directly instead of a [`Vec<JsValue>`], avoiding the cost
+    /// of marshaling through a `Vec` when the callback already has (or
+    /// can cheaply build) a JS array.
+    #[wasm_bindgen(method, js_name = flatMap)]
+    pub fn flat_map_typed(this: &Array, callback: &mut dyn FnMut(JsValue, u32, Array) -> Array) -> Array;
+
     /// The `forEach()` method executes a provided function once for each array element.
     ///
     /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/forEach)
@@ -612,6 +650,14 @@ extern "C" {
     #[wasm_bindgen(method)]
     pub fn sort(this: &Array) -> Array;
 
+    /// Like `sort()`, but with an explicit compare function. `compare`
+    /// should return a negative number if `a` sorts before `b`, a
+    /// positive number if after, and zero if they're equivalent.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/sort)
+    #[wasm_bindgen(method, js_name = sort)]
+    pub fn sort_with(this: &Array, compare: &mut dyn FnMut(JsValue, JsValue) -> f64) -> Array;
+
     /// The `splice()` method changes the contents of an array by removing existing elements and/or
     /// adding new elements.
     ///
@@ -619,6 +665,12 @@ extern "C" {
     #[wasm_bindgen(method)]
     pub fn splice(this: &Array, start: u32, delete_count: u32, item: &JsValue) -> Array;
 
+    /// Like [`splice`](Self::splice), but removes elements without
+    /// inserting any, via the same JS method called with only its first
+    /// two arguments.
+    #[wasm_bindgen(method, js_name = splice)]
+    pub fn splice_remove(this: &Array, start: u32, delete_count: u32) -> Array;
+
     /// The `toLocaleString()` method returns a string representing the elements of the array.
     /// The elements are converted to Strings using their toLocaleString methods and these
     /// Strings are separated by a locale-specific String (such as a comma “,”).
@@ -705,6 +757,15 @@ impl core::iter::ExactSizeIterator for ArrayIntoIter {}
 pub struct ArrayIter<'a> {
     range: core::ops::Range<u32>,
     array: &'a Array,
+    /// The array's length when this iterator was created. `range` is
+    /// bounded by this, so if a JS callback invoked mid-iteration (e.g.
+    /// via [`Array::for_each`] nested inside a manual loop, or simply
+    /// aliased access to the same array) shrinks the array below an
+    /// index still pending in `range`, indexing would otherwise hand back
+    /// `undefined` -- a phantom element -- rather than stopping. `next`
+    /// and `next_back` check the array's *current* length against this
+    /// guard and end the iterator early instead.
+    len_at_start: u32,
 }
 
 impl core::iter::Iterator for ArrayIter<'_> {
@@ -712,6 +773,12 @@ impl core::iter::Iterator for ArrayIter<'_> {
 
     fn next(&mut self) -> Option<Self::Item> {
         let index = self.range.next()?;
+        if index >= self.array.length().min(self.len_at_start) {
+            self.range = 0..0;
+            return None;
+        }
+        #[cfg(feature = "call-metrics")]
+        metrics::record(metrics::Category::ElementGet);
         Some(self.array.get(index))
     }
 
@@ -733,24 +800,49 @@ impl core::iter::Iterator for ArrayIter<'_> {
     where
         Self: Sized,
     {
-        let Self { range, array } = self;
-        range.last().map(|index| array.get(index))
+        let Self {
+            range,
+            array,
+            len_at_start,
+        } = self;
+        // Same guard as `next`/`next_back`: the iterator's effective end is
+        // clipped to the array's current length (and `len_at_start`), so a
+        // shrunk array doesn't hand back a phantom `undefined` element.
+        let end = range.end.min(array.length()).min(len_at_start);
+        if end <= range.start {
+            return None;
+        }
+        Some(array.get(end - 1))
     }
 
     #[inline]
     fn nth(&mut self, n: usize) -> Option<Self::Item> {
-        self.range.nth(n).map(|index| self.array.get(index))
+        let index = self.range.nth(n)?;
+        if index >= self.array.length().min(self.len_at_start) {
+            self.range = 0..0;
+            return None;
+        }
+        Some(self.array.get(index))
     }
 }
 
 impl core::iter::DoubleEndedIterator for ArrayIter<'_> {
     fn next_back(&mut self) -> Option<Self::Item> {
         let index = self.range.next_back()?;
+        if index >= self.array.length().min(self.len_at_start) {
+            self.range = 0..0;
+            return None;
+        }
         Some(self.array.get(index))
     }
 
     fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
-        self.range.nth_back(n).map(|index| self.array.get(index))
+        let index = self.range.nth_back(n)?;
+        if index >= self.array.length().min(self.len_at_start) {
+            self.range = 0..0;
+            return None;
+        }
+        Some(self.array.get(index))
     }
 }
 
@@ -758,15 +850,100 @@ impl core::iter::FusedIterator for ArrayIter<'_> {}
 
 impl core::iter::ExactSizeIterator for ArrayIter<'_> {}
 
+/// An index counted either from the start or from the end of an array,
+/// unifying the two conventions split across `Array`'s own methods
+/// (`get`/`set` count from the start; `at`, `copy_within`, and `includes`'s
+/// `from_index` count from the end when negative).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum JsIndex {
+    /// Counts forward from the first element.
+    FromStart(u32),
+    /// Counts backward from one past the last element: `FromEnd(1)` is the
+    /// last element, matching `arr.at(-1)`.
+    FromEnd(u32),
+}
+
+impl JsIndex {
+    /// Builds a `JsIndex` using the same convention as `Array::at`:
+    /// non-negative counts from the start, negative counts from the end.
+    pub fn rel(index: i32) -> JsIndex {
+        if index >= 0 {
+            JsIndex::FromStart(index as u32)
+        } else {
+            JsIndex::FromEnd(index.unsigned_abs())
+        }
+    }
+
+    /// Resolves this index against a container of `len` elements, returning
+    /// `None` if it falls outside `0..len`.
+    fn resolve(self, len: u32) -> Option<u32> {
+        match self {
+            JsIndex::FromStart(i) => {
+                if i < len {
+                    Some(i)
+                } else {
+                    None
+                }
+            }
+            JsIndex::FromEnd(i) => {
+                if i == 0 || i > len {
+                    None
+                } else {
+                    Some(len - i)
+                }
+            }
+        }
+    }
+
+    /// Resolves this index against a container of `len` elements for
+    /// slicing purposes, clamping to `0..=len` instead of failing, matching
+    /// how `Array::slice`'s start/end bounds are normalized per spec.
+    fn clamp_to(self, len: u32) -> u32 {
+        match self {
+            JsIndex::FromStart(i) => i.min(len),
+            JsIndex::FromEnd(i) => len.saturating_sub(i),
+        }
+    }
+}
+
+impl From<u32> for JsIndex {
+    fn from(index: u32) -> JsIndex {
+        JsIndex::FromStart(index)
+    }
+}
+
+impl From<i32> for JsIndex {
+    fn from(index: i32) -> JsIndex {
+        JsIndex::rel(index)
+    }
+}
+
 impl Array {
     /// Returns an iterator over the values of the JS array.
     pub fn iter(&self) -> ArrayIter<'_> {
+        let len = self.length();
         ArrayIter {
-            range: 0..self.length(),
+            range: 0..len,
             array: self,
+            len_at_start: len,
         }
     }
 
+    /// Returns an iterator over a one-shot shallow copy of this array's
+    /// elements, taken up front via [`Array::slice`].
+    ///
+    /// Unlike [`Array::iter`], which re-reads the live array on every step
+    /// and so observes any mutation a JS callback makes mid-iteration
+    /// (skipping or repeating elements, or -- before the `len_at_start`
+    /// guard -- yielding phantom `undefined`s past a shrunk end), this
+    /// iterates a private snapshot: later mutations of the original array
+    /// have no effect on it. The trade-off is the upfront cost of copying
+    /// every element, and a snapshot [`Array`] kept alive for the
+    /// iterator's lifetime.
+    pub fn iter_snapshot(&self) -> ArrayIntoIter {
+        self.slice(0, self.length()).into_iter()
+    }
+
     /// Converts the JS array into a new Vec.
     pub fn to_vec(&self) -> Vec<JsValue> {
         let len = self.length();
@@ -779,5648 +956,15459 @@ impl Array {
 
         output
     }
-}
 
-impl core::iter::IntoIterator for Array {
-    type Item = JsValue;
-    type IntoIter = ArrayIntoIter;
+    /// Converts the JS array into a fixed-size `[JsValue; N]`, failing if
+    /// its length isn't exactly `N`.
+    pub fn try_to_array<const N: usize>(&self) -> Result<[JsValue; N], LengthError> {
+        let vec = self.to_vec();
+        let actual = vec.len();
 
-    fn into_iter(self) -> Self::IntoIter {
-        ArrayIntoIter {
-            range: 0..self.length(),
-            array: self,
-        }
+        <[JsValue; N]>::try_from(vec).map_err(|_| LengthError { expected: N, actual })
     }
-}
 
-// TODO pre-initialize the Array with the correct length using TrustedLen
-impl<A> core::iter::FromIterator<A> for Array
-where
-    A: AsRef<JsValue>,
-{
-    fn from_iter<T>(iter: T) -> Array
-    where
-        T: IntoIterator<Item = A>,
-    {
-        let mut out = Array::new();
-        out.extend(iter);
-        out
-    }
-}
+    /// Converts the first `N` elements of this array into a fixed-size
+    /// `[JsValue; N]`, failing if its length is less than `N`. Any
+    /// elements beyond the first `N` are ignored.
+    pub fn try_to_array_prefix<const N: usize>(&self) -> Result<[JsValue; N], LengthError> {
+        let actual = self.length() as usize;
 
-impl<A> core::iter::Extend<A> for Array
-where
-    A: AsRef<JsValue>,
-{
-    fn extend<T>(&mut self, iter: T)
-    where
-        T: IntoIterator<Item = A>,
-    {
-        for value in iter {
-            self.push(value.as_ref());
+        if actual < N {
+            return Err(LengthError { expected: N, actual });
         }
-    }
-}
 
-impl Default for Array {
-    fn default() -> Self {
-        Self::new()
-    }
-}
+        let mut vec = Vec::with_capacity(N);
 
-// ArrayBuffer
-#[wasm_bindgen]
-extern "C" {
-    #[wasm_bindgen(extends = Object, typescript_type = "ArrayBuffer")]
-    #[derive(Clone, Debug, PartialEq, Eq)]
-    pub type ArrayBuffer;
+        for i in 0..N as u32 {
+            vec.push(self.get(i));
+        }
 
-    /// The `ArrayBuffer` object is used to represent a generic,
-    /// fixed-length raw binary data buffer. You cannot directly
-    /// manipulate the contents of an `ArrayBuffer`; instead, you
-    /// create one of the typed array objects or a `DataView` object
-    /// which represents the buffer in a specific format, and use that
-    /// to read and write the contents of the buffer.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ArrayBuffer)
-    #[wasm_bindgen(constructor)]
-    pub fn new(length: u32) -> ArrayBuffer;
+        <[JsValue; N]>::try_from(vec)
+            .map_err(|_| LengthError { expected: N, actual })
+    }
 
-    /// The byteLength property of an object which is an instance of type ArrayBuffer
-    /// it's an accessor property whose set accessor function is undefined,
-    /// meaning that you can only read this property.
-    /// The value is established when the array is constructed and cannot be changed.
-    /// This property returns 0 if this ArrayBuffer has been detached.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ArrayBuffer/byteLength)
-    #[wasm_bindgen(method, getter, js_name = byteLength)]
-    pub fn byte_length(this: &ArrayBuffer) -> u32;
+    /// Converts the JS array into a fixed-size `[f64; N]`, failing if its
+    /// length isn't exactly `N` or if any element isn't a number.
+    pub fn try_to_f64_array<const N: usize>(&self) -> Result<[f64; N], LengthOrTypeError> {
+        let actual = self.length() as usize;
 
-    /// The `isView()` method returns true if arg is one of the `ArrayBuffer`
-    /// views, such as typed array objects or a DataView; false otherwise.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ArrayBuffer/isView)
-    #[wasm_bindgen(static_method_of = ArrayBuffer, js_name = isView)]
-    pub fn is_view(value: &JsValue) -> bool;
+        if actual != N {
+            return Err(LengthOrTypeError::Length { expected: N, actual });
+        }
 
-    /// The `slice()` method returns a new `ArrayBuffer` whose contents
-    /// are a copy of this `ArrayBuffer`'s bytes from begin, inclusive,
-    /// up to end, exclusive.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ArrayBuffer/slice)
-    #[wasm_bindgen(method)]
-    pub fn slice(this: &ArrayBuffer, begin: u32) -> ArrayBuffer;
+        let mut out = [0.0; N];
 
-    /// Like `slice()` but with the `end` argument.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ArrayBuffer/slice)
-    #[wasm_bindgen(method, js_name = slice)]
-    pub fn slice_with_end(this: &ArrayBuffer, begin: u32, end: u32) -> ArrayBuffer;
-}
+        for (i, slot) in out.iter_mut().enumerate() {
+            *slot = self
+                .get(i as u32)
+                .as_f64()
+                .ok_or(LengthOrTypeError::NotANumber { index: i })?;
+        }
 
-// SharedArrayBuffer
-#[wasm_bindgen]
-extern "C" {
-    #[wasm_bindgen(extends = Object, typescript_type = "SharedArrayBuffer")]
-    #[derive(Clone, Debug)]
-    pub type SharedArrayBuffer;
+        Ok(out)
+    }
 
-    /// The `SharedArrayBuffer` object is used to represent a generic,
-    /// fixed-length raw binary data buffer, similar to the `ArrayBuffer`
-    /// object, but in a way that they can be used to create views
-    /// on shared memory. Unlike an `ArrayBuffer`, a `SharedArrayBuffer`
-    /// cannot become detached.
+    /// Visits every element of this array by index, without allocating a
+    /// `Vec` to hold them first.
     ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/SharedArrayBuffer)
-    #[wasm_bindgen(constructor)]
-    pub fn new(length: u32) -> SharedArrayBuffer;
+    /// Unlike [`Array::for_each`], `f` is a plain Rust closure rather than a
+    /// JS callback, so each call avoids a JS -> Rust round trip; only the
+    /// `Array::get` call to fetch each element crosses the boundary.
+    pub fn for_each_rust(&self, f: &mut dyn FnMut(u32, &JsValue)) {
+        for i in 0..self.length() {
+            f(i, &self.get(i));
+        }
+    }
 
-    /// The byteLength accessor property represents the length of
-    /// an `SharedArrayBuffer` in bytes. This is established when
-    /// the `SharedArrayBuffer` is constructed and cannot be changed.
+    /// Folds the elements of this array into an accumulator, without
+    /// allocating a `Vec` to hold them first.
     ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/SharedArrayBuffer/byteLength)
-    #[wasm_bindgen(method, getter, js_name = byteLength)]
-    pub fn byte_length(this: &SharedArrayBuffer) -> u32;
+    /// This is a thin wrapper around [`Array::iter`]; it's provided directly
+    /// on `Array` for call sites that don't otherwise need an iterator.
+    pub fn fold_rust<A>(&self, init: A, f: impl FnMut(A, JsValue) -> A) -> A {
+        self.iter().fold(init, f)
+    }
 
-    /// The `slice()` method returns a new `SharedArrayBuffer` whose contents
-    /// are a copy of this `SharedArrayBuffer`'s bytes from begin, inclusive,
-    /// up to end, exclusive.
+    /// Builds a new array of `len` elements, each set to `f(index)`.
     ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/SharedArrayBuffer/slice)
-    #[wasm_bindgen(method)]
-    pub fn slice(this: &SharedArrayBuffer, begin: u32) -> SharedArrayBuffer;
+    /// Preallocates with [`Array::new_with_length`] and writes each slot
+    /// with [`Array::set`] rather than [`Array::push`]ing: `push` would
+    /// have to grow the array's backing storage as it goes, while every
+    /// slot here is already reserved. Every slot ends up a real element
+    /// (never a hole), unlike a bare `new_with_length`.
+    pub fn from_fn(len: u32, mut f: impl FnMut(u32) -> JsValue) -> Array {
+        let array = Array::new_with_length(len);
+        for i in 0..len {
+            array.set(i, f(i));
+        }
+        array
+    }
 
-    /// Like `slice()` but with the `end` argument.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/SharedArrayBuffer/slice)
-    #[wasm_bindgen(method, js_name = slice)]
-    pub fn slice_with_end(this: &SharedArrayBuffer, begin: u32, end: u32) -> SharedArrayBuffer;
-}
+    /// Resizes `self` in place to `new_len` elements. Shrinking truncates
+    /// via [`Array::set_length`]; growing appends `f(index)` for each new
+    /// index, so (like [`Array::from_fn`]) every new slot is a real
+    /// element rather than a hole.
+    pub fn resize_with(&self, new_len: u32, mut f: impl FnMut(u32) -> JsValue) {
+        let len = self.length();
+        if new_len <= len {
+            self.set_length(new_len);
+        } else {
+            for i in len..new_len {
+                self.set(i, f(i));
+            }
+        }
+    }
 
-// Array Iterator
-#[wasm_bindgen]
-extern "C" {
-    /// The `keys()` method returns a new Array Iterator object that contains the
-    /// keys for each index in the array.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/keys)
-    #[wasm_bindgen(method)]
-    pub fn keys(this: &Array) -> Iterator;
+    /// Builds a new array of `len` elements, all sharing the same
+    /// `value` handle -- if `value` is an object, every slot refers to
+    /// the *same* object, not a clone of it, exactly like repeatedly
+    /// pushing the same `JsValue` would.
+    pub fn repeat(value: &JsValue, len: u32) -> Array {
+        Array::new_with_length(len).fill(value, 0, len)
+    }
 
-    /// The `entries()` method returns a new Array Iterator object that contains
-    /// the key/value pairs for each index in the array.
+    /// Inserts `value` at `index`, shifting every element at or after
+    /// `index` one position to the right. `index == length()` appends.
     ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/entries)
-    #[wasm_bindgen(method)]
-    pub fn entries(this: &Array) -> Iterator;
-
-    /// The `values()` method returns a new Array Iterator object that
-    /// contains the values for each index in the array.
+    /// # Panics
     ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/values)
-    #[wasm_bindgen(method)]
-    pub fn values(this: &Array) -> Iterator;
-}
+    /// Panics if `index > self.length()`.
+    pub fn insert(&self, index: u32, value: &JsValue) {
+        let len = self.length();
+        core::assert!(
+            index <= len,
+            "index out of bounds: the len is {} but the index is {}",
+            len,
+            index
+        );
+        self.splice(index, 0, value);
+    }
 
-/// The `Atomics` object provides atomic operations as static methods.
-/// They are used with `SharedArrayBuffer` objects.
-///
-/// The Atomic operations are installed on an `Atomics` module. Unlike
-/// the other global objects, `Atomics` is not a constructor. You cannot
-/// use it with a new operator or invoke the `Atomics` object as a
-/// function. All properties and methods of `Atomics` are static
-/// (as is the case with the Math object, for example).
-/// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics)
-#[allow(non_snake_case)]
-pub mod Atomics {
-    use super::*;
+    /// Removes and returns the element at `index`, shifting every element
+    /// after it one position to the left. Returns `None` if `index` is out
+    /// of bounds.
+    pub fn remove(&self, index: u32) -> Option<JsValue> {
+        if index >= self.length() {
+            return None;
+        }
+        let removed = self.splice_remove(index, 1);
+        Some(removed.get(0))
+    }
 
-    #[wasm_bindgen]
-    extern "C" {
-        /// The static `Atomics.add()` method adds a given value at a given
-        /// position in the array and returns the old value at that position.
-        /// This atomic operation guarantees that no other write happens
-        /// until the modified value is written back.
-        ///
-        /// You should use `add_bigint` to operate on a `BigInt64Array` or a `BigUint64Array`.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/add)
-        #[wasm_bindgen(js_namespace = Atomics, catch)]
-        pub fn add(typed_array: &JsValue, index: u32, value: i32) -> Result<i32, JsValue>;
+    /// Removes the element at `index` by moving the last element into its
+    /// place, doing the removal in O(1) without shifting the rest of the
+    /// array. Returns `None` if `index` is out of bounds.
+    pub fn swap_remove(&self, index: u32) -> Option<JsValue> {
+        let len = self.length();
+        if index >= len {
+            return None;
+        }
+        let removed = self.get(index);
+        let last = self.pop();
+        if index != len - 1 {
+            self.set(index, last);
+        }
+        Some(removed)
+    }
 
-        /// The static `Atomics.add()` method adds a given value at a given
-        /// position in the array and returns the old value at that position.
-        /// This atomic operation guarantees that no other write happens
-        /// until the modified value is written back.
-        ///
-        /// This method is used to operate on a `BigInt64Array` or a `BigUint64Array`.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/add)
-        #[wasm_bindgen(js_namespace = Atomics, catch, js_name = add)]
-        pub fn add_bigint(typed_array: &JsValue, index: u32, value: i64) -> Result<i64, JsValue>;
+    /// Removes and returns every element of `self`, leaving it empty, via
+    /// a single `splice(0, length)` call. Any other reference to `self`
+    /// (e.g. one already held by JS) observes the same emptiness, since
+    /// this mutates the array in place rather than replacing it.
+    pub fn take_all(&self) -> Array {
+        self.splice_remove(0, self.length())
+    }
 
-        /// The static `Atomics.and()` method computes a bitwise AND with a given
-        /// value at a given position in the array, and returns the old value
-        /// at that position.
-        /// This atomic operation guarantees that no other write happens
-        /// until the modified value is written back.
-        ///
-        /// You should use `and_bigint` to operate on a `BigInt64Array` or a `BigUint64Array`.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/and)
-        #[wasm_bindgen(js_namespace = Atomics, catch)]
-        pub fn and(typed_array: &JsValue, index: u32, value: i32) -> Result<i32, JsValue>;
+    /// Replaces the contents of `self` with a copy of `other`'s elements,
+    /// in place, and returns `self`'s old contents as a new array. `other`
+    /// itself is left untouched.
+    pub fn replace_with(&self, other: &Array) -> Array {
+        let old = self.take_all();
+        for value in other.iter() {
+            self.push(&value);
+        }
+        old
+    }
 
-        /// The static `Atomics.and()` method computes a bitwise AND with a given
-        /// value at a given position in the array, and returns the old value
-        /// at that position.
-        /// This atomic operation guarantees that no other write happens
-        /// until the modified value is written back.
-        ///
-        /// This method is used to operate on a `BigInt64Array` or a `BigUint64Array`.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/and)
-        #[wasm_bindgen(js_namespace = Atomics, catch, js_name = and)]
-        pub fn and_bigint(typed_array: &JsValue, index: u32, value: i64) -> Result<i64, JsValue>;
+    /// Shrinks this array to at most `len` elements, via
+    /// [`Array::set_length`]. Does nothing if `self` is already no longer
+    /// than `len` -- unlike [`Array::set_length`] directly, this never
+    /// grows the array.
+    pub fn truncate(&self, len: u32) {
+        if self.length() > len {
+            self.set_length(len);
+        }
+    }
 
-        /// The static `Atomics.compareExchange()` method exchanges a given
-        /// replacement value at a given position in the array, if a given expected
-        /// value equals the old value. It returns the old value at that position
-        /// whether it was equal to the expected value or not.
-        /// This atomic operation guarantees that no other write happens
-        /// until the modified value is written back.
-        ///
-        /// You should use `compare_exchange_bigint` to operate on a `BigInt64Array` or a `BigUint64Array`.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/compareExchange)
-        #[wasm_bindgen(js_namespace = Atomics, catch, js_name = compareExchange)]
-        pub fn compare_exchange(
-            typed_array: &JsValue,
-            index: u32,
-            expected_value: i32,
-            replacement_value: i32,
-        ) -> Result<i32, JsValue>;
+    /// Builds a new array containing every element of `self` that isn't
+    /// nullish (`null` or `undefined`), preserving order.
+    pub fn compact(&self) -> Array {
+        let out = Array::new();
+        for value in self.iter().filter(|v| !is_nullish(v)) {
+            out.push(&value);
+        }
+        out
+    }
 
-        /// The static `Atomics.compareExchange()` method exchanges a given
-        /// replacement value at a given position in the array, if a given expected
-        /// value equals the old value. It returns the old value at that position
-        /// whether it was equal to the expected value or not.
-        /// This atomic operation guarantees that no other write happens
-        /// until the modified value is written back.
-        ///
-        /// This method is used to operate on a `BigInt64Array` or a `BigUint64Array`.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/compareExchange)
-        #[wasm_bindgen(js_namespace = Atomics, catch, js_name = compareExchange)]
-        pub fn compare_exchange_bigint(
-            typed_array: &JsValue,
-            index: u32,
-            expected_value: i64,
-            replacement_value: i64,
-        ) -> Result<i64, JsValue>;
+    /// Like [`Array::compact`], but removes every falsy element (per
+    /// [`JsValue::is_truthy`]) rather than just the nullish ones, e.g. also
+    /// dropping `0`, `""`, and `false`.
+    pub fn compact_falsy(&self) -> Array {
+        let out = Array::new();
+        for value in self.iter().filter(|v| v.is_truthy()) {
+            out.push(&value);
+        }
+        out
+    }
 
-        /// The static `Atomics.exchange()` method stores a given value at a given
-        /// position in the array and returns the old value at that position.
-        /// This atomic operation guarantees that no other write happens
-        /// until the modified value is written back.
-        ///
-        /// You should use `exchange_bigint` to operate on a `BigInt64Array` or a `BigUint64Array`.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/exchange)
-        #[wasm_bindgen(js_namespace = Atomics, catch)]
-        pub fn exchange(typed_array: &JsValue, index: u32, value: i32) -> Result<i32, JsValue>;
+    /// Returns the index of the first element for which `pred` returns
+    /// `true`, driving the search from Rust (via repeated [`Array::get`])
+    /// rather than handing a JS-callable closure to [`Array::find_index`],
+    /// so no JS callback needs to be created.
+    pub fn position(&self, mut pred: impl FnMut(JsValue) -> bool) -> Option<u32> {
+        (0..self.length()).find(|&i| pred(self.get(i)))
+    }
 
-        /// The static `Atomics.exchange()` method stores a given value at a given
-        /// position in the array and returns the old value at that position.
-        /// This atomic operation guarantees that no other write happens
-        /// until the modified value is written back.
-        ///
-        /// This method is used to operate on a `BigInt64Array` or a `BigUint64Array`.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/exchange)
-        #[wasm_bindgen(js_namespace = Atomics, catch, js_name = exchange)]
-        pub fn exchange_bigint(
-            typed_array: &JsValue,
-            index: u32,
-            value: i64,
-        ) -> Result<i64, JsValue>;
+    /// Like [`Array::position`], but searches from the end, returning the
+    /// index of the last element for which `pred` returns `true`.
+    pub fn rposition(&self, mut pred: impl FnMut(JsValue) -> bool) -> Option<u32> {
+        (0..self.length()).rev().find(|&i| pred(self.get(i)))
+    }
 
-        /// The static `Atomics.isLockFree()` method is used to determine
-        /// whether to use locks or atomic operations. It returns true,
-        /// if the given size is one of the `BYTES_PER_ELEMENT` property
-        /// of integer `TypedArray` types.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/isLockFree)
-        #[wasm_bindgen(js_namespace = Atomics, js_name = isLockFree)]
-        pub fn is_lock_free(size: u32) -> bool;
+    /// Returns `true` if any element satisfies `pred`, driving the search
+    /// from Rust the same way [`Array::position`] does.
+    pub fn contains_by(&self, pred: impl FnMut(JsValue) -> bool) -> bool {
+        self.position(pred).is_some()
+    }
 
-        /// The static `Atomics.load()` method returns a value at a given
-        /// position in the array.
-        ///
-        /// You should use `load_bigint` to operate on a `BigInt64Array` or a `BigUint64Array`.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/load)
-        #[wasm_bindgen(js_namespace = Atomics, catch)]
-        pub fn load(typed_array: &JsValue, index: u32) -> Result<i32, JsValue>;
+    /// Returns the index of the first element equal to `s` per
+    /// [`JsString`]'s `==`, or `None` if there is no such element.
+    pub fn index_of_str(&self, s: &str) -> Option<u32> {
+        self.position(|v| v.as_string().map(|v| v == s).unwrap_or(false))
+    }
 
-        /// The static `Atomics.load()` method returns a value at a given
-        /// position in the array.
-        ///
-        /// This method is used to operate on a `BigInt64Array` or a `BigUint64Array`.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/load)
-        #[wasm_bindgen(js_namespace = Atomics, catch, js_name = load)]
-        pub fn load_bigint(typed_array: &JsValue, index: i64) -> Result<i64, JsValue>;
+    /// Returns the index of the first element equal to `x`, using
+    /// SameValueZero semantics like [`Array::includes`] -- in particular,
+    /// `NaN` matches `NaN` (unlike `===`/[`Array::index_of`]), and `-0.0`
+    /// matches `0.0`. Returns `None` if there is no such element or if
+    /// `x` is `NaN` and no element is `NaN`.
+    pub fn index_of_f64(&self, x: f64) -> Option<u32> {
+        self.position(|v| match v.as_f64() {
+            Some(v) => v == x || (v.is_nan() && x.is_nan()),
+            None => false,
+        })
+    }
 
-        /// The static `Atomics.notify()` method notifies up some agents that
-        /// are sleeping in the wait queue.
-        /// Note: This operation works with a shared `Int32Array` only.
-        /// If `count` is not provided, notifies all the agents in the queue.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/notify)
-        #[wasm_bindgen(js_namespace = Atomics, catch)]
-        pub fn notify(typed_array: &Int32Array, index: u32) -> Result<u32, JsValue>;
+    /// Builds a [`Map`] indexing this array's elements by `key_fn(element)`,
+    /// in a single pass. If two elements produce the same key, the later
+    /// one in iteration order wins, matching [`Map::set`]'s own overwrite
+    /// semantics.
+    pub fn key_by(&self, key_fn: &mut dyn FnMut(JsValue) -> JsValue) -> Map {
+        let out = Map::new();
+        for element in self.iter() {
+            let key = key_fn(element.clone());
+            out.set(&key, &element);
+        }
+        out
+    }
 
-        /// Notifies up to `count` agents in the wait queue.
-        #[wasm_bindgen(js_namespace = Atomics, catch, js_name = notify)]
-        pub fn notify_with_count(
-            typed_array: &Int32Array,
-            index: u32,
-            count: u32,
-        ) -> Result<u32, JsValue>;
+    /// Like [`Array::key_by`], but the key is read off each element via
+    /// `Reflect::get(element, prop)` rather than a Rust closure. Errors if
+    /// any element's getter for `prop` throws. An element missing `prop`
+    /// entirely is keyed by `undefined`, grouping every such element
+    /// together (and the last one wins, per [`Array::key_by`]).
+    pub fn key_by_prop(&self, prop: &str) -> Result<Map, JsValue> {
+        let prop = JsValue::from_str(prop);
+        let out = Map::new();
+        for element in self.iter() {
+            let key = Reflect::get(&element, &prop)?;
+            out.set(&key, &element);
+        }
+        Ok(out)
+    }
 
-        /// The static `Atomics.or()` method computes a bitwise OR with a given value
-        /// at a given position in the array, and returns the old value at that position.
-        /// This atomic operation guarantees that no other write happens
-        /// until the modified value is written back.
-        ///
-        /// You should use `or_bigint` to operate on a `BigInt64Array` or a `BigUint64Array`.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/or)
-        #[wasm_bindgen(js_namespace = Atomics, catch)]
-        pub fn or(typed_array: &JsValue, index: u32, value: i32) -> Result<i32, JsValue>;
+    /// Returns this array's `constructor` property -- ordinarily [`Array`]
+    /// itself, but a subclass's constructor for an array created by
+    /// extending `Array`.
+    pub fn constructor_of(&self) -> Function {
+        Object::constructor(self.as_ref())
+    }
 
-        /// The static `Atomics.or()` method computes a bitwise OR with a given value
-        /// at a given position in the array, and returns the old value at that position.
-        /// This atomic operation guarantees that no other write happens
-        /// until the modified value is written back.
-        ///
-        /// This method is used to operate on a `BigInt64Array` or a `BigUint64Array`.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/or)
-        #[wasm_bindgen(js_namespace = Atomics, catch, js_name = or)]
-        pub fn or_bigint(typed_array: &JsValue, index: u32, value: i64) -> Result<i64, JsValue>;
+    /// Returns the function at `self[Symbol.species]`, if any. Methods
+    /// like `map`/`slice`/`filter` consult this (falling back to the
+    /// ordinary constructor) to decide what class to build their result
+    /// as, letting an `Array` subclass override it. See
+    /// [`Array::slice_plain`] and [`Array::map_plain`] for the opposite:
+    /// guaranteeing a plain [`Array`] result regardless of what `self`'s
+    /// species says.
+    pub fn species_of(&self) -> Option<Function> {
+        Reflect::get(self.as_ref(), &Symbol::species().into())
+            .ok()
+            .and_then(|v| v.dyn_into::<Function>().ok())
+    }
 
-        /// The static `Atomics.store()` method stores a given value at the given
-        /// position in the array and returns that value.
-        ///
-        /// You should use `store_bigint` to operate on a `BigInt64Array` or a `BigUint64Array`.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/store)
-        #[wasm_bindgen(js_namespace = Atomics, catch)]
-        pub fn store(typed_array: &JsValue, index: u32, value: i32) -> Result<i32, JsValue>;
+    /// Like [`Array::slice`], but the result is always a plain [`Array`],
+    /// even if `self` is an instance of an `Array` subclass whose
+    /// `Symbol.species` points somewhere else.
+    pub fn slice_plain(&self, start: u32, end: u32) -> Array {
+        Array::from(self.slice(start, end).as_ref())
+    }
 
-        /// The static `Atomics.store()` method stores a given value at the given
-        /// position in the array and returns that value.
-        ///
-        /// This method is used to operate on a `BigInt64Array` or a `BigUint64Array`.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/store)
-        #[wasm_bindgen(js_namespace = Atomics, catch, js_name = store)]
-        pub fn store_bigint(typed_array: &JsValue, index: u32, value: i64) -> Result<i64, JsValue>;
+    /// Like [`Array::map`], but the result is always a plain [`Array`],
+    /// even if `self` is an instance of an `Array` subclass whose
+    /// `Symbol.species` points somewhere else.
+    pub fn map_plain(&self, predicate: &mut dyn FnMut(JsValue, u32, Array) -> JsValue) -> Array {
+        Array::from(self.map(predicate).as_ref())
+    }
 
-        /// The static `Atomics.sub()` method subtracts a given value at a
-        /// given position in the array and returns the old value at that position.
-        /// This atomic operation guarantees that no other write happens
-        /// until the modified value is written back.
-        ///
-        /// You should use `sub_bigint` to operate on a `BigInt64Array` or a `BigUint64Array`.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/sub)
-        #[wasm_bindgen(js_namespace = Atomics, catch)]
-        pub fn sub(typed_array: &JsValue, index: u32, value: i32) -> Result<i32, JsValue>;
+    /// Like [`Array::sort`], but orders elements by a key extracted once
+    /// per element rather than recomputed on every comparison, and leaves
+    /// `self` untouched, returning a new array -- the `Array` analogue of
+    /// the typed arrays' `to_sorted_by`.
+    ///
+    /// `key_fn` is called exactly `self.length()` times, which matters
+    /// when extracting the key (as opposed to comparing two already-
+    /// extracted keys) is the expensive part. The sort itself is stable:
+    /// elements with equal keys keep their original relative order.
+    pub fn to_sorted_by_cached_key<K: Ord>(&self, key_fn: &mut dyn FnMut(JsValue) -> K) -> Array {
+        let len = self.length();
+        let mut indexed: Vec<(K, JsValue)> = (0..len)
+            .map(|i| {
+                let value = self.get(i);
+                (key_fn(value.clone()), value)
+            })
+            .collect();
+        indexed.sort_by(|a, b| a.0.cmp(&b.0));
+        let out = Array::new_with_length(len);
+        for (i, (_, value)) in indexed.into_iter().enumerate() {
+            out.set(i as u32, value);
+        }
+        out
+    }
 
-        /// The static `Atomics.sub()` method subtracts a given value at a
-        /// given position in the array and returns the old value at that position.
-        /// This atomic operation guarantees that no other write happens
-        /// until the modified value is written back.
-        ///
-        /// This method is used to operate on a `BigInt64Array` or a `BigUint64Array`.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/sub)
-        #[wasm_bindgen(js_namespace = Atomics, catch, js_name = sub)]
-        pub fn sub_bigint(typed_array: &JsValue, index: u32, value: i64) -> Result<i64, JsValue>;
+    /// Sorts this array of numbers in place entirely in Rust, over a
+    /// single bulk [`Array::to_vec`] extraction, avoiding the cost of one
+    /// JS -> Rust round trip per comparison that [`Array::sort_with`]-style
+    /// (JS-callback-driven) sorting would pay.
+    ///
+    /// Elements for which [`JsValue::as_f64`] returns `None` sort as
+    /// `NaN`. `NaN`s sort to the end, after every real number, in their
+    /// original relative order -- the sort is otherwise stable.
+    pub fn sort_numbers(&self) -> Array {
+        let mut values: Vec<f64> = self.to_vec().into_iter().map(|v| v.as_f64().unwrap_or(f64::NAN)).collect();
+        values.sort_by(|a, b| match (a.is_nan(), b.is_nan()) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Greater,
+            (false, true) => Ordering::Less,
+            (false, false) => a.partial_cmp(b).unwrap_or(Ordering::Equal),
+        });
+        for (i, value) in values.into_iter().enumerate() {
+            self.set(i as u32, JsValue::from_f64(value));
+        }
+        self.clone()
+    }
 
-        /// The static `Atomics.wait()` method verifies that a given
-        /// position in an `Int32Array` still contains a given value
-        /// and if so sleeps, awaiting a wakeup or a timeout.
-        /// It returns a string which is either "ok", "not-equal", or "timed-out".
-        /// Note: This operation only works with a shared `Int32Array`
-        /// and may not be allowed on the main thread.
-        ///
-        /// You should use `wait_bigint` to operate on a `BigInt64Array`.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/wait)
-        #[wasm_bindgen(js_namespace = Atomics, catch)]
-        pub fn wait(typed_array: &Int32Array, index: u32, value: i32) -> Result<JsString, JsValue>;
+    /// Counts occurrences of each element, keyed by `key_fn(element)`, in
+    /// a single pass. Uses a JS `Map` for accumulation (rather than a
+    /// Rust `HashMap`) so key equality matches `Map`'s own SameValueZero
+    /// semantics -- notably, `NaN` counts as equal to itself.
+    pub fn count_by(&self, key_fn: &mut dyn FnMut(JsValue) -> JsValue) -> Map {
+        let counts = Map::new();
+        self.for_each(&mut |value, _index, _array| {
+            let key = key_fn(value);
+            counts.increment(&key, 1.0);
+        });
+        counts
+    }
 
-        /// The static `Atomics.wait()` method verifies that a given
-        /// position in an `BigInt64Array` still contains a given value
-        /// and if so sleeps, awaiting a wakeup or a timeout.
-        /// It returns a string which is either "ok", "not-equal", or "timed-out".
-        /// Note: This operation only works with a shared `BigInt64Array`
-        /// and may not be allowed on the main thread.
-        ///
-        /// You should use `wait` to operate on a `Int32Array`.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/wait)
-        #[wasm_bindgen(js_namespace = Atomics, catch, js_name = wait)]
-        pub fn wait_bigint(
-            typed_array: &BigInt64Array,
-            index: u32,
-            value: i64,
-        ) -> Result<JsString, JsValue>;
+    /// Counts occurrences of each distinct value in this array, keyed by
+    /// the element itself (SameValueZero, as in [`Array::count_by`]), a
+    /// fast path for [`Array::count_by`] with the identity key function.
+    pub fn frequencies(&self) -> Map {
+        let counts = Map::new();
+        self.for_each(&mut |value, _index, _array| {
+            counts.increment(&value, 1.0);
+        });
+        counts
+    }
 
-        /// Like `wait()`, but with timeout
-        ///
-        /// You should use `wait_with_timeout_bigint` to operate on a `BigInt64Array`.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/wait)
-        #[wasm_bindgen(js_namespace = Atomics, catch, js_name = wait)]
-        pub fn wait_with_timeout(
-            typed_array: &Int32Array,
-            index: u32,
-            value: i32,
-            timeout: f64,
-        ) -> Result<JsString, JsValue>;
+    /// Like [`Array::count_by`], but `key_fn` can fail: counting stops at
+    /// the first `Err`, which is returned as-is.
+    pub fn try_count_by(
+        &self,
+        mut key_fn: impl FnMut(JsValue) -> Result<JsValue, JsValue>,
+    ) -> Result<Map, JsValue> {
+        let counts = Map::new();
+        for value in self.iter() {
+            let key = key_fn(value)?;
+            counts.increment(&key, 1.0);
+        }
+        Ok(counts)
+    }
 
-        /// Like `wait()`, but with timeout
-        ///
-        /// You should use `wait_with_timeout` to operate on a `Int32Array`.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/wait)
-        #[wasm_bindgen(js_namespace = Atomics, catch, js_name = wait)]
-        pub fn wait_with_timeout_bigint(
-            typed_array: &BigInt64Array,
-            index: u32,
-            value: i64,
-            timeout: f64,
-        ) -> Result<JsString, JsValue>;
+    /// Folds the array into a Rust value, left to right, without the
+    /// accumulator ever crossing the JS/Rust boundary -- unlike
+    /// [`Array::reduce`], whose accumulator is itself a `JsValue` and so
+    /// gets serialized on every step. Prefer `reduce` when the reducer is
+    /// itself a JS function (e.g. one handed to you from JS); prefer `fold`
+    /// when both the reducer and the accumulator are Rust-native, such as
+    /// summing into a Rust struct or `HashMap`.
+    pub fn fold<A>(&self, init: A, mut f: impl FnMut(A, JsValue, u32) -> A) -> A {
+        let len = self.length();
+        let mut acc = init;
+        for i in 0..len {
+            acc = f(acc, self.get(i), i);
+        }
+        acc
+    }
 
-        /// The static `Atomics.waitAsync()` method verifies that a given position in an
-        /// `Int32Array` still contains a given value and if so sleeps, awaiting a
-        /// wakeup or a timeout. It returns an object with two properties. The first
-        /// property `async` is a boolean which if true indicates that the second
-        /// property `value` is a promise. If `async` is false then value is a string
-        /// whether equal to either "not-equal" or "timed-out".
-        /// Note: This operation only works with a shared `Int32Array` and may be used
-        /// on the main thread.
-        ///
-        /// You should use `wait_async_bigint` to operate on a `BigInt64Array`.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/waitAsync)
-        #[wasm_bindgen(js_namespace = Atomics, catch, js_name = waitAsync)]
-        pub fn wait_async(
-            typed_array: &Int32Array,
-            index: u32,
-            value: i32,
-        ) -> Result<Object, JsValue>;
+    /// Like [`Array::fold`], but from right to left.
+    pub fn rfold<A>(&self, init: A, mut f: impl FnMut(A, JsValue, u32) -> A) -> A {
+        let len = self.length();
+        let mut acc = init;
+        for i in (0..len).rev() {
+            acc = f(acc, self.get(i), i);
+        }
+        acc
+    }
 
-        /// The static `Atomics.waitAsync()` method verifies that a given position in an
-        /// `Int32Array` still contains a given value and if so sleeps, awaiting a
-        /// wakeup or a timeout. It returns an object with two properties. The first
-        /// property `async` is a boolean which if true indicates that the second
-        /// property `value` is a promise. If `async` is false then value is a string
-        /// whether equal to either "not-equal" or "timed-out".
-        /// Note: This operation only works with a shared `BigInt64Array` and may be used
-        /// on the main thread.
-        ///
-        /// You should use `wait_async` to operate on a `Int32Array`.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/waitAsync)
-        #[wasm_bindgen(js_namespace = Atomics, catch, js_name = waitAsync)]
-        pub fn wait_async_bigint(
-            typed_array: &BigInt64Array,
-            index: u32,
-            value: i64,
-        ) -> Result<Object, JsValue>;
+    /// Like [`Array::fold`], but `f` can fail: folding stops at the first
+    /// `Err`, which is returned as-is, leaving the partially-folded
+    /// accumulator behind.
+    pub fn try_fold<A, E>(
+        &self,
+        init: A,
+        mut f: impl FnMut(A, JsValue, u32) -> Result<A, E>,
+    ) -> Result<A, E> {
+        let len = self.length();
+        let mut acc = init;
+        for i in 0..len {
+            acc = f(acc, self.get(i), i)?;
+        }
+        Ok(acc)
+    }
 
-        /// Like `waitAsync()`, but with timeout
-        ///
-        /// You should use `wait_async_with_timeout_bigint` to operate on a `BigInt64Array`.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/waitAsync)
-        #[wasm_bindgen(js_namespace = Atomics, catch, js_name = waitAsync)]
-        pub fn wait_async_with_timeout(
-            typed_array: &Int32Array,
-            index: u32,
-            value: i32,
-            timeout: f64,
-        ) -> Result<Object, JsValue>;
+    /// Compares this array element-by-element against `other` using `f`,
+    /// short-circuiting as soon as a length mismatch or a pair fails `f`.
+    pub fn eq_by(&self, other: &Array, f: &mut dyn FnMut(JsValue, JsValue) -> bool) -> bool {
+        let len = self.length();
+        if len != other.length() {
+            return false;
+        }
+        (0..len).all(|i| f(self.get(i), other.get(i)))
+    }
 
-        /// Like `waitAsync()`, but with timeout
-        ///
-        /// You should use `wait_async_with_timeout` to operate on a `Int32Array`.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/waitAsync)
-        #[wasm_bindgen(js_namespace = Atomics, catch, js_name = waitAsync)]
-        pub fn wait_async_with_timeout_bigint(
-            typed_array: &BigInt64Array,
-            index: u32,
-            value: i64,
-            timeout: f64,
-        ) -> Result<Object, JsValue>;
+    /// Compares this array against `other`, treating each element as an
+    /// `f64` (via [`JsValue::as_f64`]). An element that isn't a number
+    /// never compares equal, and `NaN` is never equal to anything,
+    /// including another `NaN`, matching JS's own `===`.
+    pub fn eq_f64_slice(&self, other: &[f64]) -> bool {
+        if self.length() as usize != other.len() {
+            return false;
+        }
+        self.to_vec()
+            .iter()
+            .zip(other.iter())
+            .all(|(a, b)| a.as_f64() == Some(*b))
+    }
 
-        /// The static `Atomics.xor()` method computes a bitwise XOR
-        /// with a given value at a given position in the array,
-        /// and returns the old value at that position.
-        /// This atomic operation guarantees that no other write happens
-        /// until the modified value is written back.
-        ///
-        /// You should use `xor_bigint` to operate on a `BigInt64Array` or a `BigUint64Array`.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/xor)
-        #[wasm_bindgen(js_namespace = Atomics, catch)]
-        pub fn xor(typed_array: &JsValue, index: u32, value: i32) -> Result<i32, JsValue>;
+    /// Compares this array against `other`, treating each element as a
+    /// string (via [`JsValue::as_string`]).
+    pub fn eq_str_slice(&self, other: &[&str]) -> bool {
+        if self.length() as usize != other.len() {
+            return false;
+        }
+        self.to_vec()
+            .iter()
+            .zip(other.iter())
+            .all(|(a, b)| a.as_string().as_deref() == Some(*b))
+    }
 
-        /// The static `Atomics.xor()` method computes a bitwise XOR
-        /// with a given value at a given position in the array,
-        /// and returns the old value at that position.
-        /// This atomic operation guarantees that no other write happens
-        /// until the modified value is written back.
-        ///
-        /// This method is used to operate on a `BigInt64Array` or a `BigUint64Array`.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/xor)
-        #[wasm_bindgen(js_namespace = Atomics, catch, js_name = xor)]
-        pub fn xor_bigint(typed_array: &JsValue, index: u32, value: i64) -> Result<i64, JsValue>;
+    /// Returns a double-ended iterator over the values of the JS array,
+    /// yielding them back-to-front. Each element is still fetched from the
+    /// underlying JS array lazily, one at a time, same as [`Array::iter`].
+    pub fn iter_rev(&self) -> core::iter::Rev<ArrayIter<'_>> {
+        self.iter().rev()
     }
-}
 
-// BigInt
-#[wasm_bindgen]
-extern "C" {
-    #[wasm_bindgen(extends = Object, is_type_of = |v| v.is_bigint(), typescript_type = "bigint")]
-    #[derive(Clone, PartialEq, Eq)]
-    pub type BigInt;
+    /// Converts the JS array into a new `Vec`, in reverse order.
+    pub fn to_vec_reversed(&self) -> Vec<JsValue> {
+        self.iter_rev().collect()
+    }
 
-    #[wasm_bindgen(catch, js_name = BigInt)]
-    fn new_bigint(value: &JsValue) -> Result<BigInt, Error>;
+    /// Returns the first element, or `None` if the array is empty.
+    pub fn first_checked(&self) -> Option<JsValue> {
+        if self.length() == 0 {
+            None
+        } else {
+            Some(self.get(0))
+        }
+    }
 
-    #[wasm_bindgen(js_name = BigInt)]
-    fn new_bigint_unchecked(value: &JsValue) -> BigInt;
+    /// Returns the last element, or `None` if the array is empty.
+    pub fn last_checked(&self) -> Option<JsValue> {
+        let len = self.length();
+        if len == 0 {
+            None
+        } else {
+            Some(self.get(len - 1))
+        }
+    }
 
-    /// Clamps a BigInt value to a signed integer value, and returns that value.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/BigInt/asIntN)
-    #[wasm_bindgen(static_method_of = BigInt, js_name = asIntN)]
-    pub fn as_int_n(bits: f64, bigint: &BigInt) -> BigInt;
+    /// Gets the element at `idx`, accepting either a `u32` (from the start,
+    /// like [`Array::get`]) or an `i32` (negative-from-end, like
+    /// [`Array::at`]) via [`JsIndex`]. Returns `None` if out of bounds.
+    pub fn get_at(&self, idx: impl Into<JsIndex>) -> Option<JsValue> {
+        let len = self.length();
+        idx.into().resolve(len).map(|i| self.get(i))
+    }
 
-    /// Clamps a BigInt value to an unsigned integer value, and returns that value.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/BigInt/asUintN)
-    #[wasm_bindgen(static_method_of = BigInt, js_name = asUintN)]
-    pub fn as_uint_n(bits: f64, bigint: &BigInt) -> BigInt;
+    /// Sets the element at `idx`, accepting either a `u32` or a negative-
+    /// from-end `i32` via [`JsIndex`]. Returns `false` without modifying
+    /// the array if `idx` is out of bounds.
+    pub fn set_at(&self, idx: impl Into<JsIndex>, value: JsValue) -> bool {
+        let len = self.length();
+        match idx.into().resolve(len) {
+            Some(i) => {
+                self.set(i, value);
+                true
+            }
+            None => false,
+        }
+    }
 
-    /// Returns a string with a language-sensitive representation of this BigInt value. Overrides the [`Object.prototype.toLocaleString()`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/BigInt/toLocaleString) method.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/BigInt/toLocaleString)
-    #[wasm_bindgen(method, js_name = toLocaleString)]
-    pub fn to_locale_string(this: &BigInt, locales: &JsValue, options: &JsValue) -> JsString;
+    /// Like [`Array::slice`], but `start`/`end` accept negative-from-end
+    /// indices via [`JsIndex`], normalized against this array's length the
+    /// same way the spec normalizes `Array.prototype.slice`'s arguments
+    /// (clamped to `0..=length()`, an empty result if `start >= end`).
+    pub fn slice_idx(&self, start: impl Into<JsIndex>, end: impl Into<JsIndex>) -> Array {
+        let len = self.length();
+        let start = start.into().clamp_to(len);
+        let end = end.into().clamp_to(len);
+        if start >= end {
+            Array::new()
+        } else {
+            self.slice(start, end)
+        }
+    }
 
-    /// Returns a string representing this BigInt value in the specified radix (base). Overrides the [`Object.prototype.toString()`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/toString) method.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/BigInt/toString)
-    #[wasm_bindgen(catch, method, js_name = toString)]
-    pub fn to_string(this: &BigInt, radix: u8) -> Result<JsString, RangeError>;
+    /// Like [`Array::join`], but formats each element with `f` instead of
+    /// JS's default `ToString` (which produces `"[object Object]"` for
+    /// plain objects and flattens nested arrays). Built entirely in Rust
+    /// by iterating with [`Array::get`] rather than a JS `join` call.
+    pub fn join_with(&self, sep: &str, f: &mut dyn FnMut(JsValue) -> String) -> String {
+        let len = self.length();
+        let mut out = String::new();
+        for i in 0..len {
+            if i > 0 {
+                out.push_str(sep);
+            }
+            out.push_str(&f(self.get(i)));
+        }
+        out
+    }
 
-    #[wasm_bindgen(method, js_name = toString)]
-    fn to_string_unchecked(this: &BigInt, radix: u8) -> String;
+    /// Like [`Array::join_with`], but formats each element with the
+    /// `ToString` abstract operation via [`coerce::to_js_string`]
+    /// (respecting a custom `toString`/`Symbol.toPrimitive`), falling back
+    /// to `"<error>"` for an element whose conversion throws.
+    pub fn join_display(&self, sep: &str) -> String {
+        self.join_with(sep, &mut |value| {
+            coerce::to_js_string(&value)
+                .ok()
+                .and_then(|s| s.as_string())
+                .unwrap_or_else(|| String::from("<error>"))
+        })
+    }
 
-    /// Returns this BigInt value. Overrides the [`Object.prototype.valueOf()`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/valueOf) method.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/BigInt/valueOf)
-    #[wasm_bindgen(method, js_name = valueOf)]
-    pub fn value_of(this: &BigInt, radix: u8) -> BigInt;
-}
+    /// Like [`Array::join_with`], but specialized for elements that are
+    /// all numbers: formats each with [`f64`]'s own `Display`, rounded to
+    /// `precision` decimal places if given. An element that isn't a
+    /// number formats as `"NaN"`, matching `Number.prototype.toString`'s
+    /// treatment of `NaN`.
+    pub fn join_numbers(&self, sep: &str, precision: Option<u8>) -> String {
+        self.join_with(sep, &mut |value| match value.as_f64() {
+            Some(n) => match precision {
+                Some(p) => alloc::format!("{:.*}", p as usize, n),
+                None => alloc::format!("{}", n),
+            },
+            None => String::from("NaN"),
+        })
+    }
 
-impl BigInt {
-    /// Creates a new BigInt value.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/BigInt/BigInt)
-    #[inline]
-    pub fn new(value: &JsValue) -> Result<BigInt, Error> {
-        new_bigint(value)
+    /// Returns a new array with the same elements as `self`, via
+    /// `slice(0)`. Like [`Map::shallow_copy`] and [`Set::shallow_copy`],
+    /// this copies the container, not the elements: mutating the copy's
+    /// own length or slots doesn't affect `self`, but an element that's
+    /// itself an object remains shared between the two arrays.
+    pub fn shallow_copy(&self) -> Array {
+        self.slice(0, self.length())
     }
 
-    /// Applies the binary `/` JS operator on two `BigInt`s, catching and returning any `RangeError` thrown.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Division)
-    pub fn checked_div(&self, rhs: &Self) -> Result<Self, RangeError> {
-        let result = JsValue::as_ref(self).checked_div(JsValue::as_ref(rhs));
+    /// Flattens one level of nesting, for an array whose elements are
+    /// themselves arrays -- equivalent to [`Array::flat`] with `depth`
+    /// fixed at `1`, under a name that reads better at a call site that's
+    /// specifically flattening `Array<Array<T>>` rather than flattening to
+    /// an arbitrary depth.
+    pub fn flatten(&self) -> Array {
+        self.flat(1)
+    }
 
-        if result.is_instance_of::<RangeError>() {
-            Err(result.unchecked_into())
-        } else {
-            Ok(result.unchecked_into())
-        }
+    /// An alias for [`Array::flatten`], for call sites that think of this
+    /// as "concatenating the inner arrays together" rather than
+    /// "flattening".
+    pub fn concat_inner(&self) -> Array {
+        self.flatten()
     }
 
-    /// Applies the binary `**` JS operator on the two `BigInt`s.
+    /// Checks whether this array, interpreted as numbers, is sorted in
+    /// non-decreasing order, extracting every element as an `f64` in a
+    /// single pass via [`Array::to_vec`]-style iteration.
     ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Exponentiation)
-    #[inline]
-    pub fn pow(&self, rhs: &Self) -> Self {
-        JsValue::as_ref(self)
-            .pow(JsValue::as_ref(rhs))
-            .unchecked_into()
-    }
+    /// A `NaN` anywhere in the array makes it unsorted: every comparison
+    /// against a `NaN` is false, so a `NaN` element never compares as
+    /// `<=` its neighbor.
+    pub fn is_sorted(&self) -> bool {
+        let len = self.length();
+        let mut prev = match len {
+            0 => return true,
+            _ => match self.get(0).as_f64() {
+                Some(p) if p.is_nan() => return false,
+                p => p,
+            },
+        };
 
-    /// Returns a tuple of this [`BigInt`]'s absolute value along with a
-    /// [`bool`] indicating whether the [`BigInt`] was negative.
-    fn abs(&self) -> (Self, bool) {
-        if self < &BigInt::from(0) {
-            (-self, true)
-        } else {
-            (self.clone(), false)
+        for i in 1..len {
+            let current = self.get(i).as_f64();
+            match (prev, current) {
+                (Some(p), Some(c)) if p <= c => {}
+                _ => return false,
+            }
+            prev = current;
         }
+
+        true
     }
-}
 
-macro_rules! bigint_from {
-    ($($x:ident)*) => ($(
-        impl From<$x> for BigInt {
-            #[inline]
-            fn from(x: $x) -> BigInt {
-                new_bigint_unchecked(&JsValue::from(x))
-            }
+    /// Checks whether this array is sorted according to `cmp`, exiting as
+    /// soon as an out-of-order pair is found.
+    pub fn is_sorted_by(&self, cmp: &mut dyn FnMut(&JsValue, &JsValue) -> Ordering) -> bool {
+        let len = self.length();
+        if len < 2 {
+            return true;
         }
 
-        impl PartialEq<$x> for BigInt {
-            #[inline]
-            fn eq(&self, other: &$x) -> bool {
-                JsValue::from(self) == JsValue::from(BigInt::from(*other))
+        let mut prev = self.get(0);
+        for i in 1..len {
+            let current = self.get(i);
+            if cmp(&prev, &current) == Ordering::Greater {
+                return false;
             }
+            prev = current;
         }
-    )*)
-}
-bigint_from!(i8 u8 i16 u16 i32 u32 isize usize);
 
-macro_rules! bigint_from_big {
-    ($($x:ident)*) => ($(
-        impl From<$x> for BigInt {
-            #[inline]
-            fn from(x: $x) -> BigInt {
-                JsValue::from(x).unchecked_into()
-            }
-        }
+        true
+    }
 
-        impl PartialEq<$x> for BigInt {
-            #[inline]
-            fn eq(&self, other: &$x) -> bool {
-                self == &BigInt::from(*other)
+    /// Checks whether this array of strings is sorted according to
+    /// `collator`'s locale-aware ordering.
+    pub fn is_sorted_locale(&self, collator: &Intl::Collator) -> bool {
+        self.is_sorted_by(&mut |a, b| {
+            let a = a.as_string().unwrap_or_default();
+            let b = b.as_string().unwrap_or_default();
+            collator.compare_str(&a, &b)
+        })
+    }
+
+    /// Merges `self` and `other`, both assumed sorted in non-decreasing
+    /// numeric order, into a new sorted `Array` holding every element of
+    /// both (duplicates kept, order stable between equal elements).
+    /// Doesn't re-validate sortedness -- check [`Array::is_sorted`] first
+    /// if that's not already known.
+    ///
+    /// A `NaN` in either input sorts after every other value instead of
+    /// comparing false against everything and corrupting the merge; see
+    /// [`cmp_numbers_nan_last`].
+    pub fn merge_sorted(&self, other: &Array) -> Array {
+        self.merge_sorted_by(other, &mut |a, b| {
+            cmp_numbers_nan_last(a.as_f64().unwrap_or(f64::NAN), b.as_f64().unwrap_or(f64::NAN))
+        })
+    }
+
+    /// Like [`Array::merge_sorted`], but comparing elements with `cmp`
+    /// instead of assuming they're numbers, so it stays element-typed for
+    /// callers sorting by something else (strings, locale order, ...).
+    pub fn merge_sorted_by(
+        &self,
+        other: &Array,
+        cmp: &mut dyn FnMut(&JsValue, &JsValue) -> Ordering,
+    ) -> Array {
+        let merged = Array::new();
+        let (len_a, len_b) = (self.length(), other.length());
+        let (mut i, mut j) = (0, 0);
+
+        while i < len_a && j < len_b {
+            let (va, vb) = (self.get(i), other.get(j));
+            if cmp(&va, &vb) != Ordering::Greater {
+                merged.push(&va);
+                i += 1;
+            } else {
+                merged.push(&vb);
+                j += 1;
             }
         }
+        while i < len_a {
+            merged.push(&self.get(i));
+            i += 1;
+        }
+        while j < len_b {
+            merged.push(&other.get(j));
+            j += 1;
+        }
 
-        impl TryFrom<BigInt> for $x {
-            type Error = BigInt;
+        merged
+    }
 
-            #[inline]
-            fn try_from(x: BigInt) -> Result<Self, BigInt> {
-                Self::try_from(JsValue::from(x)).map_err(JsCast::unchecked_into)
+    /// Intersects `self` and `other`, both assumed sorted in
+    /// non-decreasing numeric order, via a two-pointer sweep: a value is
+    /// kept once per matching pair, so a duplicate in `self` only appears
+    /// in the result as many times as it's matched in `other`. `NaN`s
+    /// match other `NaN`s here (see [`cmp_numbers_nan_last`]), unlike
+    /// IEEE 754 equality where `NaN != NaN`.
+    pub fn intersect_sorted(&self, other: &Array) -> Array {
+        let result = Array::new();
+        let (len_a, len_b) = (self.length(), other.length());
+        let (mut i, mut j) = (0, 0);
+
+        while i < len_a && j < len_b {
+            let (va, vb) = (self.get(i), other.get(j));
+            let (fa, fb) = (va.as_f64().unwrap_or(f64::NAN), vb.as_f64().unwrap_or(f64::NAN));
+            match cmp_numbers_nan_last(fa, fb) {
+                Ordering::Equal => {
+                    result.push(&va);
+                    i += 1;
+                    j += 1;
+                }
+                Ordering::Less => i += 1,
+                Ordering::Greater => j += 1,
             }
         }
-    )*)
-}
-bigint_from_big!(i64 u64 i128 u128);
 
-impl PartialEq<Number> for BigInt {
-    #[inline]
-    fn eq(&self, other: &Number) -> bool {
-        JsValue::as_ref(self).loose_eq(JsValue::as_ref(other))
+        result
     }
-}
 
-impl Not for &BigInt {
-    type Output = BigInt;
+    /// Unions `self` and `other`, both assumed sorted in non-decreasing
+    /// numeric order, into a new sorted `Array` with duplicates removed
+    /// (within and across both inputs).
+    pub fn union_sorted(&self, other: &Array) -> Array {
+        dedup_sorted_numbers(&self.merge_sorted(other))
+    }
 
-    #[inline]
-    fn not(self) -> Self::Output {
-        JsValue::as_ref(self).bit_not().unchecked_into()
+    /// The elements of `self` that don't also appear in `other`, both
+    /// assumed sorted in non-decreasing numeric order. A duplicate in
+    /// `self` is only dropped once per matching element in `other`, so
+    /// extra copies beyond that count are kept.
+    pub fn diff_sorted(&self, other: &Array) -> Array {
+        let result = Array::new();
+        let (len_a, len_b) = (self.length(), other.length());
+        let (mut i, mut j) = (0, 0);
+
+        while i < len_a && j < len_b {
+            let (va, vb) = (self.get(i), other.get(j));
+            let (fa, fb) = (va.as_f64().unwrap_or(f64::NAN), vb.as_f64().unwrap_or(f64::NAN));
+            match cmp_numbers_nan_last(fa, fb) {
+                Ordering::Less => {
+                    result.push(&va);
+                    i += 1;
+                }
+                Ordering::Equal => {
+                    i += 1;
+                    j += 1;
+                }
+                Ordering::Greater => j += 1,
+            }
+        }
+        while i < len_a {
+            result.push(&self.get(i));
+            i += 1;
+        }
+
+        result
     }
 }
 
-forward_deref_unop!(impl Not, not for BigInt);
-forward_js_unop!(impl Neg, neg for BigInt);
-forward_js_binop!(impl BitAnd, bitand for BigInt);
-forward_js_binop!(impl BitOr, bitor for BigInt);
-forward_js_binop!(impl BitXor, bitxor for BigInt);
-forward_js_binop!(impl Shl, shl for BigInt);
-forward_js_binop!(impl Shr, shr for BigInt);
-forward_js_binop!(impl Add, add for BigInt);
-forward_js_binop!(impl Sub, sub for BigInt);
-forward_js_binop!(impl Div, div for BigInt);
-forward_js_binop!(impl Mul, mul for BigInt);
-forward_js_binop!(impl Rem, rem for BigInt);
-sum_product!(BigInt);
-
-partialord_ord!(BigInt);
+/// Compares two numbers the way [`Array::merge_sorted`] and its relatives
+/// need: like `f64`'s `PartialOrd`, except a `NaN` compares as greater
+/// than every other value (including another `NaN`, which compares
+/// equal to itself here) instead of being incomparable -- without this,
+/// a `NaN` anywhere would silently break the two-pointer sweeps these
+/// functions rely on.
+fn cmp_numbers_nan_last(a: f64, b: f64) -> Ordering {
+    match (a.is_nan(), b.is_nan()) {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Greater,
+        (false, true) => Ordering::Less,
+        (false, false) => a.partial_cmp(&b).unwrap_or(Ordering::Equal),
+    }
+}
 
-impl Default for BigInt {
-    fn default() -> Self {
-        BigInt::from(i32::default())
+/// Removes consecutive duplicate numbers from a sorted (per
+/// [`cmp_numbers_nan_last`]) array, keeping the first occurrence of each
+/// run.
+fn dedup_sorted_numbers(array: &Array) -> Array {
+    let result = Array::new();
+    let len = array.length();
+    let mut prev: Option<f64> = None;
+
+    for i in 0..len {
+        let value = array.get(i);
+        let current = value.as_f64().unwrap_or(f64::NAN);
+        let is_duplicate = matches!(prev, Some(p) if cmp_numbers_nan_last(p, current) == Ordering::Equal);
+        if !is_duplicate {
+            result.push(&value);
+        }
+        prev = Some(current);
     }
+
+    result
 }
 
-impl FromStr for BigInt {
-    type Err = Error;
+/// An `Array` known to be sorted in non-decreasing numeric order, so that
+/// [`SortedArray::binary_search`] and [`SortedArray::merge_with`] can skip
+/// re-validating it.
+///
+/// Built with [`SortedArray::new`], which checks [`Array::is_sorted`]
+/// once up front; from then on the invariant is only as good as whatever
+/// code holds the `SortedArray` not mutating the underlying `Array`
+/// through some other handle.
+#[derive(Clone, Debug)]
+pub struct SortedArray {
+    array: Array,
+}
 
-    #[inline]
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        BigInt::new(&s.into())
+impl SortedArray {
+    /// Wraps `array`, checking [`Array::is_sorted`] first and returning
+    /// `None` if it isn't.
+    pub fn new(array: Array) -> Option<SortedArray> {
+        if array.is_sorted() {
+            Some(SortedArray { array })
+        } else {
+            None
+        }
     }
-}
 
-impl fmt::Debug for BigInt {
-    #[inline]
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt::Display::fmt(self, f)
+    /// The wrapped, known-sorted array.
+    pub fn as_array(&self) -> &Array {
+        &self.array
     }
-}
 
-impl fmt::Display for BigInt {
-    #[inline]
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let (abs, is_neg) = self.abs();
-        f.pad_integral(!is_neg, "", &abs.to_string_unchecked(10))
+    /// Binary searches for `target`, returning the index of a matching
+    /// element if found, or `Err(index)` of where it would need to be
+    /// inserted to keep the array sorted, same as `slice::binary_search`.
+    pub fn binary_search(&self, target: f64) -> Result<u32, u32> {
+        let len = self.array.length();
+        let mut low = 0i64;
+        let mut high = len as i64 - 1;
+
+        while low <= high {
+            let mid = low + (high - low) / 2;
+            let value = self.array.get(mid as u32).as_f64().unwrap_or(f64::NAN);
+
+            if value == target {
+                return Ok(mid as u32);
+            } else if value < target {
+                low = mid + 1;
+            } else {
+                high = mid - 1;
+            }
+        }
+
+        Err(low as u32)
     }
-}
 
-impl fmt::Binary for BigInt {
-    #[inline]
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let (abs, is_neg) = self.abs();
-        f.pad_integral(!is_neg, "0b", &abs.to_string_unchecked(2))
+    /// Merges `self` and `other` (both sorted non-decreasing) into a new
+    /// sorted `Array`, without re-validating either input.
+    pub fn merge_with(&self, other: &SortedArray) -> Array {
+        let merged = Array::new();
+        let (a, b) = (&self.array, &other.array);
+        let (len_a, len_b) = (a.length(), b.length());
+        let (mut i, mut j) = (0, 0);
+
+        while i < len_a && j < len_b {
+            let (va, vb) = (a.get(i), b.get(j));
+            let (fa, fb) = (va.as_f64().unwrap_or(f64::NAN), vb.as_f64().unwrap_or(f64::NAN));
+            if fa <= fb {
+                merged.push(&va);
+                i += 1;
+            } else {
+                merged.push(&vb);
+                j += 1;
+            }
+        }
+        while i < len_a {
+            merged.push(&a.get(i));
+            i += 1;
+        }
+        while j < len_b {
+            merged.push(&b.get(j));
+            j += 1;
+        }
+
+        merged
     }
 }
 
-impl fmt::Octal for BigInt {
-    #[inline]
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let (abs, is_neg) = self.abs();
-        f.pad_integral(!is_neg, "0o", &abs.to_string_unchecked(8))
+impl PartialEq<[f64]> for Array {
+    fn eq(&self, other: &[f64]) -> bool {
+        self.eq_f64_slice(other)
     }
 }
 
-impl fmt::LowerHex for BigInt {
-    #[inline]
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let (abs, is_neg) = self.abs();
-        f.pad_integral(!is_neg, "0x", &abs.to_string_unchecked(16))
+impl PartialEq<Vec<f64>> for Array {
+    fn eq(&self, other: &Vec<f64>) -> bool {
+        self.eq_f64_slice(other)
     }
 }
 
-impl fmt::UpperHex for BigInt {
-    #[inline]
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let (abs, is_neg) = self.abs();
-        let mut s: String = abs.to_string_unchecked(16);
-        s.make_ascii_uppercase();
-        f.pad_integral(!is_neg, "0x", &s)
+impl PartialEq<[&str]> for Array {
+    fn eq(&self, other: &[&str]) -> bool {
+        self.eq_str_slice(other)
     }
 }
 
-// Boolean
-#[wasm_bindgen]
-extern "C" {
-    #[wasm_bindgen(extends = Object, is_type_of = |v| v.as_bool().is_some(), typescript_type = "boolean")]
-    #[derive(Clone, PartialEq, Eq)]
-    pub type Boolean;
-
-    /// The `Boolean()` constructor creates an object wrapper for a boolean value.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Boolean)
-    #[wasm_bindgen(constructor)]
-    #[deprecated(note = "recommended to use `Boolean::from` instead")]
-    #[allow(deprecated)]
-    pub fn new(value: &JsValue) -> Boolean;
-
-    /// The `valueOf()` method returns the primitive value of a `Boolean` object.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Boolean/valueOf)
-    #[wasm_bindgen(method, js_name = valueOf)]
-    pub fn value_of(this: &Boolean) -> bool;
-}
+impl core::iter::IntoIterator for Array {
+    type Item = JsValue;
+    type IntoIter = ArrayIntoIter;
 
-impl From<bool> for Boolean {
-    #[inline]
-    fn from(b: bool) -> Boolean {
-        Boolean::unchecked_from_js(JsValue::from(b))
+    fn into_iter(self) -> Self::IntoIter {
+        ArrayIntoIter {
+            range: 0..self.length(),
+            array: self,
+        }
     }
 }
 
-impl From<Boolean> for bool {
-    #[inline]
-    fn from(b: Boolean) -> bool {
-        b.value_of()
+// TODO pre-initialize the Array with the correct length using TrustedLen
+impl<A> core::iter::FromIterator<A> for Array
+where
+    A: AsRef<JsValue>,
+{
+    fn from_iter<T>(iter: T) -> Array
+    where
+        T: IntoIterator<Item = A>,
+    {
+        let mut out = Array::new();
+        out.extend(iter);
+        out
     }
 }
 
-impl PartialEq<bool> for Boolean {
-    #[inline]
-    fn eq(&self, other: &bool) -> bool {
-        self.value_of() == *other
+impl<A> core::iter::Extend<A> for Array
+where
+    A: AsRef<JsValue>,
+{
+    fn extend<T>(&mut self, iter: T)
+    where
+        T: IntoIterator<Item = A>,
+    {
+        for value in iter {
+            self.push(value.as_ref());
+        }
     }
 }
 
-impl fmt::Debug for Boolean {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        fmt::Debug::fmt(&self.value_of(), f)
+impl<A, const N: usize> From<[A; N]> for Array
+where
+    A: AsRef<JsValue>,
+{
+    fn from(values: [A; N]) -> Array {
+        values.into_iter().collect()
     }
 }
 
-impl fmt::Display for Boolean {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        fmt::Display::fmt(&self.value_of(), f)
-    }
+/// The error returned by [`Array::try_to_array`] and
+/// [`Array::try_to_array_prefix`] when the array doesn't have the
+/// required number of elements.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LengthError {
+    pub expected: usize,
+    pub actual: usize,
 }
 
-impl Default for Boolean {
-    fn default() -> Self {
-        Self::from(bool::default())
+impl fmt::Display for LengthError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "expected an array of length {}, got length {}",
+            self.expected, self.actual
+        )
     }
 }
 
-impl Not for &Boolean {
-    type Output = Boolean;
+#[cfg(feature = "std")]
+impl std::error::Error for LengthError {}
+
+/// The error returned by [`Array::try_to_f64_array`], either because the
+/// array's length doesn't match, or because one of its elements (at
+/// `index`) isn't a number.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LengthOrTypeError {
+    Length { expected: usize, actual: usize },
+    NotANumber { index: usize },
+}
 
-    #[inline]
-    fn not(self) -> Self::Output {
-        (!JsValue::as_ref(self)).into()
+impl fmt::Display for LengthOrTypeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LengthOrTypeError::Length { expected, actual } => write!(
+                f,
+                "expected an array of length {}, got length {}",
+                expected, actual
+            ),
+            LengthOrTypeError::NotANumber { index } => {
+                write!(f, "element at index {} is not a number", index)
+            }
+        }
     }
 }
 
-forward_deref_unop!(impl Not, not for Boolean);
+#[cfg(feature = "std")]
+impl std::error::Error for LengthOrTypeError {}
 
-partialord_ord!(Boolean);
+impl Default for Array {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
-// DataView
+// ArrayBuffer
 #[wasm_bindgen]
 extern "C" {
-    #[wasm_bindgen(extends = Object, typescript_type = "DataView")]
+    #[wasm_bindgen(extends = Object, typescript_type = "ArrayBuffer")]
     #[derive(Clone, Debug, PartialEq, Eq)]
-    pub type DataView;
+    pub type ArrayBuffer;
 
-    /// The `DataView` view provides a low-level interface for reading and
-    /// writing multiple number types in an `ArrayBuffer` irrespective of the
-    /// platform's endianness.
+    /// The `ArrayBuffer` object is used to represent a generic,
+    /// fixed-length raw binary data buffer. You cannot directly
+    /// manipulate the contents of an `ArrayBuffer`; instead, you
+    /// create one of the typed array objects or a `DataView` object
+    /// which represents the buffer in a specific format, and use that
+    /// to read and write the contents of the buffer.
     ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView)
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ArrayBuffer)
     #[wasm_bindgen(constructor)]
-    pub fn new(buffer: &ArrayBuffer, byteOffset: usize, byteLength: usize) -> DataView;
+    pub fn new(length: u32) -> ArrayBuffer;
 
-    /// The `DataView` view provides a low-level interface for reading and
-    /// writing multiple number types in an `ArrayBuffer` irrespective of the
-    /// platform's endianness.
+    /// The byteLength property of an object which is an instance of type ArrayBuffer
+    /// it's an accessor property whose set accessor function is undefined,
+    /// meaning that you can only read this property.
+    /// The value is established when the array is constructed and cannot be changed.
+    /// This property returns 0 if this ArrayBuffer has been detached.
     ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView)
-    #[wasm_bindgen(constructor)]
-    pub fn new_with_shared_array_buffer(
-        buffer: &SharedArrayBuffer,
-        byteOffset: usize,
-        byteLength: usize,
-    ) -> DataView;
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ArrayBuffer/byteLength)
+    #[wasm_bindgen(method, getter, js_name = byteLength)]
+    pub fn byte_length(this: &ArrayBuffer) -> u32;
 
-    /// The ArrayBuffer referenced by this view. Fixed at construction time and thus read only.
+    /// The detached accessor property indicates whether this `ArrayBuffer`
+    /// has been detached (e.g. via a transferred `postMessage` or
+    /// `structuredClone`). Once detached, `byteLength` silently reads as
+    /// `0` rather than signaling anything.
     ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/buffer)
-    #[wasm_bindgen(method, getter, structural)]
-    pub fn buffer(this: &DataView) -> ArrayBuffer;
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ArrayBuffer/detached)
+    #[wasm_bindgen(method, getter)]
+    pub fn detached(this: &ArrayBuffer) -> bool;
 
-    /// The length (in bytes) of this view from the start of its ArrayBuffer.
-    /// Fixed at construction time and thus read only.
+    /// The `isView()` method returns true if arg is one of the `ArrayBuffer`
+    /// views, such as typed array objects or a DataView; false otherwise.
     ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/byteLength)
-    #[wasm_bindgen(method, getter, structural, js_name = byteLength)]
-    pub fn byte_length(this: &DataView) -> usize;
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ArrayBuffer/isView)
+    #[wasm_bindgen(static_method_of = ArrayBuffer, js_name = isView)]
+    pub fn is_view(value: &JsValue) -> bool;
 
-    /// The offset (in bytes) of this view from the start of its ArrayBuffer.
-    /// Fixed at construction time and thus read only.
+    /// The `slice()` method returns a new `ArrayBuffer` whose contents
+    /// are a copy of this `ArrayBuffer`'s bytes from begin, inclusive,
+    /// up to end, exclusive.
     ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/byteOffset)
-    #[wasm_bindgen(method, getter, structural, js_name = byteOffset)]
-    pub fn byte_offset(this: &DataView) -> usize;
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ArrayBuffer/slice)
+    #[wasm_bindgen(method)]
+    pub fn slice(this: &ArrayBuffer, begin: u32) -> ArrayBuffer;
 
-    /// The `getInt8()` method gets a signed 8-bit integer (byte) at the
-    /// specified byte offset from the start of the DataView.
+    /// Like `slice()` but with the `end` argument.
     ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/getInt8)
-    #[wasm_bindgen(method, js_name = getInt8)]
-    pub fn get_int8(this: &DataView, byte_offset: usize) -> i8;
-
-    /// The `getUint8()` method gets a unsigned 8-bit integer (byte) at the specified
-    /// byte offset from the start of the DataView.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/getUint8)
-    #[wasm_bindgen(method, js_name = getUint8)]
-    pub fn get_uint8(this: &DataView, byte_offset: usize) -> u8;
-
-    /// The `getInt16()` method gets a signed 16-bit integer (short) at the specified
-    /// byte offset from the start of the DataView.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/getInt16)
-    #[wasm_bindgen(method, js_name = getInt16)]
-    pub fn get_int16(this: &DataView, byte_offset: usize) -> i16;
-
-    /// The `getInt16()` method gets a signed 16-bit integer (short) at the specified
-    /// byte offset from the start of the DataView.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/getInt16)
-    #[wasm_bindgen(method, js_name = getInt16)]
-    pub fn get_int16_endian(this: &DataView, byte_offset: usize, little_endian: bool) -> i16;
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ArrayBuffer/slice)
+    #[wasm_bindgen(method, js_name = slice)]
+    pub fn slice_with_end(this: &ArrayBuffer, begin: u32, end: u32) -> ArrayBuffer;
 
-    /// The `getUint16()` method gets an unsigned 16-bit integer (unsigned short) at the specified
-    /// byte offset from the start of the view.
+    /// The `ArrayBuffer()` constructor, passed an options object with a
+    /// `maxByteLength` property, creates a resizable `ArrayBuffer`.
     ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/getUint16)
-    #[wasm_bindgen(method, js_name = getUint16)]
-    pub fn get_uint16(this: &DataView, byte_offset: usize) -> u16;
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ArrayBuffer/ArrayBuffer)
+    #[wasm_bindgen(constructor)]
+    pub fn new_with_options(length: u32, options: &Object) -> ArrayBuffer;
 
-    /// The `getUint16()` method gets an unsigned 16-bit integer (unsigned short) at the specified
-    /// byte offset from the start of the view.
+    /// The resizable accessor property indicates whether this
+    /// `ArrayBuffer` can be resized with `resize()`.
     ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/getUint16)
-    #[wasm_bindgen(method, js_name = getUint16)]
-    pub fn get_uint16_endian(this: &DataView, byte_offset: usize, little_endian: bool) -> u16;
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ArrayBuffer/resizable)
+    #[wasm_bindgen(method, getter)]
+    pub fn resizable(this: &ArrayBuffer) -> bool;
 
-    /// The `getInt32()` method gets a signed 32-bit integer (long) at the specified
-    /// byte offset from the start of the DataView.
+    /// The maxByteLength accessor property returns the maximum length (in
+    /// bytes) that this `ArrayBuffer` can be resized to.
     ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/getInt32)
-    #[wasm_bindgen(method, js_name = getInt32)]
-    pub fn get_int32(this: &DataView, byte_offset: usize) -> i32;
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ArrayBuffer/maxByteLength)
+    #[wasm_bindgen(method, getter, js_name = maxByteLength)]
+    pub fn max_byte_length(this: &ArrayBuffer) -> u32;
 
-    /// The `getInt32()` method gets a signed 32-bit integer (long) at the specified
-    /// byte offset from the start of the DataView.
+    /// The `resize()` method resizes this `ArrayBuffer` to the specified
+    /// size, in bytes. Only available on a resizable `ArrayBuffer`.
     ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/getInt32)
-    #[wasm_bindgen(method, js_name = getInt32)]
-    pub fn get_int32_endian(this: &DataView, byte_offset: usize, little_endian: bool) -> i32;
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ArrayBuffer/resize)
+    #[wasm_bindgen(method)]
+    pub fn resize(this: &ArrayBuffer, new_byte_length: u32);
 
-    /// The `getUint32()` method gets an unsigned 32-bit integer (unsigned long) at the specified
-    /// byte offset from the start of the view.
+    /// The `transfer()` method creates a new `ArrayBuffer` with the same
+    /// byte content as this one, then detaches this one.
     ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/getUint32)
-    #[wasm_bindgen(method, js_name = getUint32)]
-    pub fn get_uint32(this: &DataView, byte_offset: usize) -> u32;
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ArrayBuffer/transfer)
+    #[wasm_bindgen(method)]
+    pub fn transfer(this: &ArrayBuffer) -> ArrayBuffer;
 
-    /// The `getUint32()` method gets an unsigned 32-bit integer (unsigned long) at the specified
-    /// byte offset from the start of the view.
+    /// Like `transfer()`, but with the new `ArrayBuffer`'s length.
     ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/getUint32)
-    #[wasm_bindgen(method, js_name = getUint32)]
-    pub fn get_uint32_endian(this: &DataView, byte_offset: usize, little_endian: bool) -> u32;
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ArrayBuffer/transfer)
+    #[wasm_bindgen(method, js_name = transfer)]
+    pub fn transfer_with_length(this: &ArrayBuffer, new_byte_length: u32) -> ArrayBuffer;
 
-    /// The `getFloat32()` method gets a signed 32-bit float (float) at the specified
-    /// byte offset from the start of the DataView.
+    /// The `transferToFixedLength()` method creates a new, non-resizable
+    /// `ArrayBuffer` with the same byte content as this one, then detaches
+    /// this one.
     ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/getFloat32)
-    #[wasm_bindgen(method, js_name = getFloat32)]
-    pub fn get_float32(this: &DataView, byte_offset: usize) -> f32;
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ArrayBuffer/transferToFixedLength)
+    #[wasm_bindgen(method, js_name = transferToFixedLength)]
+    pub fn transfer_to_fixed_length(this: &ArrayBuffer) -> ArrayBuffer;
 
-    /// The `getFloat32()` method gets a signed 32-bit float (float) at the specified
-    /// byte offset from the start of the DataView.
+    /// Like `transferToFixedLength()`, but with the new `ArrayBuffer`'s
+    /// length.
     ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/getFloat32)
-    #[wasm_bindgen(method, js_name = getFloat32)]
-    pub fn get_float32_endian(this: &DataView, byte_offset: usize, little_endian: bool) -> f32;
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ArrayBuffer/transferToFixedLength)
+    #[wasm_bindgen(method, js_name = transferToFixedLength)]
+    pub fn transfer_to_fixed_length_with_length(
+        this: &ArrayBuffer,
+        new_byte_length: u32,
+    ) -> ArrayBuffer;
+}
 
-    /// The `getFloat64()` method gets a signed 64-bit float (double) at the specified
-    /// byte offset from the start of the DataView.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/getFloat64)
-    #[wasm_bindgen(method, js_name = getFloat64)]
-    pub fn get_float64(this: &DataView, byte_offset: usize) -> f64;
+impl ArrayBuffer {
+    /// Creates a new resizable `ArrayBuffer` of `length` bytes that can be
+    /// grown up to `max_byte_length` bytes with `resize()`.
+    pub fn new_resizable(length: u32, max_byte_length: u32) -> ArrayBuffer {
+        let options = Object::new();
+        let _ = Reflect::set(
+            options.as_ref(),
+            &JsValue::from_str("maxByteLength"),
+            &JsValue::from_f64(max_byte_length as f64),
+        );
+        ArrayBuffer::new_with_options(length, &options)
+    }
+}
 
-    /// The `getFloat64()` method gets a signed 64-bit float (double) at the specified
-    /// byte offset from the start of the DataView.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/getFloat64)
-    #[wasm_bindgen(method, js_name = getFloat64)]
-    pub fn get_float64_endian(this: &DataView, byte_offset: usize, little_endian: bool) -> f64;
+/// The error returned by [`GrowableBuffer::push_bytes`] when appending
+/// would grow the buffer past its configured maximum capacity.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CapacityExceeded;
+
+/// A growable byte sink built on a resizable `ArrayBuffer`, so appending
+/// data doesn't require the caller to do capacity-doubling math by hand.
+pub struct GrowableBuffer {
+    buffer: ArrayBuffer,
+    view: Uint8Array,
+    len: usize,
+    max_bytes: usize,
+}
 
-    /// The `setInt8()` method stores a signed 8-bit integer (byte) value at the
-    /// specified byte offset from the start of the DataView.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/setInt8)
-    #[wasm_bindgen(method, js_name = setInt8)]
-    pub fn set_int8(this: &DataView, byte_offset: usize, value: i8);
+impl GrowableBuffer {
+    /// Creates an empty buffer that can grow up to `max_bytes` bytes.
+    pub fn with_max(max_bytes: usize) -> GrowableBuffer {
+        let buffer = ArrayBuffer::new_resizable(0, max_bytes as u32);
+        let view = Uint8Array::new(&buffer);
+        GrowableBuffer {
+            buffer,
+            view,
+            len: 0,
+            max_bytes,
+        }
+    }
 
-    /// The `setUint8()` method stores an unsigned 8-bit integer (byte) value at the
-    /// specified byte offset from the start of the DataView.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/setUint8)
-    #[wasm_bindgen(method, js_name = setUint8)]
-    pub fn set_uint8(this: &DataView, byte_offset: usize, value: u8);
+    /// The number of bytes appended so far.
+    pub fn len(&self) -> usize {
+        self.len
+    }
 
-    /// The `setInt16()` method stores a signed 16-bit integer (short) value at the
-    /// specified byte offset from the start of the DataView.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/setInt16)
-    #[wasm_bindgen(method, js_name = setInt16)]
-    pub fn set_int16(this: &DataView, byte_offset: usize, value: i16);
+    /// Returns `true` if no bytes have been appended.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
 
-    /// The `setInt16()` method stores a signed 16-bit integer (short) value at the
-    /// specified byte offset from the start of the DataView.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/setInt16)
-    #[wasm_bindgen(method, js_name = setInt16)]
-    pub fn set_int16_endian(this: &DataView, byte_offset: usize, value: i16, little_endian: bool);
+    /// Appends `data`, doubling the backing buffer's capacity (up to the
+    /// configured maximum) if it doesn't already fit. Copies `data` in a
+    /// single `Uint8Array::set` call regardless of whether a resize
+    /// happened.
+    pub fn push_bytes(&mut self, data: &[u8]) -> Result<(), CapacityExceeded> {
+        let new_len = self.len + data.len();
+        if new_len > self.max_bytes {
+            return Err(CapacityExceeded);
+        }
 
-    /// The `setUint16()` method stores an unsigned 16-bit integer (unsigned short) value at the
-    /// specified byte offset from the start of the DataView.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/setUint16)
-    #[wasm_bindgen(method, js_name = setUint16)]
-    pub fn set_uint16(this: &DataView, byte_offset: usize, value: u16);
+        if new_len > self.buffer.byte_length() as usize {
+            let mut new_capacity = core::cmp::max(self.buffer.byte_length() as usize, 1);
+            while new_capacity < new_len {
+                new_capacity *= 2;
+            }
+            new_capacity = core::cmp::min(new_capacity, self.max_bytes);
+            self.buffer.resize(new_capacity as u32);
+            self.view = Uint8Array::new(&self.buffer);
+        }
 
-    /// The `setUint16()` method stores an unsigned 16-bit integer (unsigned short) value at the
-    /// specified byte offset from the start of the DataView.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/setUint16)
-    #[wasm_bindgen(method, js_name = setUint16)]
-    pub fn set_uint16_endian(this: &DataView, byte_offset: usize, value: u16, little_endian: bool);
+        self.view
+            .subarray(self.len as u32, new_len as u32)
+            .copy_from(data);
+        self.len = new_len;
+        Ok(())
+    }
 
-    /// The `setInt32()` method stores a signed 32-bit integer (long) value at the
-    /// specified byte offset from the start of the DataView.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/setInt32)
-    #[wasm_bindgen(method, js_name = setInt32)]
-    pub fn set_int32(this: &DataView, byte_offset: usize, value: i32);
+    /// Returns a `Uint8Array` view over just the bytes appended so far.
+    pub fn as_uint8array(&self) -> Uint8Array {
+        self.view.subarray(0, self.len as u32)
+    }
 
-    /// The `setInt32()` method stores a signed 32-bit integer (long) value at the
-    /// specified byte offset from the start of the DataView.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/setInt32)
-    #[wasm_bindgen(method, js_name = setInt32)]
-    pub fn set_int32_endian(this: &DataView, byte_offset: usize, value: i32, little_endian: bool);
+    /// Consumes this buffer, transferring its backing `ArrayBuffer` into a
+    /// new, exactly-sized, non-resizable `ArrayBuffer`.
+    pub fn into_array_buffer(self) -> ArrayBuffer {
+        self.buffer
+            .transfer_to_fixed_length_with_length(self.len as u32)
+    }
 
-    /// The `setUint32()` method stores an unsigned 32-bit integer (unsigned long) value at the
-    /// specified byte offset from the start of the DataView.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/setUint32)
-    #[wasm_bindgen(method, js_name = setUint32)]
-    pub fn set_uint32(this: &DataView, byte_offset: usize, value: u32);
+    /// Empties the buffer without shrinking its backing capacity.
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+}
 
-    /// The `setUint32()` method stores an unsigned 32-bit integer (unsigned long) value at the
-    /// specified byte offset from the start of the DataView.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/setUint32)
-    #[wasm_bindgen(method, js_name = setUint32)]
-    pub fn set_uint32_endian(this: &DataView, byte_offset: usize, value: u32, little_endian: bool);
+// SharedArrayBuffer
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(extends = Object, typescript_type = "SharedArrayBuffer")]
+    #[derive(Clone, Debug)]
+    pub type SharedArrayBuffer;
 
-    /// The `setFloat32()` method stores a signed 32-bit float (float) value at the
-    /// specified byte offset from the start of the DataView.
+    /// The `SharedArrayBuffer` object is used to represent a generic,
+    /// fixed-length raw binary data buffer, similar to the `ArrayBuffer`
+    /// object, but in a way that they can be used to create views
+    /// on shared memory. Unlike an `ArrayBuffer`, a `SharedArrayBuffer`
+    /// cannot become detached.
     ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/setFloat32)
-    #[wasm_bindgen(method, js_name = setFloat32)]
-    pub fn set_float32(this: &DataView, byte_offset: usize, value: f32);
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/SharedArrayBuffer)
+    #[wasm_bindgen(constructor)]
+    pub fn new(length: u32) -> SharedArrayBuffer;
 
-    /// The `setFloat32()` method stores a signed 32-bit float (float) value at the
-    /// specified byte offset from the start of the DataView.
+    /// The byteLength accessor property represents the length of
+    /// an `SharedArrayBuffer` in bytes. This is established when
+    /// the `SharedArrayBuffer` is constructed and cannot be changed.
     ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/setFloat32)
-    #[wasm_bindgen(method, js_name = setFloat32)]
-    pub fn set_float32_endian(this: &DataView, byte_offset: usize, value: f32, little_endian: bool);
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/SharedArrayBuffer/byteLength)
+    #[wasm_bindgen(method, getter, js_name = byteLength)]
+    pub fn byte_length(this: &SharedArrayBuffer) -> u32;
 
-    /// The `setFloat64()` method stores a signed 64-bit float (double) value at the
-    /// specified byte offset from the start of the DataView.
+    /// The `slice()` method returns a new `SharedArrayBuffer` whose contents
+    /// are a copy of this `SharedArrayBuffer`'s bytes from begin, inclusive,
+    /// up to end, exclusive.
     ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/setFloat64)
-    #[wasm_bindgen(method, js_name = setFloat64)]
-    pub fn set_float64(this: &DataView, byte_offset: usize, value: f64);
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/SharedArrayBuffer/slice)
+    #[wasm_bindgen(method)]
+    pub fn slice(this: &SharedArrayBuffer, begin: u32) -> SharedArrayBuffer;
 
-    /// The `setFloat64()` method stores a signed 64-bit float (double) value at the
-    /// specified byte offset from the start of the DataView.
+    /// Like `slice()` but with the `end` argument.
     ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/setFloat64)
-    #[wasm_bindgen(method, js_name = setFloat64)]
-    pub fn set_float64_endian(this: &DataView, byte_offset: usize, value: f64, little_endian: bool);
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/SharedArrayBuffer/slice)
+    #[wasm_bindgen(method, js_name = slice)]
+    pub fn slice_with_end(this: &SharedArrayBuffer, begin: u32, end: u32) -> SharedArrayBuffer;
 }
 
-// Error
+// Array Iterator
 #[wasm_bindgen]
 extern "C" {
-    #[wasm_bindgen(extends = Object, typescript_type = "Error")]
-    #[derive(Clone, Debug, PartialEq, Eq)]
-    pub type Error;
-
-    /// The Error constructor creates an error object.
-    /// Instances of Error objects are thrown when runtime errors occur.
-    /// The Error object can also be used as a base object for user-defined exceptions.
-    /// See below for standard built-in error types.
+    /// The `keys()` method returns a new Array Iterator object that contains the
+    /// keys for each index in the array.
     ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Error)
-    #[wasm_bindgen(constructor)]
-    pub fn new(message: &str) -> Error;
-    #[wasm_bindgen(constructor)]
-    pub fn new_with_options(message: &str, options: &Object) -> Error;
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/keys)
+    #[wasm_bindgen(method)]
+    pub fn keys(this: &Array) -> Iterator;
 
-    /// The cause property is the underlying cause of the error.
-    /// Usually this is used to add context to re-thrown errors.
+    /// The `entries()` method returns a new Array Iterator object that contains
+    /// the key/value pairs for each index in the array.
     ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Error#differentiate_between_similar_errors)
-    #[wasm_bindgen(method, getter, structural)]
-    pub fn cause(this: &Error) -> JsValue;
-    #[wasm_bindgen(method, setter, structural)]
-    pub fn set_cause(this: &Error, cause: &JsValue);
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/entries)
+    #[wasm_bindgen(method)]
+    pub fn entries(this: &Array) -> Iterator;
 
-    /// The message property is a human-readable description of the error.
+    /// The `values()` method returns a new Array Iterator object that
+    /// contains the values for each index in the array.
     ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Error/message)
-    #[wasm_bindgen(method, getter, structural)]
-    pub fn message(this: &Error) -> JsString;
-    #[wasm_bindgen(method, setter, structural)]
-    pub fn set_message(this: &Error, message: &str);
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/values)
+    #[wasm_bindgen(method)]
+    pub fn values(this: &Array) -> Iterator;
+}
 
-    /// The name property represents a name for the type of error. The initial value is "Error".
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Error/name)
-    #[wasm_bindgen(method, getter, structural)]
-    pub fn name(this: &Error) -> JsString;
+/// The `Atomics` object provides atomic operations as static methods.
+/// They are used with `SharedArrayBuffer` objects.
+///
+/// The Atomic operations are installed on an `Atomics` module. Unlike
+/// the other global objects, `Atomics` is not a constructor. You cannot
+/// use it with a new operator or invoke the `Atomics` object as a
+/// function. All properties and methods of `Atomics` are static
+/// (as is the case with the Math object, for example).
+/// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics)
+#[allow(non_snake_case)]
+pub mod Atomics {
+    use super::*;
+
+    #[wasm_bindgen]
+    extern "C" {
+        /// The static `Atomics.add()` method adds a given value at a given
+        /// position in the array and returns the old value at that position.
+        /// This atomic operation guarantees that no other write happens
+        /// until the modified value is written back.
+        ///
+        /// You should use `add_bigint` to operate on a `BigInt64Array` or a `BigUint64Array`.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/add)
+        #[wasm_bindgen(js_namespace = Atomics, catch)]
+        pub fn add(typed_array: &JsValue, index: u32, value: i32) -> Result<i32, JsValue>;
+
+        /// The static `Atomics.add()` method adds a given value at a given
+        /// position in the array and returns the old value at that position.
+        /// This atomic operation guarantees that no other write happens
+        /// until the modified value is written back.
+        ///
+        /// This method is used to operate on a `BigInt64Array` or a `BigUint64Array`.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/add)
+        #[wasm_bindgen(js_namespace = Atomics, catch, js_name = add)]
+        pub fn add_bigint(typed_array: &JsValue, index: u32, value: i64) -> Result<i64, JsValue>;
+
+        /// The static `Atomics.and()` method computes a bitwise AND with a given
+        /// value at a given position in the array, and returns the old value
+        /// at that position.
+        /// This atomic operation guarantees that no other write happens
+        /// until the modified value is written back.
+        ///
+        /// You should use `and_bigint` to operate on a `BigInt64Array` or a `BigUint64Array`.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/and)
+        #[wasm_bindgen(js_namespace = Atomics, catch)]
+        pub fn and(typed_array: &JsValue, index: u32, value: i32) -> Result<i32, JsValue>;
+
+        /// The static `Atomics.and()` method computes a bitwise AND with a given
+        /// value at a given position in the array, and returns the old value
+        /// at that position.
+        /// This atomic operation guarantees that no other write happens
+        /// until the modified value is written back.
+        ///
+        /// This method is used to operate on a `BigInt64Array` or a `BigUint64Array`.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/and)
+        #[wasm_bindgen(js_namespace = Atomics, catch, js_name = and)]
+        pub fn and_bigint(typed_array: &JsValue, index: u32, value: i64) -> Result<i64, JsValue>;
+
+        /// The static `Atomics.compareExchange()` method exchanges a given
+        /// replacement value at a given position in the array, if a given expected
+        /// value equals the old value. It returns the old value at that position
+        /// whether it was equal to the expected value or not.
+        /// This atomic operation guarantees that no other write happens
+        /// until the modified value is written back.
+        ///
+        /// You should use `compare_exchange_bigint` to operate on a `BigInt64Array` or a `BigUint64Array`.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/compareExchange)
+        #[wasm_bindgen(js_namespace = Atomics, catch, js_name = compareExchange)]
+        pub fn compare_exchange(
+            typed_array: &JsValue,
+            index: u32,
+            expected_value: i32,
+            replacement_value: i32,
+        ) -> Result<i32, JsValue>;
+
+        /// The static `Atomics.compareExchange()` method exchanges a given
+        /// replacement value at a given position in the array, if a given expected
+        /// value equals the old value. It returns the old value at that position
+        /// whether it was equal to the expected value or not.
+        /// This atomic operation guarantees that no other write happens
+        /// until the modified value is written back.
+        ///
+        /// This method is used to operate on a `BigInt64Array` or a `BigUint64Array`.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/compareExchange)
+        #[wasm_bindgen(js_namespace = Atomics, catch, js_name = compareExchange)]
+        pub fn compare_exchange_bigint(
+            typed_array: &JsValue,
+            index: u32,
+            expected_value: i64,
+            replacement_value: i64,
+        ) -> Result<i64, JsValue>;
+
+        /// The static `Atomics.exchange()` method stores a given value at a given
+        /// position in the array and returns the old value at that position.
+        /// This atomic operation guarantees that no other write happens
+        /// until the modified value is written back.
+        ///
+        /// You should use `exchange_bigint` to operate on a `BigInt64Array` or a `BigUint64Array`.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/exchange)
+        #[wasm_bindgen(js_namespace = Atomics, catch)]
+        pub fn exchange(typed_array: &JsValue, index: u32, value: i32) -> Result<i32, JsValue>;
+
+        /// The static `Atomics.exchange()` method stores a given value at a given
+        /// position in the array and returns the old value at that position.
+        /// This atomic operation guarantees that no other write happens
+        /// until the modified value is written back.
+        ///
+        /// This method is used to operate on a `BigInt64Array` or a `BigUint64Array`.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/exchange)
+        #[wasm_bindgen(js_namespace = Atomics, catch, js_name = exchange)]
+        pub fn exchange_bigint(
+            typed_array: &JsValue,
+            index: u32,
+            value: i64,
+        ) -> Result<i64, JsValue>;
+
+        /// The static `Atomics.isLockFree()` method is used to determine
+        /// whether to use locks or atomic operations. It returns true,
+        /// if the given size is one of the `BYTES_PER_ELEMENT` property
+        /// of integer `TypedArray` types.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/isLockFree)
+        #[wasm_bindgen(js_namespace = Atomics, js_name = isLockFree)]
+        pub fn is_lock_free(size: u32) -> bool;
+
+        /// The static `Atomics.load()` method returns a value at a given
+        /// position in the array.
+        ///
+        /// You should use `load_bigint` to operate on a `BigInt64Array` or a `BigUint64Array`.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/load)
+        #[wasm_bindgen(js_namespace = Atomics, catch)]
+        pub fn load(typed_array: &JsValue, index: u32) -> Result<i32, JsValue>;
+
+        /// The static `Atomics.load()` method returns a value at a given
+        /// position in the array.
+        ///
+        /// This method is used to operate on a `BigInt64Array` or a `BigUint64Array`.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/load)
+        #[wasm_bindgen(js_namespace = Atomics, catch, js_name = load)]
+        pub fn load_bigint(typed_array: &JsValue, index: i64) -> Result<i64, JsValue>;
+
+        /// The static `Atomics.notify()` method notifies up some agents that
+        /// are sleeping in the wait queue.
+        /// Note: This operation works with a shared `Int32Array` only.
+        /// If `count` is not provided, notifies all the agents in the queue.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/notify)
+        #[wasm_bindgen(js_namespace = Atomics, catch)]
+        pub fn notify(typed_array: &Int32Array, index: u32) -> Result<u32, JsValue>;
+
+        /// Notifies up to `count` agents in the wait queue.
+        #[wasm_bindgen(js_namespace = Atomics, catch, js_name = notify)]
+        pub fn notify_with_count(
+            typed_array: &Int32Array,
+            index: u32,
+            count: u32,
+        ) -> Result<u32, JsValue>;
+
+        /// The static `Atomics.or()` method computes a bitwise OR with a given value
+        /// at a given position in the array, and returns the old value at that position.
+        /// This atomic operation guarantees that no other write happens
+        /// until the modified value is written back.
+        ///
+        /// You should use `or_bigint` to operate on a `BigInt64Array` or a `BigUint64Array`.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/or)
+        #[wasm_bindgen(js_namespace = Atomics, catch)]
+        pub fn or(typed_array: &JsValue, index: u32, value: i32) -> Result<i32, JsValue>;
+
+        /// The static `Atomics.or()` method computes a bitwise OR with a given value
+        /// at a given position in the array, and returns the old value at that position.
+        /// This atomic operation guarantees that no other write happens
+        /// until the modified value is written back.
+        ///
+        /// This method is used to operate on a `BigInt64Array` or a `BigUint64Array`.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/or)
+        #[wasm_bindgen(js_namespace = Atomics, catch, js_name = or)]
+        pub fn or_bigint(typed_array: &JsValue, index: u32, value: i64) -> Result<i64, JsValue>;
+
+        /// The static `Atomics.store()` method stores a given value at the given
+        /// position in the array and returns that value.
+        ///
+        /// You should use `store_bigint` to operate on a `BigInt64Array` or a `BigUint64Array`.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/store)
+        #[wasm_bindgen(js_namespace = Atomics, catch)]
+        pub fn store(typed_array: &JsValue, index: u32, value: i32) -> Result<i32, JsValue>;
+
+        /// The static `Atomics.store()` method stores a given value at the given
+        /// position in the array and returns that value.
+        ///
+        /// This method is used to operate on a `BigInt64Array` or a `BigUint64Array`.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/store)
+        #[wasm_bindgen(js_namespace = Atomics, catch, js_name = store)]
+        pub fn store_bigint(typed_array: &JsValue, index: u32, value: i64) -> Result<i64, JsValue>;
+
+        /// The static `Atomics.sub()` method subtracts a given value at a
+        /// given position in the array and returns the old value at that position.
+        /// This atomic operation guarantees that no other write happens
+        /// until the modified value is written back.
+        ///
+        /// You should use `sub_bigint` to operate on a `BigInt64Array` or a `BigUint64Array`.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/sub)
+        #[wasm_bindgen(js_namespace = Atomics, catch)]
+        pub fn sub(typed_array: &JsValue, index: u32, value: i32) -> Result<i32, JsValue>;
+
+        /// The static `Atomics.sub()` method subtracts a given value at a
+        /// given position in the array and returns the old value at that position.
+        /// This atomic operation guarantees that no other write happens
+        /// until the modified value is written back.
+        ///
+        /// This method is used to operate on a `BigInt64Array` or a `BigUint64Array`.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/sub)
+        #[wasm_bindgen(js_namespace = Atomics, catch, js_name = sub)]
+        pub fn sub_bigint(typed_array: &JsValue, index: u32, value: i64) -> Result<i64, JsValue>;
+
+        /// The static `Atomics.wait()` method verifies that a given
+        /// position in an `Int32Array` still contains a given value
+        /// and if so sleeps, awaiting a wakeup or a timeout.
+        /// It returns a string which is either "ok", "not-equal", or "timed-out".
+        /// Note: This operation only works with a shared `Int32Array`
+        /// and may not be allowed on the main thread.
+        ///
+        /// You should use `wait_bigint` to operate on a `BigInt64Array`.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/wait)
+        #[wasm_bindgen(js_namespace = Atomics, catch)]
+        pub fn wait(typed_array: &Int32Array, index: u32, value: i32) -> Result<JsString, JsValue>;
+
+        /// The static `Atomics.wait()` method verifies that a given
+        /// position in an `BigInt64Array` still contains a given value
+        /// and if so sleeps, awaiting a wakeup or a timeout.
+        /// It returns a string which is either "ok", "not-equal", or "timed-out".
+        /// Note: This operation only works with a shared `BigInt64Array`
+        /// and may not be allowed on the main thread.
+        ///
+        /// You should use `wait` to operate on a `Int32Array`.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/wait)
+        #[wasm_bindgen(js_namespace = Atomics, catch, js_name = wait)]
+        pub fn wait_bigint(
+            typed_array: &BigInt64Array,
+            index: u32,
+            value: i64,
+        ) -> Result<JsString, JsValue>;
+
+        /// Like `wait()`, but with timeout
+        ///
+        /// You should use `wait_with_timeout_bigint` to operate on a `BigInt64Array`.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/wait)
+        #[wasm_bindgen(js_namespace = Atomics, catch, js_name = wait)]
+        pub fn wait_with_timeout(
+            typed_array: &Int32Array,
+            index: u32,
+            value: i32,
+            timeout: f64,
+        ) -> Result<JsString, JsValue>;
+
+        /// Like `wait()`, but with timeout
+        ///
+        /// You should use `wait_with_timeout` to operate on a `Int32Array`.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/wait)
+        #[wasm_bindgen(js_namespace = Atomics, catch, js_name = wait)]
+        pub fn wait_with_timeout_bigint(
+            typed_array: &BigInt64Array,
+            index: u32,
+            value: i64,
+            timeout: f64,
+        ) -> Result<JsString, JsValue>;
+
+        /// The static `Atomics.waitAsync()` method verifies that a given position in an
+        /// `Int32Array` still contains a given value and if so sleeps, awaiting a
+        /// wakeup or a timeout. It returns an object with two properties. The first
+        /// property `async` is a boolean which if true indicates that the second
+        /// property `value` is a promise. If `async` is false then value is a string
+        /// whether equal to either "not-equal" or "timed-out".
+        /// Note: This operation only works with a shared `Int32Array` and may be used
+        /// on the main thread.
+        ///
+        /// You should use `wait_async_bigint` to operate on a `BigInt64Array`.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/waitAsync)
+        #[wasm_bindgen(js_namespace = Atomics, catch, js_name = waitAsync)]
+        pub fn wait_async(
+            typed_array: &Int32Array,
+            index: u32,
+            value: i32,
+        ) -> Result<AtomicsWaitAsyncResult, JsValue>;
+
+        /// The static `Atomics.waitAsync()` method verifies that a given position in an
+        /// `Int32Array` still contains a given value and if so sleeps, awaiting a
+        /// wakeup or a timeout. It returns an object with two properties. The first
+        /// property `async` is a boolean which if true indicates that the second
+        /// property `value` is a promise. If `async` is false then value is a string
+        /// whether equal to either "not-equal" or "timed-out".
+        /// Note: This operation only works with a shared `BigInt64Array` and may be used
+        /// on the main thread.
+        ///
+        /// You should use `wait_async` to operate on a `Int32Array`.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/waitAsync)
+        #[wasm_bindgen(js_namespace = Atomics, catch, js_name = waitAsync)]
+        pub fn wait_async_bigint(
+            typed_array: &BigInt64Array,
+            index: u32,
+            value: i64,
+        ) -> Result<AtomicsWaitAsyncResult, JsValue>;
+
+        /// Like `waitAsync()`, but with timeout
+        ///
+        /// You should use `wait_async_with_timeout_bigint` to operate on a `BigInt64Array`.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/waitAsync)
+        #[wasm_bindgen(js_namespace = Atomics, catch, js_name = waitAsync)]
+        pub fn wait_async_with_timeout(
+            typed_array: &Int32Array,
+            index: u32,
+            value: i32,
+            timeout: f64,
+        ) -> Result<AtomicsWaitAsyncResult, JsValue>;
+
+        /// Like `waitAsync()`, but with timeout
+        ///
+        /// You should use `wait_async_with_timeout` to operate on a `Int32Array`.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/waitAsync)
+        #[wasm_bindgen(js_namespace = Atomics, catch, js_name = waitAsync)]
+        pub fn wait_async_with_timeout_bigint(
+            typed_array: &BigInt64Array,
+            index: u32,
+            value: i64,
+            timeout: f64,
+        ) -> Result<AtomicsWaitAsyncResult, JsValue>;
+
+        /// The object returned by `Atomics.waitAsync()`. `async_` (`async`
+        /// in JS, a reserved word in Rust) is `true` when `value` is a
+        /// `Promise` that resolves to `"ok"` or `"timed-out"`, and `false`
+        /// when the wait resolved synchronously and `value` is already a
+        /// status string (`"not-equal"` or `"timed-out"`).
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/waitAsync)
+        #[wasm_bindgen(extends = Object, typescript_type = "{ async: boolean, value: any }")]
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub type AtomicsWaitAsyncResult;
+
+        /// Whether [`value`](Self::value) is a `Promise` (`true`) or an
+        /// already-resolved status string (`false`).
+        #[wasm_bindgen(method, getter, js_name = async)]
+        pub fn async_(this: &AtomicsWaitAsyncResult) -> bool;
+
+        /// Either a `Promise` resolving to `"ok"`/`"timed-out"`, or (when
+        /// [`async_`](Self::async_) is `false`) the `"not-equal"` or
+        /// `"timed-out"` status string directly.
+        #[wasm_bindgen(method, getter)]
+        pub fn value(this: &AtomicsWaitAsyncResult) -> JsValue;
+
+        /// The static `Atomics.xor()` method computes a bitwise XOR
+        /// with a given value at a given position in the array,
+        /// and returns the old value at that position.
+        /// This atomic operation guarantees that no other write happens
+        /// until the modified value is written back.
+        ///
+        /// You should use `xor_bigint` to operate on a `BigInt64Array` or a `BigUint64Array`.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/xor)
+        #[wasm_bindgen(js_namespace = Atomics, catch)]
+        pub fn xor(typed_array: &JsValue, index: u32, value: i32) -> Result<i32, JsValue>;
+
+        /// The static `Atomics.xor()` method computes a bitwise XOR
+        /// with a given value at a given position in the array,
+        /// and returns the old value at that position.
+        /// This atomic operation guarantees that no other write happens
+        /// until the modified value is written back.
+        ///
+        /// This method is used to operate on a `BigInt64Array` or a `BigUint64Array`.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/xor)
+        #[wasm_bindgen(js_namespace = Atomics, catch, js_name = xor)]
+        pub fn xor_bigint(typed_array: &JsValue, index: u32, value: i64) -> Result<i64, JsValue>;
+    }
+
+    impl AtomicsWaitAsyncResult {
+        /// Returns [`value`](Self::value) as a [`Promise`], if
+        /// [`async_`](Self::async_) is `true` -- i.e. the wait is still
+        /// pending and will resolve asynchronously. `None` if the wait
+        /// already resolved synchronously.
+        pub fn value_promise(&self) -> Option<Promise> {
+            if self.async_() {
+                self.value().dyn_into::<Promise>().ok()
+            } else {
+                None
+            }
+        }
+
+        /// Returns [`value`](Self::value) as the already-resolved status
+        /// string (`"not-equal"` or `"timed-out"`), if
+        /// [`async_`](Self::async_) is `false`. `None` if the wait is
+        /// still pending.
+        pub fn value_string(&self) -> Option<JsString> {
+            if self.async_() {
+                None
+            } else {
+                self.value().dyn_into::<JsString>().ok()
+            }
+        }
+    }
+
+    /// Returns `true` if this thread is allowed to call the synchronous
+    /// [`wait`] (rather than only [`wait_async`]) -- `false`, for
+    /// instance, on a JS main thread, where `Atomics.wait` always throws
+    /// a `TypeError` rather than blocking.
+    ///
+    /// Probed once per thread by attempting a `wait` with a zero timeout
+    /// on a throwaway, thread-local `SharedArrayBuffer`-backed
+    /// `Int32Array` (the same one [`wait_async_delay`] reuses), caching
+    /// the result; returns `false` without probing at all if
+    /// `SharedArrayBuffer` isn't available in this environment.
+    pub fn can_wait_sync() -> bool {
+        #[cfg(feature = "std")]
+        {
+            thread_local! {
+                static CAN_WAIT: bool = probe_can_wait_sync();
+            }
+            CAN_WAIT.with(|v| *v)
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            use once_cell::unsync::Lazy;
+
+            struct Wrapper(Lazy<bool>);
+
+            #[cfg(not(target_feature = "atomics"))]
+            unsafe impl Sync for Wrapper {}
+
+            #[cfg(not(target_feature = "atomics"))]
+            unsafe impl Send for Wrapper {}
+
+            #[cfg_attr(target_feature = "atomics", thread_local)]
+            static CAN_WAIT: Wrapper = Wrapper(Lazy::new(probe_can_wait_sync));
+
+            *CAN_WAIT.0
+        }
+    }
+
+    /// The value (1) deliberately doesn't match the throwaway array's
+    /// actual contents (0): the "can this agent block" check happens
+    /// before the value comparison, so a disallowed wait still throws
+    /// without us needing to race an actual waiter to observe it.
+    fn probe_can_wait_sync() -> bool {
+        if !shared_array_buffer_available() {
+            return false;
+        }
+        !matches!(
+            wait_with_timeout(&delay_array(), 0, 1, 0.0),
+            Err(ref e) if e.dyn_ref::<TypeError>().is_some()
+        )
+    }
+
+    /// A structured error from an [`Atomics`] operation, classifying the
+    /// thrown value instead of leaving callers to match on a raw
+    /// [`JsValue`].
+    #[derive(Clone, Debug)]
+    pub enum AtomicsError {
+        /// The index was out of bounds for the typed array (a thrown
+        /// `RangeError`).
+        IndexOutOfRange(RangeError),
+        /// The typed array wasn't a valid integer-indexed view over a
+        /// `SharedArrayBuffer`, or another argument had the wrong type (a
+        /// thrown `TypeError`).
+        WrongType(TypeError),
+        /// Some other value was thrown.
+        Other(JsValue),
+    }
+
+    impl AtomicsError {
+        fn from_js(error: JsValue) -> Self {
+            if error.is_instance_of::<RangeError>() {
+                AtomicsError::IndexOutOfRange(error.unchecked_into())
+            } else if error.is_instance_of::<TypeError>() {
+                AtomicsError::WrongType(error.unchecked_into())
+            } else {
+                AtomicsError::Other(error)
+            }
+        }
+    }
+
+    macro_rules! checked_op {
+        ($checked:ident, $raw:ident, ($($arg:ident: $ty:ty),*) -> $ret:ty) => {
+            /// Like the same-named function without the `_checked` suffix,
+            /// but classifies the thrown value into an [`AtomicsError`]
+            /// instead of returning it as a raw [`JsValue`].
+            pub fn $checked($($arg: $ty),*) -> Result<$ret, AtomicsError> {
+                $raw($($arg),*).map_err(AtomicsError::from_js)
+            }
+        };
+    }
+
+    checked_op!(add_checked, add, (typed_array: &JsValue, index: u32, value: i32) -> i32);
+    checked_op!(and_checked, and, (typed_array: &JsValue, index: u32, value: i32) -> i32);
+    checked_op!(or_checked, or, (typed_array: &JsValue, index: u32, value: i32) -> i32);
+    checked_op!(xor_checked, xor, (typed_array: &JsValue, index: u32, value: i32) -> i32);
+    checked_op!(sub_checked, sub, (typed_array: &JsValue, index: u32, value: i32) -> i32);
+    checked_op!(store_checked, store, (typed_array: &JsValue, index: u32, value: i32) -> i32);
+    checked_op!(load_checked, load, (typed_array: &JsValue, index: u32) -> i32);
+    checked_op!(exchange_checked, exchange, (typed_array: &JsValue, index: u32, value: i32) -> i32);
+    checked_op!(compare_exchange_checked, compare_exchange, (typed_array: &JsValue, index: u32, expected_value: i32, replacement_value: i32) -> i32);
+
+    /// Returns the thread-local `Int32Array`, backed by a small
+    /// `SharedArrayBuffer`, used by [`wait_async_delay`] -- allocated once
+    /// and reused across calls rather than once per delay.
+    fn delay_array() -> Int32Array {
+        #[cfg(feature = "std")]
+        {
+            thread_local! {
+                static ARRAY: Int32Array = Int32Array::new(&JsValue::from(SharedArrayBuffer::new(4)));
+            }
+            ARRAY.with(|a| a.clone())
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            use once_cell::unsync::Lazy;
+
+            struct Wrapper(Lazy<Int32Array>);
+
+            #[cfg(not(target_feature = "atomics"))]
+            unsafe impl Sync for Wrapper {}
+
+            #[cfg(not(target_feature = "atomics"))]
+            unsafe impl Send for Wrapper {}
+
+            #[cfg_attr(target_feature = "atomics", thread_local)]
+            static ARRAY: Wrapper =
+                Wrapper(Lazy::new(|| Int32Array::new(&JsValue::from(SharedArrayBuffer::new(4)))));
+
+            ARRAY.0.clone()
+        }
+    }
+
+    /// A promise-based delay that doesn't rely on `setTimeout`, built on
+    /// `Atomics.waitAsync` over a tiny, thread-local `SharedArrayBuffer` +
+    /// `Int32Array` (allocated once and reused across calls). Useful in
+    /// worker contexts that want to await a delay without a host-provided
+    /// timer API.
+    ///
+    /// Requires a `SharedArrayBuffer` and `Atomics.waitAsync` to both be
+    /// available; returns a descriptive `Err` rather than throwing if
+    /// either is missing (e.g. the main thread of an environment that
+    /// hasn't opted into cross-origin isolation).
+    pub fn wait_async_delay(ms: f64) -> Result<Promise, JsValue> {
+        if !shared_array_buffer_available() {
+            return Err(Error::new(
+                "wait_async_delay requires SharedArrayBuffer, which is not available in this environment",
+            )
+            .into());
+        }
+
+        let array = delay_array();
+        let result = wait_async_with_timeout(&array, 0, 0, ms)?;
+        if result.async_() {
+            result.value().dyn_into::<Promise>()
+        } else {
+            Ok(Promise::resolve(&result.value()))
+        }
+    }
+
+    fn shared_array_buffer_available() -> bool {
+        Reflect::get(global().as_ref(), &JsValue::from_str("SharedArrayBuffer"))
+            .map(|v| v.is_function())
+            .unwrap_or(false)
+    }
+}
+
+// BigInt
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(extends = Object, is_type_of = |v| v.is_bigint(), typescript_type = "bigint")]
+    #[derive(Clone, PartialEq, Eq)]
+    pub type BigInt;
+
+    #[wasm_bindgen(catch, js_name = BigInt)]
+    fn new_bigint(value: &JsValue) -> Result<BigInt, Error>;
+
+    #[wasm_bindgen(js_name = BigInt)]
+    fn new_bigint_unchecked(value: &JsValue) -> BigInt;
+
+    /// Clamps a BigInt value to a signed integer value, and returns that value.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/BigInt/asIntN)
+    #[wasm_bindgen(static_method_of = BigInt, js_name = asIntN)]
+    pub fn as_int_n(bits: f64, bigint: &BigInt) -> BigInt;
+
+    /// Clamps a BigInt value to an unsigned integer value, and returns that value.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/BigInt/asUintN)
+    #[wasm_bindgen(static_method_of = BigInt, js_name = asUintN)]
+    pub fn as_uint_n(bits: f64, bigint: &BigInt) -> BigInt;
+
+    /// Returns a string with a language-sensitive representation of this BigInt value. Overrides the [`Object.prototype.toLocaleString()`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/BigInt/toLocaleString) method.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/BigInt/toLocaleString)
+    #[wasm_bindgen(method, js_name = toLocaleString)]
+    pub fn to_locale_string(this: &BigInt, locales: &JsValue, options: &JsValue) -> JsString;
+
+    /// Returns a string representing this BigInt value in the specified radix (base). Overrides the [`Object.prototype.toString()`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/toString) method.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/BigInt/toString)
+    #[wasm_bindgen(catch, method, js_name = toString)]
+    pub fn to_string(this: &BigInt, radix: u8) -> Result<JsString, RangeError>;
+
+    #[wasm_bindgen(method, js_name = toString)]
+    fn to_string_unchecked(this: &BigInt, radix: u8) -> String;
+
+    /// Returns this BigInt value. Overrides the [`Object.prototype.valueOf()`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/valueOf) method.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/BigInt/valueOf)
+    #[wasm_bindgen(method, js_name = valueOf)]
+    pub fn value_of(this: &BigInt, radix: u8) -> BigInt;
+}
+
+macro_rules! bigint_checked_binop {
+    ($cache_fn:ident, $method:ident, $op:literal, $doc:literal) => {
+        fn $cache_fn() -> Function {
+            #[cfg(feature = "std")]
+            {
+                thread_local!(static F: Function = Function::new_with_args("a, b", concat!("return a ", $op, " b;")));
+                F.with(|f| f.clone())
+            }
+            #[cfg(not(feature = "std"))]
+            {
+                use once_cell::unsync::Lazy;
+
+                struct Wrapper(Lazy<Function>);
+
+                #[cfg(not(target_feature = "atomics"))]
+                unsafe impl Sync for Wrapper {}
+
+                #[cfg(not(target_feature = "atomics"))]
+                unsafe impl Send for Wrapper {}
+
+                #[cfg_attr(target_feature = "atomics", thread_local)]
+                static F: Wrapper =
+                    Wrapper(Lazy::new(|| Function::new_with_args("a, b", concat!("return a ", $op, " b;"))));
+
+                F.0.clone()
+            }
+        }
+
+        #[doc = $doc]
+        pub fn $method(&self, rhs: &Self) -> Result<Self, RangeError> {
+            bigint_checked_call(Self::$cache_fn(), self, rhs)
+        }
+    };
+}
+
+/// Calls a cached two-argument JS function built from one of the
+/// [`bigint_checked_binop`] operators, classifying a thrown value into a
+/// [`RangeError`] the same way [`BigInt::checked_div`] does. In practice
+/// every operator this is used for (`**`, `*`, `+`, `-`, `<<`, `%`) only
+/// ever throws `RangeError` on `BigInt` operands (negative exponent,
+/// negative shift amount, division/remainder by zero, or a result too
+/// large to represent), but a non-`RangeError` throw is still turned into
+/// one rather than silently discarded.
+fn bigint_checked_call(f: Function, a: &BigInt, b: &BigInt) -> Result<BigInt, RangeError> {
+    match f.call2(&JsValue::UNDEFINED, JsValue::as_ref(a), JsValue::as_ref(b)) {
+        Ok(v) => Ok(v.unchecked_into()),
+        Err(e) => {
+            if e.is_instance_of::<RangeError>() {
+                Err(e.unchecked_into())
+            } else {
+                Err(RangeError::new("BigInt operation threw a non-RangeError value"))
+            }
+        }
+    }
+}
+
+impl BigInt {
+    /// Creates a new BigInt value.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/BigInt/BigInt)
+    #[inline]
+    pub fn new(value: &JsValue) -> Result<BigInt, Error> {
+        new_bigint(value)
+    }
+
+    /// Applies the binary `/` JS operator on two `BigInt`s, catching and returning any `RangeError` thrown.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Division)
+    pub fn checked_div(&self, rhs: &Self) -> Result<Self, RangeError> {
+        let result = JsValue::as_ref(self).checked_div(JsValue::as_ref(rhs));
+
+        if result.is_instance_of::<RangeError>() {
+            Err(result.unchecked_into())
+        } else {
+            Ok(result.unchecked_into())
+        }
+    }
+
+    /// Applies the binary `**` JS operator on the two `BigInt`s.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Exponentiation)
+    #[inline]
+    pub fn pow(&self, rhs: &Self) -> Self {
+        JsValue::as_ref(self)
+            .pow(JsValue::as_ref(rhs))
+            .unchecked_into()
+    }
+
+    bigint_checked_binop!(
+        pow_checked_op,
+        checked_pow,
+        "**",
+        "Applies the binary `**` JS operator on two `BigInt`s, catching and returning any `RangeError` thrown (e.g. a negative exponent, or a result too large to represent).\n\n[MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Exponentiation)"
+    );
+
+    bigint_checked_binop!(
+        mul_checked_op,
+        checked_mul,
+        "*",
+        "Applies the binary `*` JS operator on two `BigInt`s, catching and returning any `RangeError` thrown (a result too large to represent).\n\n[MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Multiplication)"
+    );
+
+    bigint_checked_binop!(
+        add_checked_op,
+        checked_add,
+        "+",
+        "Applies the binary `+` JS operator on two `BigInt`s, catching and returning any `RangeError` thrown (a result too large to represent).\n\n[MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Addition)"
+    );
+
+    bigint_checked_binop!(
+        sub_checked_op,
+        checked_sub,
+        "-",
+        "Applies the binary `-` JS operator on two `BigInt`s, catching and returning any `RangeError` thrown (a result too large to represent).\n\n[MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Subtraction)"
+    );
+
+    bigint_checked_binop!(
+        shl_checked_op,
+        checked_shl,
+        "<<",
+        "Applies the binary `<<` JS operator on two `BigInt`s, catching and returning any `RangeError` thrown (a negative shift amount, or a result too large to represent).\n\n[MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Left_shift)"
+    );
+
+    bigint_checked_binop!(
+        rem_checked_op,
+        checked_rem,
+        "%",
+        "Applies the binary `%` JS operator on two `BigInt`s, catching and returning any `RangeError` thrown (a zero `rhs`).\n\n[MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Remainder)"
+    );
+
+    /// Computes `(self ^ exp) % modulus` without ever materializing the
+    /// full, unreduced power, via square-and-multiply over
+    /// [`checked_mul`](Self::checked_mul) and
+    /// [`checked_rem`](Self::checked_rem). Useful for crypto-adjacent code
+    /// where `self.pow(exp) % modulus` would build astronomically large
+    /// intermediate `BigInt`s.
+    ///
+    /// Errors if `exp` is negative or `modulus` is zero, or if any
+    /// intermediate step overflows `BigInt`'s own size limit.
+    pub fn modpow(&self, exp: &Self, modulus: &Self) -> Result<Self, RangeError> {
+        let zero = BigInt::from(0);
+        if exp < &zero {
+            return Err(RangeError::new("modpow: exponent must not be negative"));
+        }
+        if modulus == &zero {
+            return Err(RangeError::new("modpow: modulus must not be zero"));
+        }
+
+        let one = BigInt::from(1);
+        let two = BigInt::from(2);
+        let mut result = one.clone();
+        let mut base = self.checked_rem(modulus)?;
+        let mut e = exp.clone();
+        while e > zero {
+            if e.checked_rem(&two)? == one {
+                result = result.checked_mul(&base)?.checked_rem(modulus)?;
+            }
+            e = &e / &two;
+            base = base.checked_mul(&base)?.checked_rem(modulus)?;
+        }
+        Ok(result)
+    }
+
+    /// Returns a tuple of this [`BigInt`]'s absolute value along with a
+    /// [`bool`] indicating whether the [`BigInt`] was negative.
+    fn abs(&self) -> (Self, bool) {
+        if self < &BigInt::from(0) {
+            (-self, true)
+        } else {
+            (self.clone(), false)
+        }
+    }
+}
+
+macro_rules! bigint_from {
+    ($($x:ident)*) => ($(
+        impl From<$x> for BigInt {
+            #[inline]
+            fn from(x: $x) -> BigInt {
+                new_bigint_unchecked(&JsValue::from(x))
+            }
+        }
+
+        impl PartialEq<$x> for BigInt {
+            #[inline]
+            fn eq(&self, other: &$x) -> bool {
+                JsValue::from(self) == JsValue::from(BigInt::from(*other))
+            }
+        }
+    )*)
+}
+bigint_from!(i8 u8 i16 u16 i32 u32 isize usize);
+
+macro_rules! bigint_from_big {
+    ($($x:ident)*) => ($(
+        impl From<$x> for BigInt {
+            #[inline]
+            fn from(x: $x) -> BigInt {
+                JsValue::from(x).unchecked_into()
+            }
+        }
+
+        impl PartialEq<$x> for BigInt {
+            #[inline]
+            fn eq(&self, other: &$x) -> bool {
+                self == &BigInt::from(*other)
+            }
+        }
+
+        impl TryFrom<BigInt> for $x {
+            type Error = BigInt;
+
+            #[inline]
+            fn try_from(x: BigInt) -> Result<Self, BigInt> {
+                Self::try_from(JsValue::from(x)).map_err(JsCast::unchecked_into)
+            }
+        }
+    )*)
+}
+bigint_from_big!(i64 u64 i128 u128);
+
+impl PartialEq<Number> for BigInt {
+    #[inline]
+    fn eq(&self, other: &Number) -> bool {
+        JsValue::as_ref(self).loose_eq(JsValue::as_ref(other))
+    }
+}
+
+impl Not for &BigInt {
+    type Output = BigInt;
+
+    #[inline]
+    fn not(self) -> Self::Output {
+        JsValue::as_ref(self).bit_not().unchecked_into()
+    }
+}
+
+forward_deref_unop!(impl Not, not for BigInt);
+forward_js_unop!(impl Neg, neg for BigInt);
+forward_js_binop!(impl BitAnd, bitand for BigInt);
+forward_js_binop!(impl BitOr, bitor for BigInt);
+forward_js_binop!(impl BitXor, bitxor for BigInt);
+forward_js_binop!(impl Shl, shl for BigInt);
+forward_js_binop!(impl Shr, shr for BigInt);
+forward_js_binop!(impl Add, add for BigInt);
+forward_js_binop!(impl Sub, sub for BigInt);
+forward_js_binop!(impl Div, div for BigInt);
+forward_js_binop!(impl Mul, mul for BigInt);
+forward_js_binop!(impl Rem, rem for BigInt);
+sum_product!(BigInt);
+
+partialord_ord!(BigInt);
+
+impl Default for BigInt {
+    fn default() -> Self {
+        BigInt::from(i32::default())
+    }
+}
+
+impl FromStr for BigInt {
+    type Err = Error;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        BigInt::new(&s.into())
+    }
+}
+
+/// An error returned by [`BigInt::parse`] when `s` isn't a valid BigInt
+/// literal: an empty digit sequence, a misplaced `_` digit separator, or a
+/// character that isn't a valid digit for the (possibly prefixed) radix.
+/// `position` is the byte offset of the offending character within `s`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BigIntParseError {
+    pub position: usize,
+}
+
+impl fmt::Display for BigIntParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid BigInt literal at byte offset {}", self.position)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BigIntParseError {}
+
+impl BigInt {
+    /// Parses `s` as a BigInt literal, with Rust-side validation that
+    /// reports the byte offset of the first invalid character -- unlike
+    /// going straight to the `BigInt` constructor (via [`BigInt::new`] or
+    /// [`FromStr`]), whose thrown `SyntaxError` carries no such position.
+    ///
+    /// Accepts an optional leading `+`/`-` sign (applied in Rust, since the
+    /// JS `BigInt` constructor rejects a signed string outright), an
+    /// optional `0x`/`0o`/`0b` radix prefix (lowercase only, matching the
+    /// JS literal grammar), and `_` digit separators between digits,
+    /// stripped before the value is built -- e.g. `"1_000_000"` or
+    /// `"-0xdead_beef"`.
+    pub fn parse(s: &str) -> Result<BigInt, BigIntParseError> {
+        let bytes = s.as_bytes();
+        let mut i = 0;
+        let negative = match bytes.first() {
+            Some(b'+') => {
+                i += 1;
+                false
+            }
+            Some(b'-') => {
+                i += 1;
+                true
+            }
+            _ => false,
+        };
+        let sign_len = i;
+        let (radix, prefix_len): (u32, usize) = match bytes.get(i..i + 2) {
+            Some(b"0x") => (16, 2),
+            Some(b"0o") => (8, 2),
+            Some(b"0b") => (2, 2),
+            _ => (10, 0),
+        };
+        i += prefix_len;
+        let digits_start = i;
+        let mut last_was_digit = false;
+        let mut digit_count = 0usize;
+        while i < bytes.len() {
+            let b = bytes[i];
+            if b == b'_' {
+                if !last_was_digit {
+                    return Err(BigIntParseError { position: i });
+                }
+                last_was_digit = false;
+            } else if (b as char).is_digit(radix) {
+                last_was_digit = true;
+                digit_count += 1;
+            } else {
+                return Err(BigIntParseError { position: i });
+            }
+            i += 1;
+        }
+        if digit_count == 0 {
+            return Err(BigIntParseError { position: digits_start });
+        }
+        if !last_was_digit {
+            // Trailing separator, e.g. "1_".
+            return Err(BigIntParseError { position: i - 1 });
+        }
+
+        let mut cleaned = String::with_capacity(s.len());
+        cleaned.push_str(&s[sign_len..digits_start]);
+        for &b in &bytes[digits_start..] {
+            if b != b'_' {
+                cleaned.push(b as char);
+            }
+        }
+
+        let value = new_bigint(&JsValue::from_str(&cleaned))
+            .map_err(|_| BigIntParseError { position: 0 })?;
+        Ok(if negative { -&value } else { value })
+    }
+
+    /// Returns a Rust `String` representing this BigInt in the given radix
+    /// (2 through 36), same as [`BigInt::to_string`] but with the
+    /// resulting [`JsString`] already converted for the caller.
+    pub fn to_string_radix(&self, radix: u8) -> Result<String, RangeError> {
+        self.to_string(radix).map(String::from)
+    }
+}
+
+impl TryFrom<&str> for BigInt {
+    type Error = BigIntParseError;
+
+    #[inline]
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        BigInt::parse(s)
+    }
+}
+
+impl fmt::Debug for BigInt {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl fmt::Display for BigInt {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (abs, is_neg) = self.abs();
+        f.pad_integral(!is_neg, "", &abs.to_string_unchecked(10))
+    }
+}
+
+impl fmt::Binary for BigInt {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (abs, is_neg) = self.abs();
+        f.pad_integral(!is_neg, "0b", &abs.to_string_unchecked(2))
+    }
+}
+
+impl fmt::Octal for BigInt {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (abs, is_neg) = self.abs();
+        f.pad_integral(!is_neg, "0o", &abs.to_string_unchecked(8))
+    }
+}
+
+impl fmt::LowerHex for BigInt {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (abs, is_neg) = self.abs();
+        f.pad_integral(!is_neg, "0x", &abs.to_string_unchecked(16))
+    }
+}
+
+impl fmt::UpperHex for BigInt {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (abs, is_neg) = self.abs();
+        let mut s: String = abs.to_string_unchecked(16);
+        s.make_ascii_uppercase();
+        f.pad_integral(!is_neg, "0x", &s)
+    }
+}
+
+// Boolean
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(extends = Object, is_type_of = |v| v.as_bool().is_some(), typescript_type = "boolean")]
+    #[derive(Clone, PartialEq, Eq)]
+    pub type Boolean;
+
+    /// The `Boolean()` constructor creates an object wrapper for a boolean value.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Boolean)
+    #[wasm_bindgen(constructor)]
+    #[deprecated(note = "recommended to use `Boolean::from` instead")]
+    #[allow(deprecated)]
+    pub fn new(value: &JsValue) -> Boolean;
+
+    /// The `valueOf()` method returns the primitive value of a `Boolean` object.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Boolean/valueOf)
+    #[wasm_bindgen(method, js_name = valueOf)]
+    pub fn value_of(this: &Boolean) -> bool;
+}
+
+impl From<bool> for Boolean {
+    #[inline]
+    fn from(b: bool) -> Boolean {
+        Boolean::unchecked_from_js(JsValue::from(b))
+    }
+}
+
+impl From<Boolean> for bool {
+    #[inline]
+    fn from(b: Boolean) -> bool {
+        b.value_of()
+    }
+}
+
+impl PartialEq<bool> for Boolean {
+    #[inline]
+    fn eq(&self, other: &bool) -> bool {
+        self.value_of() == *other
+    }
+}
+
+impl fmt::Debug for Boolean {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.value_of(), f)
+    }
+}
+
+impl fmt::Display for Boolean {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.value_of(), f)
+    }
+}
+
+impl Default for Boolean {
+    fn default() -> Self {
+        Self::from(bool::default())
+    }
+}
+
+impl Not for &Boolean {
+    type Output = Boolean;
+
+    #[inline]
+    fn not(self) -> Self::Output {
+        (!JsValue::as_ref(self)).into()
+    }
+}
+
+forward_deref_unop!(impl Not, not for Boolean);
+
+partialord_ord!(Boolean);
+
+/// Explicit JS coercions, mirroring the ECMAScript `ToNumber`, `ToString`,
+/// `ToBoolean`, and `ToPropertyKey` abstract operations for callers that
+/// need to replicate JS semantics (including objects with a custom
+/// `Symbol.toPrimitive` or `valueOf`/`toString`) instead of relying on
+/// opaque arithmetic tricks on a `JsValue`.
+pub mod coerce {
+    use super::*;
+
+    #[wasm_bindgen]
+    extern "C" {
+        // Bound once, directly to the global `Number`/`String` functions,
+        // so repeated coercions don't look up or allocate a `Function`
+        // every call.
+        #[wasm_bindgen(js_name = Number, catch)]
+        fn number_call(value: &JsValue) -> Result<f64, JsValue>;
+
+        #[wasm_bindgen(js_name = String, catch)]
+        fn string_call(value: &JsValue) -> Result<JsString, JsValue>;
+    }
+
+    /// The `ToNumber` abstract operation: converts `value` to a number,
+    /// invoking a custom `Symbol.toPrimitive`/`valueOf` if present.
+    ///
+    /// Returns `Err` if `value` is a `Symbol`, or if coercion invokes a
+    /// user-defined method that throws.
+    pub fn to_number(value: &JsValue) -> Result<f64, JsValue> {
+        number_call(value)
+    }
+
+    /// The `ToString` abstract operation: converts `value` to a `JsString`,
+    /// invoking a custom `Symbol.toPrimitive`/`toString` if present.
+    ///
+    /// Returns a `TypeError` as `Err` if `value` is a `Symbol`, since
+    /// `ToString` always throws for symbols (unlike the `String()`
+    /// constructor, which special-cases them).
+    pub fn to_js_string(value: &JsValue) -> Result<JsString, JsValue> {
+        if value.is_symbol() {
+            return Err(TypeError::new("Cannot convert a Symbol value to a string").into());
+        }
+        string_call(value)
+    }
+
+    /// The `ToBoolean` abstract operation: converts `value` to a `bool`
+    /// using JS truthiness. This can never throw.
+    pub fn to_boolean(value: &JsValue) -> bool {
+        value.is_truthy()
+    }
+
+    /// The `ToPropertyKey` abstract operation: converts `value` to either a
+    /// `Symbol` (left as-is) or a `JsString` (via [`to_js_string`]),
+    /// suitable for use as a property key with [`Reflect`].
+    pub fn to_property_key(value: &JsValue) -> Result<JsValue, JsValue> {
+        if value.is_symbol() {
+            Ok(value.clone())
+        } else {
+            to_js_string(value).map(JsValue::from)
+        }
+    }
+
+    /// The `ToIntegerOrInfinity` abstract operation: [`to_number`]s `value`,
+    /// then maps `NaN` to `0` and truncates towards zero, leaving infinities
+    /// untouched.
+    pub fn to_integer_or_infinity(value: &JsValue) -> Result<f64, JsValue> {
+        let number = to_number(value)?;
+        if number.is_nan() {
+            Ok(0.0)
+        } else {
+            Ok(Math::trunc(number))
+        }
+    }
+}
+
+// DataView
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(extends = Object, typescript_type = "DataView")]
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub type DataView;
+
+    /// The `DataView` view provides a low-level interface for reading and
+    /// writing multiple number types in an `ArrayBuffer` irrespective of the
+    /// platform's endianness.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView)
+    #[wasm_bindgen(constructor)]
+    pub fn new(buffer: &ArrayBuffer, byteOffset: usize, byteLength: usize) -> DataView;
+
+    /// The `DataView` view provides a low-level interface for reading and
+    /// writing multiple number types in an `ArrayBuffer` irrespective of the
+    /// platform's endianness.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView)
+    #[wasm_bindgen(constructor)]
+    pub fn new_with_shared_array_buffer(
+        buffer: &SharedArrayBuffer,
+        byteOffset: usize,
+        byteLength: usize,
+    ) -> DataView;
+
+    /// The ArrayBuffer referenced by this view. Fixed at construction time and thus read only.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/buffer)
+    #[wasm_bindgen(method, getter, structural)]
+    pub fn buffer(this: &DataView) -> ArrayBuffer;
+
+    /// The length (in bytes) of this view from the start of its ArrayBuffer.
+    /// Fixed at construction time and thus read only.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/byteLength)
+    #[wasm_bindgen(method, getter, structural, js_name = byteLength)]
+    pub fn byte_length(this: &DataView) -> usize;
+
+    /// The offset (in bytes) of this view from the start of its ArrayBuffer.
+    /// Fixed at construction time and thus read only.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/byteOffset)
+    #[wasm_bindgen(method, getter, structural, js_name = byteOffset)]
+    pub fn byte_offset(this: &DataView) -> usize;
+
+    /// The `getInt8()` method gets a signed 8-bit integer (byte) at the
+    /// specified byte offset from the start of the DataView.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/getInt8)
+    #[wasm_bindgen(method, js_name = getInt8)]
+    pub fn get_int8(this: &DataView, byte_offset: usize) -> i8;
+
+    /// The `getUint8()` method gets a unsigned 8-bit integer (byte) at the specified
+    /// byte offset from the start of the DataView.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/getUint8)
+    #[wasm_bindgen(method, js_name = getUint8)]
+    pub fn get_uint8(this: &DataView, byte_offset: usize) -> u8;
+
+    /// The `getInt16()` method gets a signed 16-bit integer (short) at the specified
+    /// byte offset from the start of the DataView.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/getInt16)
+    #[wasm_bindgen(method, js_name = getInt16)]
+    pub fn get_int16(this: &DataView, byte_offset: usize) -> i16;
+
+    /// The `getInt16()` method gets a signed 16-bit integer (short) at the specified
+    /// byte offset from the start of the DataView.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/getInt16)
+    #[wasm_bindgen(method, js_name = getInt16)]
+    pub fn get_int16_endian(this: &DataView, byte_offset: usize, little_endian: bool) -> i16;
+
+    /// The `getUint16()` method gets an unsigned 16-bit integer (unsigned short) at the specified
+    /// byte offset from the start of the view.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/getUint16)
+    #[wasm_bindgen(method, js_name = getUint16)]
+    pub fn get_uint16(this: &DataView, byte_offset: usize) -> u16;
+
+    /// The `getUint16()` method gets an unsigned 16-bit integer (unsigned short) at the specified
+    /// byte offset from the start of the view.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/getUint16)
+    #[wasm_bindgen(method, js_name = getUint16)]
+    pub fn get_uint16_endian(this: &DataView, byte_offset: usize, little_endian: bool) -> u16;
+
+    /// The `getInt32()` method gets a signed 32-bit integer (long) at the specified
+    /// byte offset from the start of the DataView.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/getInt32)
+    #[wasm_bindgen(method, js_name = getInt32)]
+    pub fn get_int32(this: &DataView, byte_offset: usize) -> i32;
+
+    /// The `getInt32()` method gets a signed 32-bit integer (long) at the specified
+    /// byte offset from the start of the DataView.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/getInt32)
+    #[wasm_bindgen(method, js_name = getInt32)]
+    pub fn get_int32_endian(this: &DataView, byte_offset: usize, little_endian: bool) -> i32;
+
+    /// The `getUint32()` method gets an unsigned 32-bit integer (unsigned long) at the specified
+    /// byte offset from the start of the view.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/getUint32)
+    #[wasm_bindgen(method, js_name = getUint32)]
+    pub fn get_uint32(this: &DataView, byte_offset: usize) -> u32;
+
+    /// The `getUint32()` method gets an unsigned 32-bit integer (unsigned long) at the specified
+    /// byte offset from the start of the view.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/getUint32)
+    #[wasm_bindgen(method, js_name = getUint32)]
+    pub fn get_uint32_endian(this: &DataView, byte_offset: usize, little_endian: bool) -> u32;
+
+    /// The `getFloat32()` method gets a signed 32-bit float (float) at the specified
+    /// byte offset from the start of the DataView.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/getFloat32)
+    #[wasm_bindgen(method, js_name = getFloat32)]
+    pub fn get_float32(this: &DataView, byte_offset: usize) -> f32;
+
+    /// The `getFloat32()` method gets a signed 32-bit float (float) at the specified
+    /// byte offset from the start of the DataView.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/getFloat32)
+    #[wasm_bindgen(method, js_name = getFloat32)]
+    pub fn get_float32_endian(this: &DataView, byte_offset: usize, little_endian: bool) -> f32;
+
+    /// The `getFloat64()` method gets a signed 64-bit float (double) at the specified
+    /// byte offset from the start of the DataView.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/getFloat64)
+    #[wasm_bindgen(method, js_name = getFloat64)]
+    pub fn get_float64(this: &DataView, byte_offset: usize) -> f64;
+
+    /// The `getFloat64()` method gets a signed 64-bit float (double) at the specified
+    /// byte offset from the start of the DataView.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/getFloat64)
+    #[wasm_bindgen(method, js_name = getFloat64)]
+    pub fn get_float64_endian(this: &DataView, byte_offset: usize, little_endian: bool) -> f64;
+
+    /// The `setInt8()` method stores a signed 8-bit integer (byte) value at the
+    /// specified byte offset from the start of the DataView.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/setInt8)
+    #[wasm_bindgen(method, js_name = setInt8)]
+    pub fn set_int8(this: &DataView, byte_offset: usize, value: i8);
+
+    /// The `setUint8()` method stores an unsigned 8-bit integer (byte) value at the
+    /// specified byte offset from the start of the DataView.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/setUint8)
+    #[wasm_bindgen(method, js_name = setUint8)]
+    pub fn set_uint8(this: &DataView, byte_offset: usize, value: u8);
+
+    /// The `setInt16()` method stores a signed 16-bit integer (short) value at the
+    /// specified byte offset from the start of the DataView.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/setInt16)
+    #[wasm_bindgen(method, js_name = setInt16)]
+    pub fn set_int16(this: &DataView, byte_offset: usize, value: i16);
+
+    /// The `setInt16()` method stores a signed 16-bit integer (short) value at the
+    /// specified byte offset from the start of the DataView.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/setInt16)
+    #[wasm_bindgen(method, js_name = setInt16)]
+    pub fn set_int16_endian(this: &DataView, byte_offset: usize, value: i16, little_endian: bool);
+
+    /// The `setUint16()` method stores an unsigned 16-bit integer (unsigned short) value at the
+    /// specified byte offset from the start of the DataView.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/setUint16)
+    #[wasm_bindgen(method, js_name = setUint16)]
+    pub fn set_uint16(this: &DataView, byte_offset: usize, value: u16);
+
+    /// The `setUint16()` method stores an unsigned 16-bit integer (unsigned short) value at the
+    /// specified byte offset from the start of the DataView.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/setUint16)
+    #[wasm_bindgen(method, js_name = setUint16)]
+    pub fn set_uint16_endian(this: &DataView, byte_offset: usize, value: u16, little_endian: bool);
+
+    /// The `setInt32()` method stores a signed 32-bit integer (long) value at the
+    /// specified byte offset from the start of the DataView.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/setInt32)
+    #[wasm_bindgen(method, js_name = setInt32)]
+    pub fn set_int32(this: &DataView, byte_offset: usize, value: i32);
+
+    /// The `setInt32()` method stores a signed 32-bit integer (long) value at the
+    /// specified byte offset from the start of the DataView.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/setInt32)
+    #[wasm_bindgen(method, js_name = setInt32)]
+    pub fn set_int32_endian(this: &DataView, byte_offset: usize, value: i32, little_endian: bool);
+
+    /// The `setUint32()` method stores an unsigned 32-bit integer (unsigned long) value at the
+    /// specified byte offset from the start of the DataView.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/setUint32)
+    #[wasm_bindgen(method, js_name = setUint32)]
+    pub fn set_uint32(this: &DataView, byte_offset: usize, value: u32);
+
+    /// The `setUint32()` method stores an unsigned 32-bit integer (unsigned long) value at the
+    /// specified byte offset from the start of the DataView.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/setUint32)
+    #[wasm_bindgen(method, js_name = setUint32)]
+    pub fn set_uint32_endian(this: &DataView, byte_offset: usize, value: u32, little_endian: bool);
+
+    /// The `setFloat32()` method stores a signed 32-bit float (float) value at the
+    /// specified byte offset from the start of the DataView.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/setFloat32)
+    #[wasm_bindgen(method, js_name = setFloat32)]
+    pub fn set_float32(this: &DataView, byte_offset: usize, value: f32);
+
+    /// The `setFloat32()` method stores a signed 32-bit float (float) value at the
+    /// specified byte offset from the start of the DataView.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/setFloat32)
+    #[wasm_bindgen(method, js_name = setFloat32)]
+    pub fn set_float32_endian(this: &DataView, byte_offset: usize, value: f32, little_endian: bool);
+
+    /// The `setFloat64()` method stores a signed 64-bit float (double) value at the
+    /// specified byte offset from the start of the DataView.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/setFloat64)
+    #[wasm_bindgen(method, js_name = setFloat64)]
+    pub fn set_float64(this: &DataView, byte_offset: usize, value: f64);
+
+    /// The `setFloat64()` method stores a signed 64-bit float (double) value at the
+    /// specified byte offset from the start of the DataView.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/setFloat64)
+    #[wasm_bindgen(method, js_name = setFloat64)]
+    pub fn set_float64_endian(this: &DataView, byte_offset: usize, value: f64, little_endian: bool);
+}
+
+/// The on-the-wire type (and, where it matters, byte order) of a single
+/// column in a [`BinaryTableReader`]'s row layout.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FieldType {
+    /// A single unsigned byte.
+    U8,
+    /// An unsigned 16-bit integer.
+    U16 {
+        /// `true` for little-endian, `false` for big-endian.
+        little_endian: bool,
+    },
+    /// An unsigned 32-bit integer.
+    U32 {
+        /// `true` for little-endian, `false` for big-endian.
+        little_endian: bool,
+    },
+    /// A signed 64-bit integer, read as two 32-bit halves (`DataView` has
+    /// no `getBigInt64` binding here).
+    I64 {
+        /// `true` for little-endian, `false` for big-endian.
+        little_endian: bool,
+    },
+    /// A 32-bit float.
+    F32 {
+        /// `true` for little-endian, `false` for big-endian.
+        little_endian: bool,
+    },
+    /// A 64-bit float.
+    F64 {
+        /// `true` for little-endian, `false` for big-endian.
+        little_endian: bool,
+    },
+}
+
+impl FieldType {
+    fn byte_size(&self) -> usize {
+        match *self {
+            FieldType::U8 => 1,
+            FieldType::U16 { .. } => 2,
+            FieldType::U32 { .. } => 4,
+            FieldType::I64 { .. } => 8,
+            FieldType::F32 { .. } => 4,
+            FieldType::F64 { .. } => 8,
+        }
+    }
+}
+
+/// A single field read by [`BinaryTableReader::read_row`], tagged by the
+/// [`FieldType`] it was read as.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FieldValue {
+    /// See [`FieldType::U8`].
+    U8(u8),
+    /// See [`FieldType::U16`].
+    U16(u16),
+    /// See [`FieldType::U32`].
+    U32(u32),
+    /// See [`FieldType::I64`].
+    I64(i64),
+    /// See [`FieldType::F32`].
+    F32(f32),
+    /// See [`FieldType::F64`].
+    F64(f64),
+}
+
+impl FieldValue {
+    /// Converts this field to an `f64`, widening losslessly except for
+    /// the rare [`FieldValue::I64`] magnitude beyond `f64`'s 53-bit
+    /// mantissa.
+    pub fn as_f64(&self) -> f64 {
+        match *self {
+            FieldValue::U8(v) => v.into(),
+            FieldValue::U16(v) => v.into(),
+            FieldValue::U32(v) => v.into(),
+            FieldValue::I64(v) => v as f64,
+            FieldValue::F32(v) => v.into(),
+            FieldValue::F64(v) => v,
+        }
+    }
+}
+
+/// The error returned by [`BinaryTableReader::new`] when the view's byte
+/// length isn't an exact multiple of one row's byte size.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct LayoutError {
+    row_byte_size: usize,
+    view_byte_length: usize,
+}
+
+impl fmt::Display for LayoutError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "DataView byte length {} is not a multiple of the row byte size {}",
+            self.view_byte_length, self.row_byte_size
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for LayoutError {}
+
+/// Reads a [`DataView`] laid out as a flat row-major binary table:
+/// fixed-width rows, each shaped like `row_layout`, packed back-to-back
+/// with no padding. Useful for decoding such a binary wire format
+/// without copying the whole buffer into Rust first.
+#[derive(Clone, Debug)]
+pub struct BinaryTableReader {
+    view: DataView,
+    row_layout: Vec<FieldType>,
+    row_byte_size: usize,
+    row_count: u32,
+}
+
+impl BinaryTableReader {
+    /// Builds a reader over `view`, treating it as consecutive rows each
+    /// shaped like `row_layout`. Fails with [`LayoutError`] if `row_layout`
+    /// is empty or `view`'s byte length isn't an exact multiple of one
+    /// row's byte size.
+    pub fn new(view: &DataView, row_layout: &[FieldType]) -> Result<BinaryTableReader, LayoutError> {
+        let row_byte_size: usize = row_layout.iter().map(FieldType::byte_size).sum();
+        let view_byte_length = view.byte_length();
+        if row_byte_size == 0 || view_byte_length % row_byte_size != 0 {
+            return Err(LayoutError {
+                row_byte_size,
+                view_byte_length,
+            });
+        }
+        Ok(BinaryTableReader {
+            view: view.clone(),
+            row_layout: row_layout.to_vec(),
+            row_byte_size,
+            row_count: (view_byte_length / row_byte_size) as u32,
+        })
+    }
+
+    /// The number of rows in the table.
+    pub fn row_count(&self) -> u32 {
+        self.row_count
+    }
+
+    fn read_field(&self, byte_offset: usize, field: FieldType) -> FieldValue {
+        match field {
+            FieldType::U8 => FieldValue::U8(self.view.get_uint8(byte_offset)),
+            FieldType::U16 { little_endian } => {
+                FieldValue::U16(self.view.get_uint16_endian(byte_offset, little_endian))
+            }
+            FieldType::U32 { little_endian } => {
+                FieldValue::U32(self.view.get_uint32_endian(byte_offset, little_endian))
+            }
+            FieldType::I64 { little_endian } => {
+                let (hi, lo) = if little_endian {
+                    (
+                        self.view.get_uint32_endian(byte_offset + 4, true),
+                        self.view.get_uint32_endian(byte_offset, true),
+                    )
+                } else {
+                    (
+                        self.view.get_uint32_endian(byte_offset, false),
+                        self.view.get_uint32_endian(byte_offset + 4, false),
+                    )
+                };
+                FieldValue::I64((((hi as u64) << 32) | (lo as u64)) as i64)
+            }
+            FieldType::F32 { little_endian } => {
+                FieldValue::F32(self.view.get_float32_endian(byte_offset, little_endian))
+            }
+            FieldType::F64 { little_endian } => {
+                FieldValue::F64(self.view.get_float64_endian(byte_offset, little_endian))
+            }
+        }
+    }
+
+    /// Reads row `i`, returning one [`FieldValue`] per column in the row
+    /// layout.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i >= self.row_count()`.
+    pub fn read_row(&self, i: u32) -> Vec<FieldValue> {
+        core::assert!(i < self.row_count, "row index out of bounds");
+        let mut byte_offset = i as usize * self.row_byte_size;
+        let mut row = Vec::with_capacity(self.row_layout.len());
+        for &field in &self.row_layout {
+            row.push(self.read_field(byte_offset, field));
+            byte_offset += field.byte_size();
+        }
+        row
+    }
+
+    /// Reads every row's value at `field_index` in a single pass, widened
+    /// to `f64` via [`FieldValue::as_f64`]. Faster than calling
+    /// [`BinaryTableReader::read_row`] in a loop and discarding the other
+    /// columns, since it only ever touches the bytes of the column asked
+    /// for.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `field_index` is out of bounds of the row layout.
+    pub fn column_f64(&self, field_index: usize) -> Vec<f64> {
+        let field = self.row_layout[field_index];
+        let field_byte_offset: usize = self.row_layout[..field_index]
+            .iter()
+            .map(FieldType::byte_size)
+            .sum();
+        let base = field_byte_offset;
+        (0..self.row_count)
+            .map(|i| {
+                let byte_offset = base + i as usize * self.row_byte_size;
+                self.read_field(byte_offset, field).as_f64()
+            })
+            .collect()
+    }
+}
+
+// Error
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(extends = Object, typescript_type = "Error")]
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub type Error;
+
+    /// The Error constructor creates an error object.
+    /// Instances of Error objects are thrown when runtime errors occur.
+    /// The Error object can also be used as a base object for user-defined exceptions.
+    /// See below for standard built-in error types.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Error)
+    #[wasm_bindgen(constructor)]
+    pub fn new(message: &str) -> Error;
+    #[wasm_bindgen(constructor)]
+    pub fn new_with_options(message: &str, options: &Object) -> Error;
+
+    /// The cause property is the underlying cause of the error.
+    /// Usually this is used to add context to re-thrown errors.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Error#differentiate_between_similar_errors)
+    #[wasm_bindgen(method, getter, structural)]
+    pub fn cause(this: &Error) -> JsValue;
+    #[wasm_bindgen(method, setter, structural)]
+    pub fn set_cause(this: &Error, cause: &JsValue);
+
+    /// The non-standard but widely supported `stack` property, containing
+    /// an engine-specific trace of the call stack at the point the error
+    /// was created.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Error/stack)
+    #[wasm_bindgen(method, getter, structural)]
+    pub fn stack(this: &Error) -> JsString;
+    #[wasm_bindgen(method, setter, structural)]
+    pub fn set_stack(this: &Error, stack: &str);
+
+    /// The message property is a human-readable description of the error.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Error/message)
+    #[wasm_bindgen(method, getter, structural)]
+    pub fn message(this: &Error) -> JsString;
+    #[wasm_bindgen(method, setter, structural)]
+    pub fn set_message(this: &Error, message: &str);
+
+    /// The name property represents a name for the type of error. The initial value is "Error".
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Error/name)
+    #[wasm_bindgen(method, getter, structural)]
+    pub fn name(this: &Error) -> JsString;
     #[wasm_bindgen(method, setter, structural)]
     pub fn set_name(this: &Error, name: &str);
 
-    /// The `toString()` method returns a string representing the specified Error object
+    /// The `toString()` method returns a string representing the specified Error object
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Error/toString)
+    #[wasm_bindgen(method, js_name = toString)]
+    pub fn to_string(this: &Error) -> JsString;
+
+    /// The static `Error.isError()` method determines whether the passed
+    /// value is an `Error` instance, including across realms, without
+    /// throwing when given a non-object.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Error/isError)
+    #[wasm_bindgen(static_method_of = Error, js_name = isError)]
+    pub fn is_error(value: &JsValue) -> bool;
+}
+
+/// A plain-data snapshot of an [`Error`]'s `name` and `message`, useful for
+/// logging or carrying an error across a boundary (e.g. a worker message)
+/// that can't hold a live JS value.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ErrorReport {
+    /// The error's `name`, e.g. `"TypeError"`.
+    pub name: String,
+    /// The error's `message`.
+    pub message: String,
+}
+
+impl Error {
+    /// Snapshots this error's `name` and `message` into an [`ErrorReport`].
+    pub fn to_report(&self) -> ErrorReport {
+        ErrorReport {
+            name: self.name().into(),
+            message: self.message().into(),
+        }
+    }
+
+    /// Builds a new [`Error`] from a previously captured [`ErrorReport`],
+    /// restoring its `name` and `message`.
+    pub fn from_report(report: &ErrorReport) -> Error {
+        let error = Error::new(&report.message);
+        error.set_name(&report.name);
+        error
+    }
+
+    /// Parses this error's [`stack`](Self::stack) into structured
+    /// [`StackFrame`]s, via [`error::parse_stack`].
+    pub fn frames(&self) -> Vec<error::StackFrame> {
+        error::parse_stack(&String::from(self.stack()))
+    }
+
+    /// Like [`frames`](Self::frames), but omits frames whose `file`
+    /// contains `"wasm"` -- useful for hiding the wasm module's own
+    /// internal frames from a user-facing error report.
+    pub fn frames_strip_wasm(&self) -> Vec<error::StackFrame> {
+        self.frames()
+            .into_iter()
+            .filter(|frame| !frame.file.as_deref().unwrap_or_default().contains("wasm"))
+            .collect()
+    }
+}
+
+/// Parsing an [`Error`]'s engine-specific
+/// [`stack`](Error::stack) string into structured frames.
+pub mod error {
+    use super::*;
+
+    /// A single parsed frame of an [`Error`]'s stack trace.
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct StackFrame {
+        /// The function name, if the line named one. Absent for anonymous
+        /// or top-level frames.
+        pub function_name: Option<String>,
+        /// The source file or URL, if the line named one.
+        pub file: Option<String>,
+        /// The 1-based line number, if present.
+        pub line: Option<u32>,
+        /// The 1-based column number, if present.
+        pub column: Option<u32>,
+        /// The original, unparsed line, kept around for display or for
+        /// formats this parser doesn't recognize.
+        pub raw: String,
+    }
+
+    /// Parses `stack` (the engine-specific string from [`Error::stack`])
+    /// into one [`StackFrame`] per non-blank line.
+    ///
+    /// Recognizes V8's `"    at fn (file:1:2)"` format and
+    /// SpiderMonkey/JSC's `"fn@file:1:2"` format; a line matching neither
+    /// (including the first line of a V8 stack, which is the error's
+    /// `name: message`, not a frame) falls back to a frame with only
+    /// `raw` set. Never panics, no matter the input.
+    pub fn parse_stack(stack: &str) -> Vec<StackFrame> {
+        stack.lines().filter_map(parse_line).collect()
+    }
+
+    fn parse_line(line: &str) -> Option<StackFrame> {
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            return None;
+        }
+
+        Some(
+            parse_v8_line(trimmed)
+                .or_else(|| parse_spidermonkey_line(trimmed))
+                .unwrap_or_else(|| StackFrame {
+                    function_name: None,
+                    file: None,
+                    line: None,
+                    column: None,
+                    raw: String::from(line),
+                }),
+        )
+    }
+
+    /// Parses a V8-style `"at fn (file:1:2)"` or `"at file:1:2"` line.
+    fn parse_v8_line(trimmed: &str) -> Option<StackFrame> {
+        let rest = trimmed.strip_prefix("at ")?;
+
+        let (function_name, location) = match rest.strip_suffix(')') {
+            Some(rest) => {
+                let open = rest.rfind('(')?;
+                let name = rest[..open].trim();
+                let name = name.strip_suffix(" [as anonymous]").unwrap_or(name);
+                (
+                    if name.is_empty() { None } else { Some(String::from(name)) },
+                    &rest[open + 1..],
+                )
+            }
+            None => (None, rest),
+        };
+
+        let (file, line, column) = parse_file_line_column(location);
+        Some(StackFrame {
+            function_name,
+            file,
+            line,
+            column,
+            raw: String::from(trimmed),
+        })
+    }
+
+    /// Parses a SpiderMonkey/JSC-style `"fn@file:1:2"` line.
+    fn parse_spidermonkey_line(trimmed: &str) -> Option<StackFrame> {
+        let at = trimmed.find('@')?;
+        let name = trimmed[..at].trim();
+        let (file, line, column) = parse_file_line_column(&trimmed[at + 1..]);
+        Some(StackFrame {
+            function_name: if name.is_empty() { None } else { Some(String::from(name)) },
+            file,
+            line,
+            column,
+            raw: String::from(trimmed),
+        })
+    }
+
+    /// Splits a `"file:line:column"` (or just `"file"`, or `"file:line"`)
+    /// location into its parts, from the right, so a file path or URL
+    /// containing colons (e.g. `"https://host:port/path"`) is still
+    /// handled correctly.
+    fn parse_file_line_column(location: &str) -> (Option<String>, Option<u32>, Option<u32>) {
+        let location = location.trim();
+        if location.is_empty() {
+            return (None, None, None);
+        }
+
+        let mut parts = location.rsplitn(3, ':');
+        let last = parts.next();
+        let second_last = parts.next();
+        let rest = parts.next();
+
+        match (rest, second_last, last) {
+            (Some(file), Some(line), Some(column)) => match (line.parse(), column.parse()) {
+                (Ok(line), Ok(column)) => (Some(String::from(file)), Some(line), Some(column)),
+                // A `file:line` location whose file itself holds a colon
+                // (e.g. a URL with a port) lands here: `column` is the line.
+                _ => match column.parse() {
+                    Ok(line) => {
+                        let file = &location[..location.len() - column.len() - 1];
+                        (Some(String::from(file)), Some(line), None)
+                    }
+                    Err(_) => (Some(String::from(location)), None, None),
+                },
+            },
+            (None, Some(file), Some(line)) => match line.parse() {
+                Ok(line) => (Some(String::from(file)), Some(line), None),
+                Err(_) => (Some(String::from(location)), None, None),
+            },
+            _ => (Some(String::from(location)), None, None),
+        }
+    }
+}
+
+/// Builds an error-options object with its `cause` property set to `cause`,
+/// suitable for passing to `new_with_options` on [`Error`] or one of its
+/// built-in subclasses.
+fn options_with_cause(cause: &JsValue) -> Object {
+    let options = Object::new();
+    Reflect::set(options.as_ref(), &JsValue::from_str("cause"), cause).unwrap_throw();
+    options
+}
+
+/// Generates a `new_with_cause` constructor for an [`Error`] subclass that
+/// already has a `new_with_options(message, options)` binding, setting the
+/// `cause` property on construction rather than via a separate call to
+/// [`Error::set_cause`] afterwards.
+macro_rules! error_new_with_cause {
+    ($Type:ident) => {
+        impl $Type {
+            /// Like `new`, but also sets the error's `cause` to `cause`, as
+            /// if constructed with `new $Type(message, { cause })`.
+            pub fn new_with_cause(message: &str, cause: &JsValue) -> $Type {
+                $Type::new_with_options(message, &options_with_cause(cause))
+            }
+        }
+    };
+}
+
+error_new_with_cause!(Error);
+
+partialord_ord!(JsString);
+
+// EvalError
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(extends = Object, extends = Error, typescript_type = "EvalError")]
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub type EvalError;
+
+    /// The EvalError object indicates an error regarding the global eval() function. This
+    /// exception is not thrown by JavaScript anymore, however the EvalError object remains for
+    /// compatibility.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/EvalError)
+    #[wasm_bindgen(constructor)]
+    pub fn new(message: &str) -> EvalError;
+    #[wasm_bindgen(constructor)]
+    pub fn new_with_options(message: &str, options: &Object) -> EvalError;
+}
+
+error_new_with_cause!(EvalError);
+
+// Function
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(extends = Object, is_type_of = JsValue::is_function, typescript_type = "Function")]
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub type Function;
+
+    /// The `Function` constructor creates a new `Function` object. Calling the
+    /// constructor directly can create functions dynamically, but suffers from
+    /// security and similar (but far less significant) performance issues
+    /// similar to `eval`. However, unlike `eval`, the `Function` constructor
+    /// allows executing code in the global scope, prompting better programming
+    /// habits and allowing for more efficient code minification.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Function)
+    #[wasm_bindgen(constructor)]
+    pub fn new_with_args(args: &str, body: &str) -> Function;
+
+    /// The `Function` constructor creates a new `Function` object. Calling the
+    /// constructor directly can create functions dynamically, but suffers from
+    /// security and similar (but far less significant) performance issues
+    /// similar to `eval`. However, unlike `eval`, the `Function` constructor
+    /// allows executing code in the global scope, prompting better programming
+    /// habits and allowing for more efficient code minification.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Function)
+    #[wasm_bindgen(constructor)]
+    pub fn new_no_args(body: &str) -> Function;
+
+    /// The `apply()` method calls a function with a given this value, and arguments provided as an array
+    /// (or an array-like object).
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Function/apply)
+    #[wasm_bindgen(method, catch)]
+    pub fn apply(this: &Function, context: &JsValue, args: &Array) -> Result<JsValue, JsValue>;
+
+    /// The `call()` method calls a function with a given this value and
+    /// arguments provided individually.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Function/call)
+    #[wasm_bindgen(method, catch, js_name = call)]
+    pub fn call0(this: &Function, context: &JsValue) -> Result<JsValue, JsValue>;
+
+    /// The `call()` method calls a function with a given this value and
+    /// arguments provided individually.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Function/call)
+    #[wasm_bindgen(method, catch, js_name = call)]
+    pub fn call1(this: &Function, context: &JsValue, arg1: &JsValue) -> Result<JsValue, JsValue>;
+
+    /// The `call()` method calls a function with a given this value and
+    /// arguments provided individually.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Function/call)
+    #[wasm_bindgen(method, catch, js_name = call)]
+    pub fn call2(
+        this: &Function,
+        context: &JsValue,
+        arg1: &JsValue,
+        arg2: &JsValue,
+    ) -> Result<JsValue, JsValue>;
+
+    /// The `call()` method calls a function with a given this value and
+    /// arguments provided individually.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Function/call)
+    #[wasm_bindgen(method, catch, js_name = call)]
+    pub fn call3(
+        this: &Function,
+        context: &JsValue,
+        arg1: &JsValue,
+        arg2: &JsValue,
+        arg3: &JsValue,
+    ) -> Result<JsValue, JsValue>;
+
+    /// The `bind()` method creates a new function that, when called, has its this keyword set to the provided value,
+    /// with a given sequence of arguments preceding any provided when the new function is called.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Function/bind)
+    #[wasm_bindgen(method, js_name = bind)]
+    pub fn bind(this: &Function, context: &JsValue) -> Function;
+
+    /// The `bind()` method creates a new function that, when called, has its this keyword set to the provided value,
+    /// with a given sequence of arguments preceding any provided when the new function is called.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Function/bind)
+    #[wasm_bindgen(method, js_name = bind)]
+    pub fn bind0(this: &Function, context: &JsValue) -> Function;
+
+    /// The `bind()` method creates a new function that, when called, has its this keyword set to the provided value,
+    /// with a given sequence of arguments preceding any provided when the new function is called.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Function/bind)
+    #[wasm_bindgen(method, js_name = bind)]
+    pub fn bind1(this: &Function, context: &JsValue, arg1: &JsValue) -> Function;
+
+    /// The `bind()` method creates a new function that, when called, has its this keyword set to the provided value,
+    /// with a given sequence of arguments preceding any provided when the new function is called.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Function/bind)
+    #[wasm_bindgen(method, js_name = bind)]
+    pub fn bind2(this: &Function, context: &JsValue, arg1: &JsValue, arg2: &JsValue) -> Function;
+
+    /// The `bind()` method creates a new function that, when called, has its this keyword set to the provided value,
+    /// with a given sequence of arguments preceding any provided when the new function is called.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Function/bind)
+    #[wasm_bindgen(method, js_name = bind)]
+    pub fn bind3(
+        this: &Function,
+        context: &JsValue,
+        arg1: &JsValue,
+        arg2: &JsValue,
+        arg3: &JsValue,
+    ) -> Function;
+
+    /// The length property indicates the number of arguments expected by the function.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Function/length)
+    #[wasm_bindgen(method, getter, structural)]
+    pub fn length(this: &Function) -> u32;
+
+    /// A Function object's read-only name property indicates the function's
+    /// name as specified when it was created or "anonymous" for functions
+    /// created anonymously.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Function/name)
+    #[wasm_bindgen(method, getter, structural)]
+    pub fn name(this: &Function) -> JsString;
+
+    /// The `toString()` method returns a string representing the source code of the function.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Function/toString)
+    #[wasm_bindgen(method, js_name = toString)]
+    pub fn to_string(this: &Function) -> JsString;
+}
+
+impl Function {
+    /// Returns the `Function` value of this JS value if it's an instance of a
+    /// function.
+    ///
+    /// If this JS value is not an instance of a function then this returns
+    /// `None`.
+    #[deprecated(note = "recommended to use dyn_ref instead which is now equivalent")]
+    pub fn try_from(val: &JsValue) -> Option<&Function> {
+        val.dyn_ref()
+    }
+}
+
+impl Default for Function {
+    fn default() -> Self {
+        Self::new_no_args("")
+    }
+}
+
+impl Function {
+    /// Returns a new function equivalent to calling `self` and then passing
+    /// its result to `next`, i.e. `next(self(x))`. Built from a Rust
+    /// trampoline closure (no `eval`), so an error thrown by either stage
+    /// propagates out of the composed function as-is.
+    ///
+    /// The closure is leaked (via [`Closure::forget`]) for the lifetime of
+    /// the program, the same tradeoff [`Closure`] itself documents for any
+    /// fire-and-forget callback handed to JS.
+    pub fn and_then(&self, next: &Function) -> Function {
+        let this = self.clone();
+        let next = next.clone();
+        let closure = Closure::wrap(Box::new(move |arg: JsValue| -> Result<JsValue, JsValue> {
+            let intermediate = this.call1(&JsValue::UNDEFINED, &arg)?;
+            next.call1(&JsValue::UNDEFINED, &intermediate)
+        }) as Box<dyn FnMut(JsValue) -> Result<JsValue, JsValue>>);
+        let function: Function = closure.as_ref().unchecked_ref::<Function>().clone();
+        closure.forget();
+        function
+    }
+
+    /// Composes two functions left to right: `pipe2(a, b)` behaves like
+    /// `a.and_then(b)`.
+    pub fn pipe2(a: &Function, b: &Function) -> Function {
+        a.and_then(b)
+    }
+
+    /// Composes three functions left to right: the result behaves like
+    /// `c(b(a(x)))`.
+    pub fn pipe3(a: &Function, b: &Function, c: &Function) -> Function {
+        a.and_then(b).and_then(c)
+    }
+
+    /// Returns a function that returns its single argument unchanged.
+    pub fn identity() -> Function {
+        Function::new_with_args("x", "return x;")
+    }
+
+    /// Constructs a new instance of `self` (as if by `new self(...args)`,
+    /// via [`Reflect::construct`]) and casts the result to `T`.
+    ///
+    /// Errors if construction throws, or if the constructed instance is not
+    /// actually a `T`.
+    pub fn construct<T: JsCast>(&self, args: &Array) -> Result<T, JsValue> {
+        let instance = Reflect::construct(self, args)?;
+        instance.dyn_into::<T>()
+    }
+
+    /// Like [`Function::construct`], with no constructor arguments.
+    pub fn construct0<T: JsCast>(&self) -> Result<T, JsValue> {
+        self.construct(&Array::new())
+    }
+
+    /// Like [`Function::construct`], with a single constructor argument.
+    pub fn construct1<T: JsCast>(&self, arg1: &JsValue) -> Result<T, JsValue> {
+        let args = Array::new();
+        args.push(arg1);
+        self.construct(&args)
+    }
+
+    /// Like [`Function::construct`], with two constructor arguments.
+    pub fn construct2<T: JsCast>(&self, arg1: &JsValue, arg2: &JsValue) -> Result<T, JsValue> {
+        let args = Array::new();
+        args.push(arg1);
+        args.push(arg2);
+        self.construct(&args)
+    }
+
+    /// Calls this function with `context` as `this` and `args` as the
+    /// individual arguments, for any arity -- unlike [`Function::call0`]
+    /// through [`Function::call3`], which only cover up to three arguments.
+    ///
+    /// Built on top of [`Function::apply`] rather than `call0`/`call1`/etc,
+    /// since the number of arguments isn't known until runtime.
+    pub fn call_n(&self, context: &JsValue, args: &[&JsValue]) -> Result<JsValue, JsValue> {
+        let array = Array::new();
+        for arg in args {
+            array.push(arg);
+        }
+        self.apply(context, &array)
+    }
+
+    /// Returns the function's declared arity (its `length` property, i.e.
+    /// the number of parameters before the first default-valued or rest
+    /// parameter), or `None` if `value` isn't callable.
+    pub fn arity_of(value: &JsValue) -> Option<u32> {
+        let function = value.dyn_ref::<Function>()?;
+        Some(function.length())
+    }
+
+    /// Partially applies no arguments, just fixing `this` to `undefined`.
+    /// Sugar for `self.bind0(&JsValue::UNDEFINED)`.
+    pub fn partial0(&self) -> Function {
+        self.bind0(&JsValue::UNDEFINED)
+    }
+
+    /// Partially applies `arg1`, leaving `this` as `undefined`. Sugar for
+    /// `self.bind1(&JsValue::UNDEFINED, arg1)`.
+    pub fn partial1(&self, arg1: &JsValue) -> Function {
+        self.bind1(&JsValue::UNDEFINED, arg1)
+    }
+
+    /// Partially applies `arg1` and `arg2`, leaving `this` as `undefined`.
+    pub fn partial2(&self, arg1: &JsValue, arg2: &JsValue) -> Function {
+        self.bind2(&JsValue::UNDEFINED, arg1, arg2)
+    }
+
+    /// Partially applies `arg1` through `arg3`, leaving `this` as
+    /// `undefined`.
+    pub fn partial3(&self, arg1: &JsValue, arg2: &JsValue, arg3: &JsValue) -> Function {
+        self.bind3(&JsValue::UNDEFINED, arg1, arg2, arg3)
+    }
+
+    /// Remembers `ctx` as the `this` to use for every subsequent call, so
+    /// callers don't have to repeat it. See [`BoundFunction`].
+    pub fn with_this(&self, ctx: &JsValue) -> BoundFunction {
+        BoundFunction {
+            function: self.clone(),
+            context: ctx.clone(),
+        }
+    }
+
+    /// Returns a Rust closure that calls `self` with a single argument and
+    /// `this` set to `undefined`, for the common case of calling a
+    /// single-argument function repeatedly without re-threading the
+    /// context at each call site.
+    pub fn curry1(&self) -> impl Fn(&JsValue) -> Result<JsValue, JsValue> + '_ {
+        move |arg1: &JsValue| self.call1(&JsValue::UNDEFINED, arg1)
+    }
+
+    /// Wraps this single-argument function in a memoizing layer: calling
+    /// the result a second time with an argument that's `SameValueZero`-equal
+    /// (the equality a `Map` key uses) to a previous one returns the cached
+    /// result instead of calling `self` again. A thrown error is never
+    /// cached, so the next call with that argument retries `self`.
+    ///
+    /// Use [`memo_stats`] to read back the wrapper's hit/miss counts. The
+    /// closure backing the wrapper is leaked via [`Closure::forget`], the
+    /// same tradeoff documented on [`Function::and_then`].
+    pub fn memoized(&self) -> Function {
+        self.memoized_with_capacity(usize::MAX)
+    }
+
+    /// Like [`Function::memoized`], but once more than `limit` entries have
+    /// accumulated, evicts the oldest one (by insertion order) so a
+    /// long-running caller doesn't grow the cache unboundedly.
+    pub fn memoized_with_capacity(&self, limit: usize) -> Function {
+        let this = self.clone();
+        let cache = Map::new();
+        let order = Array::new();
+        let hits = Rc::new(RefCell::new(0u32));
+        let misses = Rc::new(RefCell::new(0u32));
+
+        let call_cache = cache.clone();
+        let call_order = order.clone();
+        let call_hits = Rc::clone(&hits);
+        let call_misses = Rc::clone(&misses);
+
+        let closure = Closure::wrap(Box::new(move |arg: JsValue| -> Result<JsValue, JsValue> {
+            if call_cache.has(&arg) {
+                *call_hits.borrow_mut() += 1;
+                return Ok(call_cache.get(&arg));
+            }
+
+            let result = this.call1(&JsValue::UNDEFINED, &arg)?;
+            *call_misses.borrow_mut() += 1;
+
+            call_cache.set(&arg, &result);
+            call_order.push(&arg);
+            if call_order.length() as usize > limit {
+                let oldest = call_order.shift();
+                call_cache.delete(&oldest);
+            }
+
+            Ok(result)
+        }) as Box<dyn FnMut(JsValue) -> Result<JsValue, JsValue>>);
+        let wrapper: Function = closure.as_ref().unchecked_ref::<Function>().clone();
+        closure.forget();
+
+        let stats_closure = Closure::wrap(Box::new(move || -> Array {
+            let snapshot = Array::new();
+            snapshot.push(&JsValue::from_f64(*hits.borrow() as f64));
+            snapshot.push(&JsValue::from_f64(*misses.borrow() as f64));
+            snapshot
+        }) as Box<dyn FnMut() -> Array>);
+        let stats_fn: Function = stats_closure.as_ref().unchecked_ref::<Function>().clone();
+        stats_closure.forget();
+
+        Reflect::set(wrapper.as_ref(), memo_stats_symbol().as_ref(), stats_fn.as_ref())
+            .unwrap_throw();
+
+        wrapper
+    }
+}
+
+/// Cache-hit/miss counters for a function returned by
+/// [`Function::memoized`] or [`Function::memoized_with_capacity`], read
+/// back via [`memo_stats`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MemoStats {
+    pub hits: u32,
+    pub misses: u32,
+}
+
+/// The well-known symbol a memoized wrapper's stats accessor is stored
+/// under, so it doesn't collide with any property of the original
+/// function.
+fn memo_stats_symbol() -> Symbol {
+    Symbol::for_("js_sys::Function::memoized stats")
+}
+
+/// Returns the cache-hit/miss counters for `f`, if `f` was returned by
+/// [`Function::memoized`] or [`Function::memoized_with_capacity`]; `None`
+/// if it carries no stats side channel.
+pub fn memo_stats(f: &Function) -> Option<MemoStats> {
+    let stats_fn = Reflect::get(f.as_ref(), memo_stats_symbol().as_ref())
+        .ok()?
+        .dyn_into::<Function>()
+        .ok()?;
+    let snapshot = stats_fn.call0(&JsValue::UNDEFINED).ok()?.dyn_into::<Array>().ok()?;
+
+    Some(MemoStats {
+        hits: snapshot.get(0).as_f64()? as u32,
+        misses: snapshot.get(1).as_f64()? as u32,
+    })
+}
+
+/// A [`Function`] paired with a `this` value to use on every call, so
+/// `function.with_this(ctx).call1(arg)` doesn't have to repeat `ctx` the
+/// way `function.call1(&ctx, arg)` would.
+///
+/// Mirrors [`Function`]'s own `call0`..`call3`/`call_n` API; see
+/// [`Function::with_this`].
+#[derive(Clone, Debug)]
+pub struct BoundFunction {
+    function: Function,
+    context: JsValue,
+}
+
+impl BoundFunction {
+    /// Calls the function with no arguments.
+    pub fn call0(&self) -> Result<JsValue, JsValue> {
+        self.function.call0(&self.context)
+    }
+
+    /// Calls the function with one argument.
+    pub fn call1(&self, arg1: &JsValue) -> Result<JsValue, JsValue> {
+        self.function.call1(&self.context, arg1)
+    }
+
+    /// Calls the function with two arguments.
+    pub fn call2(&self, arg1: &JsValue, arg2: &JsValue) -> Result<JsValue, JsValue> {
+        self.function.call2(&self.context, arg1, arg2)
+    }
+
+    /// Calls the function with three arguments.
+    pub fn call3(&self, arg1: &JsValue, arg2: &JsValue, arg3: &JsValue) -> Result<JsValue, JsValue> {
+        self.function.call3(&self.context, arg1, arg2, arg3)
+    }
+
+    /// Calls the function with any number of arguments, like
+    /// [`Function::call_n`].
+    pub fn call_n(&self, args: &[&JsValue]) -> Result<JsValue, JsValue> {
+        self.function.call_n(&self.context, args)
+    }
+
+    /// Partially applies `arg1` on top of the remembered `this`.
+    pub fn partial1(&self, arg1: &JsValue) -> BoundFunction {
+        BoundFunction {
+            function: self.function.bind1(&self.context, arg1),
+            context: self.context.clone(),
+        }
+    }
+}
+
+// Generator
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(extends = Object, typescript_type = "Generator<any, any, any>")]
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub type Generator;
+
+    /// The `next()` method returns an object with two properties done and value.
+    /// You can also provide a parameter to the next method to send a value to the generator.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Generator/next)
+    #[wasm_bindgen(method, structural, catch)]
+    pub fn next(this: &Generator, value: &JsValue) -> Result<JsValue, JsValue>;
+
+    /// The `return()` method returns the given value and finishes the generator.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Generator/return)
+    #[wasm_bindgen(method, structural, js_name = return)]
+    pub fn return_(this: &Generator, value: &JsValue) -> JsValue;
+
+    /// The `throw()` method resumes the execution of a generator by throwing an error into it
+    /// and returns an object with two properties done and value.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Generator/throw)
+    #[wasm_bindgen(method, structural, catch)]
+    pub fn throw(this: &Generator, error: &Error) -> Result<JsValue, JsValue>;
+}
+
+/// What to do with a value yielded by the generator being driven by
+/// [`Generator::drive`].
+pub enum DriveCommand {
+    /// Resume the generator, sending `next_value` in as the result of its
+    /// `yield` expression.
+    Continue(JsValue),
+    /// Call [`Generator::return_`] with `value`, ending the generator
+    /// early.
+    Return(JsValue),
+    /// Call [`Generator::throw`] with `error`, resuming the generator by
+    /// throwing into it (which it may catch and continue from).
+    Throw(Error),
+}
+
+impl Generator {
+    /// Drives this generator to completion, feeding each yielded value to
+    /// `on_yield` and resuming the generator according to the
+    /// [`DriveCommand`] it returns.
+    ///
+    /// Returns the generator's completion value: either the value the
+    /// generator itself returned (when it ran to completion or was ended
+    /// via [`DriveCommand::Return`]), or the value thrown by a
+    /// [`DriveCommand::Throw`] that the generator didn't catch, surfaced as
+    /// `Err`.
+    pub fn drive(&self, mut on_yield: impl FnMut(JsValue) -> DriveCommand) -> Result<JsValue, JsValue> {
+        let mut result = self.next(&JsValue::UNDEFINED)?;
+        loop {
+            let step: &IteratorNext = result.unchecked_ref();
+            if step.done() {
+                return Ok(step.value());
+            }
+            result = match on_yield(step.value()) {
+                DriveCommand::Continue(next_value) => self.next(&next_value)?,
+                DriveCommand::Return(value) => {
+                    let returned = self.return_(&value);
+                    let step: &IteratorNext = returned.unchecked_ref();
+                    return Ok(step.value());
+                }
+                DriveCommand::Throw(error) => self.throw(&error)?,
+            };
+        }
+    }
+
+    /// Runs this generator to completion, feeding `feed` back in as the
+    /// result of every `yield` expression, and collects every yielded
+    /// value in order.
+    pub fn collect_values(&self, feed: &JsValue) -> Result<Vec<JsValue>, JsValue> {
+        let mut values = Vec::new();
+        let mut result = self.next(&JsValue::UNDEFINED)?;
+        loop {
+            let step: &IteratorNext = result.unchecked_ref();
+            if step.done() {
+                return Ok(values);
+            }
+            values.push(step.value());
+            result = self.next(feed)?;
+        }
+    }
+}
+
+// Map
+#[wasm_bindgen]
+extern "C" {
+    /// Note: the derived [`Clone`] impl clones the handle to the
+    /// underlying JS `Map`, not the map itself -- the clone and the
+    /// original refer to the same object, and mutating one mutates the
+    /// other. Use [`Map::shallow_copy`] or [`Map::deep_copy`] for an
+    /// actual copy.
+    #[wasm_bindgen(extends = Object, typescript_type = "Map<any, any>")]
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub type Map;
+
+    /// The `clear()` method removes all elements from a Map object.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map/clear)
+    #[wasm_bindgen(method)]
+    pub fn clear(this: &Map);
+
+    /// The `delete()` method removes the specified element from a Map object.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map/delete)
+    #[wasm_bindgen(method)]
+    pub fn delete(this: &Map, key: &JsValue) -> bool;
+
+    /// The `forEach()` method executes a provided function once per each
+    /// key/value pair in the Map object, in insertion order.
+    /// Note that in Javascript land the `Key` and `Value` are reversed compared to normal expectations:
+    /// # Examples
+    /// ```
+    /// let js_map = Map::new();
+    /// js_map.for_each(&mut |value, key| {
+    ///     // Do something here...
+    /// })
+    /// ```
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map/forEach)
+    #[wasm_bindgen(method, js_name = forEach)]
+    pub fn for_each(this: &Map, callback: &mut dyn FnMut(JsValue, JsValue));
+
+    /// The `get()` method returns a specified element from a Map object.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map/get)
+    #[wasm_bindgen(method)]
+    pub fn get(this: &Map, key: &JsValue) -> JsValue;
+
+    /// The `has()` method returns a boolean indicating whether an element with
+    /// the specified key exists or not.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map/has)
+    #[wasm_bindgen(method)]
+    pub fn has(this: &Map, key: &JsValue) -> bool;
+
+    /// The Map object holds key-value pairs. Any value (both objects and
+    /// primitive values) maybe used as either a key or a value.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map)
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Map;
+
+    /// The `set()` method adds or updates an element with a specified key
+    /// and value to a Map object.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map/set)
+    #[wasm_bindgen(method)]
+    pub fn set(this: &Map, key: &JsValue, value: &JsValue) -> Map;
+
+    /// The value of size is an integer representing how many entries
+    /// the Map object has. A set accessor function for size is undefined;
+    /// you can not change this property.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map/size)
+    #[wasm_bindgen(method, getter, structural)]
+    pub fn size(this: &Map) -> u32;
+}
+
+impl Default for Map {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Map {
+    /// Returns whether this map has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.size() == 0
+    }
+
+    /// Adds `by` to the number stored at `key` (treated as `0.0` if `key`
+    /// is absent) and stores and returns the new total. One `get` and one
+    /// `set`, matching how the native `Map` methods themselves each cost a
+    /// single call.
+    pub fn increment(&self, key: &JsValue, by: f64) -> f64 {
+        let current = self.get(key).as_f64().unwrap_or(0.0);
+        let next = current + by;
+        self.set(key, &JsValue::from_f64(next));
+        next
+    }
+
+    /// Builds a new map with the same keys, mapping each value through
+    /// `f(value, key)`.
+    pub fn map_values(&self, f: &mut dyn FnMut(JsValue, &JsValue) -> JsValue) -> Map {
+        let out = Map::new();
+        self.for_each(&mut |value, key| {
+            let new_value = f(value, &key);
+            out.set(&key, &new_value);
+        });
+        out
+    }
+
+    /// Builds a new map with the same values, mapping each key through
+    /// `f(key, value)`. If two entries map to the same new key, the later
+    /// entry in iteration order wins, matching `Map::set`'s own overwrite
+    /// semantics.
+    pub fn map_keys(&self, f: &mut dyn FnMut(&JsValue, &JsValue) -> JsValue) -> Map {
+        let out = Map::new();
+        self.for_each(&mut |value, key| {
+            let new_key = f(&key, &value);
+            out.set(&new_key, &value);
+        });
+        out
+    }
+
+    /// Builds a new map containing only the entries for which
+    /// `pred(key, value)` returns `true`.
+    pub fn filter(&self, pred: &mut dyn FnMut(&JsValue, &JsValue) -> bool) -> Map {
+        let out = Map::new();
+        self.for_each(&mut |value, key| {
+            if pred(&key, &value) {
+                out.set(&key, &value);
+            }
+        });
+        out
+    }
+
+    /// Builds a new map containing every entry of `self` and `other`. A
+    /// key present in both uses `on_conflict(self_value, other_value)` to
+    /// decide the merged value.
+    pub fn merge(&self, other: &Map, on_conflict: &mut dyn FnMut(JsValue, JsValue) -> JsValue) -> Map {
+        let out = Map::new();
+        self.for_each(&mut |value, key| {
+            out.set(&key, &value);
+        });
+        other.for_each(&mut |value, key| {
+            if out.has(&key) {
+                let existing = out.get(&key);
+                let merged = on_conflict(existing, value);
+                out.set(&key, &merged);
+            } else {
+                out.set(&key, &value);
+            }
+        });
+        out
+    }
+
+    /// Returns a new `Map` with the same entries as `self`, as a distinct
+    /// object: mutating the copy (`set`/`delete`/`clear`) doesn't affect
+    /// `self`, and vice versa. The entries themselves are not copied -- a
+    /// key or value that's itself an object remains shared between the two
+    /// maps. See [`Map::deep_copy`] to sever that sharing too.
+    pub fn shallow_copy(&self) -> Map {
+        let out = Map::new();
+        self.for_each(&mut |value, key| {
+            out.set(&key, &value);
+        });
+        out
+    }
+
+    /// Like [`Map::shallow_copy`], but also deep-clones every key and
+    /// value via the global `structuredClone`, so no object is shared
+    /// between `self` and the result. Errors if `structuredClone` isn't
+    /// available in this environment, or if it throws (e.g. on a key or
+    /// value it can't clone, like a function).
+    pub fn deep_copy(&self) -> Result<Map, JsValue> {
+        let clone_fn = structured_clone_fn()?;
+        let out = Map::new();
+        for pair in self.entries().into_iter() {
+            let pair = pair?;
+            let pair: Array = pair.unchecked_into();
+            let key = clone_fn.call1(&JsValue::UNDEFINED, &pair.get(0))?;
+            let value = clone_fn.call1(&JsValue::UNDEFINED, &pair.get(1))?;
+            out.set(&key, &value);
+        }
+        Ok(out)
+    }
+
+    /// Returns up to `limit` `(key, value)` pairs starting at `offset`, in
+    /// insertion order, without materializing the entries before `offset`
+    /// or after `offset + limit`.
+    ///
+    /// This drives the native [`Map::entries`] iterator directly rather
+    /// than collecting every entry into a `Vec` first, so each skipped
+    /// entry costs only a `next()` call, with no `(key, value)` tuple
+    /// built for it.
+    pub fn entries_page(&self, offset: u32, limit: u32) -> Vec<(JsValue, JsValue)> {
+        self.entries()
+            .into_iter()
+            .skip(offset as usize)
+            .take(limit as usize)
+            .filter_map(|pair| {
+                let pair: Array = pair.ok()?.unchecked_into();
+                Some((pair.get(0), pair.get(1)))
+            })
+            .collect()
+    }
+
+    /// Returns the first `(key, value)` pair in insertion order, or `None`
+    /// if the map is empty.
+    pub fn first(&self) -> Option<(JsValue, JsValue)> {
+        self.entries_page(0, 1).pop()
+    }
+
+    /// Returns an iterator over a one-shot copy of this map's entries,
+    /// materialized up front, in insertion order.
+    ///
+    /// Unlike [`Map::entries`] (and everything built on it, like
+    /// [`Map::entries_page`]), which drives the live native iterator and so
+    /// observes any mutation a JS callback makes mid-iteration, this copies
+    /// every entry before returning, so later mutations of the map have no
+    /// effect on the iterator. The trade-off is the upfront cost of
+    /// collecting every entry, even if the caller only consumes a few.
+    pub fn iter_snapshot(&self) -> alloc::vec::IntoIter<(JsValue, JsValue)> {
+        self.entries_page(0, u32::MAX).into_iter()
+    }
+
+    /// Returns the key of the `n`th entry in insertion order, or `None` if
+    /// there are fewer than `n + 1` entries.
+    pub fn nth_key(&self, n: u32) -> Option<JsValue> {
+        self.keys()
+            .into_iter()
+            .nth(n as usize)
+            .and_then(|value| value.ok())
+    }
+
+    /// Builds a new map with keys and values swapped: each value of
+    /// `self` becomes a key, mapped to the key it came from. If two
+    /// entries share a value, the later one in iteration order wins,
+    /// matching [`Map::set`]'s own overwrite semantics. See
+    /// [`Map::invert_multi`] to keep every colliding key instead of just
+    /// the last.
+    pub fn invert(&self) -> Map {
+        let out = Map::new();
+        self.for_each(&mut |value, key| {
+            out.set(&value, &key);
+        });
+        out
+    }
+
+    /// Like [`Map::invert`], but a value shared by multiple entries maps
+    /// to an [`Array`] of every key that had it, in iteration order,
+    /// rather than just the last one.
+    pub fn invert_multi(&self) -> Map {
+        let out = Map::new();
+        self.for_each(&mut |value, key| {
+            let keys: Array = if out.has(&value) {
+                out.get(&value).unchecked_into()
+            } else {
+                let keys = Array::new();
+                out.set(&value, keys.as_ref());
+                keys
+            };
+            keys.push(&key);
+        });
+        out
+    }
+
+    /// Converts this map into an `Array` of `[key, value]` pair arrays, in
+    /// insertion order, via a single `Array.from` call over
+    /// [`Map::entries`] -- the inverse of [`Map::from_entries_array`].
+    pub fn to_entries_array(&self) -> Array {
+        Array::from(self.entries().as_ref())
+    }
+
+    /// Builds a new `Map` from an `Array` of `[key, value]` pair arrays,
+    /// the inverse of [`Map::to_entries_array`]. Later duplicate keys
+    /// overwrite earlier ones, matching [`Map::set`]'s own semantics.
+    pub fn from_entries_array(arr: &Array) -> Map {
+        let out = Map::new();
+        for pair in arr.iter() {
+            let pair: Array = pair.unchecked_into();
+            out.set(&pair.get(0), &pair.get(1));
+        }
+        out
+    }
+
+    /// Converts this map into a plain `Object`, one property per entry,
+    /// using each key, which must be a string, as the property name.
+    ///
+    /// Errors with the index of the first non-string key encountered, in
+    /// iteration order, rather than silently coercing it.
+    pub fn to_object_string_keys(&self) -> Result<Object, NonStringKey> {
+        let out = Object::new();
+        for (index, (key, value)) in self.iter_snapshot().enumerate() {
+            match key.as_string() {
+                Some(key) => {
+                    let _ = Reflect::set(out.as_ref(), &JsValue::from_str(&key), &value);
+                }
+                None => return Err(NonStringKey { index: index as u32 }),
+            }
+        }
+        Ok(out)
+    }
+
+    /// Builds a new `Map` from `obj`'s own enumerable string-keyed
+    /// properties, via a single [`Object::entries`] call -- each property
+    /// name becomes a [`JsString`] key.
+    pub fn from_object(obj: &Object) -> Map {
+        let out = Map::new();
+        for pair in Object::entries(obj).iter() {
+            let pair: Array = pair.unchecked_into();
+            out.set(&pair.get(0), &pair.get(1));
+        }
+        out
+    }
+}
+
+/// A [`Map`] key that wasn't a string, encountered while converting to a
+/// plain `Object` with [`Map::to_object_string_keys`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NonStringKey {
+    /// The iteration-order index of the offending entry.
+    pub index: u32,
+}
+
+impl fmt::Display for NonStringKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "map entry {} has a non-string key", self.index)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for NonStringKey {}
+
+/// Looks up the global `structuredClone` function, if this environment
+/// provides one.
+fn structured_clone_fn() -> Result<Function, JsValue> {
+    let value = Reflect::get(global().as_ref(), &JsValue::from_str("structuredClone"))?;
+    value.dyn_into::<Function>().map_err(|_| {
+        JsValue::from(Error::new(
+            "structuredClone is not available in this environment",
+        ))
+    })
+}
+
+// Map Iterator
+#[wasm_bindgen]
+extern "C" {
+    /// The `entries()` method returns a new Iterator object that contains
+    /// the [key, value] pairs for each element in the Map object in
+    /// insertion order.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map/entries)
+    #[wasm_bindgen(method)]
+    pub fn entries(this: &Map) -> Iterator;
+
+    /// The `keys()` method returns a new Iterator object that contains the
+    /// keys for each element in the Map object in insertion order.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map/keys)
+    #[wasm_bindgen(method)]
+    pub fn keys(this: &Map) -> Iterator;
+
+    /// The `values()` method returns a new Iterator object that contains the
+    /// values for each element in the Map object in insertion order.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map/values)
+    #[wasm_bindgen(method)]
+    pub fn values(this: &Map) -> Iterator;
+}
+
+// Iterator
+#[wasm_bindgen]
+extern "C" {
+    /// Any object that conforms to the JS iterator protocol. For example,
+    /// something returned by `myArray[Symbol.iterator]()`.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Iteration_protocols)
+    #[derive(Clone, Debug)]
+    #[wasm_bindgen(is_type_of = Iterator::looks_like_iterator, typescript_type = "Iterator<any>")]
+    pub type Iterator;
+
+    /// The `next()` method always has to return an object with appropriate
+    /// properties including done and value. If a non-object value gets returned
+    /// (such as false or undefined), a TypeError ("iterator.next() returned a
+    /// non-object value") will be thrown.
+    #[wasm_bindgen(catch, method, structural)]
+    pub fn next(this: &Iterator) -> Result<IteratorNext, JsValue>;
+}
+
+impl Iterator {
+    fn looks_like_iterator(it: &JsValue) -> bool {
+        #[wasm_bindgen]
+        extern "C" {
+            type MaybeIterator;
+
+            #[wasm_bindgen(method, getter)]
+            fn next(this: &MaybeIterator) -> JsValue;
+        }
+
+        if !it.is_object() {
+            return false;
+        }
+
+        let it = it.unchecked_ref::<MaybeIterator>();
+
+        it.next().is_function()
+    }
+
+    /// Builds a JS iterator backed by a Rust closure: each call to the
+    /// result's `next()` calls `f`, and an `Err` it returns is thrown as
+    /// the JS exception. The closure is leaked for the lifetime of the
+    /// returned iterator, the usual [`Closure::forget`] tradeoff any
+    /// fire-and-forget callback handed to JS makes in this crate.
+    fn from_next(mut f: impl FnMut() -> Result<IteratorNext, JsValue> + 'static) -> Iterator {
+        let closure = Closure::wrap(Box::new(move |_this: JsValue| -> Result<IteratorNext, JsValue> {
+            f()
+        }) as Box<dyn FnMut(JsValue) -> Result<IteratorNext, JsValue>>);
+        let next_function: Function = closure.as_ref().unchecked_ref::<Function>().clone();
+        closure.forget();
+
+        let obj = Object::new();
+        let _ = Reflect::set(obj.as_ref(), &JsValue::from_str("next"), next_function.as_ref());
+        obj.unchecked_into()
+    }
+
+    /// Combines `self` and `other` into a new JS iterator of `[a, b]`
+    /// two-element [`Array`]s, pulling one item from each side per
+    /// `next()` call and finishing as soon as either side does. Both
+    /// sides are pulled lazily, `self` first: when `self` is the shorter
+    /// side nothing past its end is requested from `other`, and when
+    /// `other` is the shorter side one extra item is pulled from `self`
+    /// before the end is seen.
+    pub fn zip_js(&self, other: &Iterator) -> Iterator {
+        let a = self.clone();
+        let b = other.clone();
+        Iterator::from_next(move || {
+            let an = a.next()?;
+            if an.done() {
+                return Ok(iterator_done());
+            }
+            let bn = b.next()?;
+            if bn.done() {
+                return Ok(iterator_done());
+            }
+            let pair = Array::new();
+            pair.push(&an.value());
+            pair.push(&bn.value());
+            Ok(iterator_value(&pair.into()))
+        })
+    }
+
+    /// Wraps `self` in a new JS iterator of `[index, value]` two-element
+    /// [`Array`]s, pairing each yielded value with its zero-based index as
+    /// it's pulled, lazily.
+    pub fn enumerate_js(&self) -> Iterator {
+        let it = self.clone();
+        let mut index: u32 = 0;
+        Iterator::from_next(move || {
+            let n = it.next()?;
+            if n.done() {
+                return Ok(iterator_done());
+            }
+            let pair = Array::new();
+            pair.push(&Number::from(index));
+            pair.push(&n.value());
+            index += 1;
+            Ok(iterator_value(&pair.into()))
+        })
+    }
+
+    /// Combines `self` and `other` into a native Rust iterator of
+    /// `(Result<JsValue, JsValue>, Result<JsValue, JsValue>)` pairs, for
+    /// when the JS-side laziness of [`zip_js`](Self::zip_js) isn't needed
+    /// and plain Rust iterator combinators are more convenient. Like
+    /// [`core::iter::Iterator::zip`], stops as soon as either side's
+    /// `next()` returns `None` (i.e. `done`).
+    pub fn zip_rust(&self, other: &Iterator) -> core::iter::Zip<IntoIter, IntoIter> {
+        self.clone().into_iter().zip(other.clone())
+    }
+}
+
+fn iterator_done() -> IteratorNext {
+    let obj = Object::new();
+    let _ = Reflect::set(obj.as_ref(), &JsValue::from_str("done"), &JsValue::TRUE);
+    obj.unchecked_into()
+}
+
+fn iterator_value(value: &JsValue) -> IteratorNext {
+    let obj = Object::new();
+    let _ = Reflect::set(obj.as_ref(), &JsValue::from_str("done"), &JsValue::FALSE);
+    let _ = Reflect::set(obj.as_ref(), &JsValue::from_str("value"), value);
+    obj.unchecked_into()
+}
+
+// Async Iterator
+#[wasm_bindgen]
+extern "C" {
+    /// Any object that conforms to the JS async iterator protocol. For example,
+    /// something returned by `myObject[Symbol.asyncIterator]()`.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/for-await...of)
+    #[derive(Clone, Debug)]
+    #[wasm_bindgen(is_type_of = Iterator::looks_like_iterator, typescript_type = "AsyncIterator<any>")]
+    pub type AsyncIterator;
+
+    /// The `next()` method always has to return a Promise which resolves to an object
+    /// with appropriate properties including done and value. If a non-object value
+    /// gets returned (such as false or undefined), a TypeError ("iterator.next()
+    /// returned a non-object value") will be thrown.
+    #[wasm_bindgen(catch, method, structural)]
+    pub fn next(this: &AsyncIterator) -> Result<Promise, JsValue>;
+}
+
+impl AsyncIterator {
+    /// Wraps a Rust [`core::iter::Iterator`] into a JS object conforming to
+    /// the async iterator protocol: each `next()` call pulls the next item
+    /// out of `iter` and hands it back already wrapped in a resolved (or,
+    /// for an `Err`, rejected) [`Promise`].
+    ///
+    /// A full bridge from a Rust async source (something that itself
+    /// awaits between items, i.e. a `Stream`) would need the `futures`
+    /// crate's `Stream` trait, which isn't a dependency of this crate, so
+    /// this only covers synchronous Rust iterators -- good enough to
+    /// expose existing Rust data as something [`Array::from_async`] or a
+    /// `for await` loop on the JS side can consume. The closure backing
+    /// the returned iterator is leaked for its lifetime, the usual
+    /// [`Closure::forget`] tradeoff any fire-and-forget callback handed to
+    /// JS makes in this crate.
+    pub fn from_rust_iter<I>(iter: I) -> AsyncIterator
+    where
+        I: core::iter::Iterator<Item = Result<JsValue, JsValue>> + 'static,
+    {
+        let iter = Rc::new(RefCell::new(iter));
+        let closure = Closure::wrap(Box::new(move |_this: JsValue| -> Promise {
+            match iter.borrow_mut().next() {
+                Some(Ok(value)) => Promise::resolve(&iterator_value(&value).into()),
+                Some(Err(err)) => Promise::reject(&err),
+                None => Promise::resolve(&iterator_done().into()),
+            }
+        }) as Box<dyn FnMut(JsValue) -> Promise>);
+        let next_function: Function = closure.as_ref().unchecked_ref::<Function>().clone();
+        closure.forget();
+
+        let obj = Object::new();
+        let _ = Reflect::set(obj.as_ref(), &JsValue::from_str("next"), next_function.as_ref());
+        obj.unchecked_into()
+    }
+}
+
+/// An iterator over the JS `Symbol.iterator` iteration protocol.
+///
+/// Use the `IntoIterator for &js_sys::Iterator` implementation to create this.
+pub struct Iter<'a> {
+    js: &'a Iterator,
+    state: IterState,
+}
+
+/// An iterator over the JS `Symbol.iterator` iteration protocol.
+///
+/// Use the `IntoIterator for js_sys::Iterator` implementation to create this.
+pub struct IntoIter {
+    js: Iterator,
+    state: IterState,
+}
+
+struct IterState {
+    done: bool,
+}
+
+impl<'a> IntoIterator for &'a Iterator {
+    type Item = Result<JsValue, JsValue>;
+    type IntoIter = Iter<'a>;
+
+    fn into_iter(self) -> Iter<'a> {
+        Iter {
+            js: self,
+            state: IterState::new(),
+        }
+    }
+}
+
+impl core::iter::Iterator for Iter<'_> {
+    type Item = Result<JsValue, JsValue>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.state.next(self.js)
+    }
+}
+
+impl IntoIterator for Iterator {
+    type Item = Result<JsValue, JsValue>;
+    type IntoIter = IntoIter;
+
+    fn into_iter(self) -> IntoIter {
+        IntoIter {
+            js: self,
+            state: IterState::new(),
+        }
+    }
+}
+
+impl core::iter::Iterator for IntoIter {
+    type Item = Result<JsValue, JsValue>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.state.next(&self.js)
+    }
+}
+
+impl IterState {
+    fn new() -> IterState {
+        IterState { done: false }
+    }
+
+    fn next(&mut self, js: &Iterator) -> Option<Result<JsValue, JsValue>> {
+        if self.done {
+            return None;
+        }
+        let next = match js.next() {
+            Ok(val) => val,
+            Err(e) => {
+                self.done = true;
+                return Some(Err(e));
+            }
+        };
+        if next.done() {
+            self.done = true;
+            None
+        } else {
+            #[cfg(feature = "call-metrics")]
+            metrics::record(metrics::Category::ElementGet);
+            Some(Ok(next.value()))
+        }
+    }
+}
+
+/// Create an iterator over `val` using the JS iteration protocol and
+/// `Symbol.iterator`.
+pub fn try_iter(val: &JsValue) -> Result<Option<IntoIter>, JsValue> {
+    let iter_sym = Symbol::iterator();
+    let iter_fn = Reflect::get(val, iter_sym.as_ref())?;
+
+    let iter_fn: Function = match iter_fn.dyn_into() {
+        Ok(iter_fn) => iter_fn,
+        Err(_) => return Ok(None),
+    };
+
+    let it: Iterator = match iter_fn.call0(val)?.dyn_into() {
+        Ok(it) => it,
+        Err(_) => return Ok(None),
+    };
+
+    Ok(Some(it.into_iter()))
+}
+
+/// Returns `true` if `value` is `null` or `undefined`, the two values the
+/// JS `??` operator and `?.` operator treat as "nullish".
+pub fn is_nullish(value: &JsValue) -> bool {
+    value.is_null() || value.is_undefined()
+}
+
+/// Applies the binary `??` JS operator: returns `a` unless it's nullish
+/// (`null` or `undefined`), in which case returns `b`.
+pub fn nullish_coalesce<'a>(a: &'a JsValue, b: &'a JsValue) -> &'a JsValue {
+    if is_nullish(a) {
+        b
+    } else {
+        a
+    }
+}
+
+// IteratorNext
+#[wasm_bindgen]
+extern "C" {
+    /// The result of calling `next()` on a JS iterator.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Iteration_protocols)
+    #[wasm_bindgen(extends = Object, typescript_type = "IteratorResult<any>")]
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub type IteratorNext;
+
+    /// Has the value `true` if the iterator is past the end of the iterated
+    /// sequence. In this case value optionally specifies the return value of
+    /// the iterator.
+    ///
+    /// Has the value `false` if the iterator was able to produce the next value
+    /// in the sequence. This is equivalent of not specifying the done property
+    /// altogether.
+    #[wasm_bindgen(method, getter, structural)]
+    pub fn done(this: &IteratorNext) -> bool;
+
+    /// Any JavaScript value returned by the iterator. Can be omitted when done
+    /// is true.
+    #[wasm_bindgen(method, getter, structural)]
+    pub fn value(this: &IteratorNext) -> JsValue;
+}
+
+#[allow(non_snake_case)]
+pub mod Math {
+    use super::*;
+
+    // Math
+    #[wasm_bindgen]
+    extern "C" {
+        /// The `Math.abs()` function returns the absolute value of a number, that is
+        /// Math.abs(x) = |x|
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/abs)
+        #[wasm_bindgen(js_namespace = Math)]
+        pub fn abs(x: f64) -> f64;
+
+        /// The `Math.acos()` function returns the arccosine (in radians) of a
+        /// number, that is ∀x∊[-1;1]
+        /// Math.acos(x) = arccos(x) = the unique y∊[0;π] such that cos(y)=x
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/acos)
+        #[wasm_bindgen(js_namespace = Math)]
+        pub fn acos(x: f64) -> f64;
+
+        /// The `Math.acosh()` function returns the hyperbolic arc-cosine of a
+        /// number, that is ∀x ≥ 1
+        /// Math.acosh(x) = arcosh(x) = the unique y ≥ 0 such that cosh(y) = x
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/acosh)
+        #[wasm_bindgen(js_namespace = Math)]
+        pub fn acosh(x: f64) -> f64;
+
+        /// The `Math.asin()` function returns the arcsine (in radians) of a
+        /// number, that is ∀x ∊ [-1;1]
+        /// Math.asin(x) = arcsin(x) = the unique y∊[-π2;π2] such that sin(y) = x
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/asin)
+        #[wasm_bindgen(js_namespace = Math)]
+        pub fn asin(x: f64) -> f64;
+
+        /// The `Math.asinh()` function returns the hyperbolic arcsine of a
+        /// number, that is Math.asinh(x) = arsinh(x) = the unique y such that sinh(y) = x
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/asinh)
+        #[wasm_bindgen(js_namespace = Math)]
+        pub fn asinh(x: f64) -> f64;
+
+        /// The `Math.atan()` function returns the arctangent (in radians) of a
+        /// number, that is Math.atan(x) = arctan(x) = the unique y ∊ [-π2;π2]such that
+        /// tan(y) = x
+        #[wasm_bindgen(js_namespace = Math)]
+        pub fn atan(x: f64) -> f64;
+
+        /// The `Math.atan2()` function returns the arctangent of the quotient of
+        /// its arguments.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/atan2)
+        #[wasm_bindgen(js_namespace = Math)]
+        pub fn atan2(y: f64, x: f64) -> f64;
+
+        /// The `Math.atanh()` function returns the hyperbolic arctangent of a number,
+        /// that is ∀x ∊ (-1,1), Math.atanh(x) = arctanh(x) = the unique y such that
+        /// tanh(y) = x
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/atanh)
+        #[wasm_bindgen(js_namespace = Math)]
+        pub fn atanh(x: f64) -> f64;
+
+        /// The `Math.cbrt() `function returns the cube root of a number, that is
+        /// Math.cbrt(x) = ∛x = the unique y such that y^3 = x
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/cbrt)
+        #[wasm_bindgen(js_namespace = Math)]
+        pub fn cbrt(x: f64) -> f64;
+
+        /// The `Math.ceil()` function returns the smallest integer greater than
+        /// or equal to a given number.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/ceil)
+        #[wasm_bindgen(js_namespace = Math)]
+        pub fn ceil(x: f64) -> f64;
+
+        /// The `Math.clz32()` function returns the number of leading zero bits in
+        /// the 32-bit binary representation of a number.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/clz32)
+        #[wasm_bindgen(js_namespace = Math)]
+        pub fn clz32(x: i32) -> u32;
+
+        /// The `Math.cos()` static function returns the cosine of the specified angle,
+        /// which must be specified in radians. This value is length(adjacent)/length(hypotenuse).
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/cos)
+        #[wasm_bindgen(js_namespace = Math)]
+        pub fn cos(x: f64) -> f64;
+
+        /// The `Math.cosh()` function returns the hyperbolic cosine of a number,
+        /// that can be expressed using the constant e.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/cosh)
+        #[wasm_bindgen(js_namespace = Math)]
+        pub fn cosh(x: f64) -> f64;
+
+        /// The `Math.exp()` function returns e^x, where x is the argument, and e is Euler's number
+        /// (also known as Napier's constant), the base of the natural logarithms.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/exp)
+        #[wasm_bindgen(js_namespace = Math)]
+        pub fn exp(x: f64) -> f64;
+
+        /// The `Math.expm1()` function returns e^x - 1, where x is the argument, and e the base of the
+        /// natural logarithms.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/expm1)
+        #[wasm_bindgen(js_namespace = Math)]
+        pub fn expm1(x: f64) -> f64;
+
+        /// The `Math.floor()` function returns the largest integer less than or
+        /// equal to a given number.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/floor)
+        #[wasm_bindgen(js_namespace = Math)]
+        pub fn floor(x: f64) -> f64;
+
+        /// The `Math.fround()` function returns the nearest 32-bit single precision float representation
+        /// of a Number.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/fround)
+        #[wasm_bindgen(js_namespace = Math)]
+        pub fn fround(x: f64) -> f32;
+
+        /// The `Math.hypot()` function returns the square root of the sum of squares of its arguments.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/hypot)
+        #[wasm_bindgen(js_namespace = Math)]
+        pub fn hypot(x: f64, y: f64) -> f64;
+
+        /// The `Math.imul()` function returns the result of the C-like 32-bit multiplication of the
+        /// two parameters.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/imul)
+        #[wasm_bindgen(js_namespace = Math)]
+        pub fn imul(x: i32, y: i32) -> i32;
+
+        /// The `Math.log()` function returns the natural logarithm (base e) of a number.
+        /// The JavaScript `Math.log()` function is equivalent to ln(x) in mathematics.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/log)
+        #[wasm_bindgen(js_namespace = Math)]
+        pub fn log(x: f64) -> f64;
+
+        /// The `Math.log10()` function returns the base 10 logarithm of a number.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/log10)
+        #[wasm_bindgen(js_namespace = Math)]
+        pub fn log10(x: f64) -> f64;
+
+        /// The `Math.log1p()` function returns the natural logarithm (base e) of 1 + a number.
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/log1p)
+        #[wasm_bindgen(js_namespace = Math)]
+        pub fn log1p(x: f64) -> f64;
+
+        /// The `Math.log2()` function returns the base 2 logarithm of a number.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/log2)
+        #[wasm_bindgen(js_namespace = Math)]
+        pub fn log2(x: f64) -> f64;
+
+        /// The `Math.max()` function returns the largest of two numbers.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/max)
+        #[wasm_bindgen(js_namespace = Math)]
+        pub fn max(x: f64, y: f64) -> f64;
+
+        /// The static function `Math.min()` returns the lowest-valued number passed into it.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/min)
+        #[wasm_bindgen(js_namespace = Math)]
+        pub fn min(x: f64, y: f64) -> f64;
+
+        /// The `Math.pow()` function returns the base to the exponent power, that is, base^exponent.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/pow)
+        #[wasm_bindgen(js_namespace = Math)]
+        pub fn pow(base: f64, exponent: f64) -> f64;
+
+        /// The `Math.random()` function returns a floating-point, pseudo-random number
+        /// in the range 0–1 (inclusive of 0, but not 1) with approximately uniform distribution
+        /// over that range — which you can then scale to your desired range.
+        /// The implementation selects the initial seed to the random number generation algorithm;
+        /// it cannot be chosen or reset by the user.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/random)
+        #[wasm_bindgen(js_namespace = Math)]
+        pub fn random() -> f64;
+
+        /// The `Math.round()` function returns the value of a number rounded to the nearest integer.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/round)
+        #[wasm_bindgen(js_namespace = Math)]
+        pub fn round(x: f64) -> f64;
+
+        /// The `Math.sign()` function returns the sign of a number, indicating whether the number is
+        /// positive, negative or zero.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/sign)
+        #[wasm_bindgen(js_namespace = Math)]
+        pub fn sign(x: f64) -> f64;
+
+        /// The `Math.sin()` function returns the sine of a number.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/sin)
+        #[wasm_bindgen(js_namespace = Math)]
+        pub fn sin(x: f64) -> f64;
+
+        /// The `Math.sinh()` function returns the hyperbolic sine of a number, that can be expressed
+        /// using the constant e: Math.sinh(x) = (e^x - e^-x)/2
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/sinh)
+        #[wasm_bindgen(js_namespace = Math)]
+        pub fn sinh(x: f64) -> f64;
+
+        /// The `Math.sqrt()` function returns the square root of a number, that is
+        /// ∀x ≥ 0, Math.sqrt(x) = √x = the unique y ≥ 0 such that y^2 = x
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/sqrt)
+        #[wasm_bindgen(js_namespace = Math)]
+        pub fn sqrt(x: f64) -> f64;
+
+        /// The `Math.tan()` function returns the tangent of a number.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/tan)
+        #[wasm_bindgen(js_namespace = Math)]
+        pub fn tan(x: f64) -> f64;
+
+        /// The `Math.tanh()` function returns the hyperbolic tangent of a number, that is
+        /// tanh x = sinh x / cosh x = (e^x - e^-x)/(e^x + e^-x) = (e^2x - 1)/(e^2x + 1)
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/tanh)
+        #[wasm_bindgen(js_namespace = Math)]
+        pub fn tanh(x: f64) -> f64;
+
+        /// The `Math.trunc()` function returns the integer part of a number by removing any fractional
+        /// digits.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/trunc)
+        #[wasm_bindgen(js_namespace = Math)]
+        pub fn trunc(x: f64) -> f64;
+    }
+}
+
+// Number.
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(extends = Object, is_type_of = |v| v.as_f64().is_some(), typescript_type = "number")]
+    #[derive(Clone, PartialEq)]
+    pub type Number;
+
+    /// The `Number.isFinite()` method determines whether the passed value is a finite number.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/isFinite)
+    #[wasm_bindgen(static_method_of = Number, js_name = isFinite)]
+    pub fn is_finite(value: &JsValue) -> bool;
+
+    /// The `Number.isInteger()` method determines whether the passed value is an integer.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/isInteger)
+    #[wasm_bindgen(static_method_of = Number, js_name = isInteger)]
+    pub fn is_integer(value: &JsValue) -> bool;
+
+    /// The `Number.isNaN()` method determines whether the passed value is `NaN` and its type is Number.
+    /// It is a more robust version of the original, global isNaN().
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/isNaN)
+    #[wasm_bindgen(static_method_of = Number, js_name = isNaN)]
+    pub fn is_nan(value: &JsValue) -> bool;
+
+    /// The `Number.isSafeInteger()` method determines whether the provided value is a number
+    /// that is a safe integer.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/isSafeInteger)
+    #[wasm_bindgen(static_method_of = Number, js_name = isSafeInteger)]
+    pub fn is_safe_integer(value: &JsValue) -> bool;
+
+    /// The `Number` JavaScript object is a wrapper object allowing
+    /// you to work with numerical values. A `Number` object is
+    /// created using the `Number()` constructor.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number)
+    #[wasm_bindgen(constructor)]
+    #[deprecated(note = "recommended to use `Number::from` instead")]
+    #[allow(deprecated)]
+    pub fn new(value: &JsValue) -> Number;
+
+    #[wasm_bindgen(constructor)]
+    fn new_from_str(value: &str) -> Number;
+
+    /// The `Number.parseInt()` method parses a string argument and returns an
+    /// integer of the specified radix or base.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/parseInt)
+    #[wasm_bindgen(static_method_of = Number, js_name = parseInt)]
+    pub fn parse_int(text: &str, radix: u8) -> f64;
+
+    /// The `Number.parseFloat()` method parses a string argument and returns a
+    /// floating point number.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/parseFloat)
+    #[wasm_bindgen(static_method_of = Number, js_name = parseFloat)]
+    pub fn parse_float(text: &str) -> f64;
+
+    /// The `toLocaleString()` method returns a string with a language sensitive
+    /// representation of this number.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/toLocaleString)
+    #[wasm_bindgen(method, js_name = toLocaleString)]
+    pub fn to_locale_string(this: &Number, locale: &str) -> JsString;
+
+    /// The `toPrecision()` method returns a string representing the Number
+    /// object to the specified precision.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/toPrecision)
+    #[wasm_bindgen(catch, method, js_name = toPrecision)]
+    pub fn to_precision(this: &Number, precision: u8) -> Result<JsString, JsValue>;
+
+    /// The `toFixed()` method returns a string representing the Number
+    /// object using fixed-point notation.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/toFixed)
+    #[wasm_bindgen(catch, method, js_name = toFixed)]
+    pub fn to_fixed(this: &Number, digits: u8) -> Result<JsString, JsValue>;
+
+    /// The `toExponential()` method returns a string representing the Number
+    /// object in exponential notation.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/toExponential)
+    #[wasm_bindgen(catch, method, js_name = toExponential)]
+    pub fn to_exponential(this: &Number, fraction_digits: u8) -> Result<JsString, JsValue>;
+
+    /// The `toString()` method returns a string representing the
+    /// specified Number object.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/toString)
+    #[wasm_bindgen(catch, method, js_name = toString)]
+    pub fn to_string(this: &Number, radix: u8) -> Result<JsString, JsValue>;
+
+    /// The `valueOf()` method returns the wrapped primitive value of
+    /// a Number object.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/valueOf)
+    #[wasm_bindgen(method, js_name = valueOf)]
+    pub fn value_of(this: &Number) -> f64;
+}
+
+impl Number {
+    /// The smallest interval between two representable numbers.
+    ///
+    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/EPSILON)
+    pub const EPSILON: f64 = f64::EPSILON;
+    /// The maximum safe integer in JavaScript (2^53 - 1).
+    ///
+    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/MAX_SAFE_INTEGER)
+    pub const MAX_SAFE_INTEGER: f64 = 9007199254740991.0;
+    /// The largest positive representable number.
+    ///
+    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/MAX_VALUE)
+    pub const MAX_VALUE: f64 = f64::MAX;
+    /// The minimum safe integer in JavaScript (-(2^53 - 1)).
+    ///
+    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/MIN_SAFE_INTEGER)
+    pub const MIN_SAFE_INTEGER: f64 = -9007199254740991.0;
+    /// The smallest positive representable number—that is, the positive number closest to zero
+    /// (without actually being zero).
+    ///
+    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/MIN_VALUE)
+    // Cannot use f64::MIN_POSITIVE since that is the smallest **normal** positive number.
+    pub const MIN_VALUE: f64 = 5E-324;
+    /// Special "Not a Number" value.
+    ///
+    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/NaN)
+    pub const NAN: f64 = f64::NAN;
+    /// Special value representing negative infinity. Returned on overflow.
+    ///
+    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/NEGATIVE_INFINITY)
+    pub const NEGATIVE_INFINITY: f64 = f64::NEG_INFINITY;
+    /// Special value representing infinity. Returned on overflow.
+    ///
+    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/POSITIVE_INFINITY)
+    pub const POSITIVE_INFINITY: f64 = f64::INFINITY;
+
+    /// Applies the binary `**` JS operator on the two `Number`s.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Exponentiation)
+    #[inline]
+    pub fn pow(&self, rhs: &Self) -> Self {
+        JsValue::as_ref(self)
+            .pow(JsValue::as_ref(rhs))
+            .unchecked_into()
+    }
+
+    /// Applies the binary `>>>` JS operator on the two `Number`s.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Unsigned_right_shift)
+    #[inline]
+    pub fn unsigned_shr(&self, rhs: &Self) -> Self {
+        Number::from(JsValue::as_ref(self).unsigned_shr(JsValue::as_ref(rhs)))
+    }
+
+    /// Like [`Number::parse_int`], but returns a [`ParseNumError`] instead of
+    /// the NaN sentinel when `text` does not start with a valid number in the
+    /// given `radix`.
+    pub fn try_parse_int(text: &str, radix: u8) -> Result<i64, ParseNumError> {
+        let result = Number::parse_int(text, radix);
+        if result.is_nan() {
+            Err(ParseNumError(()))
+        } else {
+            Ok(result as i64)
+        }
+    }
+
+    /// Like [`Number::parse_float`], but returns a [`ParseNumError`] instead
+    /// of the NaN sentinel when `text` does not start with a valid number.
+    pub fn try_parse_float(text: &str) -> Result<f64, ParseNumError> {
+        let result = Number::parse_float(text);
+        if result.is_nan() {
+            Err(ParseNumError(()))
+        } else {
+            Ok(result)
+        }
+    }
+}
+
+/// The error type returned by [`Number::try_parse_int`] and
+/// [`Number::try_parse_float`] when the input does not start with a valid
+/// number.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ParseNumError(());
+
+impl fmt::Display for ParseNumError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str("invalid number")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseNumError {}
+
+macro_rules! number_from {
+    ($($x:ident)*) => ($(
+        impl From<$x> for Number {
+            #[inline]
+            fn from(x: $x) -> Number {
+                Number::unchecked_from_js(JsValue::from(x))
+            }
+        }
+
+        impl PartialEq<$x> for Number {
+            #[inline]
+            fn eq(&self, other: &$x) -> bool {
+                self.value_of() == f64::from(*other)
+            }
+        }
+    )*)
+}
+number_from!(i8 u8 i16 u16 i32 u32 f32 f64);
+
+/// The error type returned when a checked integral type conversion fails.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct TryFromIntError(());
+
+impl fmt::Display for TryFromIntError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str("out of range integral type conversion attempted")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TryFromIntError {}
+
+macro_rules! number_try_from {
+    ($($x:ident)*) => ($(
+        impl TryFrom<$x> for Number {
+            type Error = TryFromIntError;
+
+            #[inline]
+            fn try_from(x: $x) -> Result<Number, Self::Error> {
+                let x_f64 = x as f64;
+                if (Number::MIN_SAFE_INTEGER..=Number::MAX_SAFE_INTEGER).contains(&x_f64) {
+                    Ok(Number::from(x_f64))
+                } else {
+                    Err(TryFromIntError(()))
+                }
+            }
+        }
+    )*)
+}
+number_try_from!(i64 u64 i128 u128);
+
+// TODO: add this on the next major version, when blanket impl is removed
+/*
+impl convert::TryFrom<JsValue> for Number {
+    type Error = Error;
+
+    fn try_from(value: JsValue) -> Result<Self, Self::Error> {
+        return match f64::try_from(value) {
+            Ok(num) => Ok(Number::from(num)),
+            Err(jsval) => Err(jsval.unchecked_into())
+        }
+    }
+}
+*/
+
+impl From<&Number> for f64 {
+    #[inline]
+    fn from(n: &Number) -> f64 {
+        n.value_of()
+    }
+}
+
+impl From<Number> for f64 {
+    #[inline]
+    fn from(n: Number) -> f64 {
+        <f64 as From<&'_ Number>>::from(&n)
+    }
+}
+
+impl fmt::Debug for Number {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.value_of(), f)
+    }
+}
+
+impl fmt::Display for Number {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.value_of(), f)
+    }
+}
+
+impl Default for Number {
+    fn default() -> Self {
+        Self::from(f64::default())
+    }
+}
+
+impl PartialEq<BigInt> for Number {
+    #[inline]
+    fn eq(&self, other: &BigInt) -> bool {
+        JsValue::as_ref(self).loose_eq(JsValue::as_ref(other))
+    }
+}
+
+impl Not for &Number {
+    type Output = BigInt;
+
+    #[inline]
+    fn not(self) -> Self::Output {
+        JsValue::as_ref(self).bit_not().unchecked_into()
+    }
+}
+
+forward_deref_unop!(impl Not, not for Number);
+forward_js_unop!(impl Neg, neg for Number);
+forward_js_binop!(impl BitAnd, bitand for Number);
+forward_js_binop!(impl BitOr, bitor for Number);
+forward_js_binop!(impl BitXor, bitxor for Number);
+forward_js_binop!(impl Shl, shl for Number);
+forward_js_binop!(impl Shr, shr for Number);
+forward_js_binop!(impl Add, add for Number);
+forward_js_binop!(impl Sub, sub for Number);
+forward_js_binop!(impl Div, div for Number);
+forward_js_binop!(impl Mul, mul for Number);
+forward_js_binop!(impl Rem, rem for Number);
+
+sum_product!(Number);
+
+impl PartialOrd for Number {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if Number::is_nan(self) || Number::is_nan(other) {
+            None
+        } else if self == other {
+            Some(Ordering::Equal)
+        } else if self.lt(other) {
+            Some(Ordering::Less)
+        } else {
+            Some(Ordering::Greater)
+        }
+    }
+
+    #[inline]
+    fn lt(&self, other: &Self) -> bool {
+        JsValue::as_ref(self).lt(JsValue::as_ref(other))
+    }
+
+    #[inline]
+    fn le(&self, other: &Self) -> bool {
+        JsValue::as_ref(self).le(JsValue::as_ref(other))
+    }
+
+    #[inline]
+    fn ge(&self, other: &Self) -> bool {
+        JsValue::as_ref(self).ge(JsValue::as_ref(other))
+    }
+
+    #[inline]
+    fn gt(&self, other: &Self) -> bool {
+        JsValue::as_ref(self).gt(JsValue::as_ref(other))
+    }
+}
+
+impl FromStr for Number {
+    type Err = Infallible;
+
+    #[allow(deprecated)]
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Number::new_from_str(s))
+    }
+}
+
+// Date.
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(extends = Object, typescript_type = "Date")]
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub type Date;
+
+    /// The `getDate()` method returns the day of the month for the
+    /// specified date according to local time.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getDate)
+    #[wasm_bindgen(method, js_name = getDate)]
+    pub fn get_date(this: &Date) -> u32;
+
+    /// The `getDay()` method returns the day of the week for the specified date according to local time,
+    /// where 0 represents Sunday. For the day of the month see getDate().
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getDay)
+    #[wasm_bindgen(method, js_name = getDay)]
+    pub fn get_day(this: &Date) -> u32;
+
+    /// The `getFullYear()` method returns the year of the specified date according to local time.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getFullYear)
+    #[wasm_bindgen(method, js_name = getFullYear)]
+    pub fn get_full_year(this: &Date) -> u32;
+
+    /// The `getHours()` method returns the hour for the specified date, according to local time.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getHours)
+    #[wasm_bindgen(method, js_name = getHours)]
+    pub fn get_hours(this: &Date) -> u32;
+
+    /// The `getMilliseconds()` method returns the milliseconds in the specified date according to local time.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getMilliseconds)
+    #[wasm_bindgen(method, js_name = getMilliseconds)]
+    pub fn get_milliseconds(this: &Date) -> u32;
+
+    /// The `getMinutes()` method returns the minutes in the specified date according to local time.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getMinutes)
+    #[wasm_bindgen(method, js_name = getMinutes)]
+    pub fn get_minutes(this: &Date) -> u32;
+
+    /// The `getMonth()` method returns the month in the specified date according to local time,
+    /// as a zero-based value (where zero indicates the first month of the year).
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getMonth)
+    #[wasm_bindgen(method, js_name = getMonth)]
+    pub fn get_month(this: &Date) -> u32;
+
+    /// The `getSeconds()` method returns the seconds in the specified date according to local time.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getSeconds)
+    #[wasm_bindgen(method, js_name = getSeconds)]
+    pub fn get_seconds(this: &Date) -> u32;
+
+    /// The `getTime()` method returns the numeric value corresponding to the time for the specified date
+    /// according to universal time.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getTime)
+    #[wasm_bindgen(method, js_name = getTime)]
+    pub fn get_time(this: &Date) -> f64;
+
+    /// The `getTimezoneOffset()` method returns the time zone difference, in minutes,
+    /// from current locale (host system settings) to UTC.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getTimezoneOffset)
+    #[wasm_bindgen(method, js_name = getTimezoneOffset)]
+    pub fn get_timezone_offset(this: &Date) -> f64;
+
+    /// The `getUTCDate()` method returns the day (date) of the month in the specified date
+    /// according to universal time.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getUTCDate)
+    #[wasm_bindgen(method, js_name = getUTCDate)]
+    pub fn get_utc_date(this: &Date) -> u32;
+
+    /// The `getUTCDay()` method returns the day of the week in the specified date according to universal time,
+    /// where 0 represents Sunday.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getUTCDay)
+    #[wasm_bindgen(method, js_name = getUTCDay)]
+    pub fn get_utc_day(this: &Date) -> u32;
+
+    /// The `getUTCFullYear()` method returns the year in the specified date according to universal time.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getUTCFullYear)
+    #[wasm_bindgen(method, js_name = getUTCFullYear)]
+    pub fn get_utc_full_year(this: &Date) -> u32;
+
+    /// The `getUTCHours()` method returns the hours in the specified date according to universal time.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getUTCHours)
+    #[wasm_bindgen(method, js_name = getUTCHours)]
+    pub fn get_utc_hours(this: &Date) -> u32;
+
+    /// The `getUTCMilliseconds()` method returns the milliseconds in the specified date
+    /// according to universal time.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getUTCMilliseconds)
+    #[wasm_bindgen(method, js_name = getUTCMilliseconds)]
+    pub fn get_utc_milliseconds(this: &Date) -> u32;
+
+    /// The `getUTCMinutes()` method returns the minutes in the specified date according to universal time.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getUTCMinutes)
+    #[wasm_bindgen(method, js_name = getUTCMinutes)]
+    pub fn get_utc_minutes(this: &Date) -> u32;
+
+    /// The `getUTCMonth()` returns the month of the specified date according to universal time,
+    /// as a zero-based value (where zero indicates the first month of the year).
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getUTCMonth)
+    #[wasm_bindgen(method, js_name = getUTCMonth)]
+    pub fn get_utc_month(this: &Date) -> u32;
+
+    /// The `getUTCSeconds()` method returns the seconds in the specified date according to universal time.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getUTCSeconds)
+    #[wasm_bindgen(method, js_name = getUTCSeconds)]
+    pub fn get_utc_seconds(this: &Date) -> u32;
+
+    /// Creates a JavaScript `Date` instance that represents
+    /// a single moment in time. `Date` objects are based on a time value that is
+    /// the number of milliseconds since 1 January 1970 UTC.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date)
+    #[wasm_bindgen(constructor)]
+    pub fn new(init: &JsValue) -> Date;
+
+    /// Creates a JavaScript `Date` instance that represents the current moment in
+    /// time.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date)
+    #[wasm_bindgen(constructor)]
+    pub fn new_0() -> Date;
+
+    /// Creates a JavaScript `Date` instance that represents
+    /// a single moment in time. `Date` objects are based on a time value that is
+    /// the number of milliseconds since 1 January 1970 UTC.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date)
+    #[wasm_bindgen(constructor)]
+    pub fn new_with_year_month(year: u32, month: i32) -> Date;
+
+    /// Creates a JavaScript `Date` instance that represents
+    /// a single moment in time. `Date` objects are based on a time value that is
+    /// the number of milliseconds since 1 January 1970 UTC.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date)
+    #[wasm_bindgen(constructor)]
+    pub fn new_with_year_month_day(year: u32, month: i32, day: i32) -> Date;
+
+    /// Creates a JavaScript `Date` instance that represents
+    /// a single moment in time. `Date` objects are based on a time value that is
+    /// the number of milliseconds since 1 January 1970 UTC.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date)
+    #[wasm_bindgen(constructor)]
+    pub fn new_with_year_month_day_hr(year: u32, month: i32, day: i32, hr: i32) -> Date;
+
+    /// Creates a JavaScript `Date` instance that represents
+    /// a single moment in time. `Date` objects are based on a time value that is
+    /// the number of milliseconds since 1 January 1970 UTC.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date)
+    #[wasm_bindgen(constructor)]
+    pub fn new_with_year_month_day_hr_min(
+        year: u32,
+        month: i32,
+        day: i32,
+        hr: i32,
+        min: i32,
+    ) -> Date;
+
+    /// Creates a JavaScript `Date` instance that represents
+    /// a single moment in time. `Date` objects are based on a time value that is
+    /// the number of milliseconds since 1 January 1970 UTC.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date)
+    #[wasm_bindgen(constructor)]
+    pub fn new_with_year_month_day_hr_min_sec(
+        year: u32,
+        month: i32,
+        day: i32,
+        hr: i32,
+        min: i32,
+        sec: i32,
+    ) -> Date;
+
+    /// Creates a JavaScript `Date` instance that represents
+    /// a single moment in time. `Date` objects are based on a time value that is
+    /// the number of milliseconds since 1 January 1970 UTC.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date)
+    #[wasm_bindgen(constructor)]
+    pub fn new_with_year_month_day_hr_min_sec_milli(
+        year: u32,
+        month: i32,
+        day: i32,
+        hr: i32,
+        min: i32,
+        sec: i32,
+        milli: i32,
+    ) -> Date;
+
+    /// The `Date.now()` method returns the number of milliseconds
+    /// elapsed since January 1, 1970 00:00:00 UTC.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/now)
+    #[wasm_bindgen(static_method_of = Date)]
+    pub fn now() -> f64;
+
+    /// The `Date.parse()` method parses a string representation of a date, and returns the number of milliseconds
+    /// since January 1, 1970, 00:00:00 UTC or `NaN` if the string is unrecognized or, in some cases,
+    /// contains illegal date values (e.g. 2015-02-31).
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/parse)
+    #[wasm_bindgen(static_method_of = Date)]
+    pub fn parse(date: &str) -> f64;
+
+    /// The `setDate()` method sets the day of the Date object relative to the beginning of the currently set month.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setDate)
+    #[wasm_bindgen(method, js_name = setDate)]
+    pub fn set_date(this: &Date, day: u32) -> f64;
+
+    /// The `setFullYear()` method sets the full year for a specified date according to local time.
+    /// Returns new timestamp.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setFullYear)
+    #[wasm_bindgen(method, js_name = setFullYear)]
+    pub fn set_full_year(this: &Date, year: u32) -> f64;
+
+    /// The `setFullYear()` method sets the full year for a specified date according to local time.
+    /// Returns new timestamp.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setFullYear)
+    #[wasm_bindgen(method, js_name = setFullYear)]
+    pub fn set_full_year_with_month(this: &Date, year: u32, month: i32) -> f64;
+
+    /// The `setFullYear()` method sets the full year for a specified date according to local time.
+    /// Returns new timestamp.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setFullYear)
+    #[wasm_bindgen(method, js_name = setFullYear)]
+    pub fn set_full_year_with_month_date(this: &Date, year: u32, month: i32, date: i32) -> f64;
+
+    /// The `setHours()` method sets the hours for a specified date according to local time,
+    /// and returns the number of milliseconds since January 1, 1970 00:00:00 UTC until the time represented
+    /// by the updated Date instance.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setHours)
+    #[wasm_bindgen(method, js_name = setHours)]
+    pub fn set_hours(this: &Date, hours: u32) -> f64;
+
+    /// The `setMilliseconds()` method sets the milliseconds for a specified date according to local time.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setMilliseconds)
+    #[wasm_bindgen(method, js_name = setMilliseconds)]
+    pub fn set_milliseconds(this: &Date, milliseconds: u32) -> f64;
+
+    /// The `setMinutes()` method sets the minutes for a specified date according to local time.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setMinutes)
+    #[wasm_bindgen(method, js_name = setMinutes)]
+    pub fn set_minutes(this: &Date, minutes: u32) -> f64;
+
+    /// The `setMonth()` method sets the month for a specified date according to the currently set year.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setMonth)
+    #[wasm_bindgen(method, js_name = setMonth)]
+    pub fn set_month(this: &Date, month: u32) -> f64;
+
+    /// The `setSeconds()` method sets the seconds for a specified date according to local time.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setSeconds)
+    #[wasm_bindgen(method, js_name = setSeconds)]
+    pub fn set_seconds(this: &Date, seconds: u32) -> f64;
+
+    /// The `setTime()` method sets the Date object to the time represented by a number of milliseconds
+    /// since January 1, 1970, 00:00:00 UTC.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setTime)
+    #[wasm_bindgen(method, js_name = setTime)]
+    pub fn set_time(this: &Date, time: f64) -> f64;
+
+    /// The `setUTCDate()` method sets the day of the month for a specified date
+    /// according to universal time.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setUTCDate)
+    #[wasm_bindgen(method, js_name = setUTCDate)]
+    pub fn set_utc_date(this: &Date, day: u32) -> f64;
+
+    /// The `setUTCFullYear()` method sets the full year for a specified date according to universal time.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setUTCFullYear)
+    #[wasm_bindgen(method, js_name = setUTCFullYear)]
+    pub fn set_utc_full_year(this: &Date, year: u32) -> f64;
+
+    /// The `setUTCFullYear()` method sets the full year for a specified date according to universal time.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setUTCFullYear)
+    #[wasm_bindgen(method, js_name = setUTCFullYear)]
+    pub fn set_utc_full_year_with_month(this: &Date, year: u32, month: i32) -> f64;
+
+    /// The `setUTCFullYear()` method sets the full year for a specified date according to universal time.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setUTCFullYear)
+    #[wasm_bindgen(method, js_name = setUTCFullYear)]
+    pub fn set_utc_full_year_with_month_date(this: &Date, year: u32, month: i32, date: i32) -> f64;
+
+    /// The `setUTCHours()` method sets the hour for a specified date according to universal time,
+    /// and returns the number of milliseconds since  January 1, 1970 00:00:00 UTC until the time
+    /// represented by the updated Date instance.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setUTCHours)
+    #[wasm_bindgen(method, js_name = setUTCHours)]
+    pub fn set_utc_hours(this: &Date, hours: u32) -> f64;
+
+    /// The `setUTCMilliseconds()` method sets the milliseconds for a specified date
+    /// according to universal time.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setUTCMilliseconds)
+    #[wasm_bindgen(method, js_name = setUTCMilliseconds)]
+    pub fn set_utc_milliseconds(this: &Date, milliseconds: u32) -> f64;
+
+    /// The `setUTCMinutes()` method sets the minutes for a specified date according to universal time.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setUTCMinutes)
+    #[wasm_bindgen(method, js_name = setUTCMinutes)]
+    pub fn set_utc_minutes(this: &Date, minutes: u32) -> f64;
+
+    /// The `setUTCMonth()` method sets the month for a specified date according to universal time.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setUTCMonth)
+    #[wasm_bindgen(method, js_name = setUTCMonth)]
+    pub fn set_utc_month(this: &Date, month: u32) -> f64;
+
+    /// The `setUTCSeconds()` method sets the seconds for a specified date according to universal time.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setUTCSeconds)
+    #[wasm_bindgen(method, js_name = setUTCSeconds)]
+    pub fn set_utc_seconds(this: &Date, seconds: u32) -> f64;
+
+    /// The `toDateString()` method returns the date portion of a Date object
+    /// in human readable form in American English.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toDateString)
+    #[wasm_bindgen(method, js_name = toDateString)]
+    pub fn to_date_string(this: &Date) -> JsString;
+
+    /// The `toISOString()` method returns a string in simplified extended ISO format (ISO
+    /// 8601), which is always 24 or 27 characters long (YYYY-MM-DDTHH:mm:ss.sssZ or
+    /// ±YYYYYY-MM-DDTHH:mm:ss.sssZ, respectively). The timezone is always zero UTC offset,
+    /// as denoted by the suffix "Z"
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toISOString)
+    #[wasm_bindgen(method, js_name = toISOString)]
+    pub fn to_iso_string(this: &Date) -> JsString;
+
+    /// The `toJSON()` method returns a string representation of the Date object.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toJSON)
+    #[wasm_bindgen(method, js_name = toJSON)]
+    pub fn to_json(this: &Date) -> JsString;
+
+    /// The `toLocaleDateString()` method returns a string with a language sensitive
+    /// representation of the date portion of this date. The new locales and options
+    /// arguments let applications specify the language whose formatting conventions
+    /// should be used and allow to customize the behavior of the function.
+    /// In older implementations, which ignore the locales and options arguments,
+    /// the locale used and the form of the string
+    /// returned are entirely implementation dependent.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toLocaleDateString)
+    #[wasm_bindgen(method, js_name = toLocaleDateString)]
+    pub fn to_locale_date_string(this: &Date, locale: &str, options: &JsValue) -> JsString;
+
+    /// The `toLocaleString()` method returns a string with a language sensitive
+    /// representation of this date. The new locales and options arguments
+    /// let applications specify the language whose formatting conventions
+    /// should be used and customize the behavior of the function.
+    /// In older implementations, which ignore the locales
+    /// and options arguments, the locale used and the form of the string
+    /// returned are entirely implementation dependent.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toLocaleString)
+    #[wasm_bindgen(method, js_name = toLocaleString)]
+    pub fn to_locale_string(this: &Date, locale: &str, options: &JsValue) -> JsString;
+
+    /// The `toLocaleTimeString()` method returns a string with a language sensitive
+    /// representation of the time portion of this date. The new locales and options
+    /// arguments let applications specify the language whose formatting conventions should be
+    /// used and customize the behavior of the function. In older implementations, which ignore
+    /// the locales and options arguments, the locale used and the form of the string
+    /// returned are entirely implementation dependent.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toLocaleTimeString)
+    #[wasm_bindgen(method, js_name = toLocaleTimeString)]
+    pub fn to_locale_time_string(this: &Date, locale: &str) -> JsString;
+
+    /// The `toString()` method returns a string representing
+    /// the specified Date object.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toString)
+    #[wasm_bindgen(method, js_name = toString)]
+    pub fn to_string(this: &Date) -> JsString;
+
+    /// The `toTimeString()` method returns the time portion of a Date object in human
+    /// readable form in American English.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toTimeString)
+    #[wasm_bindgen(method, js_name = toTimeString)]
+    pub fn to_time_string(this: &Date) -> JsString;
+
+    /// The `toUTCString()` method converts a date to a string,
+    /// using the UTC time zone.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toUTCString)
+    #[wasm_bindgen(method, js_name = toUTCString)]
+    pub fn to_utc_string(this: &Date) -> JsString;
+
+    /// The `Date.UTC()` method accepts the same parameters as the
+    /// longest form of the constructor, and returns the number of
+    /// milliseconds in a `Date` object since January 1, 1970,
+    /// 00:00:00, universal time.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/UTC)
+    #[wasm_bindgen(static_method_of = Date, js_name = UTC)]
+    pub fn utc(year: f64, month: f64) -> f64;
+
+    /// The `valueOf()` method  returns the primitive value of
+    /// a Date object.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/valueOf)
+    #[wasm_bindgen(method, js_name = valueOf)]
+    pub fn value_of(this: &Date) -> f64;
+}
+
+/// The error returned by [`Date`]'s `try_set_*` methods and
+/// [`Date::checked`] when a time value ends up `NaN` -- JS's way of
+/// saying the date is invalid, e.g. from parsing a malformed string or
+/// setting a component to a value that overflows the representable
+/// range.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InvalidDate;
+
+impl fmt::Display for InvalidDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "date is invalid (time value is NaN)")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidDate {}
+
+impl Date {
+    /// Returns `false` if this date's time value is `NaN` (an "Invalid
+    /// Date"), which happens e.g. when [`Date::new`] is given an
+    /// unparseable string.
+    pub fn is_valid(&self) -> bool {
+        !self.value_of().is_nan()
+    }
+
+    /// Turns this date into a `Result`, so a `Date::new(...)` that might
+    /// have produced an Invalid Date can be handled with `?` instead of
+    /// silently carried around.
+    pub fn checked(self) -> Result<Date, InvalidDate> {
+        if self.is_valid() {
+            Ok(self)
+        } else {
+            Err(InvalidDate)
+        }
+    }
+
+    /// Returns a clone of `self` clamped to the inclusive range `[min,
+    /// max]`, comparing by time value. Assumes `min <= max`.
+    pub fn clamp(&self, min: &Date, max: &Date) -> Date {
+        let time = self.value_of();
+        if time < min.value_of() {
+            min.clone()
+        } else if time > max.value_of() {
+            max.clone()
+        } else {
+            self.clone()
+        }
+    }
+
+    /// The `setDate()` method sets the day of the Date object relative to
+    /// the beginning of the currently set month, returning [`InvalidDate`]
+    /// instead of `NaN` if the result is invalid.
+    pub fn try_set_date(&self, day: u32) -> Result<f64, InvalidDate> {
+        check_date_result(self.set_date(day))
+    }
+
+    /// The `setFullYear()` method sets the full year for a specified date
+    /// according to local time, returning [`InvalidDate`] instead of
+    /// `NaN` if the result is invalid.
+    pub fn try_set_full_year(&self, year: u32) -> Result<f64, InvalidDate> {
+        check_date_result(self.set_full_year(year))
+    }
+
+    /// Same as [`Date::try_set_full_year`], but also sets the month.
+    pub fn try_set_full_year_with_month(&self, year: u32, month: i32) -> Result<f64, InvalidDate> {
+        check_date_result(self.set_full_year_with_month(year, month))
+    }
+
+    /// Same as [`Date::try_set_full_year`], but also sets the month and
+    /// day of the month.
+    pub fn try_set_full_year_with_month_date(
+        &self,
+        year: u32,
+        month: i32,
+        date: i32,
+    ) -> Result<f64, InvalidDate> {
+        check_date_result(self.set_full_year_with_month_date(year, month, date))
+    }
+
+    /// The `setHours()` method sets the hours for a specified date
+    /// according to local time, returning [`InvalidDate`] instead of
+    /// `NaN` if the result is invalid.
+    pub fn try_set_hours(&self, hours: u32) -> Result<f64, InvalidDate> {
+        check_date_result(self.set_hours(hours))
+    }
+
+    /// The `setMilliseconds()` method sets the milliseconds for a
+    /// specified date according to local time, returning [`InvalidDate`]
+    /// instead of `NaN` if the result is invalid.
+    pub fn try_set_milliseconds(&self, milliseconds: u32) -> Result<f64, InvalidDate> {
+        check_date_result(self.set_milliseconds(milliseconds))
+    }
+
+    /// The `setMinutes()` method sets the minutes for a specified date
+    /// according to local time, returning [`InvalidDate`] instead of
+    /// `NaN` if the result is invalid.
+    pub fn try_set_minutes(&self, minutes: u32) -> Result<f64, InvalidDate> {
+        check_date_result(self.set_minutes(minutes))
+    }
+
+    /// The `setMonth()` method sets the month for a specified date
+    /// according to the currently set year, returning [`InvalidDate`]
+    /// instead of `NaN` if the result is invalid.
+    pub fn try_set_month(&self, month: u32) -> Result<f64, InvalidDate> {
+        check_date_result(self.set_month(month))
+    }
+
+    /// The `setSeconds()` method sets the seconds for a specified date
+    /// according to local time, returning [`InvalidDate`] instead of
+    /// `NaN` if the result is invalid.
+    pub fn try_set_seconds(&self, seconds: u32) -> Result<f64, InvalidDate> {
+        check_date_result(self.set_seconds(seconds))
+    }
+
+    /// The `setTime()` method sets the Date object to the time
+    /// represented by a number of milliseconds since the epoch, returning
+    /// [`InvalidDate`] instead of `NaN` if `time` itself is `NaN`.
+    pub fn try_set_time(&self, time: f64) -> Result<f64, InvalidDate> {
+        check_date_result(self.set_time(time))
+    }
+
+    /// The `setUTCDate()` method sets the day of the month for a
+    /// specified date according to universal time, returning
+    /// [`InvalidDate`] instead of `NaN` if the result is invalid.
+    pub fn try_set_utc_date(&self, day: u32) -> Result<f64, InvalidDate> {
+        check_date_result(self.set_utc_date(day))
+    }
+
+    /// The `setUTCFullYear()` method sets the full year for a specified
+    /// date according to universal time, returning [`InvalidDate`]
+    /// instead of `NaN` if the result is invalid.
+    pub fn try_set_utc_full_year(&self, year: u32) -> Result<f64, InvalidDate> {
+        check_date_result(self.set_utc_full_year(year))
+    }
+
+    /// Same as [`Date::try_set_utc_full_year`], but also sets the month.
+    pub fn try_set_utc_full_year_with_month(
+        &self,
+        year: u32,
+        month: i32,
+    ) -> Result<f64, InvalidDate> {
+        check_date_result(self.set_utc_full_year_with_month(year, month))
+    }
+
+    /// Same as [`Date::try_set_utc_full_year`], but also sets the month
+    /// and day of the month.
+    pub fn try_set_utc_full_year_with_month_date(
+        &self,
+        year: u32,
+        month: i32,
+        date: i32,
+    ) -> Result<f64, InvalidDate> {
+        check_date_result(self.set_utc_full_year_with_month_date(year, month, date))
+    }
+
+    /// The `setUTCHours()` method sets the hour for a specified date
+    /// according to universal time, returning [`InvalidDate`] instead of
+    /// `NaN` if the result is invalid.
+    pub fn try_set_utc_hours(&self, hours: u32) -> Result<f64, InvalidDate> {
+        check_date_result(self.set_utc_hours(hours))
+    }
+
+    /// The `setUTCMilliseconds()` method sets the milliseconds for a
+    /// specified date according to universal time, returning
+    /// [`InvalidDate`] instead of `NaN` if the result is invalid.
+    pub fn try_set_utc_milliseconds(&self, milliseconds: u32) -> Result<f64, InvalidDate> {
+        check_date_result(self.set_utc_milliseconds(milliseconds))
+    }
+
+    /// The `setUTCMinutes()` method sets the minutes for a specified date
+    /// according to universal time, returning [`InvalidDate`] instead of
+    /// `NaN` if the result is invalid.
+    pub fn try_set_utc_minutes(&self, minutes: u32) -> Result<f64, InvalidDate> {
+        check_date_result(self.set_utc_minutes(minutes))
+    }
+
+    /// The `setUTCMonth()` method sets the month for a specified date
+    /// according to universal time, returning [`InvalidDate`] instead of
+    /// `NaN` if the result is invalid.
+    pub fn try_set_utc_month(&self, month: u32) -> Result<f64, InvalidDate> {
+        check_date_result(self.set_utc_month(month))
+    }
+
+    /// The `setUTCSeconds()` method sets the seconds for a specified date
+    /// according to universal time, returning [`InvalidDate`] instead of
+    /// `NaN` if the result is invalid.
+    pub fn try_set_utc_seconds(&self, seconds: u32) -> Result<f64, InvalidDate> {
+        check_date_result(self.set_utc_seconds(seconds))
+    }
+}
+
+fn check_date_result(time: f64) -> Result<f64, InvalidDate> {
+    if time.is_nan() {
+        Err(InvalidDate)
+    } else {
+        Ok(time)
+    }
+}
+
+// Object.
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(typescript_type = "object")]
+    #[derive(Clone, Debug)]
+    pub type Object;
+
+    /// The `Object.assign()` method is used to copy the values of all enumerable
+    /// own properties from one or more source objects to a target object. It
+    /// will return the target object.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/assign)
+    #[wasm_bindgen(static_method_of = Object)]
+    pub fn assign(target: &Object, source: &Object) -> Object;
+
+    /// The `Object.assign()` method is used to copy the values of all enumerable
+    /// own properties from one or more source objects to a target object. It
+    /// will return the target object.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/assign)
+    #[wasm_bindgen(static_method_of = Object, js_name = assign)]
+    pub fn assign2(target: &Object, source1: &Object, source2: &Object) -> Object;
+
+    /// The `Object.assign()` method is used to copy the values of all enumerable
+    /// own properties from one or more source objects to a target object. It
+    /// will return the target object.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/assign)
+    #[wasm_bindgen(static_method_of = Object, js_name = assign)]
+    pub fn assign3(target: &Object, source1: &Object, source2: &Object, source3: &Object)
+        -> Object;
+
+    /// The constructor property returns a reference to the `Object` constructor
+    /// function that created the instance object.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/constructor)
+    #[wasm_bindgen(method, getter)]
+    pub fn constructor(this: &Object) -> Function;
+
+    /// The `Object.create()` method creates a new object, using an existing
+    /// object to provide the newly created object's prototype.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/create)
+    #[wasm_bindgen(static_method_of = Object)]
+    pub fn create(prototype: &Object) -> Object;
+
+    /// The `Object.create()` method creates a new object, using an existing
+    /// object to provide the newly created object's prototype, and an
+    /// object of property descriptors to add to the newly created object.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/create)
+    #[wasm_bindgen(static_method_of = Object, js_name = create)]
+    pub fn create_with_properties(prototype: &Object, properties: &Object) -> Object;
+
+    /// The static method `Object.defineProperty()` defines a new
+    /// property directly on an object, or modifies an existing
+    /// property on an object, and returns the object.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/defineProperty)
+    #[wasm_bindgen(static_method_of = Object, js_name = defineProperty)]
+    pub fn define_property(obj: &Object, prop: &JsValue, descriptor: &Object) -> Object;
+
+    /// The `Object.defineProperties()` method defines new or modifies
+    /// existing properties directly on an object, returning the
+    /// object.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/defineProperties)
+    #[wasm_bindgen(static_method_of = Object, js_name = defineProperties)]
+    pub fn define_properties(obj: &Object, props: &Object) -> Object;
+
+    /// The `Object.entries()` method returns an array of a given
+    /// object's own enumerable property [key, value] pairs, in the
+    /// same order as that provided by a for...in loop (the difference
+    /// being that a for-in loop enumerates properties in the
+    /// prototype chain as well).
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/entries)
+    #[wasm_bindgen(static_method_of = Object)]
+    pub fn entries(object: &Object) -> Array;
+
+    /// The `Object.freeze()` method freezes an object: that is, prevents new
+    /// properties from being added to it; prevents existing properties from
+    /// being removed; and prevents existing properties, or their enumerability,
+    /// configurability, or writability, from being changed, it also prevents
+    /// the prototype from being changed. The method returns the passed object.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/freeze)
+    #[wasm_bindgen(static_method_of = Object)]
+    pub fn freeze(value: &Object) -> Object;
+
+    /// The `Object.fromEntries()` method transforms a list of key-value pairs
+    /// into an object.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/fromEntries)
+    #[wasm_bindgen(static_method_of = Object, catch, js_name = fromEntries)]
+    pub fn from_entries(iterable: &JsValue) -> Result<Object, JsValue>;
+
+    /// The `Object.getOwnPropertyDescriptor()` method returns a
+    /// property descriptor for an own property (that is, one directly
+    /// present on an object and not in the object's prototype chain)
+    /// of a given object.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/getOwnPropertyDescriptor)
+    #[wasm_bindgen(static_method_of = Object, js_name = getOwnPropertyDescriptor)]
+    pub fn get_own_property_descriptor(obj: &Object, prop: &JsValue) -> JsValue;
+
+    /// The `Object.getOwnPropertyDescriptors()` method returns all own
+    /// property descriptors of a given object.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/getOwnPropertyDescriptors)
+    #[wasm_bindgen(static_method_of = Object, js_name = getOwnPropertyDescriptors)]
+    pub fn get_own_property_descriptors(obj: &Object) -> JsValue;
+
+    /// The `Object.getOwnPropertyNames()` method returns an array of
+    /// all properties (including non-enumerable properties except for
+    /// those which use Symbol) found directly upon a given object.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/getOwnPropertyNames)
+    #[wasm_bindgen(static_method_of = Object, js_name = getOwnPropertyNames)]
+    pub fn get_own_property_names(obj: &Object) -> Array;
+
+    /// The `Object.getOwnPropertySymbols()` method returns an array of
+    /// all symbol properties found directly upon a given object.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/getOwnPropertySymbols)
+    #[wasm_bindgen(static_method_of = Object, js_name = getOwnPropertySymbols)]
+    pub fn get_own_property_symbols(obj: &Object) -> Array;
+
+    /// The `Object.getPrototypeOf()` method returns the prototype
+    /// (i.e. the value of the internal [[Prototype]] property) of the
+    /// specified object.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/getPrototypeOf)
+    #[wasm_bindgen(static_method_of = Object, js_name = getPrototypeOf)]
+    pub fn get_prototype_of(obj: &JsValue) -> Object;
+
+    /// The `hasOwnProperty()` method returns a boolean indicating whether the
+    /// object has the specified property as its own property (as opposed to
+    /// inheriting it).
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/hasOwnProperty)
+    #[wasm_bindgen(method, js_name = hasOwnProperty)]
+    pub fn has_own_property(this: &Object, property: &JsValue) -> bool;
+
+    /// The `Object.hasOwn()` method returns a boolean indicating whether the
+    /// object passed in has the specified property as its own property (as
+    /// opposed to inheriting it).
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/hasOwn)
+    #[wasm_bindgen(static_method_of = Object, js_name = hasOwn)]
+    pub fn has_own(instance: &Object, property: &JsValue) -> bool;
+
+    /// The `Object.is()` method determines whether two values are the same value.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/is)
+    #[wasm_bindgen(static_method_of = Object)]
+    pub fn is(value_1: &JsValue, value_2: &JsValue) -> bool;
+
+    /// The `Object.isExtensible()` method determines if an object is extensible
+    /// (whether it can have new properties added to it).
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/isExtensible)
+    #[wasm_bindgen(static_method_of = Object, js_name = isExtensible)]
+    pub fn is_extensible(object: &Object) -> bool;
+
+    /// The `Object.isFrozen()` determines if an object is frozen.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/isFrozen)
+    #[wasm_bindgen(static_method_of = Object, js_name = isFrozen)]
+    pub fn is_frozen(object: &Object) -> bool;
+
+    /// The `Object.isSealed()` method determines if an object is sealed.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/isSealed)
+    #[wasm_bindgen(static_method_of = Object, js_name = isSealed)]
+    pub fn is_sealed(object: &Object) -> bool;
+
+    /// The `isPrototypeOf()` method checks if an object exists in another
+    /// object's prototype chain.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/isPrototypeOf)
+    #[wasm_bindgen(method, js_name = isPrototypeOf)]
+    pub fn is_prototype_of(this: &Object, value: &JsValue) -> bool;
+
+    /// The `Object.keys()` method returns an array of a given object's property
+    /// names, in the same order as we get with a normal loop.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/keys)
+    #[wasm_bindgen(static_method_of = Object)]
+    pub fn keys(object: &Object) -> Array;
+
+    /// The [`Object`] constructor creates an object wrapper.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object)
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Object;
+
+    /// The `Object.preventExtensions()` method prevents new properties from
+    /// ever being added to an object (i.e. prevents future extensions to the
+    /// object).
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/preventExtensions)
+    #[wasm_bindgen(static_method_of = Object, js_name = preventExtensions)]
+    pub fn prevent_extensions(object: &Object);
+
+    /// The `propertyIsEnumerable()` method returns a Boolean indicating
+    /// whether the specified property is enumerable.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/propertyIsEnumerable)
+    #[wasm_bindgen(method, js_name = propertyIsEnumerable)]
+    pub fn property_is_enumerable(this: &Object, property: &JsValue) -> bool;
+
+    /// The `Object.seal()` method seals an object, preventing new properties
+    /// from being added to it and marking all existing properties as
+    /// non-configurable.  Values of present properties can still be changed as
+    /// long as they are writable.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/seal)
+    #[wasm_bindgen(static_method_of = Object)]
+    pub fn seal(value: &Object) -> Object;
+
+    /// The `Object.setPrototypeOf()` method sets the prototype (i.e., the
+    /// internal `[[Prototype]]` property) of a specified object to another
+    /// object or `null`.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/setPrototypeOf)
+    #[wasm_bindgen(static_method_of = Object, js_name = setPrototypeOf)]
+    pub fn set_prototype_of(object: &Object, prototype: &Object) -> Object;
+
+    /// The `toLocaleString()` method returns a string representing the object.
+    /// This method is meant to be overridden by derived objects for
+    /// locale-specific purposes.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/toLocaleString)
+    #[wasm_bindgen(method, js_name = toLocaleString)]
+    pub fn to_locale_string(this: &Object) -> JsString;
+
+    /// The `toString()` method returns a string representing the object.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/toString)
+    #[wasm_bindgen(method, js_name = toString)]
+    pub fn to_string(this: &Object) -> JsString;
+
+    /// The `valueOf()` method returns the primitive value of the
+    /// specified object.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/valueOf)
+    #[wasm_bindgen(method, js_name = valueOf)]
+    pub fn value_of(this: &Object) -> Object;
+
+    /// The `Object.values()` method returns an array of a given object's own
+    /// enumerable property values, in the same order as that provided by a
+    /// `for...in` loop (the difference being that a for-in loop enumerates
+    /// properties in the prototype chain as well).
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/values)
+    #[wasm_bindgen(static_method_of = Object)]
+    pub fn values(object: &Object) -> Array;
+}
+
+/// Returns a cached `function(o) { return Object.keys(o).length; }`, used
+/// by [`Object::try_own_key_count`] to get a throw-surfacing count of an
+/// object's own enumerable string-keyed properties without a second
+/// round trip through Rust for the intermediate `Array`.
+///
+/// Built the same way as [`bigint_checked_binop`]'s cached operator
+/// functions: a `Function` composed once via [`Function::new_with_args`]
+/// (no `eval`), not a literal string evaluated per call.
+fn own_key_count_fn() -> Function {
+    #[cfg(feature = "std")]
+    {
+        thread_local!(static F: Function = Function::new_with_args("o", "return Object.keys(o).length;"));
+        F.with(|f| f.clone())
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        use once_cell::unsync::Lazy;
+
+        struct Wrapper(Lazy<Function>);
+
+        #[cfg(not(target_feature = "atomics"))]
+        unsafe impl Sync for Wrapper {}
+
+        #[cfg(not(target_feature = "atomics"))]
+        unsafe impl Send for Wrapper {}
+
+        #[cfg_attr(target_feature = "atomics", thread_local)]
+        static F: Wrapper =
+            Wrapper(Lazy::new(|| Function::new_with_args("o", "return Object.keys(o).length;")));
+
+        F.0.clone()
+    }
+}
+
+impl Object {
+    /// Returns whether `obj` has no own enumerable string-keyed
+    /// properties, per [`Object::keys`] (symbol keys don't count, matching
+    /// `Object.keys`'s own semantics).
+    ///
+    /// This still allocates and discards the intermediate `Array`
+    /// [`Object::keys`] returns; see [`Object::try_own_key_count`] for a
+    /// version that surfaces a throwing `ownKeys` trap instead of
+    /// panicking, at the same allocation cost.
+    pub fn is_empty(obj: &Object) -> bool {
+        Object::keys(obj).length() == 0
+    }
+
+    /// Returns the number of own enumerable string-keyed properties `obj`
+    /// has, per [`Object::keys`].
+    ///
+    /// Like [`Object::is_empty`], this still materializes the keys
+    /// `Array` just to read its length.
+    pub fn own_key_count(obj: &Object) -> u32 {
+        Object::keys(obj).length()
+    }
+
+    /// Like [`Object::own_key_count`], but via a single cached JS function
+    /// call (see [`own_key_count_fn`]) rather than a `keys()` call plus a
+    /// separate `length` getter round trip, and catching rather than
+    /// panicking if `obj`'s `ownKeys` trap (e.g. a throwing `Proxy`)
+    /// throws.
+    pub fn try_own_key_count(obj: &Object) -> Result<u32, JsValue> {
+        let result = own_key_count_fn().call1(&JsValue::UNDEFINED, obj.as_ref())?;
+        Ok(result.as_f64().unwrap_or(0.0) as u32)
+    }
+
+    /// Returns the `Object` value of this JS value if it's an instance of an
+    /// object.
+    ///
+    /// If this JS value is not an instance of an object then this returns
+    /// `None`.
+    pub fn try_from(val: &JsValue) -> Option<&Object> {
+        if val.is_object() {
+            Some(val.unchecked_ref())
+        } else {
+            None
+        }
+    }
+
+    /// Creates a new object whose prototype is `null`, using
+    /// `Object.create(null)`. Unlike [`Object::new`], the result has no
+    /// inherited properties at all, not even `toString`.
+    pub fn create_null() -> Object {
+        Object::create(JsValue::NULL.unchecked_ref())
+    }
+
+    /// Like [`Object::create`], but accepts any JS value castable to
+    /// [`Object`] as the prototype, so callers don't need to upcast first.
+    pub fn create_typed<T: JsCast>(prototype: &T) -> Object {
+        Object::create(prototype.unchecked_ref())
+    }
+
+    /// Like [`Object::create_with_properties`], but builds the property
+    /// descriptor map from a simple list of `(name, value)` pairs. Each
+    /// property is added as writable, enumerable, and configurable.
+    pub fn create_with_property_map(prototype: &Object, properties: &[(&str, JsValue)]) -> Object {
+        let descriptors = Object::new();
+        for (key, value) in properties {
+            let descriptor = Object::new();
+            let _ = Reflect::set(descriptor.as_ref(), &JsValue::from_str("value"), value);
+            let _ = Reflect::set(descriptor.as_ref(), &JsValue::from_str("writable"), &JsValue::TRUE);
+            let _ = Reflect::set(
+                descriptor.as_ref(),
+                &JsValue::from_str("enumerable"),
+                &JsValue::TRUE,
+            );
+            let _ = Reflect::set(
+                descriptor.as_ref(),
+                &JsValue::from_str("configurable"),
+                &JsValue::TRUE,
+            );
+            let _ = Reflect::set(descriptors.as_ref(), &JsValue::from_str(key), descriptor.as_ref());
+        }
+        Object::create_with_properties(prototype, &descriptors)
+    }
+}
+
+/// A frozen, null-prototype object mapping each of a fixed set of string
+/// variants to itself -- a `JsValue`-based stand-in for an enum: it can be
+/// handed to JS as a namespace of named constants (`MyEnum.Foo === "Foo"`)
+/// while Rust retains the ability to validate an externally supplied
+/// value against the known variants.
+#[derive(Clone, Debug)]
+pub struct JsEnum {
+    object: Object,
+    variants: Vec<JsString>,
+}
+
+impl JsEnum {
+    /// Builds a [`JsEnum`] whose variants are the given strings. The
+    /// backing object has one property per variant (key and value are
+    /// the same string), a `null` prototype, and is frozen via
+    /// [`Object::freeze`], so nothing can add, remove, or reassign a
+    /// variant after the fact.
+    pub fn from_variants(variants: &[&str]) -> JsEnum {
+        let object = Object::create_null();
+        for variant in variants {
+            let _ = Reflect::set(
+                object.as_ref(),
+                &JsValue::from_str(variant),
+                &JsValue::from_str(variant),
+            );
+        }
+        Object::freeze(&object);
+        JsEnum {
+            object,
+            variants: variants.iter().map(|v| JsString::from(*v)).collect(),
+        }
+    }
+
+    /// Returns `true` if `s` names one of this enum's variants.
+    pub fn has(&self, s: &str) -> bool {
+        self.variants.iter().any(|v| *v == s)
+    }
+
+    /// Validates that `value` is a string naming one of this enum's
+    /// variants, returning it as a [`JsString`] on success and an
+    /// [`EnumParseError`] listing the valid options otherwise.
+    pub fn parse(&self, value: &JsValue) -> Result<JsString, EnumParseError> {
+        if let Some(s) = value.as_string() {
+            if self.has(&s) {
+                return Ok(JsString::from(s));
+            }
+        }
+        Err(EnumParseError {
+            value: value.clone(),
+            variants: self.variants.clone(),
+        })
+    }
+
+    /// Returns this enum's variants, in the order they were declared.
+    pub fn values(&self) -> Array {
+        let array = Array::new();
+        for variant in &self.variants {
+            array.push(variant.as_ref());
+        }
+        array
+    }
+
+    /// Returns the frozen, null-prototype backing object, for handing to
+    /// JS as a namespace of constants.
+    pub fn as_object(&self) -> &Object {
+        &self.object
+    }
+}
+
+/// The error returned by [`JsEnum::parse`] when a value doesn't name one
+/// of the enum's variants.
+#[derive(Clone, Debug)]
+pub struct EnumParseError {
+    value: JsValue,
+    variants: Vec<JsString>,
+}
+
+impl EnumParseError {
+    /// The value that failed to parse.
+    pub fn value(&self) -> &JsValue {
+        &self.value
+    }
+}
+
+impl fmt::Display for EnumParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?} is not one of the valid variants (", self.value)?;
+        for (i, variant) in self.variants.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{:?}", String::from(variant))?;
+        }
+        f.write_str(")")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for EnumParseError {}
+
+/// Options controlling [`Object::diff`].
+#[derive(Clone, Copy, Debug)]
+pub struct DiffOptions {
+    max_depth: usize,
+    array_by_identity: bool,
+}
+
+impl DiffOptions {
+    /// Starts a new builder: unbounded depth, arrays compared recursively
+    /// like plain objects (not by identity).
+    pub fn new() -> Self {
+        DiffOptions {
+            max_depth: usize::MAX,
+            array_by_identity: false,
+        }
+    }
+
+    /// Stops descending past `depth` levels of nesting; anything still
+    /// different at that depth is reported as a single [`DiffKind::Changed`]
+    /// entry for the whole subtree rather than being walked further.
+    pub fn max_depth(mut self, depth: usize) -> Self {
+        self.max_depth = depth;
+        self
+    }
+
+    /// If `enabled`, arrays are compared by reference identity
+    /// ([`Object::is`]) instead of element-by-element: an array replaced by
+    /// a different array (even with identical contents) is reported as
+    /// [`DiffKind::Changed`] in full, and an unchanged reference is skipped
+    /// entirely without being walked.
+    pub fn array_by_identity(mut self, enabled: bool) -> Self {
+        self.array_by_identity = enabled;
+        self
+    }
+}
+
+impl Default for DiffOptions {
+    fn default() -> Self {
+        DiffOptions::new()
+    }
+}
+
+/// The kind of change a [`DiffEntry`] records.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DiffKind {
+    /// The property exists in the new object but not the old one.
+    Added,
+    /// The property exists in the old object but not the new one.
+    Removed,
+    /// The property exists in both but its value differs.
+    Changed,
+}
+
+/// One change produced by [`Object::diff`].
+#[derive(Clone, Debug)]
+pub struct DiffEntry {
+    path: Vec<String>,
+    kind: DiffKind,
+    old_value: JsValue,
+    new_value: JsValue,
+}
+
+impl DiffEntry {
+    /// The property path from the diffed root to this change, e.g.
+    /// `["user", "address", "city"]`.
+    pub fn path(&self) -> &[String] {
+        &self.path
+    }
+
+    /// Whether this change added, removed, or changed the value at
+    /// [`DiffEntry::path`].
+    pub fn kind(&self) -> DiffKind {
+        self.kind
+    }
+
+    /// The value at this path in the old object, or `undefined` if
+    /// [`DiffEntry::kind`] is [`DiffKind::Added`].
+    pub fn old_value(&self) -> &JsValue {
+        &self.old_value
+    }
+
+    /// The value at this path in the new object, or `undefined` if
+    /// [`DiffEntry::kind`] is [`DiffKind::Removed`].
+    pub fn new_value(&self) -> &JsValue {
+        &self.new_value
+    }
+}
+
+/// How [`Object::merge_deep`] combines array-valued properties that are
+/// present on both sides.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ArrayMergeStrategy {
+    /// The array from `b` replaces the one from `a` entirely.
+    Replace,
+    /// The elements of `b`'s array are appended after `a`'s.
+    Concat,
+    /// Elements are merged position by position (recursing into plain
+    /// objects at matching indices); any extra trailing elements from the
+    /// longer array are kept as-is.
+    ByIndex,
+}
+
+/// Options controlling [`Object::merge_deep`].
+#[derive(Clone, Copy, Debug)]
+pub struct MergeOptions {
+    array_strategy: ArrayMergeStrategy,
+}
+
+impl MergeOptions {
+    /// Starts a new builder: arrays are replaced wholesale, matching
+    /// `Object.assign`-style shallow-merge behavior for that one case.
+    pub fn new() -> Self {
+        MergeOptions {
+            array_strategy: ArrayMergeStrategy::Replace,
+        }
+    }
+
+    /// Sets how array-valued properties present on both sides are combined.
+    pub fn array_strategy(mut self, strategy: ArrayMergeStrategy) -> Self {
+        self.array_strategy = strategy;
+        self
+    }
+}
+
+impl Default for MergeOptions {
+    fn default() -> Self {
+        MergeOptions::new()
+    }
+}
+
+impl Object {
+    /// Computes the set of changes needed to turn `old` into `new`,
+    /// walking own enumerable keys iteratively (an explicit stack, not
+    /// recursion, so depth is bounded only by `opts`) and comparing leaves
+    /// with [`Object::is`] (`SameValue`).
+    ///
+    /// Cycles (a value reachable from itself through both `old` and `new`
+    /// at once) are detected by tracking the identity pairs currently being
+    /// walked and are treated as unchanged rather than recursed into again.
+    pub fn diff(old: &Object, new: &Object, opts: &DiffOptions) -> Vec<DiffEntry> {
+        let mut out = Vec::new();
+        // A `None` frame marks where a pair's children are all walked and
+        // the pair leaves `visiting` again.
+        let mut stack: Vec<Option<(Vec<String>, JsValue, JsValue)>> =
+            alloc::vec![Some((Vec::new(), JsValue::from(old.clone()), JsValue::from(new.clone())))];
+        let mut visiting: Vec<(JsValue, JsValue)> = Vec::new();
+
+        while let Some(frame) = stack.pop() {
+            let Some((path, old_v, new_v)) = frame else {
+                visiting.pop();
+                continue;
+            };
+            let old_obj = old_v.dyn_ref::<Object>().filter(|_| !old_v.is_function());
+            let new_obj = new_v.dyn_ref::<Object>().filter(|_| !new_v.is_function());
+
+            let (old_obj, new_obj) = match (old_obj, new_obj) {
+                (Some(o), Some(n)) => (o, n),
+                _ => {
+                    if !Object::is(&old_v, &new_v) {
+                        out.push(DiffEntry {
+                            path,
+                            kind: DiffKind::Changed,
+                            old_value: old_v,
+                            new_value: new_v,
+                        });
+                    }
+                    continue;
+                }
+            };
+
+            if opts.array_by_identity && old_v.dyn_ref::<Array>().is_some() && new_v.dyn_ref::<Array>().is_some()
+            {
+                if !Object::is(&old_v, &new_v) {
+                    out.push(DiffEntry {
+                        path,
+                        kind: DiffKind::Changed,
+                        old_value: old_v,
+                        new_value: new_v,
+                    });
+                }
+                continue;
+            }
+
+            if path.len() >= opts.max_depth {
+                if !Object::is(&old_v, &new_v) {
+                    out.push(DiffEntry {
+                        path,
+                        kind: DiffKind::Changed,
+                        old_value: old_v,
+                        new_value: new_v,
+                    });
+                }
+                continue;
+            }
+
+            if visiting
+                .iter()
+                .any(|(o, n)| Object::is(o, &old_v) && Object::is(n, &new_v))
+            {
+                continue;
+            }
+            visiting.push((old_v.clone(), new_v.clone()));
+            stack.push(None);
+
+            let mut keys: Vec<String> = Vec::new();
+            for array in [Object::keys(old_obj), Object::keys(new_obj)] {
+                for i in 0..array.length() {
+                    if let Some(key) = array.get(i).as_string() {
+                        if !keys.contains(&key) {
+                            keys.push(key);
+                        }
+                    }
+                }
+            }
+
+            for key in keys {
+                let key_value = JsValue::from_str(&key);
+                let old_has = Reflect::has(old_obj.as_ref(), &key_value).unwrap_or(false);
+                let new_has = Reflect::has(new_obj.as_ref(), &key_value).unwrap_or(false);
+                let mut child_path = path.clone();
+                child_path.push(key);
+
+                match (old_has, new_has) {
+                    (true, false) => {
+                        let old_child = Reflect::get(old_obj.as_ref(), &key_value).unwrap_or(JsValue::UNDEFINED);
+                        out.push(DiffEntry {
+                            path: child_path,
+                            kind: DiffKind::Removed,
+                            old_value: old_child,
+                            new_value: JsValue::UNDEFINED,
+                        });
+                    }
+                    (false, true) => {
+                        let new_child = Reflect::get(new_obj.as_ref(), &key_value).unwrap_or(JsValue::UNDEFINED);
+                        out.push(DiffEntry {
+                            path: child_path,
+                            kind: DiffKind::Added,
+                            old_value: JsValue::UNDEFINED,
+                            new_value: new_child,
+                        });
+                    }
+                    (true, true) => {
+                        let old_child = Reflect::get(old_obj.as_ref(), &key_value).unwrap_or(JsValue::UNDEFINED);
+                        let new_child = Reflect::get(new_obj.as_ref(), &key_value).unwrap_or(JsValue::UNDEFINED);
+                        stack.push(Some((child_path, old_child, new_child)));
+                    }
+                    (false, false) => {}
+                }
+            }
+        }
+
+        out
+    }
+
+    /// Applies a diff produced by [`Object::diff`] to `target`, mutating it
+    /// in place to match what the diffed `new` object looked like.
+    ///
+    /// Intermediate path segments must already resolve to objects on
+    /// `target`; this does not create intermediate containers.
+    pub fn apply_diff(target: &Object, diff: &[DiffEntry]) -> Result<(), JsValue> {
+        for entry in diff {
+            let (last_key, parents) = match entry.path.split_last() {
+                Some(split) => split,
+                None => continue,
+            };
+
+            let mut cursor = target.clone();
+            for key in parents {
+                let next = Reflect::get(cursor.as_ref(), &JsValue::from_str(key))?;
+                cursor = next
+                    .dyn_into::<Object>()
+                    .map_err(|_| JsValue::from_str("diff path does not resolve to an object"))?;
+            }
+
+            let key_value = JsValue::from_str(last_key);
+            match entry.kind {
+                DiffKind::Removed => {
+                    Reflect::delete_property(cursor.as_ref(), &key_value)?;
+                }
+                DiffKind::Added | DiffKind::Changed => {
+                    Reflect::set(cursor.as_ref(), &key_value, &entry.new_value)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Copies the own enumerable properties of each of `sources`, in order,
+    /// onto `target`, as if by calling [`Object::assign`] once per source.
+    ///
+    /// Later sources win over earlier ones, and all of them win over
+    /// whatever `target` already had, matching `Object.assign(target,
+    /// ...sources)` semantics.
+    pub fn assign_all(target: &Object, sources: &[&Object]) -> Object {
+        for source in sources {
+            Object::assign(target, source);
+        }
+        target.clone()
+    }
+
+    /// Recursively merges the own enumerable properties of `b` into a fresh
+    /// copy of `a`.
+    ///
+    /// Where both sides have a plain object at the same key, the merge
+    /// descends into it instead of replacing it outright. Arrays are
+    /// combined according to `opts`. `"__proto__"`, `"constructor"`, and
+    /// `"prototype"` keys are skipped everywhere in the walk, so a merge
+    /// can never be used to pollute a prototype chain.
+    ///
+    /// Cycles (a value reachable from itself through both `a` and `b` at
+    /// once) are rejected with an `Err` rather than silently truncated,
+    /// since unlike a diff there's no well-defined merged value to report
+    /// for one. This walks with real recursion (rather than the explicit
+    /// stack [`Object::diff`] uses) specifically so the ancestor set below
+    /// unwinds on return from each call -- a pair revisited through a
+    /// sibling branch, rather than through an actual cycle, must not be
+    /// mistaken for one.
+    pub fn merge_deep(a: &Object, b: &Object, opts: &MergeOptions) -> Result<Object, JsValue> {
+        fn is_unsafe_key(key: &str) -> bool {
+            key == "__proto__" || key == "constructor" || key == "prototype"
+        }
+
+        fn merge_into(
+            dest: &Object,
+            a_obj: &Object,
+            b_obj: &Object,
+            opts: &MergeOptions,
+            visiting: &mut Vec<(JsValue, JsValue)>,
+        ) -> Result<(), JsValue> {
+            let a_v: JsValue = a_obj.clone().into();
+            let b_v: JsValue = b_obj.clone().into();
+            if visiting
+                .iter()
+                .any(|(x, y)| Object::is(x, &a_v) && Object::is(y, &b_v))
+            {
+                return Err(JsValue::from_str("Object::merge_deep: cycle detected"));
+            }
+            visiting.push((a_v, b_v));
+
+            let b_keys = Object::keys(b_obj);
+            for i in 0..b_keys.length() {
+                let Some(key) = b_keys.get(i).as_string() else {
+                    continue;
+                };
+                if is_unsafe_key(&key) {
+                    continue;
+                }
+                let key_value = JsValue::from_str(&key);
+                let b_child = Reflect::get(b_obj.as_ref(), &key_value)?;
+                let a_child = Reflect::get(a_obj.as_ref(), &key_value)?;
+
+                let a_is_array = a_child.dyn_ref::<Array>().is_some();
+                let b_is_array = b_child.dyn_ref::<Array>().is_some();
+                if let (Some(a_arr), Some(b_arr)) = (a_child.dyn_ref::<Array>(), b_child.dyn_ref::<Array>()) {
+                    let merged = match opts.array_strategy {
+                        ArrayMergeStrategy::Replace => b_arr.clone(),
+                        ArrayMergeStrategy::Concat => a_arr.concat(b_arr),
+                        ArrayMergeStrategy::ByIndex => {
+                            let result = Array::new();
+                            let len = a_arr.length().max(b_arr.length());
+                            for idx in 0..len {
+                                if idx < a_arr.length() && idx < b_arr.length() {
+                                    let av = a_arr.get(idx);
+                                    let bv = b_arr.get(idx);
+                                    if let (Some(ao), Some(bo)) = (av.dyn_ref::<Object>(), bv.dyn_ref::<Object>()) {
+                                        if av.dyn_ref::<Array>().is_none() && bv.dyn_ref::<Array>().is_none() {
+                                            let child_dest = Object::new();
+                                            Object::assign(&child_dest, ao);
+                                            merge_into(&child_dest, ao, bo, opts, visiting)?;
+                                            result.push(&child_dest);
+                                            continue;
+                                        }
+                                    }
+                                    result.push(&bv);
+                                } else if idx < a_arr.length() {
+                                    result.push(&a_arr.get(idx));
+                                } else {
+                                    result.push(&b_arr.get(idx));
+                                }
+                            }
+                            result
+                        }
+                    };
+                    Reflect::set(dest.as_ref(), &key_value, &merged)?;
+                    continue;
+                }
+
+                match (a_child.dyn_into::<Object>(), b_child.clone().dyn_into::<Object>()) {
+                    (Ok(a_child_obj), Ok(b_child_obj)) if !a_is_array && !b_is_array => {
+                        let child_dest = Object::new();
+                        Object::assign(&child_dest, &a_child_obj);
+                        Reflect::set(dest.as_ref(), &key_value, &child_dest)?;
+                        merge_into(&child_dest, &a_child_obj, &b_child_obj, opts, visiting)?;
+                    }
+                    _ => {
+                        Reflect::set(dest.as_ref(), &key_value, &b_child)?;
+                    }
+                }
+            }
+
+            visiting.pop();
+            Ok(())
+        }
+
+        let out = Object::new();
+        Object::assign(&out, a);
+
+        let mut visiting: Vec<(JsValue, JsValue)> = Vec::new();
+        merge_into(&out, a, b, opts, &mut visiting)?;
+
+        Ok(out)
+    }
+}
+
+impl PartialEq for Object {
+    #[inline]
+    fn eq(&self, other: &Object) -> bool {
+        Object::is(self.as_ref(), other.as_ref())
+    }
+}
+
+impl Eq for Object {}
+
+impl Default for Object {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Why a [`FromJsObject`] field helper failed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FieldErrorKind {
+    /// The field was not present on the object (or was `undefined`).
+    Missing,
+    /// The field was present but didn't convert to the expected type.
+    WrongType {
+        /// The type name the corresponding [`FromJsValue`] impl expected.
+        expected: &'static str,
+    },
+}
+
+/// The error returned by the `field_*` helpers and by [`FromJsObject`]
+/// implementations: the dotted path of the field that failed to convert,
+/// plus why.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FieldError {
+    /// The dotted path to the field that failed, e.g. `"address.zip"`.
+    pub field: String,
+    /// What went wrong reading it.
+    pub kind: FieldErrorKind,
+}
+
+impl FieldError {
+    /// Builds a "field was missing" error for `field`.
+    pub fn missing(field: &str) -> Self {
+        FieldError {
+            field: field.into(),
+            kind: FieldErrorKind::Missing,
+        }
+    }
+
+    /// Builds a "field had the wrong type" error for `field`, naming the
+    /// `expected` type.
+    pub fn wrong_type(field: &str, expected: &'static str) -> Self {
+        FieldError {
+            field: field.into(),
+            kind: FieldErrorKind::WrongType { expected },
+        }
+    }
+
+    /// Returns a copy of this error with `prefix.` prepended to the field
+    /// path, used by nested [`FromJsObject`] impls to report the full path
+    /// from the outermost struct.
+    pub fn nest(self, prefix: &str) -> Self {
+        FieldError {
+            field: alloc::format!("{}.{}", prefix, self.field),
+            kind: self.kind,
+        }
+    }
+}
+
+/// A lightweight, hand-written alternative to full `serde` integration:
+/// implement this for a Rust struct to pull its fields out of a JS `Object`
+/// one at a time via the `field_*` helpers, with errors that name the exact
+/// field (and, through [`FieldError::nest`], the exact field *path*) that
+/// failed to convert.
+pub trait FromJsObject: Sized {
+    /// Builds `Self` from the properties of `obj`.
+    fn from_js_object(obj: &Object) -> Result<Self, FieldError>;
+}
+
+/// A value that can be read out of a single JS object property, used as the
+/// leaf conversion by the `field_*` helpers below.
+pub trait FromJsValue: Sized {
+    /// The name used in [`FieldErrorKind::WrongType`] when `value` doesn't
+    /// convert, e.g. `"string"` or `"Array"`.
+    const EXPECTED: &'static str;
+
+    /// Converts `value` into `Self`, or `None` if `value` isn't of the
+    /// expected type.
+    fn from_js_value(value: &JsValue) -> Option<Self>;
+}
+
+impl FromJsValue for String {
+    const EXPECTED: &'static str = "string";
+
+    fn from_js_value(value: &JsValue) -> Option<Self> {
+        value.as_string()
+    }
+}
+
+impl FromJsValue for f64 {
+    const EXPECTED: &'static str = "number";
+
+    fn from_js_value(value: &JsValue) -> Option<Self> {
+        value.as_f64()
+    }
+}
+
+impl FromJsValue for bool {
+    const EXPECTED: &'static str = "boolean";
+
+    fn from_js_value(value: &JsValue) -> Option<Self> {
+        value.as_bool()
+    }
+}
+
+impl FromJsValue for i32 {
+    const EXPECTED: &'static str = "number";
+
+    fn from_js_value(value: &JsValue) -> Option<Self> {
+        value.as_f64().map(|n| n as i32)
+    }
+}
+
+impl FromJsValue for u32 {
+    const EXPECTED: &'static str = "number";
+
+    fn from_js_value(value: &JsValue) -> Option<Self> {
+        value.as_f64().map(|n| n as u32)
+    }
+}
+
+impl FromJsValue for JsValue {
+    const EXPECTED: &'static str = "any";
+
+    fn from_js_value(value: &JsValue) -> Option<Self> {
+        Some(value.clone())
+    }
+}
+
+impl FromJsValue for Object {
+    const EXPECTED: &'static str = "object";
+
+    fn from_js_value(value: &JsValue) -> Option<Self> {
+        Object::try_from(value).cloned()
+    }
+}
+
+impl FromJsValue for Array {
+    const EXPECTED: &'static str = "Array";
+
+    fn from_js_value(value: &JsValue) -> Option<Self> {
+        if Array::is_array(value) {
+            Some(value.clone().unchecked_into())
+        } else {
+            None
+        }
+    }
+}
+
+impl FromJsValue for JsString {
+    const EXPECTED: &'static str = "string";
+
+    fn from_js_value(value: &JsValue) -> Option<Self> {
+        value.dyn_ref::<JsString>().cloned()
+    }
+}
+
+impl<T: FromJsValue> FromJsValue for Vec<T> {
+    const EXPECTED: &'static str = "Array";
+
+    fn from_js_value(value: &JsValue) -> Option<Self> {
+        if !Array::is_array(value) {
+            return None;
+        }
+        let array: &Array = value.unchecked_ref();
+        array.iter().map(|v| T::from_js_value(&v)).collect()
+    }
+}
+
+impl<T: FromJsValue> FromJsValue for Option<T> {
+    const EXPECTED: &'static str = "any";
+
+    fn from_js_value(value: &JsValue) -> Option<Self> {
+        if value.is_null() || value.is_undefined() {
+            Some(None)
+        } else {
+            T::from_js_value(value).map(Some)
+        }
+    }
+}
+
+fn field_value(obj: &Object, name: &str) -> Result<JsValue, FieldError> {
+    let key = JsValue::from_str(name);
+    let present = Reflect::has(obj.as_ref(), &key).unwrap_or(false);
+    if !present {
+        return Err(FieldError::missing(name));
+    }
+    Reflect::get(obj.as_ref(), &key).map_err(|_| FieldError::missing(name))
+}
+
+fn field_typed<T: FromJsValue>(obj: &Object, name: &str) -> Result<T, FieldError> {
+    let value = field_value(obj, name)?;
+    if value.is_undefined() {
+        return Err(FieldError::missing(name));
+    }
+    T::from_js_value(&value).ok_or_else(|| FieldError::wrong_type(name, T::EXPECTED))
+}
+
+/// Reads the `name` property of `obj` as a `String`.
+pub fn field_string(obj: &Object, name: &str) -> Result<String, FieldError> {
+    field_typed(obj, name)
+}
+
+/// Reads the `name` property of `obj` as an `f64`.
+pub fn field_f64(obj: &Object, name: &str) -> Result<f64, FieldError> {
+    field_typed(obj, name)
+}
+
+/// Reads the `name` property of `obj` as a `bool`.
+pub fn field_bool(obj: &Object, name: &str) -> Result<bool, FieldError> {
+    field_typed(obj, name)
+}
+
+/// Reads the `name` property of `obj` as a JS array, converting each
+/// element with `T::from_js_value`.
+pub fn field_array<T: FromJsValue>(obj: &Object, name: &str) -> Result<Vec<T>, FieldError> {
+    field_typed(obj, name)
+}
+
+/// Reads the `name` property of `obj`, treating it as absent when it's
+/// missing, `null`, or `undefined` rather than erroring.
+pub fn field_optional<T: FromJsValue>(obj: &Object, name: &str) -> Result<Option<T>, FieldError> {
+    let key = JsValue::from_str(name);
+    let present = Reflect::has(obj.as_ref(), &key).unwrap_or(false);
+    if !present {
+        return Ok(None);
+    }
+    let value = Reflect::get(obj.as_ref(), &key).map_err(|_| FieldError::missing(name))?;
+    if value.is_null() || value.is_undefined() {
+        return Ok(None);
+    }
+    T::from_js_value(&value)
+        .map(Some)
+        .ok_or_else(|| FieldError::wrong_type(name, T::EXPECTED))
+}
+
+/// A lightweight structural shape used by [`Object::matches_shape`] and
+/// [`Array::matches_shape`] to validate a decoded JS value without pulling
+/// in `serde`.
+#[derive(Clone, Debug)]
+pub enum Shape {
+    /// A JS string.
+    Str,
+    /// A JS number.
+    Num,
+    /// A JS boolean.
+    Bool,
+    /// `null`.
+    Null,
+    /// Matches any value.
+    Any,
+    /// Matches if the value is missing, `null`, or `undefined`, or if it
+    /// matches the inner shape.
+    Opt(Box<Shape>),
+    /// A JS array whose every element matches the inner shape.
+    Arr(Box<Shape>),
+    /// A JS object whose named fields each match their shape.
+    Obj(Vec<(&'static str, Shape)>),
+}
+
+/// Why a value failed [`Object::matches_shape`] or [`Array::matches_shape`],
+/// carrying a JSON-pointer-style path (e.g. `"/items/2/price"`) to the
+/// first mismatch.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ShapeError {
+    /// JSON-pointer-style path to the value that didn't match.
+    pub path: String,
+}
+
+/// Checks `value` against `shape`, walking iteratively with an explicit
+/// stack so a deeply nested JS value can't overflow the Rust call stack.
+fn shape_matches(value: &JsValue, shape: &Shape) -> Result<(), ShapeError> {
+    let mut stack: Vec<(JsValue, &Shape, String)> = alloc::vec![(value.clone(), shape, String::new())];
+
+    while let Some((value, shape, path)) = stack.pop() {
+        match shape {
+            Shape::Any => {}
+            Shape::Null => {
+                if !value.is_null() {
+                    return Err(ShapeError { path });
+                }
+            }
+            Shape::Str => {
+                if !value.is_string() {
+                    return Err(ShapeError { path });
+                }
+            }
+            Shape::Num => {
+                if value.as_f64().is_none() {
+                    return Err(ShapeError { path });
+                }
+            }
+            Shape::Bool => {
+                if value.as_bool().is_none() {
+                    return Err(ShapeError { path });
+                }
+            }
+            Shape::Opt(inner) => {
+                if !(value.is_null() || value.is_undefined()) {
+                    stack.push((value, inner, path));
+                }
+            }
+            Shape::Arr(inner) => {
+                let array = value
+                    .dyn_ref::<Array>()
+                    .ok_or_else(|| ShapeError { path: path.clone() })?;
+                // Pushed in reverse so they pop in order and the first
+                // mismatch in document order is the one reported.
+                for i in (0..array.length()).rev() {
+                    let item_path = alloc::format!("{}/{}", path, i);
+                    stack.push((array.get(i), inner, item_path));
+                }
+            }
+            Shape::Obj(fields) => {
+                let object = value
+                    .dyn_ref::<Object>()
+                    .ok_or_else(|| ShapeError { path: path.clone() })?;
+                for (name, field_shape) in fields.iter().rev() {
+                    let field_path = alloc::format!("{}/{}", path, name);
+                    let field_value = Reflect::get(object.as_ref(), &JsValue::from_str(name))
+                        .map_err(|_| ShapeError {
+                            path: field_path.clone(),
+                        })?;
+                    stack.push((field_value, field_shape, field_path));
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+impl Object {
+    /// Checks that this object's fields structurally match `shape`,
+    /// returning the JSON-pointer-style path of the first mismatch on
+    /// failure.
+    pub fn matches_shape(&self, shape: &Shape) -> Result<(), ShapeError> {
+        shape_matches(self.as_ref(), shape)
+    }
+}
+
+impl Array {
+    /// Checks that this array structurally matches `shape` (typically a
+    /// [`Shape::Arr`]), returning the JSON-pointer-style path of the first
+    /// mismatch on failure.
+    pub fn matches_shape(&self, shape: &Shape) -> Result<(), ShapeError> {
+        shape_matches(self.as_ref(), shape)
+    }
+}
+
+// Proxy
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(typescript_type = "ProxyConstructor")]
+    #[derive(Clone, Debug)]
+    pub type Proxy;
+
+    /// The [`Proxy`] object is used to define custom behavior for fundamental
+    /// operations (e.g. property lookup, assignment, enumeration, function
+    /// invocation, etc).
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Proxy)
+    #[wasm_bindgen(constructor)]
+    pub fn new(target: &JsValue, handler: &Object) -> Proxy;
+
+    /// The `Proxy.revocable()` method is used to create a revocable [`Proxy`]
+    /// object.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Proxy/revocable)
+    #[wasm_bindgen(static_method_of = Proxy)]
+    pub fn revocable(target: &JsValue, handler: &Object) -> Object;
+}
+
+#[derive(Default)]
+struct ObservedMapState {
+    on_set: Option<Box<dyn FnMut(JsValue, JsValue)>>,
+    on_delete: Option<Box<dyn FnMut(JsValue)>>,
+    on_clear: Option<Box<dyn FnMut()>>,
+}
+
+/// A [`Map`] wrapped in a [`Proxy`] whose mutating methods (`set`,
+/// `delete`, `clear`) invoke registered Rust callbacks, so Rust code can
+/// observe mutations made by JS holding a reference to
+/// [`ObservedMap::as_map`].
+///
+/// Every other method (`get`, `has`, `size`, iteration, ...) is passed
+/// through unmodified via the proxy's `get` trap, explicitly re-bound to
+/// the real underlying `Map`: those methods read the map's internal slots
+/// directly and throw `TypeError` if called with a `Proxy` (rather than an
+/// actual `Map`) as `this`.
+pub struct ObservedMap {
+    map: Map,
+    proxy: Map,
+    state: Rc<RefCell<ObservedMapState>>,
+    _get_trap: Closure<dyn FnMut(JsValue, JsValue) -> JsValue>,
+    _set_trap: Closure<dyn FnMut(JsValue, JsValue) -> JsValue>,
+    _delete_trap: Closure<dyn FnMut(JsValue) -> bool>,
+    _clear_trap: Closure<dyn FnMut()>,
+}
+
+impl ObservedMap {
+    /// Wraps `map` for observation. `map` itself is still usable directly;
+    /// only mutations made through [`ObservedMap::as_map`] are observed.
+    pub fn new(map: Map) -> ObservedMap {
+        let state = Rc::new(RefCell::new(ObservedMapState::default()));
+
+        let set_target = map.clone();
+        let set_state = state.clone();
+        let set_trap = Closure::wrap(Box::new(move |key: JsValue, value: JsValue| -> JsValue {
+            set_target.set(&key, &value);
+            if let Some(on_set) = set_state.borrow_mut().on_set.as_mut() {
+                on_set(key, value);
+            }
+            set_target.clone().into()
+        }) as Box<dyn FnMut(JsValue, JsValue) -> JsValue>);
+        let set_function: Function = set_trap.as_ref().unchecked_ref::<Function>().clone();
+
+        let delete_target = map.clone();
+        let delete_state = state.clone();
+        let delete_trap = Closure::wrap(Box::new(move |key: JsValue| -> bool {
+            let existed = delete_target.delete(&key);
+            if existed {
+                if let Some(on_delete) = delete_state.borrow_mut().on_delete.as_mut() {
+                    on_delete(key);
+                }
+            }
+            existed
+        }) as Box<dyn FnMut(JsValue) -> bool>);
+        let delete_function: Function = delete_trap.as_ref().unchecked_ref::<Function>().clone();
+
+        let clear_target = map.clone();
+        let clear_state = state.clone();
+        let clear_trap = Closure::wrap(Box::new(move || {
+            clear_target.clear();
+            if let Some(on_clear) = clear_state.borrow_mut().on_clear.as_mut() {
+                on_clear();
+            }
+        }) as Box<dyn FnMut()>);
+        let clear_function: Function = clear_trap.as_ref().unchecked_ref::<Function>().clone();
+
+        let get_target = map.clone();
+        let get_trap = Closure::wrap(Box::new(move |_target: JsValue, prop: JsValue| -> JsValue {
+            match prop.as_string().as_deref() {
+                Some("set") => return set_function.clone().into(),
+                Some("delete") => return delete_function.clone().into(),
+                Some("clear") => return clear_function.clone().into(),
+                _ => {}
+            }
+            let value = Reflect::get(get_target.as_ref(), &prop).unwrap_or(JsValue::UNDEFINED);
+            match value.dyn_ref::<Function>() {
+                Some(function) => function.bind(get_target.as_ref()).into(),
+                None => value,
+            }
+        }) as Box<dyn FnMut(JsValue, JsValue) -> JsValue>);
+        let get_function: Function = get_trap.as_ref().unchecked_ref::<Function>().clone();
+
+        let handler = Object::new();
+        let _ = Reflect::set(handler.as_ref(), &JsValue::from_str("get"), get_function.as_ref());
+        let proxy: Map = Proxy::new(map.as_ref(), &handler).unchecked_into();
+
+        ObservedMap {
+            map,
+            proxy,
+            state,
+            _get_trap: get_trap,
+            _set_trap: set_trap,
+            _delete_trap: delete_trap,
+            _clear_trap: clear_trap,
+        }
+    }
+
+    /// Registers `f` to run (with the key and value that were set) every
+    /// time [`ObservedMap::as_map`]'s `set` method is called. Replaces any
+    /// previously registered callback.
+    pub fn on_set(&self, f: impl FnMut(JsValue, JsValue) + 'static) {
+        self.state.borrow_mut().on_set = Some(Box::new(f));
+    }
+
+    /// Registers `f` to run (with the deleted key) every time
+    /// [`ObservedMap::as_map`]'s `delete` method actually removes an entry.
+    /// Replaces any previously registered callback.
+    pub fn on_delete(&self, f: impl FnMut(JsValue) + 'static) {
+        self.state.borrow_mut().on_delete = Some(Box::new(f));
+    }
+
+    /// Registers `f` to run every time [`ObservedMap::as_map`]'s `clear`
+    /// method is called. Replaces any previously registered callback.
+    pub fn on_clear(&self, f: impl FnMut() + 'static) {
+        self.state.borrow_mut().on_clear = Some(Box::new(f));
+    }
+
+    /// Returns the real, unproxied map passed to [`ObservedMap::new`].
+    pub fn inner(&self) -> &Map {
+        &self.map
+    }
+
+    /// Returns the proxied view to hand to JS: calling its `set`, `delete`,
+    /// or `clear` methods triggers the registered callbacks.
+    pub fn as_map(&self) -> &Map {
+        &self.proxy
+    }
+}
+
+#[derive(Default)]
+struct ObservedSetState {
+    on_add: Option<Box<dyn FnMut(JsValue)>>,
+    on_delete: Option<Box<dyn FnMut(JsValue)>>,
+    on_clear: Option<Box<dyn FnMut()>>,
+}
+
+/// A [`Set`] wrapped in a [`Proxy`] whose mutating methods (`add`,
+/// `delete`, `clear`) invoke registered Rust callbacks. See [`ObservedMap`]
+/// for the full rationale; this is the same design applied to `Set`.
+pub struct ObservedSet {
+    set: Set,
+    proxy: Set,
+    state: Rc<RefCell<ObservedSetState>>,
+    _get_trap: Closure<dyn FnMut(JsValue, JsValue) -> JsValue>,
+    _add_trap: Closure<dyn FnMut(JsValue) -> JsValue>,
+    _delete_trap: Closure<dyn FnMut(JsValue) -> bool>,
+    _clear_trap: Closure<dyn FnMut()>,
+}
+
+impl ObservedSet {
+    /// Wraps `set` for observation. `set` itself is still usable directly;
+    /// only mutations made through [`ObservedSet::as_set`] are observed.
+    pub fn new(set: Set) -> ObservedSet {
+        let state = Rc::new(RefCell::new(ObservedSetState::default()));
+
+        let add_target = set.clone();
+        let add_state = state.clone();
+        let add_trap = Closure::wrap(Box::new(move |value: JsValue| -> JsValue {
+            add_target.add(&value);
+            if let Some(on_add) = add_state.borrow_mut().on_add.as_mut() {
+                on_add(value);
+            }
+            add_target.clone().into()
+        }) as Box<dyn FnMut(JsValue) -> JsValue>);
+        let add_function: Function = add_trap.as_ref().unchecked_ref::<Function>().clone();
+
+        let delete_target = set.clone();
+        let delete_state = state.clone();
+        let delete_trap = Closure::wrap(Box::new(move |value: JsValue| -> bool {
+            let existed = delete_target.delete(&value);
+            if existed {
+                if let Some(on_delete) = delete_state.borrow_mut().on_delete.as_mut() {
+                    on_delete(value);
+                }
+            }
+            existed
+        }) as Box<dyn FnMut(JsValue) -> bool>);
+        let delete_function: Function = delete_trap.as_ref().unchecked_ref::<Function>().clone();
+
+        let clear_target = set.clone();
+        let clear_state = state.clone();
+        let clear_trap = Closure::wrap(Box::new(move || {
+            clear_target.clear();
+            if let Some(on_clear) = clear_state.borrow_mut().on_clear.as_mut() {
+                on_clear();
+            }
+        }) as Box<dyn FnMut()>);
+        let clear_function: Function = clear_trap.as_ref().unchecked_ref::<Function>().clone();
+
+        let get_target = set.clone();
+        let get_trap = Closure::wrap(Box::new(move |_target: JsValue, prop: JsValue| -> JsValue {
+            match prop.as_string().as_deref() {
+                Some("add") => return add_function.clone().into(),
+                Some("delete") => return delete_function.clone().into(),
+                Some("clear") => return clear_function.clone().into(),
+                _ => {}
+            }
+            let value = Reflect::get(get_target.as_ref(), &prop).unwrap_or(JsValue::UNDEFINED);
+            match value.dyn_ref::<Function>() {
+                Some(function) => function.bind(get_target.as_ref()).into(),
+                None => value,
+            }
+        }) as Box<dyn FnMut(JsValue, JsValue) -> JsValue>);
+        let get_function: Function = get_trap.as_ref().unchecked_ref::<Function>().clone();
+
+        let handler = Object::new();
+        let _ = Reflect::set(handler.as_ref(), &JsValue::from_str("get"), get_function.as_ref());
+        let proxy: Set = Proxy::new(set.as_ref(), &handler).unchecked_into();
+
+        ObservedSet {
+            set,
+            proxy,
+            state,
+            _get_trap: get_trap,
+            _add_trap: add_trap,
+            _delete_trap: delete_trap,
+            _clear_trap: clear_trap,
+        }
+    }
+
+    /// Registers `f` to run (with the added value) every time
+    /// [`ObservedSet::as_set`]'s `add` method is called. Replaces any
+    /// previously registered callback.
+    pub fn on_add(&self, f: impl FnMut(JsValue) + 'static) {
+        self.state.borrow_mut().on_add = Some(Box::new(f));
+    }
+
+    /// Registers `f` to run (with the deleted value) every time
+    /// [`ObservedSet::as_set`]'s `delete` method actually removes an entry.
+    /// Replaces any previously registered callback.
+    pub fn on_delete(&self, f: impl FnMut(JsValue) + 'static) {
+        self.state.borrow_mut().on_delete = Some(Box::new(f));
+    }
+
+    /// Registers `f` to run every time [`ObservedSet::as_set`]'s `clear`
+    /// method is called. Replaces any previously registered callback.
+    pub fn on_clear(&self, f: impl FnMut() + 'static) {
+        self.state.borrow_mut().on_clear = Some(Box::new(f));
+    }
+
+    /// Returns the real, unproxied set passed to [`ObservedSet::new`].
+    pub fn inner(&self) -> &Set {
+        &self.set
+    }
+
+    /// Returns the proxied view to hand to JS: calling its `add`,
+    /// `delete`, or `clear` methods triggers the registered callbacks.
+    pub fn as_set(&self) -> &Set {
+        &self.proxy
+    }
+}
+
+/// Returns the per-thread cache mapping an object to the read-only view
+/// [`Proxy`]s already built for it (its deep and its shallow view, stored
+/// apart), so that repeated calls to
+/// [`Object::read_only_view`] (or [`Object::read_only_view_shallow`]) on the
+/// same object, including the recursive wrapping a deep view does for nested
+/// objects, hand back the identical proxy rather than a fresh one each time.
+fn read_only_view_cache() -> WeakMap {
+    #[cfg(feature = "std")]
+    {
+        thread_local!(static CACHE: WeakMap = WeakMap::new());
+        CACHE.with(|c| c.clone())
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        use once_cell::unsync::Lazy;
+
+        struct Wrapper<T>(Lazy<T>);
+
+        #[cfg(not(target_feature = "atomics"))]
+        unsafe impl<T> Sync for Wrapper<T> {}
+
+        #[cfg(not(target_feature = "atomics"))]
+        unsafe impl<T> Send for Wrapper<T> {}
+
+        #[cfg_attr(target_feature = "atomics", thread_local)]
+        static CACHE: Wrapper<WeakMap> = Wrapper(Lazy::new(WeakMap::new));
+
+        CACHE.0.clone()
+    }
+}
+
+/// Builds a throwing trap closure for one of the mutating `Proxy` traps
+/// (`set`, `defineProperty`, `deleteProperty`, `setPrototypeOf`), leaking it
+/// and handing back the resulting [`Function`].
+///
+/// All of these traps are called with varying arity, but since the closure
+/// ignores its arguments entirely we represent it with the widest signature
+/// (four `JsValue`s) and let JS simply drop the extras.
+fn throwing_trap(message: &'static str) -> Function {
+    let closure = Closure::wrap(Box::new(
+        move |_a: JsValue, _b: JsValue, _c: JsValue, _d: JsValue| -> Result<bool, JsValue> {
+            Err(TypeError::new(message).into())
+        },
+    ) as Box<dyn FnMut(JsValue, JsValue, JsValue, JsValue) -> Result<bool, JsValue>>);
+    let function: Function = closure.as_ref().unchecked_ref::<Function>().clone();
+    closure.forget();
+    function
+}
+
+impl Object {
+    /// Returns a read-only [`Proxy`] view of `target`: any attempt to
+    /// `set`, `defineProperty`, `deleteProperty`, or `setPrototypeOf` on the
+    /// returned object throws a [`TypeError`]. Unlike
+    /// [`Object::read_only_view_shallow`], this is a *deep* view -- any
+    /// plain object or array read back out of it through `get` is itself
+    /// wrapped in a read-only view before being returned, recursively.
+    /// Functions are passed through unwrapped and callable.
+    ///
+    /// Calling this repeatedly on the same object, or encountering the same
+    /// nested object while walking a deep view, always returns the same
+    /// proxy rather than building a new one, so identity comparisons
+    /// (`===` in JS) on repeated reads of the same value hold.
+    pub fn read_only_view(target: &Object) -> Object {
+        Object::build_read_only_view(target, true)
+    }
+
+    /// Like [`Object::read_only_view`], but only `target` itself is
+    /// protected: nested objects or arrays read back out through `get` are
+    /// handed back as-is, fully mutable.
+    pub fn read_only_view_shallow(target: &Object) -> Object {
+        Object::build_read_only_view(target, false)
+    }
+
+    fn build_read_only_view(target: &Object, deep: bool) -> Object {
+        // Each cache entry is a two-slot array: the deep view at 0, the
+        // shallow one at 1.
+        let cache = read_only_view_cache();
+        let slot = if deep { 0 } else { 1 };
+        let views = match cache.get(target).dyn_into::<Array>() {
+            Ok(views) => views,
+            Err(_) => {
+                let views = Array::new();
+                cache.set(target, views.as_ref());
+                views
+            }
+        };
+        let existing = views.get(slot);
+        if !existing.is_undefined() {
+            if let Ok(proxy) = existing.dyn_into::<Object>() {
+                return proxy;
+            }
+        }
+
+        let get_target = target.clone();
+        let get_trap = Closure::wrap(Box::new(move |_t: JsValue, prop: JsValue| -> JsValue {
+            let value = Reflect::get(get_target.as_ref(), &prop).unwrap_or(JsValue::UNDEFINED);
+            if !deep || value.is_function() || value.is_null() || !value.is_object() {
+                return value;
+            }
+            match value.dyn_ref::<Object>() {
+                Some(obj) => Object::build_read_only_view(obj, true).into(),
+                None => value,
+            }
+        }) as Box<dyn FnMut(JsValue, JsValue) -> JsValue>);
+        let get_function: Function = get_trap.as_ref().unchecked_ref::<Function>().clone();
+        get_trap.forget();
+
+        let handler = Object::new();
+        let _ = Reflect::set(handler.as_ref(), &JsValue::from_str("get"), get_function.as_ref());
+        let _ = Reflect::set(
+            handler.as_ref(),
+            &JsValue::from_str("set"),
+            throwing_trap("Cannot assign to a property of a read-only view").as_ref(),
+        );
+        let _ = Reflect::set(
+            handler.as_ref(),
+            &JsValue::from_str("defineProperty"),
+            throwing_trap("Cannot define a property on a read-only view").as_ref(),
+        );
+        let _ = Reflect::set(
+            handler.as_ref(),
+            &JsValue::from_str("deleteProperty"),
+            throwing_trap("Cannot delete a property of a read-only view").as_ref(),
+        );
+        let _ = Reflect::set(
+            handler.as_ref(),
+            &JsValue::from_str("setPrototypeOf"),
+            throwing_trap("Cannot change the prototype of a read-only view").as_ref(),
+        );
+
+        let proxy: Object = Proxy::new(target.as_ref(), &handler).unchecked_into();
+        views.set(slot, proxy.clone().into());
+        proxy
+    }
+
+    /// Returns `self`'s own, non-index string keys (enumerable or not) sorted in
+    /// ascending order of their UTF-16 code unit values (the ordering
+    /// `<`/`>` would give two [`JsString`]s), rather than the insertion
+    /// order [`Object::keys`] preserves.
+    ///
+    /// Array-index keys (e.g. `"0"`, `"2"`) and symbol keys are omitted;
+    /// use [`Reflect::own_keys_partitioned`] if you need those too.
+    pub fn own_string_keys_sorted(&self) -> Vec<JsString> {
+        let partitioned = Reflect::own_keys_partitioned(self.as_ref()).unwrap_or_default();
+        let mut strings = partitioned.strings;
+        strings.sort_by(|a, b| a.iter().cmp(b.iter()));
+        strings
+    }
+}
+
+// RangeError
+#[wasm_bindgen]
+extern "C" {
+    /// The `RangeError` object indicates an error when a value is not in the set
+    /// or range of allowed values.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RangeError)
+    #[wasm_bindgen(extends = Error, extends = Object, typescript_type = "RangeError")]
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub type RangeError;
+
+    /// The `RangeError` object indicates an error when a value is not in the set
+    /// or range of allowed values.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RangeError)
+    #[wasm_bindgen(constructor)]
+    pub fn new(message: &str) -> RangeError;
+    #[wasm_bindgen(constructor)]
+    pub fn new_with_options(message: &str, options: &Object) -> RangeError;
+}
+
+error_new_with_cause!(RangeError);
+
+// ReferenceError
+#[wasm_bindgen]
+extern "C" {
+    /// The `ReferenceError` object represents an error when a non-existent
+    /// variable is referenced.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ReferenceError)
+    #[wasm_bindgen(extends = Error, extends = Object, typescript_type = "ReferenceError")]
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub type ReferenceError;
+
+    /// The `ReferenceError` object represents an error when a non-existent
+    /// variable is referenced.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ReferenceError)
+    #[wasm_bindgen(constructor)]
+    pub fn new(message: &str) -> ReferenceError;
+    #[wasm_bindgen(constructor)]
+    pub fn new_with_options(message: &str, options: &Object) -> ReferenceError;
+}
+
+error_new_with_cause!(ReferenceError);
+
+#[allow(non_snake_case)]
+pub mod Reflect {
+    use super::*;
+
+    // Reflect
+    #[wasm_bindgen]
+    extern "C" {
+        /// The static `Reflect.apply()` method calls a target function with
+        /// arguments as specified.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/apply)
+        #[wasm_bindgen(js_namespace = Reflect, catch)]
+        pub fn apply(
+            target: &Function,
+            this_argument: &JsValue,
+            arguments_list: &Array,
+        ) -> Result<JsValue, JsValue>;
+
+        /// The static `Reflect.construct()` method acts like the new operator, but
+        /// as a function.  It is equivalent to calling `new target(...args)`. It
+        /// gives also the added option to specify a different prototype.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/construct)
+        #[wasm_bindgen(js_namespace = Reflect, catch)]
+        pub fn construct(target: &Function, arguments_list: &Array) -> Result<JsValue, JsValue>;
+
+        /// The static `Reflect.construct()` method acts like the new operator, but
+        /// as a function.  It is equivalent to calling `new target(...args)`. It
+        /// gives also the added option to specify a different prototype.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/construct)
+        #[wasm_bindgen(js_namespace = Reflect, js_name = construct, catch)]
+        pub fn construct_with_new_target(
+            target: &Function,
+            arguments_list: &Array,
+            new_target: &Function,
+        ) -> Result<JsValue, JsValue>;
+
+        /// The static `Reflect.defineProperty()` method is like
+        /// `Object.defineProperty()` but returns a `Boolean`.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/defineProperty)
+        #[wasm_bindgen(js_namespace = Reflect, js_name = defineProperty, catch)]
+        pub fn define_property(
+            target: &Object,
+            property_key: &JsValue,
+            attributes: &Object,
+        ) -> Result<bool, JsValue>;
+
+        /// The static `Reflect.deleteProperty()` method allows to delete
+        /// properties.  It is like the `delete` operator as a function.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/deleteProperty)
+        #[wasm_bindgen(js_namespace = Reflect, js_name = deleteProperty, catch)]
+        pub fn delete_property(target: &Object, key: &JsValue) -> Result<bool, JsValue>;
+
+        /// The static `Reflect.get()` method works like getting a property from
+        /// an object (`target[propertyKey]`) as a function.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/get)
+        #[wasm_bindgen(js_namespace = Reflect, catch)]
+        pub fn get(target: &JsValue, key: &JsValue) -> Result<JsValue, JsValue>;
+
+        /// The same as [`get`](fn.get.html)
+        /// except the key is an `f64`, which is slightly faster.
+        #[wasm_bindgen(js_namespace = Reflect, js_name = "get", catch)]
+        pub fn get_f64(target: &JsValue, key: f64) -> Result<JsValue, JsValue>;
+
+        /// The same as [`get`](fn.get.html)
+        /// except the key is a `u32`, which is slightly faster.
+        #[wasm_bindgen(js_namespace = Reflect, js_name = "get", catch)]
+        pub fn get_u32(target: &JsValue, key: u32) -> Result<JsValue, JsValue>;
+
+        /// The static `Reflect.getOwnPropertyDescriptor()` method is similar to
+        /// `Object.getOwnPropertyDescriptor()`. It returns a property descriptor
+        /// of the given property if it exists on the object, `undefined` otherwise.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/getOwnPropertyDescriptor)
+        #[wasm_bindgen(js_namespace = Reflect, js_name = getOwnPropertyDescriptor, catch)]
+        pub fn get_own_property_descriptor(
+            target: &Object,
+            property_key: &JsValue,
+        ) -> Result<JsValue, JsValue>;
+
+        /// The static `Reflect.getPrototypeOf()` method is almost the same
+        /// method as `Object.getPrototypeOf()`. It returns the prototype
+        /// (i.e. the value of the internal `[[Prototype]]` property) of
+        /// the specified object.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/getPrototypeOf)
+        #[wasm_bindgen(js_namespace = Reflect, js_name = getPrototypeOf, catch)]
+        pub fn get_prototype_of(target: &JsValue) -> Result<Object, JsValue>;
+
+        /// The static `Reflect.has()` method works like the in operator as a
+        /// function.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/has)
+        #[wasm_bindgen(js_namespace = Reflect, catch)]
+        pub fn has(target: &JsValue, property_key: &JsValue) -> Result<bool, JsValue>;
+
+        /// The static `Reflect.isExtensible()` method determines if an object is
+        /// extensible (whether it can have new properties added to it). It is
+        /// similar to `Object.isExtensible()`, but with some differences.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/isExtensible)
+        #[wasm_bindgen(js_namespace = Reflect, js_name = isExtensible, catch)]
+        pub fn is_extensible(target: &Object) -> Result<bool, JsValue>;
+
+        /// The static `Reflect.ownKeys()` method returns an array of the
+        /// target object's own property keys.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/ownKeys)
+        #[wasm_bindgen(js_namespace = Reflect, js_name = ownKeys, catch)]
+        pub fn own_keys(target: &JsValue) -> Result<Array, JsValue>;
+
+        /// The static `Reflect.preventExtensions()` method prevents new
+        /// properties from ever being added to an object (i.e. prevents
+        /// future extensions to the object). It is similar to
+        /// `Object.preventExtensions()`, but with some differences.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/preventExtensions)
+        #[wasm_bindgen(js_namespace = Reflect, js_name = preventExtensions, catch)]
+        pub fn prevent_extensions(target: &Object) -> Result<bool, JsValue>;
+
+        /// The static `Reflect.set()` method works like setting a
+        /// property on an object.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/set)
+        #[wasm_bindgen(js_namespace = Reflect, catch)]
+        pub fn set(
+            target: &JsValue,
+            property_key: &JsValue,
+            value: &JsValue,
+        ) -> Result<bool, JsValue>;
+
+        /// The same as [`set`](fn.set.html)
+        /// except the key is an `f64`, which is slightly faster.
+        #[wasm_bindgen(js_namespace = Reflect, js_name = "set", catch)]
+        pub fn set_f64(
+            target: &JsValue,
+            property_key: f64,
+            value: &JsValue,
+        ) -> Result<bool, JsValue>;
+
+        /// The same as [`set`](fn.set.html)
+        /// except the key is a `u32`, which is slightly faster.
+        #[wasm_bindgen(js_namespace = Reflect, js_name = "set", catch)]
+        pub fn set_u32(
+            target: &JsValue,
+            property_key: u32,
+            value: &JsValue,
+        ) -> Result<bool, JsValue>;
+
+        /// The static `Reflect.set()` method works like setting a
+        /// property on an object.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/set)
+        #[wasm_bindgen(js_namespace = Reflect, js_name = set, catch)]
+        pub fn set_with_receiver(
+            target: &JsValue,
+            property_key: &JsValue,
+            value: &JsValue,
+            receiver: &JsValue,
+        ) -> Result<bool, JsValue>;
+
+        /// The static `Reflect.setPrototypeOf()` method is the same
+        /// method as `Object.setPrototypeOf()`. It sets the prototype
+        /// (i.e., the internal `[[Prototype]]` property) of a specified
+        /// object to another object or to null.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/setPrototypeOf)
+        #[wasm_bindgen(js_namespace = Reflect, js_name = setPrototypeOf, catch)]
+        pub fn set_prototype_of(target: &Object, prototype: &JsValue) -> Result<bool, JsValue>;
+    }
+
+    /// Like [`construct_with_new_target`], but checks that the result is an
+    /// `Object` (which it always is per the spec, short of `ctor` being a
+    /// proxy with a pathological `construct` trap) rather than leaving the
+    /// caller to cast the raw `JsValue`.
+    pub fn construct_with_new_target_checked(
+        target: &Function,
+        arguments_list: &Array,
+        new_target: &Function,
+    ) -> Result<Object, JsValue> {
+        construct_with_new_target(target, arguments_list, new_target)?.dyn_into::<Object>()
+    }
+
+    /// The result of [`own_keys_partitioned`]: the target's own property
+    /// keys split into array indices, ordinary string keys, and symbol
+    /// keys, each preserving the relative order they appear in within
+    /// `[[OwnPropertyKeys]]`.
+    #[derive(Debug, Clone, Default)]
+    pub struct OwnKeys {
+        /// Keys that are canonical array indices (e.g. `"0"`, `"2"`),
+        /// which `[[OwnPropertyKeys]]` always enumerates first, in
+        /// ascending numeric order.
+        pub indices: Vec<u32>,
+        /// Non-index string keys, in insertion order.
+        pub strings: Vec<JsString>,
+        /// Symbol keys, in insertion order.
+        pub symbols: Vec<Symbol>,
+    }
+
+    /// Returns `true` if `key` is a "canonical numeric string" that names
+    /// an array index per the spec's `[[OwnPropertyKeys]]` ordering rule:
+    /// `ToString(ToUint32(key)) === key`. This means `"0"` and `"2"` are
+    /// index keys, but `"01"` and `"-0"` are not (they have no canonical
+    /// decimal representation matching themselves) and stay ordinary
+    /// string keys.
+    fn is_canonical_array_index(key: &str) -> Option<u32> {
+        if key == "0" {
+            return Some(0);
+        }
+        let n: u32 = key.parse().ok()?;
+        if n == 0 {
+            return None;
+        }
+        let mut buf = String::new();
+        write!(buf, "{}", n).ok()?;
+        if buf == key {
+            Some(n)
+        } else {
+            None
+        }
+    }
+
+    /// Like [`own_keys`], but partitions the target's own property keys
+    /// into array indices, string keys, and symbol keys in a single pass,
+    /// preserving the relative ordering within each group mandated by
+    /// `[[OwnPropertyKeys]]` (integer indices first in ascending order,
+    /// then strings, then symbols, each in their own insertion order).
+    pub fn own_keys_partitioned(target: &JsValue) -> Result<OwnKeys, JsValue> {
+        let keys = own_keys(target)?;
+        let mut result = OwnKeys::default();
+        for key in keys.iter() {
+            if key.is_symbol() {
+                result.symbols.push(key.unchecked_into());
+            } else {
+                let key: JsString = key.unchecked_into();
+                let s = String::from(&key);
+                match is_canonical_array_index(&s) {
+                    Some(index) => result.indices.push(index),
+                    None => result.strings.push(key),
+                }
+            }
+        }
+        Ok(result)
+    }
+}
+
+// RegExp
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(extends = Object, typescript_type = "RegExp")]
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub type RegExp;
+
+    /// The `exec()` method executes a search for a match in a specified
+    /// string. Returns a result array, or null.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/exec)
+    #[wasm_bindgen(method)]
+    pub fn exec(this: &RegExp, text: &str) -> Option<Array>;
+
+    /// The flags property returns a string consisting of the flags of
+    /// the current regular expression object.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/flags)
+    #[wasm_bindgen(method, getter)]
+    pub fn flags(this: &RegExp) -> JsString;
+
+    /// The global property indicates whether or not the "g" flag is
+    /// used with the regular expression. global is a read-only
+    /// property of an individual regular expression instance.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/global)
+    #[wasm_bindgen(method, getter)]
+    pub fn global(this: &RegExp) -> bool;
+
+    /// The ignoreCase property indicates whether or not the "i" flag
+    /// is used with the regular expression. ignoreCase is a read-only
+    /// property of an individual regular expression instance.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/ignoreCase)
+    #[wasm_bindgen(method, getter, js_name = ignoreCase)]
+    pub fn ignore_case(this: &RegExp) -> bool;
+
+    /// The non-standard input property is a static property of
+    /// regular expressions that contains the string against which a
+    /// regular expression is matched. RegExp.$_ is an alias for this
+    /// property.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/input)
+    #[wasm_bindgen(static_method_of = RegExp, getter)]
+    pub fn input() -> JsString;
+
+    /// The lastIndex is a read/write integer property of regular expression
+    /// instances that specifies the index at which to start the next match.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/lastIndex)
+    #[wasm_bindgen(structural, getter = lastIndex, method)]
+    pub fn last_index(this: &RegExp) -> u32;
+
+    /// The lastIndex is a read/write integer property of regular expression
+    /// instances that specifies the index at which to start the next match.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/lastIndex)
+    #[wasm_bindgen(structural, setter = lastIndex, method)]
+    pub fn set_last_index(this: &RegExp, index: u32);
+
+    /// The non-standard lastMatch property is a static and read-only
+    /// property of regular expressions that contains the last matched
+    /// characters. `RegExp.$&` is an alias for this property.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/lastMatch)
+    #[wasm_bindgen(static_method_of = RegExp, getter, js_name = lastMatch)]
+    pub fn last_match() -> JsString;
+
+    /// The non-standard lastParen property is a static and read-only
+    /// property of regular expressions that contains the last
+    /// parenthesized substring match, if any. `RegExp.$+` is an alias
+    /// for this property.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/lastParen)
+    #[wasm_bindgen(static_method_of = RegExp, getter, js_name = lastParen)]
+    pub fn last_paren() -> JsString;
+
+    /// The non-standard leftContext property is a static and
+    /// read-only property of regular expressions that contains the
+    /// substring preceding the most recent match. `RegExp.$`` is an
+    /// alias for this property.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/leftContext)
+    #[wasm_bindgen(static_method_of = RegExp, getter, js_name = leftContext)]
+    pub fn left_context() -> JsString;
+
+    /// The multiline property indicates whether or not the "m" flag
+    /// is used with the regular expression. multiline is a read-only
+    /// property of an individual regular expression instance.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/multiline)
+    #[wasm_bindgen(method, getter)]
+    pub fn multiline(this: &RegExp) -> bool;
+
+    /// The non-standard $1, $2, $3, $4, $5, $6, $7, $8, $9 properties
+    /// are static and read-only properties of regular expressions
+    /// that contain parenthesized substring matches.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/n)
+    #[wasm_bindgen(static_method_of = RegExp, getter, js_name = "$1")]
+    pub fn n1() -> JsString;
+    #[wasm_bindgen(static_method_of = RegExp, getter, js_name = "$2")]
+    pub fn n2() -> JsString;
+    #[wasm_bindgen(static_method_of = RegExp, getter, js_name = "$3")]
+    pub fn n3() -> JsString;
+    #[wasm_bindgen(static_method_of = RegExp, getter, js_name = "$4")]
+    pub fn n4() -> JsString;
+    #[wasm_bindgen(static_method_of = RegExp, getter, js_name = "$5")]
+    pub fn n5() -> JsString;
+    #[wasm_bindgen(static_method_of = RegExp, getter, js_name = "$6")]
+    pub fn n6() -> JsString;
+    #[wasm_bindgen(static_method_of = RegExp, getter, js_name = "$7")]
+    pub fn n7() -> JsString;
+    #[wasm_bindgen(static_method_of = RegExp, getter, js_name = "$8")]
+    pub fn n8() -> JsString;
+    #[wasm_bindgen(static_method_of = RegExp, getter, js_name = "$9")]
+    pub fn n9() -> JsString;
+
+    /// The `RegExp` constructor creates a regular expression object for matching text with a pattern.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp)
+    #[wasm_bindgen(constructor)]
+    pub fn new(pattern: &str, flags: &str) -> RegExp;
+    #[wasm_bindgen(constructor)]
+    pub fn new_regexp(pattern: &RegExp, flags: &str) -> RegExp;
+
+    /// The non-standard rightContext property is a static and
+    /// read-only property of regular expressions that contains the
+    /// substring following the most recent match. `RegExp.$'` is an
+    /// alias for this property.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/rightContext)
+    #[wasm_bindgen(static_method_of = RegExp, getter, js_name = rightContext)]
+    pub fn right_context() -> JsString;
+
+    /// The source property returns a String containing the source
+    /// text of the regexp object, and it doesn't contain the two
+    /// forward slashes on both sides and any flags.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/source)
+    #[wasm_bindgen(method, getter)]
+    pub fn source(this: &RegExp) -> JsString;
+
+    /// The sticky property reflects whether or not the search is
+    /// sticky (searches in strings only from the index indicated by
+    /// the lastIndex property of this regular expression). sticky is
+    /// a read-only property of an individual regular expression
+    /// object.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/sticky)
+    #[wasm_bindgen(method, getter)]
+    pub fn sticky(this: &RegExp) -> bool;
+
+    /// The `test()` method executes a search for a match between a
+    /// regular expression and a specified string. Returns true or
+    /// false.
     ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Error/toString)
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/test)
+    #[wasm_bindgen(method)]
+    pub fn test(this: &RegExp, text: &str) -> bool;
+
+    /// The `toString()` method returns a string representing the
+    /// regular expression.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/toString)
     #[wasm_bindgen(method, js_name = toString)]
-    pub fn to_string(this: &Error) -> JsString;
+    pub fn to_string(this: &RegExp) -> JsString;
+
+    /// The unicode property indicates whether or not the "u" flag is
+    /// used with a regular expression. unicode is a read-only
+    /// property of an individual regular expression instance.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/unicode)
+    #[wasm_bindgen(method, getter)]
+    pub fn unicode(this: &RegExp) -> bool;
 }
 
-partialord_ord!(JsString);
+impl RegExp {
+    /// Escapes the characters in `text` that are special in a regular
+    /// expression pattern, so the result matches `text` literally when
+    /// embedded in a pattern.
+    pub fn escape(text: &str) -> String {
+        let mut escaped = String::with_capacity(text.len());
+        for c in text.chars() {
+            if matches!(
+                c,
+                '.' | '*' | '+' | '?' | '^' | '$' | '{' | '}' | '(' | ')' | '|' | '[' | ']' | '\\' | '/'
+            ) {
+                escaped.push('\\');
+            }
+            escaped.push(c);
+        }
+        escaped
+    }
+}
 
-// EvalError
+/// Why a [`RegExpBuilder`] failed to [`build`](RegExpBuilder::build) a
+/// [`RegExp`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RegExpBuilderError {
+    /// Both the `u` (unicode) and `v` (unicodeSets) flags were set. The
+    /// spec forbids combining them on a single regular expression.
+    ConflictingUnicodeFlags,
+}
+
+/// A builder for [`RegExp`] that sets flags through named methods instead
+/// of a hand-assembled flags string, and validates the flag combination
+/// before constructing the regular expression.
+///
+/// ```no_run
+/// use js_sys::RegExpBuilder;
+///
+/// let re = RegExpBuilder::new("a.b")
+///     .global(true)
+///     .ignore_case(true)
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct RegExpBuilder<'a> {
+    source: &'a str,
+    global: bool,
+    ignore_case: bool,
+    multiline: bool,
+    dot_all: bool,
+    unicode: bool,
+    unicode_sets: bool,
+    sticky: bool,
+    has_indices: bool,
+}
+
+impl<'a> RegExpBuilder<'a> {
+    /// Starts a new builder for a regular expression with the given
+    /// pattern `source`. No flags are set.
+    pub fn new(source: &'a str) -> Self {
+        RegExpBuilder {
+            source,
+            ..RegExpBuilder::default()
+        }
+    }
+
+    /// Sets the `g` (global) flag.
+    pub fn global(mut self, enabled: bool) -> Self {
+        self.global = enabled;
+        self
+    }
+
+    /// Sets the `i` (ignoreCase) flag.
+    pub fn ignore_case(mut self, enabled: bool) -> Self {
+        self.ignore_case = enabled;
+        self
+    }
+
+    /// Sets the `m` (multiline) flag.
+    pub fn multiline(mut self, enabled: bool) -> Self {
+        self.multiline = enabled;
+        self
+    }
+
+    /// Sets the `s` (dotAll) flag.
+    pub fn dot_all(mut self, enabled: bool) -> Self {
+        self.dot_all = enabled;
+        self
+    }
+
+    /// Sets the `u` (unicode) flag. Conflicts with [`unicode_sets`](Self::unicode_sets).
+    pub fn unicode(mut self, enabled: bool) -> Self {
+        self.unicode = enabled;
+        self
+    }
+
+    /// Sets the `v` (unicodeSets) flag. Conflicts with [`unicode`](Self::unicode).
+    pub fn unicode_sets(mut self, enabled: bool) -> Self {
+        self.unicode_sets = enabled;
+        self
+    }
+
+    /// Sets the `y` (sticky) flag.
+    pub fn sticky(mut self, enabled: bool) -> Self {
+        self.sticky = enabled;
+        self
+    }
+
+    /// Sets the `d` (hasIndices) flag.
+    pub fn has_indices(mut self, enabled: bool) -> Self {
+        self.has_indices = enabled;
+        self
+    }
+
+    /// Builds the [`RegExp`], treating `source` as a regular expression
+    /// pattern.
+    ///
+    /// Returns [`RegExpBuilderError::ConflictingUnicodeFlags`] if both the
+    /// `u` and `v` flags were set.
+    pub fn build(&self) -> Result<RegExp, RegExpBuilderError> {
+        self.validate()?;
+        Ok(RegExp::new(self.source, &self.flags()))
+    }
+
+    /// Builds the [`RegExp`], first escaping `source` with [`RegExp::escape`]
+    /// so it matches the literal text rather than being interpreted as a
+    /// pattern.
+    ///
+    /// Returns [`RegExpBuilderError::ConflictingUnicodeFlags`] if both the
+    /// `u` and `v` flags were set.
+    pub fn build_literal(&self) -> Result<RegExp, RegExpBuilderError> {
+        self.validate()?;
+        let escaped = RegExp::escape(self.source);
+        Ok(RegExp::new(&escaped, &self.flags()))
+    }
+
+    fn validate(&self) -> Result<(), RegExpBuilderError> {
+        if self.unicode && self.unicode_sets {
+            return Err(RegExpBuilderError::ConflictingUnicodeFlags);
+        }
+        Ok(())
+    }
+
+    fn flags(&self) -> String {
+        let mut flags = String::new();
+        if self.has_indices {
+            flags.push('d');
+        }
+        if self.global {
+            flags.push('g');
+        }
+        if self.ignore_case {
+            flags.push('i');
+        }
+        if self.multiline {
+            flags.push('m');
+        }
+        if self.dot_all {
+            flags.push('s');
+        }
+        if self.unicode {
+            flags.push('u');
+        }
+        if self.unicode_sets {
+            flags.push('v');
+        }
+        if self.sticky {
+            flags.push('y');
+        }
+        flags
+    }
+}
+
+// Set
 #[wasm_bindgen]
 extern "C" {
-    #[wasm_bindgen(extends = Object, extends = Error, typescript_type = "EvalError")]
+    /// Note: the derived [`Clone`] impl clones the handle to the
+    /// underlying JS `Set`, not the set itself -- the clone and the
+    /// original refer to the same object, and mutating one mutates the
+    /// other. Use [`Set::shallow_copy`] for an actual copy.
+    #[wasm_bindgen(extends = Object, typescript_type = "Set<any>")]
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub type Set;
+
+    /// The `add()` method appends a new element with a specified value to the
+    /// end of a [`Set`] object.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/add)
+    #[wasm_bindgen(method)]
+    pub fn add(this: &Set, value: &JsValue) -> Set;
+
+    /// The `clear()` method removes all elements from a [`Set`] object.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/clear)
+    #[wasm_bindgen(method)]
+    pub fn clear(this: &Set);
+
+    /// The `delete()` method removes the specified element from a [`Set`]
+    /// object.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/delete)
+    #[wasm_bindgen(method)]
+    pub fn delete(this: &Set, value: &JsValue) -> bool;
+
+    /// The `forEach()` method executes a provided function once for each value
+    /// in the Set object, in insertion order.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/forEach)
+    #[wasm_bindgen(method, js_name = forEach)]
+    pub fn for_each(this: &Set, callback: &mut dyn FnMut(JsValue, JsValue, Set));
+
+    /// The `has()` method returns a boolean indicating whether an element with
+    /// the specified value exists in a [`Set`] object or not.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/has)
+    #[wasm_bindgen(method)]
+    pub fn has(this: &Set, value: &JsValue) -> bool;
+
+    /// The [`Set`] object lets you store unique values of any type, whether
+    /// primitive values or object references.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set)
+    #[wasm_bindgen(constructor)]
+    pub fn new(init: &JsValue) -> Set;
+
+    /// The size accessor property returns the number of elements in a [`Set`]
+    /// object.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/de/docs/Web/JavaScript/Reference/Global_Objects/Set/size)
+    #[wasm_bindgen(method, getter, structural)]
+    pub fn size(this: &Set) -> u32;
+}
+
+impl Default for Set {
+    fn default() -> Self {
+        Self::new(&JsValue::UNDEFINED)
+    }
+}
+
+impl Set {
+    /// Returns whether this set has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.size() == 0
+    }
+
+    /// Returns a new `Set` with the same elements as `self`, as a distinct
+    /// object: mutating the copy (`add`/`delete`/`clear`) doesn't affect
+    /// `self`, and vice versa. The elements themselves are not copied -- an
+    /// element that's itself an object remains shared between the two sets.
+    pub fn shallow_copy(&self) -> Set {
+        let out = Set::new(&JsValue::UNDEFINED);
+        self.for_each(&mut |value, _value2, _set| {
+            out.add(&value);
+        });
+        out
+    }
+
+    /// Like [`Set::for_each`], but the callback can fail: iteration stops
+    /// at the first `Err`, which is returned as-is. Unlike the native
+    /// `forEach` (which always visits every element), this is driven
+    /// manually through [`Set::values`] so it can stop early.
+    pub fn try_for_each(&self, mut f: impl FnMut(JsValue) -> Result<(), JsValue>) -> Result<(), JsValue> {
+        for value in self.values() {
+            f(value?)?;
+        }
+        Ok(())
+    }
+
+    /// Returns up to `limit` values starting at `offset`, in insertion
+    /// order, without materializing the values before `offset` or after
+    /// `offset + limit`.
+    ///
+    /// This drives the native [`Set::values`] iterator directly rather
+    /// than collecting every value into a `Vec` first, so each skipped
+    /// value costs only a `next()` call.
+    pub fn values_page(&self, offset: u32, limit: u32) -> Vec<JsValue> {
+        self.values()
+            .into_iter()
+            .skip(offset as usize)
+            .take(limit as usize)
+            .filter_map(|value| value.ok())
+            .collect()
+    }
+
+    /// Returns the first value in insertion order, or `None` if the set is
+    /// empty.
+    pub fn first(&self) -> Option<JsValue> {
+        self.values_page(0, 1).pop()
+    }
+
+    /// Returns the `n`th value in insertion order, or `None` if there are
+    /// fewer than `n + 1` values.
+    pub fn nth(&self, n: u32) -> Option<JsValue> {
+        self.values()
+            .into_iter()
+            .nth(n as usize)
+            .and_then(|value| value.ok())
+    }
+
+    /// Returns an iterator over a one-shot copy of this set's values,
+    /// materialized up front, in insertion order.
+    ///
+    /// Unlike [`Set::values`] (and everything built on it, like
+    /// [`Set::values_page`]), which drives the live native iterator and so
+    /// observes any mutation a JS callback makes mid-iteration, this copies
+    /// every value before returning, so later mutations of the set have no
+    /// effect on the iterator. The trade-off is the upfront cost of
+    /// collecting every value, even if the caller only consumes a few.
+    pub fn iter_snapshot(&self) -> alloc::vec::IntoIter<JsValue> {
+        self.values_page(0, u32::MAX).into_iter()
+    }
+}
+
+impl IntoIterator for &Set {
+    type Item = Result<JsValue, JsValue>;
+    type IntoIter = IntoIter;
+
+    /// Iterates this set's values in insertion order, the same order
+    /// [`Set::values`] does.
+    fn into_iter(self) -> IntoIter {
+        self.values().into_iter()
+    }
+}
+
+// SetIterator
+#[wasm_bindgen]
+extern "C" {
+    /// The `entries()` method returns a new Iterator object that contains an
+    /// array of [value, value] for each element in the Set object, in insertion
+    /// order. For Set objects there is no key like in Map objects. However, to
+    /// keep the API similar to the Map object, each entry has the same value
+    /// for its key and value here, so that an array [value, value] is returned.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/entries)
+    #[wasm_bindgen(method)]
+    pub fn entries(set: &Set) -> Iterator;
+
+    /// The `keys()` method is an alias for this method (for similarity with
+    /// Map objects); it behaves exactly the same and returns values
+    /// of Set elements.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/values)
+    #[wasm_bindgen(method)]
+    pub fn keys(set: &Set) -> Iterator;
+
+    /// The `values()` method returns a new Iterator object that contains the
+    /// values for each element in the Set object in insertion order.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/values)
+    #[wasm_bindgen(method)]
+    pub fn values(set: &Set) -> Iterator;
+}
+
+// SyntaxError
+#[wasm_bindgen]
+extern "C" {
+    /// A `SyntaxError` is thrown when the JavaScript engine encounters tokens or
+    /// token order that does not conform to the syntax of the language when
+    /// parsing code.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/SyntaxError)
+    #[wasm_bindgen(extends = Error, extends = Object, typescript_type = "SyntaxError")]
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub type SyntaxError;
+
+    /// A `SyntaxError` is thrown when the JavaScript engine encounters tokens or
+    /// token order that does not conform to the syntax of the language when
+    /// parsing code.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/SyntaxError)
+    #[wasm_bindgen(constructor)]
+    pub fn new(message: &str) -> SyntaxError;
+    #[wasm_bindgen(constructor)]
+    pub fn new_with_options(message: &str, options: &Object) -> SyntaxError;
+}
+
+error_new_with_cause!(SyntaxError);
+
+// TypeError
+#[wasm_bindgen]
+extern "C" {
+    /// The `TypeError` object represents an error when a value is not of the
+    /// expected type.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypeError)
+    #[wasm_bindgen(extends = Error, extends = Object, typescript_type = "TypeError")]
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub type TypeError;
+
+    /// The `TypeError` object represents an error when a value is not of the
+    /// expected type.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypeError)
+    #[wasm_bindgen(constructor)]
+    pub fn new(message: &str) -> TypeError;
+    #[wasm_bindgen(constructor)]
+    pub fn new_with_options(message: &str, options: &Object) -> TypeError;
+}
+
+error_new_with_cause!(TypeError);
+
+// URIError
+#[wasm_bindgen]
+extern "C" {
+    /// The `URIError` object represents an error when a global URI handling
+    /// function was used in a wrong way.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/URIError)
+    #[wasm_bindgen(extends = Error, extends = Object, js_name = URIError, typescript_type = "URIError")]
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub type UriError;
+
+    /// The `URIError` object represents an error when a global URI handling
+    /// function was used in a wrong way.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/URIError)
+    #[wasm_bindgen(constructor, js_class = "URIError")]
+    pub fn new(message: &str) -> UriError;
+    #[wasm_bindgen(constructor, js_class = "URIError")]
+    pub fn new_with_options(message: &str, options: &Object) -> UriError;
+}
+
+error_new_with_cause!(UriError);
+
+// AggregateError
+#[wasm_bindgen]
+extern "C" {
+    /// The `AggregateError` object represents an error when several errors
+    /// need to be wrapped in a single error, e.g. by `Promise.any()` when
+    /// all of the promises passed to it reject.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/AggregateError)
+    #[wasm_bindgen(extends = Error, extends = Object, typescript_type = "AggregateError")]
     #[derive(Clone, Debug, PartialEq, Eq)]
-    pub type EvalError;
+    pub type AggregateError;
 
-    /// The EvalError object indicates an error regarding the global eval() function. This
-    /// exception is not thrown by JavaScript anymore, however the EvalError object remains for
-    /// compatibility.
+    /// The `AggregateError` object represents an error when several errors
+    /// need to be wrapped in a single error, e.g. by `Promise.any()` when
+    /// all of the promises passed to it reject.
     ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/EvalError)
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/AggregateError)
     #[wasm_bindgen(constructor)]
-    pub fn new(message: &str) -> EvalError;
+    pub fn new(errors: &Array, message: &str) -> AggregateError;
+    #[wasm_bindgen(constructor)]
+    pub fn new_with_options(errors: &Array, message: &str, options: &Object) -> AggregateError;
+
+    /// The errors property holds the errors that were aggregated.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/AggregateError/errors)
+    #[wasm_bindgen(method, getter, structural)]
+    pub fn errors(this: &AggregateError) -> Array;
 }
 
-// Function
+impl AggregateError {
+    /// Like [`AggregateError::new`], but also sets the error's `cause` to
+    /// `cause`, as if constructed with
+    /// `new AggregateError(errors, message, { cause })`.
+    pub fn new_with_cause(errors: &Array, message: &str, cause: &JsValue) -> AggregateError {
+        AggregateError::new_with_options(errors, message, &options_with_cause(cause))
+    }
+}
+
+// WeakMap
 #[wasm_bindgen]
 extern "C" {
-    #[wasm_bindgen(extends = Object, is_type_of = JsValue::is_function, typescript_type = "Function")]
+    #[wasm_bindgen(extends = Object, typescript_type = "WeakMap<object, any>")]
     #[derive(Clone, Debug, PartialEq, Eq)]
-    pub type Function;
+    pub type WeakMap;
 
-    /// The `Function` constructor creates a new `Function` object. Calling the
-    /// constructor directly can create functions dynamically, but suffers from
-    /// security and similar (but far less significant) performance issues
-    /// similar to `eval`. However, unlike `eval`, the `Function` constructor
-    /// allows executing code in the global scope, prompting better programming
-    /// habits and allowing for more efficient code minification.
+    /// The [`WeakMap`] object is a collection of key/value pairs in which the
+    /// keys are weakly referenced.  The keys must be objects and the values can
+    /// be arbitrary values.
     ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Function)
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap)
     #[wasm_bindgen(constructor)]
-    pub fn new_with_args(args: &str, body: &str) -> Function;
+    pub fn new() -> WeakMap;
 
-    /// The `Function` constructor creates a new `Function` object. Calling the
-    /// constructor directly can create functions dynamically, but suffers from
-    /// security and similar (but far less significant) performance issues
-    /// similar to `eval`. However, unlike `eval`, the `Function` constructor
-    /// allows executing code in the global scope, prompting better programming
-    /// habits and allowing for more efficient code minification.
+    /// The `set()` method sets the value for the key in the [`WeakMap`] object.
+    /// Returns the [`WeakMap`] object.
     ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Function)
-    #[wasm_bindgen(constructor)]
-    pub fn new_no_args(body: &str) -> Function;
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap/set)
+    #[wasm_bindgen(method, js_class = "WeakMap")]
+    pub fn set(this: &WeakMap, key: &Object, value: &JsValue) -> WeakMap;
 
-    /// The `apply()` method calls a function with a given this value, and arguments provided as an array
-    /// (or an array-like object).
+    /// The `get()` method returns a specified by key element
+    /// from a [`WeakMap`] object.
     ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Function/apply)
-    #[wasm_bindgen(method, catch)]
-    pub fn apply(this: &Function, context: &JsValue, args: &Array) -> Result<JsValue, JsValue>;
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap/get)
+    #[wasm_bindgen(method)]
+    pub fn get(this: &WeakMap, key: &Object) -> JsValue;
 
-    /// The `call()` method calls a function with a given this value and
-    /// arguments provided individually.
+    /// The `has()` method returns a boolean indicating whether an element with
+    /// the specified key exists in the [`WeakMap`] object or not.
     ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Function/call)
-    #[wasm_bindgen(method, catch, js_name = call)]
-    pub fn call0(this: &Function, context: &JsValue) -> Result<JsValue, JsValue>;
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap/has)
+    #[wasm_bindgen(method)]
+    pub fn has(this: &WeakMap, key: &Object) -> bool;
 
-    /// The `call()` method calls a function with a given this value and
-    /// arguments provided individually.
+    /// The `delete()` method removes the specified element from a [`WeakMap`]
+    /// object.
     ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Function/call)
-    #[wasm_bindgen(method, catch, js_name = call)]
-    pub fn call1(this: &Function, context: &JsValue, arg1: &JsValue) -> Result<JsValue, JsValue>;
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap/delete)
+    #[wasm_bindgen(method)]
+    pub fn delete(this: &WeakMap, key: &Object) -> bool;
+}
 
-    /// The `call()` method calls a function with a given this value and
-    /// arguments provided individually.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Function/call)
-    #[wasm_bindgen(method, catch, js_name = call)]
-    pub fn call2(
-        this: &Function,
-        context: &JsValue,
-        arg1: &JsValue,
-        arg2: &JsValue,
-    ) -> Result<JsValue, JsValue>;
+impl Default for WeakMap {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
-    /// The `call()` method calls a function with a given this value and
-    /// arguments provided individually.
+/// A strongly-typed side table for attaching metadata to arbitrary JS
+/// objects without mutating them, built on a [`WeakMap`] so entries for a
+/// key are collected once nothing else references that key.
+///
+/// Keys are restricted to `&Object` at compile time (the same restriction
+/// [`WeakMap`] itself enforces at runtime), and values are restricted to a
+/// single [`JsCast`] type `V`, so callers don't have to downcast `JsValue`
+/// on every lookup.
+///
+/// # Example
+///
+/// Caching a computed layout `Object` per DOM-like node object:
+///
+/// ```no_run
+/// # use js_sys::{Object, SideTable};
+/// let layouts = SideTable::<Object>::new();
+///
+/// fn layout_for(layouts: &SideTable<Object>, node: &Object) -> Object {
+///     layouts.get_or_insert_with(node, Object::new)
+/// }
+/// ```
+pub struct SideTable<V> {
+    map: WeakMap,
+    marker: core::marker::PhantomData<V>,
+}
+
+impl<V: JsCast> SideTable<V> {
+    /// Creates a new, empty side table.
+    pub fn new() -> Self {
+        SideTable {
+            map: WeakMap::new(),
+            marker: core::marker::PhantomData,
+        }
+    }
+
+    /// Returns the value associated with `key`, if any.
+    pub fn get(&self, key: &Object) -> Option<V> {
+        let value = self.map.get(key);
+        if value.is_undefined() {
+            None
+        } else {
+            value.dyn_into::<V>().ok()
+        }
+    }
+
+    /// Associates `key` with `value`, replacing any previous value.
+    pub fn set(&self, key: &Object, value: &V) {
+        self.map.set(key, value.as_ref());
+    }
+
+    /// Removes the value associated with `key`. Returns whether a value was
+    /// present.
+    pub fn remove(&self, key: &Object) -> bool {
+        self.map.delete(key)
+    }
+
+    /// Returns the value associated with `key`, computing and storing it
+    /// with `f` if it's not already present. A present key takes one
+    /// `WeakMap.get` call; an absent one takes a `WeakMap.get` and then a
+    /// `WeakMap.set`.
+    pub fn get_or_insert_with(&self, key: &Object, f: impl FnOnce() -> V) -> V
+    where
+        V: Clone,
+    {
+        if let Some(value) = self.get(key) {
+            return value;
+        }
+        let value = f();
+        self.set(key, &value);
+        value
+    }
+
+    /// `WeakMap` deliberately has no `size`: exposing how many entries are
+    /// still alive would make garbage collection observable. This always
+    /// returns `None`; it exists so callers don't go looking for a `len()`
+    /// that can't exist.
+    pub fn len_hint(&self) -> Option<usize> {
+        None
+    }
+}
+
+impl<V: JsCast> Default for SideTable<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// WeakSet
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(extends = Object, typescript_type = "WeakSet<object>")]
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub type WeakSet;
+
+    /// The `WeakSet` object lets you store weakly held objects in a collection.
     ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Function/call)
-    #[wasm_bindgen(method, catch, js_name = call)]
-    pub fn call3(
-        this: &Function,
-        context: &JsValue,
-        arg1: &JsValue,
-        arg2: &JsValue,
-        arg3: &JsValue,
-    ) -> Result<JsValue, JsValue>;
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakSet)
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> WeakSet;
 
-    /// The `bind()` method creates a new function that, when called, has its this keyword set to the provided value,
-    /// with a given sequence of arguments preceding any provided when the new function is called.
+    /// The `has()` method returns a boolean indicating whether an object exists
+    /// in a WeakSet or not.
     ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Function/bind)
-    #[wasm_bindgen(method, js_name = bind)]
-    pub fn bind(this: &Function, context: &JsValue) -> Function;
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakSet/has)
+    #[wasm_bindgen(method)]
+    pub fn has(this: &WeakSet, value: &Object) -> bool;
 
-    /// The `bind()` method creates a new function that, when called, has its this keyword set to the provided value,
-    /// with a given sequence of arguments preceding any provided when the new function is called.
+    /// The `add()` method appends a new object to the end of a WeakSet object.
     ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Function/bind)
-    #[wasm_bindgen(method, js_name = bind)]
-    pub fn bind0(this: &Function, context: &JsValue) -> Function;
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakSet/add)
+    #[wasm_bindgen(method)]
+    pub fn add(this: &WeakSet, value: &Object) -> WeakSet;
 
-    /// The `bind()` method creates a new function that, when called, has its this keyword set to the provided value,
-    /// with a given sequence of arguments preceding any provided when the new function is called.
+    /// The `delete()` method removes the specified element from a WeakSet
+    /// object.
     ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Function/bind)
-    #[wasm_bindgen(method, js_name = bind)]
-    pub fn bind1(this: &Function, context: &JsValue, arg1: &JsValue) -> Function;
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakSet/delete)
+    #[wasm_bindgen(method)]
+    pub fn delete(this: &WeakSet, value: &Object) -> bool;
+}
+
+impl Default for WeakSet {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(js_sys_unstable_apis)]
+#[allow(non_snake_case)]
+pub mod Temporal;
+
+#[allow(non_snake_case)]
+pub mod WebAssembly {
+    use super::*;
+
+    // WebAssembly
+    #[wasm_bindgen]
+    extern "C" {
+        /// The `WebAssembly.compile()` function compiles a `WebAssembly.Module`
+        /// from WebAssembly binary code.  This function is useful if it is
+        /// necessary to a compile a module before it can be instantiated
+        /// (otherwise, the `WebAssembly.instantiate()` function should be used).
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WebAssembly/compile)
+        #[wasm_bindgen(js_namespace = WebAssembly)]
+        pub fn compile(buffer_source: &JsValue) -> Promise;
+
+        /// The `WebAssembly.compileStreaming()` function compiles a
+        /// `WebAssembly.Module` module directly from a streamed underlying
+        /// source. This function is useful if it is necessary to a compile a
+        /// module before it can be instantiated (otherwise, the
+        /// `WebAssembly.instantiateStreaming()` function should be used).
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WebAssembly/compileStreaming)
+        #[wasm_bindgen(js_namespace = WebAssembly, js_name = compileStreaming)]
+        pub fn compile_streaming(response: &Promise) -> Promise;
+
+        /// The `WebAssembly.instantiate()` function allows you to compile and
+        /// instantiate WebAssembly code.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WebAssembly/instantiate)
+        #[wasm_bindgen(js_namespace = WebAssembly, js_name = instantiate)]
+        pub fn instantiate_buffer(buffer: &[u8], imports: &Object) -> Promise;
+
+        /// The `WebAssembly.instantiate()` function allows you to compile and
+        /// instantiate WebAssembly code.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WebAssembly/instantiate)
+        #[wasm_bindgen(js_namespace = WebAssembly, js_name = instantiate)]
+        pub fn instantiate_module(module: &Module, imports: &Object) -> Promise;
+
+        /// The `WebAssembly.instantiateStreaming()` function compiles and
+        /// instantiates a WebAssembly module directly from a streamed
+        /// underlying source. This is the most efficient, optimized way to load
+        /// Wasm code.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WebAssembly/instantiateStreaming)
+        #[wasm_bindgen(js_namespace = WebAssembly, js_name = instantiateStreaming)]
+        pub fn instantiate_streaming(response: &Promise, imports: &Object) -> Promise;
+
+        /// The `WebAssembly.validate()` function validates a given typed
+        /// array of WebAssembly binary code, returning whether the bytes
+        /// form a valid Wasm module (`true`) or not (`false`).
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WebAssembly/validate)
+        #[wasm_bindgen(js_namespace = WebAssembly, catch)]
+        pub fn validate(buffer_source: &JsValue) -> Result<bool, JsValue>;
+    }
+
+    // WebAssembly.CompileError
+    #[wasm_bindgen]
+    extern "C" {
+        /// The `WebAssembly.CompileError()` constructor creates a new
+        /// WebAssembly `CompileError` object, which indicates an error during
+        /// WebAssembly decoding or validation.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WebAssembly/CompileError)
+        #[wasm_bindgen(extends = Error, js_namespace = WebAssembly, typescript_type = "WebAssembly.CompileError")]
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub type CompileError;
 
-    /// The `bind()` method creates a new function that, when called, has its this keyword set to the provided value,
-    /// with a given sequence of arguments preceding any provided when the new function is called.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Function/bind)
-    #[wasm_bindgen(method, js_name = bind)]
-    pub fn bind2(this: &Function, context: &JsValue, arg1: &JsValue, arg2: &JsValue) -> Function;
+        /// The `WebAssembly.CompileError()` constructor creates a new
+        /// WebAssembly `CompileError` object, which indicates an error during
+        /// WebAssembly decoding or validation.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WebAssembly/CompileError)
+        #[wasm_bindgen(constructor, js_namespace = WebAssembly)]
+        pub fn new(message: &str) -> CompileError;
+    }
 
-    /// The `bind()` method creates a new function that, when called, has its this keyword set to the provided value,
-    /// with a given sequence of arguments preceding any provided when the new function is called.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Function/bind)
-    #[wasm_bindgen(method, js_name = bind)]
-    pub fn bind3(
-        this: &Function,
-        context: &JsValue,
-        arg1: &JsValue,
-        arg2: &JsValue,
-        arg3: &JsValue,
-    ) -> Function;
+    // WebAssembly.Instance
+    #[wasm_bindgen]
+    extern "C" {
+        /// A `WebAssembly.Instance` object is a stateful, executable instance
+        /// of a `WebAssembly.Module`. Instance objects contain all the exported
+        /// WebAssembly functions that allow calling into WebAssembly code from
+        /// JavaScript.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WebAssembly/Instance)
+        #[wasm_bindgen(extends = Object, js_namespace = WebAssembly, typescript_type = "WebAssembly.Instance")]
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub type Instance;
 
-    /// The length property indicates the number of arguments expected by the function.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Function/length)
-    #[wasm_bindgen(method, getter, structural)]
-    pub fn length(this: &Function) -> u32;
+        /// The `WebAssembly.Instance()` constructor function can be called to
+        /// synchronously instantiate a given `WebAssembly.Module`
+        /// object. However, the primary way to get an `Instance` is through the
+        /// asynchronous `WebAssembly.instantiateStreaming()` function.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WebAssembly/Instance)
+        #[wasm_bindgen(catch, constructor, js_namespace = WebAssembly)]
+        pub fn new(module: &Module, imports: &Object) -> Result<Instance, JsValue>;
 
-    /// A Function object's read-only name property indicates the function's
-    /// name as specified when it was created or "anonymous" for functions
-    /// created anonymously.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Function/name)
-    #[wasm_bindgen(method, getter, structural)]
-    pub fn name(this: &Function) -> JsString;
+        /// The `exports` readonly property of the `WebAssembly.Instance` object
+        /// prototype returns an object containing as its members all the
+        /// functions exported from the WebAssembly module instance, to allow
+        /// them to be accessed and used by JavaScript.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WebAssembly/Instance/exports)
+        #[wasm_bindgen(getter, method, js_namespace = WebAssembly)]
+        pub fn exports(this: &Instance) -> Object;
+    }
 
-    /// The `toString()` method returns a string representing the source code of the function.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Function/toString)
-    #[wasm_bindgen(method, js_name = toString)]
-    pub fn to_string(this: &Function) -> JsString;
-}
+    // WebAssembly.LinkError
+    #[wasm_bindgen]
+    extern "C" {
+        /// The `WebAssembly.LinkError()` constructor creates a new WebAssembly
+        /// LinkError object, which indicates an error during module
+        /// instantiation (besides traps from the start function).
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WebAssembly/LinkError)
+        #[wasm_bindgen(extends = Error, js_namespace = WebAssembly, typescript_type = "WebAssembly.LinkError")]
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub type LinkError;
 
-impl Function {
-    /// Returns the `Function` value of this JS value if it's an instance of a
-    /// function.
-    ///
-    /// If this JS value is not an instance of a function then this returns
-    /// `None`.
-    #[deprecated(note = "recommended to use dyn_ref instead which is now equivalent")]
-    pub fn try_from(val: &JsValue) -> Option<&Function> {
-        val.dyn_ref()
+        /// The `WebAssembly.LinkError()` constructor creates a new WebAssembly
+        /// LinkError object, which indicates an error during module
+        /// instantiation (besides traps from the start function).
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WebAssembly/LinkError)
+        #[wasm_bindgen(constructor, js_namespace = WebAssembly)]
+        pub fn new(message: &str) -> LinkError;
     }
-}
 
-impl Default for Function {
-    fn default() -> Self {
-        Self::new_no_args("")
-    }
-}
+    // WebAssembly.RuntimeError
+    #[wasm_bindgen]
+    extern "C" {
+        /// The `WebAssembly.RuntimeError()` constructor creates a new WebAssembly
+        /// `RuntimeError` object — the type that is thrown whenever WebAssembly
+        /// specifies a trap.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WebAssembly/RuntimeError)
+        #[wasm_bindgen(extends = Error, js_namespace = WebAssembly, typescript_type = "WebAssembly.RuntimeError")]
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub type RuntimeError;
 
-// Generator
-#[wasm_bindgen]
-extern "C" {
-    #[wasm_bindgen(extends = Object, typescript_type = "Generator<any, any, any>")]
-    #[derive(Clone, Debug, PartialEq, Eq)]
-    pub type Generator;
+        /// The `WebAssembly.RuntimeError()` constructor creates a new WebAssembly
+        /// `RuntimeError` object — the type that is thrown whenever WebAssembly
+        /// specifies a trap.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WebAssembly/RuntimeError)
+        #[wasm_bindgen(constructor, js_namespace = WebAssembly)]
+        pub fn new(message: &str) -> RuntimeError;
+    }
 
-    /// The `next()` method returns an object with two properties done and value.
-    /// You can also provide a parameter to the next method to send a value to the generator.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Generator/next)
-    #[wasm_bindgen(method, structural, catch)]
-    pub fn next(this: &Generator, value: &JsValue) -> Result<JsValue, JsValue>;
+    // WebAssembly.Module
+    #[wasm_bindgen]
+    extern "C" {
+        /// A `WebAssembly.Module` object contains stateless WebAssembly code
+        /// that has already been compiled by the browser and can be
+        /// efficiently shared with Workers, and instantiated multiple times.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WebAssembly/Module)
+        #[wasm_bindgen(js_namespace = WebAssembly, extends = Object, typescript_type = "WebAssembly.Module")]
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub type Module;
 
-    /// The `return()` method returns the given value and finishes the generator.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Generator/return)
-    #[wasm_bindgen(method, structural, js_name = return)]
-    pub fn return_(this: &Generator, value: &JsValue) -> JsValue;
+        /// A `WebAssembly.Module` object contains stateless WebAssembly code
+        /// that has already been compiled by the browser and can be
+        /// efficiently shared with Workers, and instantiated multiple times.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WebAssembly/Module)
+        #[wasm_bindgen(constructor, js_namespace = WebAssembly, catch)]
+        pub fn new(buffer_source: &JsValue) -> Result<Module, JsValue>;
 
-    /// The `throw()` method resumes the execution of a generator by throwing an error into it
-    /// and returns an object with two properties done and value.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Generator/throw)
-    #[wasm_bindgen(method, structural, catch)]
-    pub fn throw(this: &Generator, error: &Error) -> Result<JsValue, JsValue>;
-}
+        /// The `WebAssembly.customSections()` function returns a copy of the
+        /// contents of all custom sections in the given module with the given
+        /// string name.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WebAssembly/Module/customSections)
+        #[wasm_bindgen(static_method_of = Module, js_namespace = WebAssembly, js_name = customSections)]
+        pub fn custom_sections(module: &Module, sectionName: &str) -> Array;
 
-// Map
-#[wasm_bindgen]
-extern "C" {
-    #[wasm_bindgen(extends = Object, typescript_type = "Map<any, any>")]
-    #[derive(Clone, Debug, PartialEq, Eq)]
-    pub type Map;
+        /// The `WebAssembly.exports()` function returns an array containing
+        /// descriptions of all the declared exports of the given `Module`.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WebAssembly/Module/exports)
+        #[wasm_bindgen(static_method_of = Module, js_namespace = WebAssembly)]
+        pub fn exports(module: &Module) -> Array;
 
-    /// The `clear()` method removes all elements from a Map object.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map/clear)
-    #[wasm_bindgen(method)]
-    pub fn clear(this: &Map);
+        /// The `WebAssembly.imports()` function returns an array containing
+        /// descriptions of all the declared imports of the given `Module`.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WebAssembly/Module/imports)
+        #[wasm_bindgen(static_method_of = Module, js_namespace = WebAssembly)]
+        pub fn imports(module: &Module) -> Array;
+    }
 
-    /// The `delete()` method removes the specified element from a Map object.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map/delete)
-    #[wasm_bindgen(method)]
-    pub fn delete(this: &Map, key: &JsValue) -> bool;
+    // WebAssembly.Table
+    #[wasm_bindgen]
+    extern "C" {
+        /// The `WebAssembly.Table()` constructor creates a new `Table` object
+        /// of the given size and element type.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WebAssembly/Table)
+        #[wasm_bindgen(js_namespace = WebAssembly, extends = Object, typescript_type = "WebAssembly.Table")]
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub type Table;
 
-    /// The `forEach()` method executes a provided function once per each
-    /// key/value pair in the Map object, in insertion order.
-    /// Note that in Javascript land the `Key` and `Value` are reversed compared to normal expectations:
-    /// # Examples
-    /// ```
-    /// let js_map = Map::new();
-    /// js_map.for_each(&mut |value, key| {
-    ///     // Do something here...
-    /// })
-    /// ```
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map/forEach)
-    #[wasm_bindgen(method, js_name = forEach)]
-    pub fn for_each(this: &Map, callback: &mut dyn FnMut(JsValue, JsValue));
+        /// The `WebAssembly.Table()` constructor creates a new `Table` object
+        /// of the given size and element type.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WebAssembly/Table)
+        #[wasm_bindgen(constructor, js_namespace = WebAssembly, catch)]
+        pub fn new(table_descriptor: &Object) -> Result<Table, JsValue>;
 
-    /// The `get()` method returns a specified element from a Map object.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map/get)
-    #[wasm_bindgen(method)]
-    pub fn get(this: &Map, key: &JsValue) -> JsValue;
+        /// The length prototype property of the `WebAssembly.Table` object
+        /// returns the length of the table, i.e. the number of elements in the
+        /// table.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WebAssembly/Table/length)
+        #[wasm_bindgen(method, getter, js_namespace = WebAssembly)]
+        pub fn length(this: &Table) -> u32;
 
-    /// The `has()` method returns a boolean indicating whether an element with
-    /// the specified key exists or not.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map/has)
-    #[wasm_bindgen(method)]
-    pub fn has(this: &Map, key: &JsValue) -> bool;
+        /// The `get()` prototype method of the `WebAssembly.Table()` object
+        /// retrieves a function reference stored at a given index.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WebAssembly/Table/get)
+        #[wasm_bindgen(method, catch, js_namespace = WebAssembly)]
+        pub fn get(this: &Table, index: u32) -> Result<Function, JsValue>;
 
-    /// The Map object holds key-value pairs. Any value (both objects and
-    /// primitive values) maybe used as either a key or a value.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map)
-    #[wasm_bindgen(constructor)]
-    pub fn new() -> Map;
+        /// The `grow()` prototype method of the `WebAssembly.Table` object
+        /// increases the size of the `Table` instance by a specified number of
+        /// elements.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WebAssembly/Table/grow)
+        #[wasm_bindgen(method, catch, js_namespace = WebAssembly)]
+        pub fn grow(this: &Table, additional_capacity: u32) -> Result<u32, JsValue>;
 
-    /// The `set()` method adds or updates an element with a specified key
-    /// and value to a Map object.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map/set)
-    #[wasm_bindgen(method)]
-    pub fn set(this: &Map, key: &JsValue, value: &JsValue) -> Map;
+        /// The `set()` prototype method of the `WebAssembly.Table` object mutates a
+        /// reference stored at a given index to a different value.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WebAssembly/Table/set)
+        #[wasm_bindgen(method, catch, js_namespace = WebAssembly)]
+        pub fn set(this: &Table, index: u32, function: &Function) -> Result<(), JsValue>;
+    }
 
-    /// The value of size is an integer representing how many entries
-    /// the Map object has. A set accessor function for size is undefined;
-    /// you can not change this property.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map/size)
-    #[wasm_bindgen(method, getter, structural)]
-    pub fn size(this: &Map) -> u32;
-}
+    // WebAssembly.Tag
+    #[wasm_bindgen]
+    extern "C" {
+        /// The `WebAssembly.Tag()` constructor creates a new `Tag` object
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WebAssembly/Tag)
+        #[wasm_bindgen(js_namespace = WebAssembly, extends = Object, typescript_type = "WebAssembly.Tag")]
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub type Tag;
 
-impl Default for Map {
-    fn default() -> Self {
-        Self::new()
+        /// The `WebAssembly.Tag()` constructor creates a new `Tag` object
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WebAssembly/Tag)
+        #[wasm_bindgen(constructor, js_namespace = WebAssembly, catch)]
+        pub fn new(tag_descriptor: &Object) -> Result<Tag, JsValue>;
     }
-}
 
-// Map Iterator
-#[wasm_bindgen]
-extern "C" {
-    /// The `entries()` method returns a new Iterator object that contains
-    /// the [key, value] pairs for each element in the Map object in
-    /// insertion order.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map/entries)
-    #[wasm_bindgen(method)]
-    pub fn entries(this: &Map) -> Iterator;
+    // WebAssembly.Exception
+    #[wasm_bindgen]
+    extern "C" {
+        /// The `WebAssembly.Exception()` constructor creates a new `Exception` object
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WebAssembly/Exception)
+        #[wasm_bindgen(js_namespace = WebAssembly, extends = Object, typescript_type = "WebAssembly.Exception")]
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub type Exception;
 
-    /// The `keys()` method returns a new Iterator object that contains the
-    /// keys for each element in the Map object in insertion order.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map/keys)
-    #[wasm_bindgen(method)]
-    pub fn keys(this: &Map) -> Iterator;
+        /// The `WebAssembly.Exception()` constructor creates a new `Exception` object
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WebAssembly/Exception)
+        #[wasm_bindgen(constructor, js_namespace = WebAssembly, catch)]
+        pub fn new(tag: &Tag, payload: &Array) -> Result<Exception, JsValue>;
 
-    /// The `values()` method returns a new Iterator object that contains the
-    /// values for each element in the Map object in insertion order.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map/values)
-    #[wasm_bindgen(method)]
-    pub fn values(this: &Map) -> Iterator;
-}
+        /// The `WebAssembly.Exception()` constructor creates a new `Exception` object
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WebAssembly/Exception)
+        #[wasm_bindgen(constructor, js_namespace = WebAssembly, catch)]
+        pub fn new_with_options(
+            tag: &Tag,
+            payload: &Array,
+            options: &Object,
+        ) -> Result<Exception, JsValue>;
 
-// Iterator
-#[wasm_bindgen]
-extern "C" {
-    /// Any object that conforms to the JS iterator protocol. For example,
-    /// something returned by `myArray[Symbol.iterator]()`.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Iteration_protocols)
-    #[derive(Clone, Debug)]
-    #[wasm_bindgen(is_type_of = Iterator::looks_like_iterator, typescript_type = "Iterator<any>")]
-    pub type Iterator;
+        /// The `is()` prototype method of the `WebAssembly.Exception` can be used to
+        /// test if the Exception matches a given tag.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WebAssembly/Exception/is)
+        #[wasm_bindgen(method, js_namespace = WebAssembly)]
+        pub fn is(this: &Exception, tag: &Tag) -> bool;
 
-    /// The `next()` method always has to return an object with appropriate
-    /// properties including done and value. If a non-object value gets returned
-    /// (such as false or undefined), a TypeError ("iterator.next() returned a
-    /// non-object value") will be thrown.
-    #[wasm_bindgen(catch, method, structural)]
-    pub fn next(this: &Iterator) -> Result<IteratorNext, JsValue>;
-}
+        /// The `getArg()` prototype method of the `WebAssembly.Exception` can be used
+        /// to get the value of a specified item in the exception's data arguments
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WebAssembly/Exception/getArg)
+        #[wasm_bindgen(method, js_namespace = WebAssembly, js_name = getArg, catch)]
+        pub fn get_arg(this: &Exception, tag: &Tag, index: u32) -> Result<JsValue, JsValue>;
+    }
 
-impl Iterator {
-    fn looks_like_iterator(it: &JsValue) -> bool {
-        #[wasm_bindgen]
-        extern "C" {
-            type MaybeIterator;
+    // WebAssembly.Global
+    #[wasm_bindgen]
+    extern "C" {
+        /// The `WebAssembly.Global()` constructor creates a new `Global` object
+        /// of the given type and value.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WebAssembly/Global)
+        #[wasm_bindgen(js_namespace = WebAssembly, extends = Object, typescript_type = "WebAssembly.Global")]
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub type Global;
 
-            #[wasm_bindgen(method, getter)]
-            fn next(this: &MaybeIterator) -> JsValue;
-        }
+        /// The `WebAssembly.Global()` constructor creates a new `Global` object
+        /// of the given type and value.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WebAssembly/Global)
+        #[wasm_bindgen(constructor, js_namespace = WebAssembly, catch)]
+        pub fn new(global_descriptor: &Object, value: &JsValue) -> Result<Global, JsValue>;
 
-        if !it.is_object() {
-            return false;
-        }
+        /// The value prototype property of the `WebAssembly.Global` object
+        /// returns the value of the global.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WebAssembly/Global)
+        #[wasm_bindgen(method, getter, structural, js_namespace = WebAssembly)]
+        pub fn value(this: &Global) -> JsValue;
+        #[wasm_bindgen(method, setter = value, structural, js_namespace = WebAssembly)]
+        pub fn set_value(this: &Global, value: &JsValue);
+    }
 
-        let it = it.unchecked_ref::<MaybeIterator>();
+    // WebAssembly.Memory
+    #[wasm_bindgen]
+    extern "C" {
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WebAssembly/Memory)
+        #[wasm_bindgen(js_namespace = WebAssembly, extends = Object, typescript_type = "WebAssembly.Memory")]
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub type Memory;
 
-        it.next().is_function()
+        /// The `WebAssembly.Memory()` constructor creates a new `Memory` object
+        /// which is a resizable `ArrayBuffer` that holds the raw bytes of
+        /// memory accessed by a WebAssembly `Instance`.
+        ///
+        /// A memory created by JavaScript or in WebAssembly code will be
+        /// accessible and mutable from both JavaScript and WebAssembly.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WebAssembly/Memory)
+        #[wasm_bindgen(constructor, js_namespace = WebAssembly, catch)]
+        pub fn new(descriptor: &Object) -> Result<Memory, JsValue>;
+
+        /// An accessor property that returns the buffer contained in the
+        /// memory.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WebAssembly/Memory/buffer)
+        #[wasm_bindgen(method, getter, js_namespace = WebAssembly)]
+        pub fn buffer(this: &Memory) -> JsValue;
+
+        /// The `grow()` prototype method of the `Memory` object increases the
+        /// size of the memory instance by a specified number of WebAssembly
+        /// pages.
+        ///
+        /// Takes the number of pages to grow (64KiB in size) and returns the
+        /// previous size of memory, in pages.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WebAssembly/Memory/grow)
+        #[wasm_bindgen(method, js_namespace = WebAssembly)]
+        pub fn grow(this: &Memory, pages: u32) -> u32;
     }
 }
 
-// Async Iterator
-#[wasm_bindgen]
-extern "C" {
-    /// Any object that conforms to the JS async iterator protocol. For example,
-    /// something returned by `myObject[Symbol.asyncIterator]()`.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/for-await...of)
-    #[derive(Clone, Debug)]
-    #[wasm_bindgen(is_type_of = Iterator::looks_like_iterator, typescript_type = "AsyncIterator<any>")]
-    pub type AsyncIterator;
+/// The `JSON` object contains methods for parsing [JavaScript Object
+/// Notation (JSON)](https://json.org/) and converting values to JSON. It
+/// can't be called or constructed, and aside from its two method
+/// properties, it has no interesting functionality of its own.
+#[allow(non_snake_case)]
+pub mod JSON {
+    use super::*;
 
-    /// The `next()` method always has to return a Promise which resolves to an object
-    /// with appropriate properties including done and value. If a non-object value
-    /// gets returned (such as false or undefined), a TypeError ("iterator.next()
-    /// returned a non-object value") will be thrown.
-    #[wasm_bindgen(catch, method, structural)]
-    pub fn next(this: &AsyncIterator) -> Result<Promise, JsValue>;
-}
+    // JSON
+    #[wasm_bindgen]
+    extern "C" {
+        /// The `JSON.parse()` method parses a JSON string, constructing the
+        /// JavaScript value or object described by the string.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/JSON/parse)
+        #[wasm_bindgen(catch, js_namespace = JSON)]
+        pub fn parse(text: &str) -> Result<JsValue, JsValue>;
+
+        /// The `JSON.stringify()` method converts a JavaScript value to a JSON string.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/JSON/stringify)
+        #[wasm_bindgen(catch, js_namespace = JSON)]
+        pub fn stringify(obj: &JsValue) -> Result<JsString, JsValue>;
+
+        /// The `JSON.stringify()` method converts a JavaScript value to a JSON string.
+        ///
+        /// The `replacer` argument is a function that alters the behavior of the stringification
+        /// process, or an array of String and Number objects that serve as a whitelist
+        /// for selecting/filtering the properties of the value object to be included
+        /// in the JSON string. If this value is null or not provided, all properties
+        /// of the object are included in the resulting JSON string.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/JSON/stringify)
+        #[wasm_bindgen(catch, js_namespace = JSON, js_name = stringify)]
+        pub fn stringify_with_replacer(
+            obj: &JsValue,
+            replacer: &JsValue,
+        ) -> Result<JsString, JsValue>;
 
-/// An iterator over the JS `Symbol.iterator` iteration protocol.
-///
-/// Use the `IntoIterator for &js_sys::Iterator` implementation to create this.
-pub struct Iter<'a> {
-    js: &'a Iterator,
-    state: IterState,
-}
+        /// The `JSON.stringify()` method converts a JavaScript value to a JSON string.
+        ///
+        /// The `replacer` argument is a function that alters the behavior of the stringification
+        /// process, or an array of String and Number objects that serve as a whitelist
+        /// for selecting/filtering the properties of the value object to be included
+        /// in the JSON string. If this value is null or not provided, all properties
+        /// of the object are included in the resulting JSON string.
+        ///
+        /// The `space` argument is a String or Number object that's used to insert white space into
+        /// the output JSON string for readability purposes. If this is a Number, it
+        /// indicates the number of space characters to use as white space; this number
+        /// is capped at 10 (if it is greater, the value is just 10). Values less than
+        /// 1 indicate that no space should be used. If this is a String, the string
+        /// (or the first 10 characters of the string, if it's longer than that) is
+        /// used as white space. If this parameter is not provided (or is null), no
+        /// white space is used.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/JSON/stringify)
+        #[wasm_bindgen(catch, js_namespace = JSON, js_name = stringify)]
+        pub fn stringify_with_replacer_and_space(
+            obj: &JsValue,
+            replacer: &JsValue,
+            space: &JsValue,
+        ) -> Result<JsString, JsValue>;
 
-/// An iterator over the JS `Symbol.iterator` iteration protocol.
-///
-/// Use the `IntoIterator for js_sys::Iterator` implementation to create this.
-pub struct IntoIter {
-    js: Iterator,
-    state: IterState,
-}
+    }
 
-struct IterState {
-    done: bool,
-}
+    /// Why [`stringify_canonical`] couldn't serialize a value.
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub enum CanonicalJsonErrorKind {
+        /// The value was `undefined`, a function, a symbol, or a `BigInt`
+        /// -- none of which have an unambiguous JSON representation, so
+        /// none are allowed to silently disappear or get coerced away when
+        /// the result is used as a hash/cache key.
+        Unserializable,
+        /// The value contains a reference cycle.
+        Cycle,
+    }
 
-impl<'a> IntoIterator for &'a Iterator {
-    type Item = Result<JsValue, JsValue>;
-    type IntoIter = Iter<'a>;
+    /// The error returned by [`stringify_canonical`] and
+    /// [`equal_canonical`]: the path to the offending value (e.g.
+    /// `"users.2.roles"`, array indices written as plain segments), plus
+    /// why it couldn't be serialized.
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct CanonicalJsonError {
+        /// The path to the offending value, rooted at the value passed to
+        /// [`stringify_canonical`]/[`equal_canonical`].
+        pub path: String,
+        /// Why the value at [`CanonicalJsonError::path`] couldn't be
+        /// serialized.
+        pub kind: CanonicalJsonErrorKind,
+    }
 
-    fn into_iter(self) -> Iter<'a> {
-        Iter {
-            js: self,
-            state: IterState::new(),
+    impl fmt::Display for CanonicalJsonError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self.kind {
+                CanonicalJsonErrorKind::Unserializable => {
+                    write!(f, "value at `{}` has no canonical JSON representation", self.path)
+                }
+                CanonicalJsonErrorKind::Cycle => write!(f, "cycle detected at `{}`", self.path),
+            }
         }
     }
-}
 
-impl core::iter::Iterator for Iter<'_> {
-    type Item = Result<JsValue, JsValue>;
+    #[cfg(feature = "std")]
+    impl std::error::Error for CanonicalJsonError {}
 
-    fn next(&mut self) -> Option<Self::Item> {
-        self.state.next(self.js)
+    fn unserializable(path: &[String]) -> CanonicalJsonError {
+        CanonicalJsonError {
+            path: path.join("."),
+            kind: CanonicalJsonErrorKind::Unserializable,
+        }
     }
-}
 
-impl IntoIterator for Iterator {
-    type Item = Result<JsValue, JsValue>;
-    type IntoIter = IntoIter;
+    fn cycle(path: &[String]) -> CanonicalJsonError {
+        CanonicalJsonError {
+            path: path.join("."),
+            kind: CanonicalJsonErrorKind::Cycle,
+        }
+    }
 
-    fn into_iter(self) -> IntoIter {
-        IntoIter {
-            js: self,
-            state: IterState::new(),
+    fn canonicalize(
+        value: &JsValue,
+        path: &mut Vec<String>,
+        visiting: &mut Vec<JsValue>,
+    ) -> Result<String, CanonicalJsonError> {
+        if value.is_undefined() || value.is_function() || value.is_symbol() {
+            return Err(unserializable(path));
+        }
+        if value.is_null() {
+            return Ok(String::from("null"));
+        }
+        if value.as_bool().is_some() || value.as_f64().is_some() || value.is_string() {
+            // Leans on the engine's own number-to-string and string-escaping
+            // algorithms rather than reimplementing them, so e.g. `0.1 + 0.2`
+            // formats exactly the way `JSON.stringify` would.
+            return stringify(value)
+                .map(String::from)
+                .map_err(|_| unserializable(path));
+        }
+        if let Some(array) = value.dyn_ref::<Array>() {
+            if visiting.iter().any(|v| Object::is(v, value)) {
+                return Err(cycle(path));
+            }
+            visiting.push(value.clone());
+            let mut parts = Vec::with_capacity(array.length() as usize);
+            for i in 0..array.length() {
+                path.push(alloc::format!("{}", i));
+                let part = canonicalize(&array.get(i), path, visiting);
+                path.pop();
+                parts.push(part?);
+            }
+            visiting.pop();
+            return Ok(alloc::format!("[{}]", parts.join(",")));
+        }
+        if let Some(object) = value.dyn_ref::<Object>() {
+            if visiting.iter().any(|v| Object::is(v, value)) {
+                return Err(cycle(path));
+            }
+            visiting.push(value.clone());
+            let mut keys: Vec<String> = Object::keys(object)
+                .iter()
+                .filter_map(|k| k.as_string())
+                .collect();
+            keys.sort();
+            let mut parts = Vec::with_capacity(keys.len());
+            for key in keys {
+                let child = Reflect::get(object.as_ref(), &JsValue::from_str(&key)).map_err(|_| unserializable(path))?;
+                path.push(key.clone());
+                let value_str = canonicalize(&child, path, visiting);
+                path.pop();
+                let key_str = stringify(&JsValue::from_str(&key)).map_err(|_| unserializable(path))?;
+                parts.push(alloc::format!("{}:{}", String::from(key_str), value_str?));
+            }
+            visiting.pop();
+            return Ok(alloc::format!("{{{}}}", parts.join(",")));
         }
+        Err(unserializable(path))
     }
-}
 
-impl core::iter::Iterator for IntoIter {
-    type Item = Result<JsValue, JsValue>;
+    /// Serializes `value` to JSON the way [`stringify`] does, except object
+    /// keys are sorted so that two objects with the same properties in a
+    /// different insertion order produce identical output -- useful for
+    /// hashing or caching by the contents of a JS config object rather than
+    /// by its construction history.
+    ///
+    /// Unlike [`stringify`], `undefined`, functions, symbols, and `BigInt`s
+    /// are rejected everywhere (including nested inside objects and arrays,
+    /// where plain `JSON.stringify` would silently drop or coerce them)
+    /// with a [`CanonicalJsonError`] naming the offending path, and a
+    /// reference cycle is reported the same way instead of throwing a raw
+    /// `RangeError` from exhausting the call stack.
+    pub fn stringify_canonical(value: &JsValue) -> Result<String, CanonicalJsonError> {
+        canonicalize(value, &mut Vec::new(), &mut Vec::new())
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        self.state.next(&self.js)
+    /// Returns whether `a` and `b` canonicalize to the same JSON text, i.e.
+    /// are structurally equal regardless of object key order.
+    pub fn equal_canonical(a: &JsValue, b: &JsValue) -> Result<bool, CanonicalJsonError> {
+        Ok(stringify_canonical(a)? == stringify_canonical(b)?)
     }
-}
 
-impl IterState {
-    fn new() -> IterState {
-        IterState { done: false }
+    /// Why [`parse_array`] or [`parse_object`] couldn't produce a typed
+    /// result.
+    #[derive(Clone, Debug)]
+    pub enum ParseCastError {
+        /// `JSON.parse` itself threw -- carries the thrown error, usually a
+        /// `SyntaxError`.
+        Syntax(JsValue),
+        /// The parsed value wasn't an array.
+        NotAnArray,
+        /// The parsed value wasn't a plain object.
+        NotAnObject,
+        /// An element (for [`parse_array`]) or property value (for
+        /// [`parse_object`], counting keys in `Object.keys` order) didn't
+        /// cast to the expected type.
+        InvalidElement { index: usize },
     }
 
-    fn next(&mut self, js: &Iterator) -> Option<Result<JsValue, JsValue>> {
-        if self.done {
-            return None;
+    impl fmt::Display for ParseCastError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                ParseCastError::Syntax(_) => write!(f, "text is not valid JSON"),
+                ParseCastError::NotAnArray => write!(f, "parsed value is not an array"),
+                ParseCastError::NotAnObject => write!(f, "parsed value is not a plain object"),
+                ParseCastError::InvalidElement { index } => {
+                    write!(f, "element at index {} has an unexpected type", index)
+                }
+            }
         }
-        let next = match js.next() {
-            Ok(val) => val,
-            Err(e) => {
-                self.done = true;
-                return Some(Err(e));
+    }
+
+    #[cfg(feature = "std")]
+    impl std::error::Error for ParseCastError {}
+
+    /// Parses `text` and checks that the result is a JS array, returning a
+    /// [`ParseCastError::Syntax`] if `text` isn't valid JSON and
+    /// [`ParseCastError::NotAnArray`] if the top-level value isn't an
+    /// array. If `validate` is set, every element is additionally checked
+    /// to cast to `T`, with [`ParseCastError::InvalidElement`] naming the
+    /// first index that doesn't.
+    pub fn parse_array<T: JsCast>(text: &str, validate: bool) -> Result<Array, ParseCastError> {
+        let value = parse(text).map_err(ParseCastError::Syntax)?;
+        let array = value
+            .dyn_into::<Array>()
+            .map_err(|_| ParseCastError::NotAnArray)?;
+
+        if validate {
+            for (index, element) in array.iter().enumerate() {
+                if element.dyn_ref::<T>().is_none() {
+                    return Err(ParseCastError::InvalidElement { index });
+                }
             }
-        };
-        if next.done() {
-            self.done = true;
-            None
-        } else {
-            Some(Ok(next.value()))
         }
+
+        Ok(array)
     }
-}
 
-/// Create an iterator over `val` using the JS iteration protocol and
-/// `Symbol.iterator`.
-pub fn try_iter(val: &JsValue) -> Result<Option<IntoIter>, JsValue> {
-    let iter_sym = Symbol::iterator();
-    let iter_fn = Reflect::get(val, iter_sym.as_ref())?;
+    /// Parses `text` and checks that the result is a plain JS object
+    /// (rejecting arrays), returning a [`ParseCastError::Syntax`] if `text`
+    /// isn't valid JSON and [`ParseCastError::NotAnObject`] otherwise. If
+    /// `validate` is set, every own property value is additionally checked
+    /// to cast to `T`, with [`ParseCastError::InvalidElement`] naming the
+    /// index (in `Object.keys` order) of the first one that doesn't.
+    pub fn parse_object<T: JsCast>(text: &str, validate: bool) -> Result<Object, ParseCastError> {
+        let value = parse(text).map_err(ParseCastError::Syntax)?;
+
+        if !value.is_object() || Array::is_array(&value) {
+            return Err(ParseCastError::NotAnObject);
+        }
 
-    let iter_fn: Function = match iter_fn.dyn_into() {
-        Ok(iter_fn) => iter_fn,
-        Err(_) => return Ok(None),
-    };
+        let object = value
+            .dyn_into::<Object>()
+            .map_err(|_| ParseCastError::NotAnObject)?;
 
-    let it: Iterator = match iter_fn.call0(val)?.dyn_into() {
-        Ok(it) => it,
-        Err(_) => return Ok(None),
-    };
+        if validate {
+            for (index, key) in Object::keys(&object).iter().enumerate() {
+                let property = Reflect::get(object.as_ref(), &key)
+                    .map_err(|_| ParseCastError::InvalidElement { index })?;
 
-    Ok(Some(it.into_iter()))
+                if property.dyn_ref::<T>().is_none() {
+                    return Err(ParseCastError::InvalidElement { index });
+                }
+            }
+        }
+
+        Ok(object)
+    }
 }
 
-// IteratorNext
+// JsString
 #[wasm_bindgen]
 extern "C" {
-    /// The result of calling `next()` on a JS iterator.
+    #[wasm_bindgen(js_name = String, extends = Object, is_type_of = JsValue::is_string, typescript_type = "string")]
+    #[derive(Clone, PartialEq, Eq)]
+    pub type JsString;
+
+    /// The length property of a String object indicates the length of a string,
+    /// in UTF-16 code units.
     ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Iteration_protocols)
-    #[wasm_bindgen(extends = Object, typescript_type = "IteratorResult<any>")]
-    #[derive(Clone, Debug, PartialEq, Eq)]
-    pub type IteratorNext;
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/length)
+    #[wasm_bindgen(method, getter, structural)]
+    pub fn length(this: &JsString) -> u32;
+
+    /// The 'at()' method returns a new string consisting of the single UTF-16
+    /// code unit located at the specified offset into the string, counting from
+    /// the end if it's negative.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/at)
+    #[wasm_bindgen(method, js_class = "String")]
+    pub fn at(this: &JsString, index: i32) -> Option<JsString>;
+
+    /// The String object's `charAt()` method returns a new string consisting of
+    /// the single UTF-16 code unit located at the specified offset into the
+    /// string.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/charAt)
+    #[wasm_bindgen(method, js_class = "String", js_name = charAt)]
+    pub fn char_at(this: &JsString, index: u32) -> JsString;
+
+    /// The `charCodeAt()` method returns an integer between 0 and 65535
+    /// representing the UTF-16 code unit at the given index (the UTF-16 code
+    /// unit matches the Unicode code point for code points representable in a
+    /// single UTF-16 code unit, but might also be the first code unit of a
+    /// surrogate pair for code points not representable in a single UTF-16 code
+    /// unit, e.g. Unicode code points > 0x10000).  If you want the entire code
+    /// point value, use `codePointAt()`.
+    ///
+    /// Returns `NaN` if index is out of range.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/charCodeAt)
+    #[wasm_bindgen(method, js_class = "String", js_name = charCodeAt)]
+    pub fn char_code_at(this: &JsString, index: u32) -> f64;
+
+    /// The `codePointAt()` method returns a non-negative integer that is the
+    /// Unicode code point value.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/codePointAt)
+    #[wasm_bindgen(method, js_class = "String", js_name = codePointAt)]
+    pub fn code_point_at(this: &JsString, pos: u32) -> JsValue;
+
+    /// The `concat()` method concatenates the string arguments to the calling
+    /// string and returns a new string.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/concat)
+    #[wasm_bindgen(method, js_class = "String")]
+    pub fn concat(this: &JsString, string_2: &JsValue) -> JsString;
+
+    /// The `endsWith()` method determines whether a string ends with the characters of a
+    /// specified string, returning true or false as appropriate.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/endsWith)
+    #[wasm_bindgen(method, js_class = "String", js_name = endsWith)]
+    pub fn ends_with(this: &JsString, search_string: &str, length: i32) -> bool;
+
+    /// The static `String.fromCharCode()` method returns a string created from
+    /// the specified sequence of UTF-16 code units.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/fromCharCode)
+    ///
+    /// # Notes
+    ///
+    /// There are a few bindings to `from_char_code` in `js-sys`: `from_char_code1`, `from_char_code2`, etc...
+    /// with different arities.
+    ///
+    /// Additionally, this function accepts `u16` for character codes, but
+    /// fixing others requires a breaking change release
+    /// (see https://github.com/rustwasm/wasm-bindgen/issues/1460 for details).
+    #[wasm_bindgen(static_method_of = JsString, js_class = "String", js_name = fromCharCode, variadic)]
+    pub fn from_char_code(char_codes: &[u16]) -> JsString;
+
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/fromCharCode)
+    #[wasm_bindgen(static_method_of = JsString, js_class = "String", js_name = fromCharCode)]
+    pub fn from_char_code1(a: u32) -> JsString;
+
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/fromCharCode)
+    #[wasm_bindgen(static_method_of = JsString, js_class = "String", js_name = fromCharCode)]
+    pub fn from_char_code2(a: u32, b: u32) -> JsString;
+
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/fromCharCode)
+    #[wasm_bindgen(static_method_of = JsString, js_class = "String", js_name = fromCharCode)]
+    pub fn from_char_code3(a: u32, b: u32, c: u32) -> JsString;
+
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/fromCharCode)
+    #[wasm_bindgen(static_method_of = JsString, js_class = "String", js_name = fromCharCode)]
+    pub fn from_char_code4(a: u32, b: u32, c: u32, d: u32) -> JsString;
+
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/fromCharCode)
+    #[wasm_bindgen(static_method_of = JsString, js_class = "String", js_name = fromCharCode)]
+    pub fn from_char_code5(a: u32, b: u32, c: u32, d: u32, e: u32) -> JsString;
+
+    /// The static `String.fromCodePoint()` method returns a string created by
+    /// using the specified sequence of code points.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/fromCodePoint)
+    ///
+    /// # Exceptions
+    ///
+    /// A RangeError is thrown if an invalid Unicode code point is given
+    ///
+    /// # Notes
+    ///
+    /// There are a few bindings to `from_code_point` in `js-sys`: `from_code_point1`, `from_code_point2`, etc...
+    /// with different arities.
+    #[wasm_bindgen(catch, static_method_of = JsString, js_class = "String", js_name = fromCodePoint, variadic)]
+    pub fn from_code_point(code_points: &[u32]) -> Result<JsString, JsValue>;
+
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/fromCodePoint)
+    #[wasm_bindgen(catch, static_method_of = JsString, js_class = "String", js_name = fromCodePoint)]
+    pub fn from_code_point1(a: u32) -> Result<JsString, JsValue>;
 
-    /// Has the value `true` if the iterator is past the end of the iterated
-    /// sequence. In this case value optionally specifies the return value of
-    /// the iterator.
-    ///
-    /// Has the value `false` if the iterator was able to produce the next value
-    /// in the sequence. This is equivalent of not specifying the done property
-    /// altogether.
-    #[wasm_bindgen(method, getter, structural)]
-    pub fn done(this: &IteratorNext) -> bool;
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/fromCodePoint)
+    #[wasm_bindgen(catch, static_method_of = JsString, js_class = "String", js_name = fromCodePoint)]
+    pub fn from_code_point2(a: u32, b: u32) -> Result<JsString, JsValue>;
 
-    /// Any JavaScript value returned by the iterator. Can be omitted when done
-    /// is true.
-    #[wasm_bindgen(method, getter, structural)]
-    pub fn value(this: &IteratorNext) -> JsValue;
-}
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/fromCodePoint)
+    #[wasm_bindgen(catch, static_method_of = JsString, js_class = "String", js_name = fromCodePoint)]
+    pub fn from_code_point3(a: u32, b: u32, c: u32) -> Result<JsString, JsValue>;
 
-#[allow(non_snake_case)]
-pub mod Math {
-    use super::*;
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/fromCodePoint)
+    #[wasm_bindgen(catch, static_method_of = JsString, js_class = "String", js_name = fromCodePoint)]
+    pub fn from_code_point4(a: u32, b: u32, c: u32, d: u32) -> Result<JsString, JsValue>;
 
-    // Math
-    #[wasm_bindgen]
-    extern "C" {
-        /// The `Math.abs()` function returns the absolute value of a number, that is
-        /// Math.abs(x) = |x|
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/abs)
-        #[wasm_bindgen(js_namespace = Math)]
-        pub fn abs(x: f64) -> f64;
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/fromCodePoint)
+    #[wasm_bindgen(catch, static_method_of = JsString, js_class = "String", js_name = fromCodePoint)]
+    pub fn from_code_point5(a: u32, b: u32, c: u32, d: u32, e: u32) -> Result<JsString, JsValue>;
 
-        /// The `Math.acos()` function returns the arccosine (in radians) of a
-        /// number, that is ∀x∊[-1;1]
-        /// Math.acos(x) = arccos(x) = the unique y∊[0;π] such that cos(y)=x
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/acos)
-        #[wasm_bindgen(js_namespace = Math)]
-        pub fn acos(x: f64) -> f64;
+    /// The `includes()` method determines whether one string may be found
+    /// within another string, returning true or false as appropriate.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/includes)
+    #[wasm_bindgen(method, js_class = "String")]
+    pub fn includes(this: &JsString, search_string: &str, position: i32) -> bool;
 
-        /// The `Math.acosh()` function returns the hyperbolic arc-cosine of a
-        /// number, that is ∀x ≥ 1
-        /// Math.acosh(x) = arcosh(x) = the unique y ≥ 0 such that cosh(y) = x
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/acosh)
-        #[wasm_bindgen(js_namespace = Math)]
-        pub fn acosh(x: f64) -> f64;
+    /// The `indexOf()` method returns the index within the calling String
+    /// object of the first occurrence of the specified value, starting the
+    /// search at fromIndex.  Returns -1 if the value is not found.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/indexOf)
+    #[wasm_bindgen(method, js_class = "String", js_name = indexOf)]
+    pub fn index_of(this: &JsString, search_value: &str, from_index: i32) -> i32;
 
-        /// The `Math.asin()` function returns the arcsine (in radians) of a
-        /// number, that is ∀x ∊ [-1;1]
-        /// Math.asin(x) = arcsin(x) = the unique y∊[-π2;π2] such that sin(y) = x
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/asin)
-        #[wasm_bindgen(js_namespace = Math)]
-        pub fn asin(x: f64) -> f64;
+    /// The `lastIndexOf()` method returns the index within the calling String
+    /// object of the last occurrence of the specified value, searching
+    /// backwards from fromIndex.  Returns -1 if the value is not found.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/lastIndexOf)
+    #[wasm_bindgen(method, js_class = "String", js_name = lastIndexOf)]
+    pub fn last_index_of(this: &JsString, search_value: &str, from_index: i32) -> i32;
 
-        /// The `Math.asinh()` function returns the hyperbolic arcsine of a
-        /// number, that is Math.asinh(x) = arsinh(x) = the unique y such that sinh(y) = x
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/asinh)
-        #[wasm_bindgen(js_namespace = Math)]
-        pub fn asinh(x: f64) -> f64;
+    /// The `localeCompare()` method returns a number indicating whether
+    /// a reference string comes before or after or is the same as
+    /// the given string in sort order.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/localeCompare)
+    #[wasm_bindgen(method, js_class = "String", js_name = localeCompare)]
+    pub fn locale_compare(
+        this: &JsString,
+        compare_string: &str,
+        locales: &Array,
+        options: &Object,
+    ) -> i32;
 
-        /// The `Math.atan()` function returns the arctangent (in radians) of a
-        /// number, that is Math.atan(x) = arctan(x) = the unique y ∊ [-π2;π2]such that
-        /// tan(y) = x
-        #[wasm_bindgen(js_namespace = Math)]
-        pub fn atan(x: f64) -> f64;
+    /// The `match()` method retrieves the matches when matching a string against a regular expression.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/match)
+    #[wasm_bindgen(method, js_class = "String", js_name = match)]
+    pub fn match_(this: &JsString, pattern: &RegExp) -> Option<Object>;
 
-        /// The `Math.atan2()` function returns the arctangent of the quotient of
-        /// its arguments.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/atan2)
-        #[wasm_bindgen(js_namespace = Math)]
-        pub fn atan2(y: f64, x: f64) -> f64;
+    /// The `match_all()` method is similar to `match()`, but gives an iterator of `exec()` arrays, which preserve capture groups.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/matchAll)
+    #[wasm_bindgen(method, js_class = "String", js_name = matchAll)]
+    pub fn match_all(this: &JsString, pattern: &RegExp) -> Iterator;
 
-        /// The `Math.atanh()` function returns the hyperbolic arctangent of a number,
-        /// that is ∀x ∊ (-1,1), Math.atanh(x) = arctanh(x) = the unique y such that
-        /// tanh(y) = x
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/atanh)
-        #[wasm_bindgen(js_namespace = Math)]
-        pub fn atanh(x: f64) -> f64;
+    /// The `normalize()` method returns the Unicode Normalization Form
+    /// of a given string (if the value isn't a string, it will be converted to one first).
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/normalize)
+    #[wasm_bindgen(method, js_class = "String")]
+    pub fn normalize(this: &JsString, form: &str) -> JsString;
 
-        /// The `Math.cbrt() `function returns the cube root of a number, that is
-        /// Math.cbrt(x) = ∛x = the unique y such that y^3 = x
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/cbrt)
-        #[wasm_bindgen(js_namespace = Math)]
-        pub fn cbrt(x: f64) -> f64;
+    /// The `padEnd()` method pads the current string with a given string
+    /// (repeated, if needed) so that the resulting string reaches a given
+    /// length. The padding is applied from the end (right) of the current
+    /// string.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/padEnd)
+    #[wasm_bindgen(method, js_class = "String", js_name = padEnd)]
+    pub fn pad_end(this: &JsString, target_length: u32, pad_string: &str) -> JsString;
 
-        /// The `Math.ceil()` function returns the smallest integer greater than
-        /// or equal to a given number.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/ceil)
-        #[wasm_bindgen(js_namespace = Math)]
-        pub fn ceil(x: f64) -> f64;
+    /// The `padStart()` method pads the current string with another string
+    /// (repeated, if needed) so that the resulting string reaches the given
+    /// length. The padding is applied from the start (left) of the current
+    /// string.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/padStart)
+    #[wasm_bindgen(method, js_class = "String", js_name = padStart)]
+    pub fn pad_start(this: &JsString, target_length: u32, pad_string: &str) -> JsString;
 
-        /// The `Math.clz32()` function returns the number of leading zero bits in
-        /// the 32-bit binary representation of a number.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/clz32)
-        #[wasm_bindgen(js_namespace = Math)]
-        pub fn clz32(x: i32) -> u32;
+    /// The `repeat()` method constructs and returns a new string which contains the specified
+    /// number of copies of the string on which it was called, concatenated together.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/repeat)
+    #[wasm_bindgen(method, js_class = "String")]
+    pub fn repeat(this: &JsString, count: i32) -> JsString;
 
-        /// The `Math.cos()` static function returns the cosine of the specified angle,
-        /// which must be specified in radians. This value is length(adjacent)/length(hypotenuse).
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/cos)
-        #[wasm_bindgen(js_namespace = Math)]
-        pub fn cos(x: f64) -> f64;
+    /// The `replace()` method returns a new string with some or all matches of a pattern
+    /// replaced by a replacement. The pattern can be a string or a RegExp, and
+    /// the replacement can be a string or a function to be called for each match.
+    ///
+    /// Note: The original string will remain unchanged.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/replace)
+    #[wasm_bindgen(method, js_class = "String")]
+    pub fn replace(this: &JsString, pattern: &str, replacement: &str) -> JsString;
 
-        /// The `Math.cosh()` function returns the hyperbolic cosine of a number,
-        /// that can be expressed using the constant e.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/cosh)
-        #[wasm_bindgen(js_namespace = Math)]
-        pub fn cosh(x: f64) -> f64;
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/replace)
+    #[wasm_bindgen(method, js_class = "String", js_name = replace)]
+    pub fn replace_with_function(
+        this: &JsString,
+        pattern: &str,
+        replacement: &Function,
+    ) -> JsString;
 
-        /// The `Math.exp()` function returns e^x, where x is the argument, and e is Euler's number
-        /// (also known as Napier's constant), the base of the natural logarithms.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/exp)
-        #[wasm_bindgen(js_namespace = Math)]
-        pub fn exp(x: f64) -> f64;
+    #[wasm_bindgen(method, js_class = "String", js_name = replace)]
+    pub fn replace_by_pattern(this: &JsString, pattern: &RegExp, replacement: &str) -> JsString;
 
-        /// The `Math.expm1()` function returns e^x - 1, where x is the argument, and e the base of the
-        /// natural logarithms.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/expm1)
-        #[wasm_bindgen(js_namespace = Math)]
-        pub fn expm1(x: f64) -> f64;
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/replace)
+    #[wasm_bindgen(method, js_class = "String", js_name = replace)]
+    pub fn replace_by_pattern_with_function(
+        this: &JsString,
+        pattern: &RegExp,
+        replacement: &Function,
+    ) -> JsString;
 
-        /// The `Math.floor()` function returns the largest integer less than or
-        /// equal to a given number.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/floor)
-        #[wasm_bindgen(js_namespace = Math)]
-        pub fn floor(x: f64) -> f64;
+    /// The `replace_all()` method returns a new string with all matches of a pattern
+    /// replaced by a replacement. The pattern can be a string or a global RegExp, and
+    /// the replacement can be a string or a function to be called for each match.
+    ///
+    /// Note: The original string will remain unchanged.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/replaceAll)
+    #[wasm_bindgen(method, js_class = "String", js_name = replaceAll)]
+    pub fn replace_all(this: &JsString, pattern: &str, replacement: &str) -> JsString;
 
-        /// The `Math.fround()` function returns the nearest 32-bit single precision float representation
-        /// of a Number.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/fround)
-        #[wasm_bindgen(js_namespace = Math)]
-        pub fn fround(x: f64) -> f32;
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/replaceAll)
+    #[wasm_bindgen(method, js_class = "String", js_name = replaceAll)]
+    pub fn replace_all_with_function(
+        this: &JsString,
+        pattern: &str,
+        replacement: &Function,
+    ) -> JsString;
 
-        /// The `Math.hypot()` function returns the square root of the sum of squares of its arguments.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/hypot)
-        #[wasm_bindgen(js_namespace = Math)]
-        pub fn hypot(x: f64, y: f64) -> f64;
+    #[wasm_bindgen(method, js_class = "String", js_name = replaceAll)]
+    pub fn replace_all_by_pattern(this: &JsString, pattern: &RegExp, replacement: &str)
+        -> JsString;
 
-        /// The `Math.imul()` function returns the result of the C-like 32-bit multiplication of the
-        /// two parameters.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/imul)
-        #[wasm_bindgen(js_namespace = Math)]
-        pub fn imul(x: i32, y: i32) -> i32;
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/replaceAll)
+    #[wasm_bindgen(method, js_class = "String", js_name = replaceAll)]
+    pub fn replace_all_by_pattern_with_function(
+        this: &JsString,
+        pattern: &RegExp,
+        replacement: &Function,
+    ) -> JsString;
 
-        /// The `Math.log()` function returns the natural logarithm (base e) of a number.
-        /// The JavaScript `Math.log()` function is equivalent to ln(x) in mathematics.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/log)
-        #[wasm_bindgen(js_namespace = Math)]
-        pub fn log(x: f64) -> f64;
+    /// The `search()` method executes a search for a match between
+    /// a regular expression and this String object.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/search)
+    #[wasm_bindgen(method, js_class = "String")]
+    pub fn search(this: &JsString, pattern: &RegExp) -> i32;
 
-        /// The `Math.log10()` function returns the base 10 logarithm of a number.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/log10)
-        #[wasm_bindgen(js_namespace = Math)]
-        pub fn log10(x: f64) -> f64;
+    /// The `slice()` method extracts a section of a string and returns it as a
+    /// new string, without modifying the original string.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/slice)
+    #[wasm_bindgen(method, js_class = "String")]
+    pub fn slice(this: &JsString, start: u32, end: u32) -> JsString;
 
-        /// The `Math.log1p()` function returns the natural logarithm (base e) of 1 + a number.
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/log1p)
-        #[wasm_bindgen(js_namespace = Math)]
-        pub fn log1p(x: f64) -> f64;
+    /// The `split()` method splits a String object into an array of strings by separating the string
+    /// into substrings, using a specified separator string to determine where to make each split.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/split)
+    #[wasm_bindgen(method, js_class = "String")]
+    pub fn split(this: &JsString, separator: &str) -> Array;
 
-        /// The `Math.log2()` function returns the base 2 logarithm of a number.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/log2)
-        #[wasm_bindgen(js_namespace = Math)]
-        pub fn log2(x: f64) -> f64;
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/split)
+    #[wasm_bindgen(method, js_class = "String", js_name = split)]
+    pub fn split_limit(this: &JsString, separator: &str, limit: u32) -> Array;
 
-        /// The `Math.max()` function returns the largest of two numbers.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/max)
-        #[wasm_bindgen(js_namespace = Math)]
-        pub fn max(x: f64, y: f64) -> f64;
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/split)
+    #[wasm_bindgen(method, js_class = "String", js_name = split)]
+    pub fn split_by_pattern(this: &JsString, pattern: &RegExp) -> Array;
 
-        /// The static function `Math.min()` returns the lowest-valued number passed into it.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/min)
-        #[wasm_bindgen(js_namespace = Math)]
-        pub fn min(x: f64, y: f64) -> f64;
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/split)
+    #[wasm_bindgen(method, js_class = "String", js_name = split)]
+    pub fn split_by_pattern_limit(this: &JsString, pattern: &RegExp, limit: u32) -> Array;
 
-        /// The `Math.pow()` function returns the base to the exponent power, that is, base^exponent.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/pow)
-        #[wasm_bindgen(js_namespace = Math)]
-        pub fn pow(base: f64, exponent: f64) -> f64;
+    /// The `startsWith()` method determines whether a string begins with the
+    /// characters of a specified string, returning true or false as
+    /// appropriate.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/startsWith)
+    #[wasm_bindgen(method, js_class = "String", js_name = startsWith)]
+    pub fn starts_with(this: &JsString, search_string: &str, position: u32) -> bool;
 
-        /// The `Math.random()` function returns a floating-point, pseudo-random number
-        /// in the range 0–1 (inclusive of 0, but not 1) with approximately uniform distribution
-        /// over that range — which you can then scale to your desired range.
-        /// The implementation selects the initial seed to the random number generation algorithm;
-        /// it cannot be chosen or reset by the user.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/random)
-        #[wasm_bindgen(js_namespace = Math)]
-        pub fn random() -> f64;
+    /// The `substring()` method returns the part of the string between the
+    /// start and end indexes, or to the end of the string.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/substring)
+    #[wasm_bindgen(method, js_class = "String")]
+    pub fn substring(this: &JsString, index_start: u32, index_end: u32) -> JsString;
 
-        /// The `Math.round()` function returns the value of a number rounded to the nearest integer.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/round)
-        #[wasm_bindgen(js_namespace = Math)]
-        pub fn round(x: f64) -> f64;
+    /// The `substr()` method returns the part of a string between
+    /// the start index and a number of characters after it.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/substr)
+    #[wasm_bindgen(method, js_class = "String")]
+    pub fn substr(this: &JsString, start: i32, length: i32) -> JsString;
 
-        /// The `Math.sign()` function returns the sign of a number, indicating whether the number is
-        /// positive, negative or zero.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/sign)
-        #[wasm_bindgen(js_namespace = Math)]
-        pub fn sign(x: f64) -> f64;
+    /// The `toLocaleLowerCase()` method returns the calling string value converted to lower case,
+    /// according to any locale-specific case mappings.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/toLocaleLowerCase)
+    #[wasm_bindgen(method, js_class = "String", js_name = toLocaleLowerCase)]
+    pub fn to_locale_lower_case(this: &JsString, locale: Option<&str>) -> JsString;
 
-        /// The `Math.sin()` function returns the sine of a number.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/sin)
-        #[wasm_bindgen(js_namespace = Math)]
-        pub fn sin(x: f64) -> f64;
+    /// The `toLocaleUpperCase()` method returns the calling string value converted to upper case,
+    /// according to any locale-specific case mappings.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/ja/docs/Web/JavaScript/Reference/Global_Objects/String/toLocaleUpperCase)
+    #[wasm_bindgen(method, js_class = "String", js_name = toLocaleUpperCase)]
+    pub fn to_locale_upper_case(this: &JsString, locale: Option<&str>) -> JsString;
 
-        /// The `Math.sinh()` function returns the hyperbolic sine of a number, that can be expressed
-        /// using the constant e: Math.sinh(x) = (e^x - e^-x)/2
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/sinh)
-        #[wasm_bindgen(js_namespace = Math)]
-        pub fn sinh(x: f64) -> f64;
+    /// The `toLowerCase()` method returns the calling string value
+    /// converted to lower case.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/toLowerCase)
+    #[wasm_bindgen(method, js_class = "String", js_name = toLowerCase)]
+    pub fn to_lower_case(this: &JsString) -> JsString;
 
-        /// The `Math.sqrt()` function returns the square root of a number, that is
-        /// ∀x ≥ 0, Math.sqrt(x) = √x = the unique y ≥ 0 such that y^2 = x
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/sqrt)
-        #[wasm_bindgen(js_namespace = Math)]
-        pub fn sqrt(x: f64) -> f64;
+    /// The `toString()` method returns a string representing the specified
+    /// object.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/toString)
+    #[wasm_bindgen(method, js_class = "String", js_name = toString)]
+    pub fn to_string(this: &JsString) -> JsString;
 
-        /// The `Math.tan()` function returns the tangent of a number.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/tan)
-        #[wasm_bindgen(js_namespace = Math)]
-        pub fn tan(x: f64) -> f64;
+    /// The `toUpperCase()` method returns the calling string value converted to
+    /// uppercase (the value will be converted to a string if it isn't one).
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/toUpperCase)
+    #[wasm_bindgen(method, js_class = "String", js_name = toUpperCase)]
+    pub fn to_upper_case(this: &JsString) -> JsString;
 
-        /// The `Math.tanh()` function returns the hyperbolic tangent of a number, that is
-        /// tanh x = sinh x / cosh x = (e^x - e^-x)/(e^x + e^-x) = (e^2x - 1)/(e^2x + 1)
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/tanh)
-        #[wasm_bindgen(js_namespace = Math)]
-        pub fn tanh(x: f64) -> f64;
+    /// The `isWellFormed()` method returns `true` if this string contains no
+    /// lone surrogates, i.e. `String::from(..)` would be lossless.
+    ///
+    /// This is equivalent to [`JsString::is_valid_utf16`], but calls into the
+    /// native implementation instead of decoding the UTF-16 code units in
+    /// Rust.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/isWellFormed)
+    #[wasm_bindgen(method, js_class = "String", js_name = isWellFormed)]
+    pub fn is_well_formed(this: &JsString) -> bool;
 
-        /// The `Math.trunc()` function returns the integer part of a number by removing any fractional
-        /// digits.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/trunc)
-        #[wasm_bindgen(js_namespace = Math)]
-        pub fn trunc(x: f64) -> f64;
-    }
-}
+    /// The `toWellFormed()` method returns a new string where lone surrogates
+    /// of this string are each replaced with the Unicode replacement
+    /// character U+FFFD.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/toWellFormed)
+    #[wasm_bindgen(method, js_class = "String", js_name = toWellFormed)]
+    pub fn to_well_formed(this: &JsString) -> JsString;
 
-// Number.
-#[wasm_bindgen]
-extern "C" {
-    #[wasm_bindgen(extends = Object, is_type_of = |v| v.as_f64().is_some(), typescript_type = "number")]
-    #[derive(Clone, PartialEq)]
-    pub type Number;
+    /// The `trim()` method removes whitespace from both ends of a string.
+    /// Whitespace in this context is all the whitespace characters (space, tab,
+    /// no-break space, etc.) and all the line terminator characters (LF, CR,
+    /// etc.).
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/trim)
+    #[wasm_bindgen(method, js_class = "String")]
+    pub fn trim(this: &JsString) -> JsString;
 
-    /// The `Number.isFinite()` method determines whether the passed value is a finite number.
+    /// The `trimEnd()` method removes whitespace from the end of a string.
+    /// `trimRight()` is an alias of this method.
     ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/isFinite)
-    #[wasm_bindgen(static_method_of = Number, js_name = isFinite)]
-    pub fn is_finite(value: &JsValue) -> bool;
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/trimEnd)
+    #[wasm_bindgen(method, js_class = "String", js_name = trimEnd)]
+    pub fn trim_end(this: &JsString) -> JsString;
 
-    /// The `Number.isInteger()` method determines whether the passed value is an integer.
+    /// The `trimEnd()` method removes whitespace from the end of a string.
+    /// `trimRight()` is an alias of this method.
     ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/isInteger)
-    #[wasm_bindgen(static_method_of = Number, js_name = isInteger)]
-    pub fn is_integer(value: &JsValue) -> bool;
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/trimEnd)
+    #[wasm_bindgen(method, js_class = "String", js_name = trimRight)]
+    pub fn trim_right(this: &JsString) -> JsString;
 
-    /// The `Number.isNaN()` method determines whether the passed value is `NaN` and its type is Number.
-    /// It is a more robust version of the original, global isNaN().
+    /// The `trimStart()` method removes whitespace from the beginning of a
+    /// string. `trimLeft()` is an alias of this method.
     ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/isNaN)
-    #[wasm_bindgen(static_method_of = Number, js_name = isNaN)]
-    pub fn is_nan(value: &JsValue) -> bool;
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/trimStart)
+    #[wasm_bindgen(method, js_class = "String", js_name = trimStart)]
+    pub fn trim_start(this: &JsString) -> JsString;
 
-    /// The `Number.isSafeInteger()` method determines whether the provided value is a number
-    /// that is a safe integer.
+    /// The `trimStart()` method removes whitespace from the beginning of a
+    /// string. `trimLeft()` is an alias of this method.
     ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/isSafeInteger)
-    #[wasm_bindgen(static_method_of = Number, js_name = isSafeInteger)]
-    pub fn is_safe_integer(value: &JsValue) -> bool;
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/trimStart)
+    #[wasm_bindgen(method, js_class = "String", js_name = trimLeft)]
+    pub fn trim_left(this: &JsString) -> JsString;
 
-    /// The `Number` JavaScript object is a wrapper object allowing
-    /// you to work with numerical values. A `Number` object is
-    /// created using the `Number()` constructor.
+    /// The `valueOf()` method returns the primitive value of a `String` object.
     ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number)
-    #[wasm_bindgen(constructor)]
-    #[deprecated(note = "recommended to use `Number::from` instead")]
-    #[allow(deprecated)]
-    pub fn new(value: &JsValue) -> Number;
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/valueOf)
+    #[wasm_bindgen(method, js_class = "String", js_name = valueOf)]
+    pub fn value_of(this: &JsString) -> JsString;
 
-    #[wasm_bindgen(constructor)]
-    fn new_from_str(value: &str) -> Number;
+    /// The static `raw()` method is a tag function of template literals,
+    /// similar to the `r` prefix in Python or the `@` prefix in C# for string literals.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/raw)
+    #[wasm_bindgen(catch, variadic, static_method_of = JsString, js_class = "String")]
+    pub fn raw(call_site: &Object, substitutions: &Array) -> Result<JsString, JsValue>;
 
-    /// The `Number.parseInt()` method parses a string argument and returns an
-    /// integer of the specified radix or base.
+    /// The static `raw()` method is a tag function of template literals,
+    /// similar to the `r` prefix in Python or the `@` prefix in C# for string literals.
     ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/parseInt)
-    #[wasm_bindgen(static_method_of = Number, js_name = parseInt)]
-    pub fn parse_int(text: &str, radix: u8) -> f64;
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/raw)
+    #[wasm_bindgen(catch, static_method_of = JsString, js_class = "String", js_name = raw)]
+    pub fn raw_0(call_site: &Object) -> Result<JsString, JsValue>;
 
-    /// The `Number.parseFloat()` method parses a string argument and returns a
-    /// floating point number.
+    /// The static `raw()` method is a tag function of template literals,
+    /// similar to the `r` prefix in Python or the `@` prefix in C# for string literals.
     ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/parseFloat)
-    #[wasm_bindgen(static_method_of = Number, js_name = parseFloat)]
-    pub fn parse_float(text: &str) -> f64;
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/raw)
+    #[wasm_bindgen(catch, static_method_of = JsString, js_class = "String", js_name = raw)]
+    pub fn raw_1(call_site: &Object, substitutions_1: &str) -> Result<JsString, JsValue>;
 
-    /// The `toLocaleString()` method returns a string with a language sensitive
-    /// representation of this number.
+    /// The static `raw()` method is a tag function of template literals,
+    /// similar to the `r` prefix in Python or the `@` prefix in C# for string literals.
     ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/toLocaleString)
-    #[wasm_bindgen(method, js_name = toLocaleString)]
-    pub fn to_locale_string(this: &Number, locale: &str) -> JsString;
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/raw)
+    #[wasm_bindgen(catch, static_method_of = JsString, js_class = "String", js_name = raw)]
+    pub fn raw_2(
+        call_site: &Object,
+        substitutions_1: &str,
+        substitutions_2: &str,
+    ) -> Result<JsString, JsValue>;
 
-    /// The `toPrecision()` method returns a string representing the Number
-    /// object to the specified precision.
+    /// The static `raw()` method is a tag function of template literals,
+    /// similar to the `r` prefix in Python or the `@` prefix in C# for string literals.
     ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/toPrecision)
-    #[wasm_bindgen(catch, method, js_name = toPrecision)]
-    pub fn to_precision(this: &Number, precision: u8) -> Result<JsString, JsValue>;
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/raw)
+    #[wasm_bindgen(catch, static_method_of = JsString, js_class = "String", js_name = raw)]
+    pub fn raw_3(
+        call_site: &Object,
+        substitutions_1: &str,
+        substitutions_2: &str,
+        substitutions_3: &str,
+    ) -> Result<JsString, JsValue>;
 
-    /// The `toFixed()` method returns a string representing the Number
-    /// object using fixed-point notation.
+    /// The static `raw()` method is a tag function of template literals,
+    /// similar to the `r` prefix in Python or the `@` prefix in C# for string literals.
     ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/toFixed)
-    #[wasm_bindgen(catch, method, js_name = toFixed)]
-    pub fn to_fixed(this: &Number, digits: u8) -> Result<JsString, JsValue>;
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/raw)
+    #[wasm_bindgen(catch, static_method_of = JsString, js_class = "String", js_name = raw)]
+    pub fn raw_4(
+        call_site: &Object,
+        substitutions_1: &str,
+        substitutions_2: &str,
+        substitutions_3: &str,
+        substitutions_4: &str,
+    ) -> Result<JsString, JsValue>;
 
-    /// The `toExponential()` method returns a string representing the Number
-    /// object in exponential notation.
+    /// The static `raw()` method is a tag function of template literals,
+    /// similar to the `r` prefix in Python or the `@` prefix in C# for string literals.
     ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/toExponential)
-    #[wasm_bindgen(catch, method, js_name = toExponential)]
-    pub fn to_exponential(this: &Number, fraction_digits: u8) -> Result<JsString, JsValue>;
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/raw)
+    #[wasm_bindgen(catch, static_method_of = JsString, js_class = "String", js_name = raw)]
+    pub fn raw_5(
+        call_site: &Object,
+        substitutions_1: &str,
+        substitutions_2: &str,
+        substitutions_3: &str,
+        substitutions_4: &str,
+        substitutions_5: &str,
+    ) -> Result<JsString, JsValue>;
 
-    /// The `toString()` method returns a string representing the
-    /// specified Number object.
+    /// The static `raw()` method is a tag function of template literals,
+    /// similar to the `r` prefix in Python or the `@` prefix in C# for string literals.
     ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/toString)
-    #[wasm_bindgen(catch, method, js_name = toString)]
-    pub fn to_string(this: &Number, radix: u8) -> Result<JsString, JsValue>;
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/raw)
+    #[wasm_bindgen(catch, static_method_of = JsString, js_class = "String", js_name = raw)]
+    pub fn raw_6(
+        call_site: &Object,
+        substitutions_1: &str,
+        substitutions_2: &str,
+        substitutions_3: &str,
+        substitutions_4: &str,
+        substitutions_5: &str,
+        substitutions_6: &str,
+    ) -> Result<JsString, JsValue>;
 
-    /// The `valueOf()` method returns the wrapped primitive value of
-    /// a Number object.
+    /// The static `raw()` method is a tag function of template literals,
+    /// similar to the `r` prefix in Python or the `@` prefix in C# for string literals.
     ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/valueOf)
-    #[wasm_bindgen(method, js_name = valueOf)]
-    pub fn value_of(this: &Number) -> f64;
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/raw)
+    #[wasm_bindgen(catch, static_method_of = JsString, js_class = "String", js_name = raw)]
+    pub fn raw_7(
+        call_site: &Object,
+        substitutions_1: &str,
+        substitutions_2: &str,
+        substitutions_3: &str,
+        substitutions_4: &str,
+        substitutions_5: &str,
+        substitutions_6: &str,
+        substitutions_7: &str,
+    ) -> Result<JsString, JsValue>;
 }
 
-impl Number {
-    /// The smallest interval between two representable numbers.
-    ///
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/EPSILON)
-    pub const EPSILON: f64 = f64::EPSILON;
-    /// The maximum safe integer in JavaScript (2^53 - 1).
+/// The error returned by [`JsString::slice_checked`] and
+/// [`JsString::slice_chars`] when the given range is out of bounds.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct SliceError {
+    start: u32,
+    end: u32,
+    len: u32,
+}
+
+impl fmt::Display for SliceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "range {}..{} is out of bounds for a string of length {}",
+            self.start, self.end, self.len
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SliceError {}
+
+fn resolve_range(range: impl RangeBounds<u32>, len: u32) -> (u32, u32) {
+    let start = match range.start_bound() {
+        Bound::Included(&s) => s,
+        Bound::Excluded(&s) => s + 1,
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&e) => e + 1,
+        Bound::Excluded(&e) => e,
+        Bound::Unbounded => len,
+    };
+    (start, end)
+}
+
+impl JsString {
+    /// Returns the `JsString` value of this JS value if it's an instance of a
+    /// string.
     ///
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/MAX_SAFE_INTEGER)
-    pub const MAX_SAFE_INTEGER: f64 = 9007199254740991.0;
-    /// The largest positive representable number.
+    /// If this JS value is not an instance of a string then this returns
+    /// `None`.
+    #[deprecated(note = "recommended to use dyn_ref instead which is now equivalent")]
+    pub fn try_from(val: &JsValue) -> Option<&JsString> {
+        val.dyn_ref()
+    }
+
+    /// Returns whether this string is a valid UTF-16 string.
     ///
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/MAX_VALUE)
-    pub const MAX_VALUE: f64 = f64::MAX;
-    /// The minimum safe integer in JavaScript (-(2^53 - 1)).
+    /// This is useful for learning whether `String::from(..)` will return a
+    /// lossless representation of the JS string. If this string contains
+    /// unpaired surrogates then `String::from` will succeed but it will be a
+    /// lossy representation of the JS string because unpaired surrogates will
+    /// become replacement characters.
     ///
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/MIN_SAFE_INTEGER)
-    pub const MIN_SAFE_INTEGER: f64 = -9007199254740991.0;
-    /// The smallest positive representable number—that is, the positive number closest to zero
-    /// (without actually being zero).
+    /// If this function returns `false` then to get a lossless representation
+    /// of the string you'll need to manually use the `iter` method (or the
+    /// `char_code_at` accessor) to view the raw character codes.
     ///
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/MIN_VALUE)
-    // Cannot use f64::MIN_POSITIVE since that is the smallest **normal** positive number.
-    pub const MIN_VALUE: f64 = 5E-324;
-    /// Special "Not a Number" value.
+    /// For more information, see the documentation on [JS strings vs Rust
+    /// strings][docs]
     ///
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/NaN)
-    pub const NAN: f64 = f64::NAN;
-    /// Special value representing negative infinity. Returned on overflow.
+    /// [docs]: https://rustwasm.github.io/docs/wasm-bindgen/reference/types/str.html
+    pub fn is_valid_utf16(&self) -> bool {
+        core::char::decode_utf16(self.iter()).all(|i| i.is_ok())
+    }
+
+    /// Returns an iterator over the `u16` character codes that make up this JS
+    /// string.
     ///
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/NEGATIVE_INFINITY)
-    pub const NEGATIVE_INFINITY: f64 = f64::NEG_INFINITY;
-    /// Special value representing infinity. Returned on overflow.
+    /// This method will call `char_code_at` for each code in this JS string,
+    /// returning an iterator of the codes in sequence.
+    pub fn iter(
+        &self,
+    ) -> impl ExactSizeIterator<Item = u16> + DoubleEndedIterator<Item = u16> + '_ {
+        (0..self.length()).map(move |i| self.char_code_at(i) as u16)
+    }
+
+    /// Builds a `JsString` out of the given UTF-16 code units, by way of
+    /// `String.fromCharCode`.
     ///
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/POSITIVE_INFINITY)
-    pub const POSITIVE_INFINITY: f64 = f64::INFINITY;
+    /// Unlike `String::from_utf16` this does not validate that `units` forms
+    /// well-formed UTF-16: lone surrogates are preserved as-is, matching the
+    /// lenient semantics of JS strings.
+    pub fn from_utf16(units: &[u16]) -> JsString {
+        JsString::from_char_code(units)
+    }
 
-    /// Applies the binary `**` JS operator on the two `Number`s.
+    /// Collects the UTF-16 code units that make up this JS string into a
+    /// `Vec`.
     ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Exponentiation)
-    #[inline]
-    pub fn pow(&self, rhs: &Self) -> Self {
-        JsValue::as_ref(self)
-            .pow(JsValue::as_ref(rhs))
-            .unchecked_into()
+    /// This is a convenience wrapper around [`JsString::iter`] for callers
+    /// that want an owned buffer rather than an iterator.
+    pub fn to_utf16(&self) -> Vec<u16> {
+        self.iter().collect()
     }
 
-    /// Applies the binary `>>>` JS operator on the two `Number`s.
+    /// Builds a `JsString` out of the given Unicode code points, by way of
+    /// `String.fromCodePoint`.
     ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Unsigned_right_shift)
-    #[inline]
-    pub fn unsigned_shr(&self, rhs: &Self) -> Self {
-        Number::from(JsValue::as_ref(self).unsigned_shr(JsValue::as_ref(rhs)))
+    /// Returns an error if any of `points` is not a valid Unicode code point.
+    pub fn from_code_points(points: &[u32]) -> Result<JsString, RangeError> {
+        JsString::from_code_point(points).map_err(|e| e.unchecked_into())
     }
-}
 
-macro_rules! number_from {
-    ($($x:ident)*) => ($(
-        impl From<$x> for Number {
-            #[inline]
-            fn from(x: $x) -> Number {
-                Number::unchecked_from_js(JsValue::from(x))
-            }
+    /// Like [`JsString::slice`], but `range`'s bounds are UTF-16 code unit
+    /// offsets that are checked against this string's length rather than
+    /// clamped the way the underlying JS method's are, and a range that
+    /// starts after it ends or that exceeds the length is a [`SliceError`]
+    /// rather than an empty or clamped result.
+    pub fn slice_checked(&self, range: impl RangeBounds<u32>) -> Result<JsString, SliceError> {
+        let len = self.length();
+        let (start, end) = resolve_range(range, len);
+        if start > end || end > len {
+            return Err(SliceError { start, end, len });
         }
+        Ok(self.slice(start, end))
+    }
 
-        impl PartialEq<$x> for Number {
-            #[inline]
-            fn eq(&self, other: &$x) -> bool {
-                self.value_of() == f64::from(*other)
+    /// Maps a Unicode scalar-value (char) index to its UTF-16 code unit
+    /// offset, by walking [`JsString::iter`] through [`core::char::decode_utf16`].
+    /// Returns the one-past-the-end offset if `char_index` equals the
+    /// total char count.
+    fn char_index_to_utf16(&self, char_index: u32) -> Option<u32> {
+        let mut utf16_offset = 0u32;
+        let mut char_count = 0u32;
+        for unit in core::char::decode_utf16(self.iter()) {
+            if char_count == char_index {
+                return Some(utf16_offset);
             }
+            utf16_offset += match unit {
+                Ok(c) => c.len_utf16() as u32,
+                Err(_) => 1,
+            };
+            char_count += 1;
+        }
+        if char_count == char_index {
+            Some(utf16_offset)
+        } else {
+            None
         }
-    )*)
-}
-number_from!(i8 u8 i16 u16 i32 u32 f32 f64);
-
-/// The error type returned when a checked integral type conversion fails.
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
-pub struct TryFromIntError(());
-
-impl fmt::Display for TryFromIntError {
-    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.write_str("out of range integral type conversion attempted")
     }
-}
-
-#[cfg(feature = "std")]
-impl std::error::Error for TryFromIntError {}
-
-macro_rules! number_try_from {
-    ($($x:ident)*) => ($(
-        impl TryFrom<$x> for Number {
-            type Error = TryFromIntError;
-
-            #[inline]
-            fn try_from(x: $x) -> Result<Number, Self::Error> {
-                let x_f64 = x as f64;
-                if (Number::MIN_SAFE_INTEGER..=Number::MAX_SAFE_INTEGER).contains(&x_f64) {
-                    Ok(Number::from(x_f64))
-                } else {
-                    Err(TryFromIntError(()))
-                }
-            }
+
+    /// Like [`JsString::slice_checked`], but `range`'s bounds are Unicode
+    /// scalar-value (char) indices rather than UTF-16 code unit offsets,
+    /// so a range boundary can never fall inside a surrogate pair.
+    pub fn slice_chars(&self, range: impl RangeBounds<u32>) -> Result<JsString, SliceError> {
+        let char_count = core::char::decode_utf16(self.iter()).count() as u32;
+        let (start_char, end_char) = resolve_range(range, char_count);
+        if start_char > end_char || end_char > char_count {
+            return Err(SliceError {
+                start: start_char,
+                end: end_char,
+                len: char_count,
+            });
         }
-    )*)
-}
-number_try_from!(i64 u64 i128 u128);
+        let start = self.char_index_to_utf16(start_char).unwrap_or(0);
+        let end = self.char_index_to_utf16(end_char).unwrap_or(start);
+        Ok(self.slice(start, end))
+    }
 
-// TODO: add this on the next major version, when blanket impl is removed
-/*
-impl convert::TryFrom<JsValue> for Number {
-    type Error = Error;
+    /// Returns `true` if `idx` falls strictly between the two UTF-16 code
+    /// units of a surrogate pair (so slicing at `idx` would split it).
+    fn splits_surrogate_pair(&self, idx: u32) -> bool {
+        if idx == 0 || idx >= self.length() {
+            return false;
+        }
+        let prev = self.char_code_at(idx - 1) as u32;
+        let next = self.char_code_at(idx) as u32;
+        (0xD800..=0xDBFF).contains(&prev) && (0xDC00..=0xDFFF).contains(&next)
+    }
 
-    fn try_from(value: JsValue) -> Result<Self, Self::Error> {
-        return match f64::try_from(value) {
-            Ok(num) => Ok(Number::from(num)),
-            Err(jsval) => Err(jsval.unchecked_into())
+    /// Splits this string into two at UTF-16 code unit offset `idx`:
+    /// `(self[..idx], self[idx..])`.
+    ///
+    /// In a debug build, asserts that `idx` doesn't fall inside a
+    /// surrogate pair (use [`JsString::slice_chars`]'s char-indexed
+    /// boundaries to avoid that by construction).
+    pub fn split_at(&self, idx: u32) -> (JsString, JsString) {
+        core::debug_assert!(
+            !self.splits_surrogate_pair(idx),
+            "split_at index {} falls inside a surrogate pair",
+            idx
+        );
+        (self.slice(0, idx), self.slice(idx, self.length()))
+    }
+
+    /// Truncates this string to at most `max_chars` Unicode scalar values
+    /// (not UTF-16 code units), appending `ellipsis` in place of the
+    /// removed tail if it had to cut anything -- `ellipsis`'s own chars
+    /// count against `max_chars`, so the result is never longer than
+    /// `max_chars` chars unless `ellipsis` alone is longer, in which case
+    /// the result is `ellipsis` itself. Pass `""` for no ellipsis.
+    pub fn truncate_chars(&self, max_chars: u32, ellipsis: &str) -> JsString {
+        let char_count = core::char::decode_utf16(self.iter()).count() as u32;
+        if char_count <= max_chars {
+            return self.clone();
         }
+        let ellipsis_chars = ellipsis.chars().count() as u32;
+        let keep = max_chars.saturating_sub(ellipsis_chars);
+        let head = self
+            .slice_chars(0..keep)
+            .unwrap_or_else(|_| JsString::from(""));
+        head.concat(&JsValue::from_str(ellipsis))
     }
-}
-*/
 
-impl From<&Number> for f64 {
-    #[inline]
-    fn from(n: &Number) -> f64 {
-        n.value_of()
+    /// If this string consists of a single Unicode code point, then this method
+    /// converts it into a Rust `char` without doing any allocations.
+    ///
+    /// If this JS value is not a valid UTF-8 or consists of more than a single
+    /// codepoint, then this returns `None`.
+    ///
+    /// Note that a single Unicode code point might be represented as more than
+    /// one code unit on the JavaScript side. For example, a JavaScript string
+    /// `"\uD801\uDC37"` is actually a single Unicode code point U+10437 which
+    /// corresponds to a character '𐐷'.
+    pub fn as_char(&self) -> Option<char> {
+        let len = self.length();
+
+        if len == 0 || len > 2 {
+            return None;
+        }
+
+        // This will be simplified when definitions are fixed:
+        // https://github.com/rustwasm/wasm-bindgen/issues/1362
+        let cp = self.code_point_at(0).as_f64().unwrap_throw() as u32;
+
+        let c = core::char::from_u32(cp)?;
+
+        if c.len_utf16() as u32 == len {
+            Some(c)
+        } else {
+            None
+        }
     }
 }
 
-impl From<Number> for f64 {
-    #[inline]
-    fn from(n: Number) -> f64 {
-        <f64 as From<&'_ Number>>::from(&n)
+impl PartialEq<str> for JsString {
+    #[allow(clippy::cmp_owned)] // prevent infinite recursion
+    fn eq(&self, other: &str) -> bool {
+        String::from(self) == other
     }
 }
 
-impl fmt::Debug for Number {
-    #[inline]
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        fmt::Debug::fmt(&self.value_of(), f)
+impl<'a> PartialEq<&'a str> for JsString {
+    fn eq(&self, other: &&'a str) -> bool {
+        <JsString as PartialEq<str>>::eq(self, other)
     }
 }
 
-impl fmt::Display for Number {
-    #[inline]
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        fmt::Display::fmt(&self.value_of(), f)
+impl PartialEq<String> for JsString {
+    fn eq(&self, other: &String) -> bool {
+        <JsString as PartialEq<str>>::eq(self, other)
     }
 }
 
-impl Default for Number {
-    fn default() -> Self {
-        Self::from(f64::default())
+impl<'a> PartialEq<&'a String> for JsString {
+    fn eq(&self, other: &&'a String) -> bool {
+        <JsString as PartialEq<str>>::eq(self, other)
     }
 }
 
-impl PartialEq<BigInt> for Number {
-    #[inline]
-    fn eq(&self, other: &BigInt) -> bool {
-        JsValue::as_ref(self).loose_eq(JsValue::as_ref(other))
+impl<'a> From<&'a str> for JsString {
+    fn from(s: &'a str) -> Self {
+        JsString::unchecked_from_js(JsValue::from_str(s))
     }
 }
 
-impl Not for &Number {
-    type Output = BigInt;
-
-    #[inline]
-    fn not(self) -> Self::Output {
-        JsValue::as_ref(self).bit_not().unchecked_into()
+impl From<String> for JsString {
+    fn from(s: String) -> Self {
+        From::from(&*s)
     }
 }
 
-forward_deref_unop!(impl Not, not for Number);
-forward_js_unop!(impl Neg, neg for Number);
-forward_js_binop!(impl BitAnd, bitand for Number);
-forward_js_binop!(impl BitOr, bitor for Number);
-forward_js_binop!(impl BitXor, bitxor for Number);
-forward_js_binop!(impl Shl, shl for Number);
-forward_js_binop!(impl Shr, shr for Number);
-forward_js_binop!(impl Add, add for Number);
-forward_js_binop!(impl Sub, sub for Number);
-forward_js_binop!(impl Div, div for Number);
-forward_js_binop!(impl Mul, mul for Number);
-forward_js_binop!(impl Rem, rem for Number);
-
-sum_product!(Number);
-
-impl PartialOrd for Number {
+impl From<char> for JsString {
     #[inline]
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        if Number::is_nan(self) || Number::is_nan(other) {
-            None
-        } else if self == other {
-            Some(Ordering::Equal)
-        } else if self.lt(other) {
-            Some(Ordering::Less)
-        } else {
-            Some(Ordering::Greater)
-        }
+    fn from(c: char) -> Self {
+        JsString::from_code_point1(c as u32).unwrap_throw()
     }
+}
 
-    #[inline]
-    fn lt(&self, other: &Self) -> bool {
-        JsValue::as_ref(self).lt(JsValue::as_ref(other))
+impl<'a> From<&'a JsString> for String {
+    fn from(s: &'a JsString) -> Self {
+        s.obj.as_string().unwrap_throw()
     }
+}
 
-    #[inline]
-    fn le(&self, other: &Self) -> bool {
-        JsValue::as_ref(self).le(JsValue::as_ref(other))
+impl From<JsString> for String {
+    fn from(s: JsString) -> Self {
+        From::from(&s)
     }
+}
 
+impl fmt::Debug for JsString {
     #[inline]
-    fn ge(&self, other: &Self) -> bool {
-        JsValue::as_ref(self).ge(JsValue::as_ref(other))
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&String::from(self), f)
     }
+}
 
+impl fmt::Display for JsString {
     #[inline]
-    fn gt(&self, other: &Self) -> bool {
-        JsValue::as_ref(self).gt(JsValue::as_ref(other))
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&String::from(self), f)
     }
 }
 
-impl FromStr for Number {
-    type Err = Infallible;
-
-    #[allow(deprecated)]
-    #[inline]
+impl str::FromStr for JsString {
+    type Err = convert::Infallible;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(Number::new_from_str(s))
+        Ok(JsString::from(s))
     }
 }
 
-// Date.
+// Symbol
 #[wasm_bindgen]
 extern "C" {
-    #[wasm_bindgen(extends = Object, typescript_type = "Date")]
-    #[derive(Clone, Debug, PartialEq, Eq)]
-    pub type Date;
+    #[wasm_bindgen(is_type_of = JsValue::is_symbol, typescript_type = "Symbol")]
+    #[derive(Clone, Debug)]
+    pub type Symbol;
 
-    /// The `getDate()` method returns the day of the month for the
-    /// specified date according to local time.
+    /// The `Symbol.hasInstance` well-known symbol is used to determine
+    /// if a constructor object recognizes an object as its instance.
+    /// The `instanceof` operator's behavior can be customized by this symbol.
     ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getDate)
-    #[wasm_bindgen(method, js_name = getDate)]
-    pub fn get_date(this: &Date) -> u32;
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol/hasInstance)
+    #[wasm_bindgen(static_method_of = Symbol, getter, structural, js_name = hasInstance)]
+    pub fn has_instance() -> Symbol;
+
+    /// The `Symbol.isConcatSpreadable` well-known symbol is used to configure
+    /// if an object should be flattened to its array elements when using the
+    /// `Array.prototype.concat()` method.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol/isConcatSpreadable)
+    #[wasm_bindgen(static_method_of = Symbol, getter, structural, js_name = isConcatSpreadable)]
+    pub fn is_concat_spreadable() -> Symbol;
+
+    /// The `Symbol.asyncIterator` well-known symbol specifies the default AsyncIterator for an object.
+    /// If this property is set on an object, it is an async iterable and can be used in a `for await...of` loop.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol/asyncIterator)
+    #[wasm_bindgen(static_method_of = Symbol, getter, structural, js_name = asyncIterator)]
+    pub fn async_iterator() -> Symbol;
+
+    /// The `Symbol.iterator` well-known symbol specifies the default iterator
+    /// for an object.  Used by `for...of`.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol/iterator)
+    #[wasm_bindgen(static_method_of = Symbol, getter, structural)]
+    pub fn iterator() -> Symbol;
+
+    /// The `Symbol.match` well-known symbol specifies the matching of a regular
+    /// expression against a string. This function is called by the
+    /// `String.prototype.match()` method.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol/match)
+    #[wasm_bindgen(static_method_of = Symbol, getter, structural, js_name = match)]
+    pub fn match_() -> Symbol;
+
+    /// The `Symbol.replace` well-known symbol specifies the method that
+    /// replaces matched substrings of a string.  This function is called by the
+    /// `String.prototype.replace()` method.
+    ///
+    /// For more information, see `RegExp.prototype[@@replace]()` and
+    /// `String.prototype.replace()`.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol/replace)
+    #[wasm_bindgen(static_method_of = Symbol, getter, structural)]
+    pub fn replace() -> Symbol;
+
+    /// The `Symbol.search` well-known symbol specifies the method that returns
+    /// the index within a string that matches the regular expression.  This
+    /// function is called by the `String.prototype.search()` method.
+    ///
+    /// For more information, see `RegExp.prototype[@@search]()` and
+    /// `String.prototype.search()`.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol/search)
+    #[wasm_bindgen(static_method_of = Symbol, getter, structural)]
+    pub fn search() -> Symbol;
+
+    /// The well-known symbol `Symbol.species` specifies a function-valued
+    /// property that the constructor function uses to create derived objects.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol/species)
+    #[wasm_bindgen(static_method_of = Symbol, getter, structural)]
+    pub fn species() -> Symbol;
+
+    /// The `Symbol.split` well-known symbol specifies the method that splits a
+    /// string at the indices that match a regular expression.  This function is
+    /// called by the `String.prototype.split()` method.
+    ///
+    /// For more information, see `RegExp.prototype[@@split]()` and
+    /// `String.prototype.split()`.
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol/split)
+    #[wasm_bindgen(static_method_of = Symbol, getter, structural)]
+    pub fn split() -> Symbol;
+
+    /// The `Symbol.toPrimitive` is a symbol that specifies a function valued
+    /// property that is called to convert an object to a corresponding
+    /// primitive value.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol/toPrimitive)
+    #[wasm_bindgen(static_method_of = Symbol, getter, structural, js_name = toPrimitive)]
+    pub fn to_primitive() -> Symbol;
 
-    /// The `getDay()` method returns the day of the week for the specified date according to local time,
-    /// where 0 represents Sunday. For the day of the month see getDate().
+    /// The `Symbol.toStringTag` well-known symbol is a string valued property
+    /// that is used in the creation of the default string description of an
+    /// object.  It is accessed internally by the `Object.prototype.toString()`
+    /// method.
     ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getDay)
-    #[wasm_bindgen(method, js_name = getDay)]
-    pub fn get_day(this: &Date) -> u32;
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol/toString)
+    #[wasm_bindgen(static_method_of = Symbol, getter, structural, js_name = toStringTag)]
+    pub fn to_string_tag() -> Symbol;
 
-    /// The `getFullYear()` method returns the year of the specified date according to local time.
+    /// The `Symbol.for(key)` method searches for existing symbols in a runtime-wide symbol registry with
+    /// the given key and returns it if found.
+    /// Otherwise a new symbol gets created in the global symbol registry with this key.
     ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getFullYear)
-    #[wasm_bindgen(method, js_name = getFullYear)]
-    pub fn get_full_year(this: &Date) -> u32;
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol/for)
+    #[wasm_bindgen(static_method_of = Symbol, js_name = for)]
+    pub fn for_(key: &str) -> Symbol;
 
-    /// The `getHours()` method returns the hour for the specified date, according to local time.
+    /// The `Symbol.keyFor(sym)` method retrieves a shared symbol key from the global symbol registry for the given symbol.
     ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getHours)
-    #[wasm_bindgen(method, js_name = getHours)]
-    pub fn get_hours(this: &Date) -> u32;
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol/keyFor)
+    #[wasm_bindgen(static_method_of = Symbol, js_name = keyFor)]
+    pub fn key_for(sym: &Symbol) -> JsValue;
 
-    /// The `getMilliseconds()` method returns the milliseconds in the specified date according to local time.
+    /// The `toString()` method returns a string representing the specified Symbol object.
     ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getMilliseconds)
-    #[wasm_bindgen(method, js_name = getMilliseconds)]
-    pub fn get_milliseconds(this: &Date) -> u32;
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol/toString)
+    #[wasm_bindgen(method, js_name = toString)]
+    pub fn to_string(this: &Symbol) -> JsString;
 
-    /// The `getMinutes()` method returns the minutes in the specified date according to local time.
+    /// The `Symbol.unscopables` well-known symbol is used to specify an object
+    /// value of whose own and inherited property names are excluded from the
+    /// with environment bindings of the associated object.
     ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getMinutes)
-    #[wasm_bindgen(method, js_name = getMinutes)]
-    pub fn get_minutes(this: &Date) -> u32;
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol/unscopables)
+    #[wasm_bindgen(static_method_of = Symbol, getter, structural)]
+    pub fn unscopables() -> Symbol;
 
-    /// The `getMonth()` method returns the month in the specified date according to local time,
-    /// as a zero-based value (where zero indicates the first month of the year).
+    /// The `valueOf()` method returns the primitive value of a Symbol object.
     ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getMonth)
-    #[wasm_bindgen(method, js_name = getMonth)]
-    pub fn get_month(this: &Date) -> u32;
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol/valueOf)
+    #[wasm_bindgen(method, js_name = valueOf)]
+    pub fn value_of(this: &Symbol) -> Symbol;
 
-    /// The `getSeconds()` method returns the seconds in the specified date according to local time.
+    /// Calls `Symbol(description)`, producing a new unique symbol (unlike
+    /// [`Symbol::for_`], which shares symbols through the global registry).
     ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getSeconds)
-    #[wasm_bindgen(method, js_name = getSeconds)]
-    pub fn get_seconds(this: &Date) -> u32;
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol/Symbol)
+    #[wasm_bindgen(js_name = Symbol)]
+    fn new_symbol(description: &str) -> Symbol;
+}
 
-    /// The `getTime()` method returns the numeric value corresponding to the time for the specified date
-    /// according to universal time.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getTime)
-    #[wasm_bindgen(method, js_name = getTime)]
-    pub fn get_time(this: &Date) -> f64;
+impl Symbol {
+    /// Creates a new, always-unique symbol with the given `description`,
+    /// named for the intent of keying private/internal metadata on an
+    /// object rather than public API surface. Two calls with the same
+    /// `description` never collide -- unlike [`Symbol::for_`], this does
+    /// not consult the global symbol registry.
+    pub fn new_private(description: &str) -> Symbol {
+        new_symbol(description)
+    }
+}
 
-    /// The `getTimezoneOffset()` method returns the time zone difference, in minutes,
-    /// from current locale (host system settings) to UTC.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getTimezoneOffset)
-    #[wasm_bindgen(method, js_name = getTimezoneOffset)]
-    pub fn get_timezone_offset(this: &Date) -> f64;
+/// Returns the per-thread table mapping each symbol seen by
+/// [`Symbol::id_hash`] to an auto-incremented id, so that repeated calls for
+/// the same symbol hand back the same id.
+///
+/// This is a plain [`Map`] rather than a [`WeakMap`]: symbols aren't
+/// `extends = Object` in this crate's type hierarchy, so they can't be used
+/// as `WeakMap` keys. The trade-off is that every symbol ever hashed is kept
+/// alive for the life of the program; callers who hash unbounded numbers of
+/// short-lived symbols should keep that in mind.
+fn symbol_id_table() -> Map {
+    #[cfg(feature = "std")]
+    {
+        thread_local!(static TABLE: Map = Map::new());
+        TABLE.with(|t| t.clone())
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        use once_cell::unsync::Lazy;
 
-    /// The `getUTCDate()` method returns the day (date) of the month in the specified date
-    /// according to universal time.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getUTCDate)
-    #[wasm_bindgen(method, js_name = getUTCDate)]
-    pub fn get_utc_date(this: &Date) -> u32;
+        struct Wrapper<T>(Lazy<T>);
 
-    /// The `getUTCDay()` method returns the day of the week in the specified date according to universal time,
-    /// where 0 represents Sunday.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getUTCDay)
-    #[wasm_bindgen(method, js_name = getUTCDay)]
-    pub fn get_utc_day(this: &Date) -> u32;
+        #[cfg(not(target_feature = "atomics"))]
+        unsafe impl<T> Sync for Wrapper<T> {}
 
-    /// The `getUTCFullYear()` method returns the year in the specified date according to universal time.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getUTCFullYear)
-    #[wasm_bindgen(method, js_name = getUTCFullYear)]
-    pub fn get_utc_full_year(this: &Date) -> u32;
+        #[cfg(not(target_feature = "atomics"))]
+        unsafe impl<T> Send for Wrapper<T> {}
 
-    /// The `getUTCHours()` method returns the hours in the specified date according to universal time.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getUTCHours)
-    #[wasm_bindgen(method, js_name = getUTCHours)]
-    pub fn get_utc_hours(this: &Date) -> u32;
+        #[cfg_attr(target_feature = "atomics", thread_local)]
+        static TABLE: Wrapper<Map> = Wrapper(Lazy::new(Map::new));
 
-    /// The `getUTCMilliseconds()` method returns the milliseconds in the specified date
-    /// according to universal time.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getUTCMilliseconds)
-    #[wasm_bindgen(method, js_name = getUTCMilliseconds)]
-    pub fn get_utc_milliseconds(this: &Date) -> u32;
+        TABLE.0.clone()
+    }
+}
 
-    /// The `getUTCMinutes()` method returns the minutes in the specified date according to universal time.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getUTCMinutes)
-    #[wasm_bindgen(method, js_name = getUTCMinutes)]
-    pub fn get_utc_minutes(this: &Date) -> u32;
+impl Symbol {
+    /// Returns a stable identity for this symbol, obtained by storing an
+    /// auto-incremented id for it in a per-thread side table on first
+    /// request and handing back the same id on every later call.
+    ///
+    /// This makes it possible to build Rust-side collections keyed by
+    /// symbol identity (see [`SymbolMap`]) without relying on `Hash`/`Eq`
+    /// impls this crate doesn't provide directly on `Symbol`.
+    pub fn id_hash(&self) -> u64 {
+        let table = symbol_id_table();
+        if let Some(id) = table.get(self.as_ref()).as_f64() {
+            return id as u64;
+        }
+        let id = table.size() as u64;
+        table.set(self.as_ref(), &JsValue::from_f64(id as f64));
+        id
+    }
 
-    /// The `getUTCMonth()` returns the month of the specified date according to universal time,
-    /// as a zero-based value (where zero indicates the first month of the year).
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getUTCMonth)
-    #[wasm_bindgen(method, js_name = getUTCMonth)]
-    pub fn get_utc_month(this: &Date) -> u32;
+    /// Returns whether `a` and `b` are the same symbol, using JS strict
+    /// equality (the same notion `===` uses for symbols: identity, not
+    /// description).
+    pub fn same(a: &Symbol, b: &Symbol) -> bool {
+        a == b
+    }
+}
 
-    /// The `getUTCSeconds()` method returns the seconds in the specified date according to universal time.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getUTCSeconds)
-    #[wasm_bindgen(method, js_name = getUTCSeconds)]
-    pub fn get_utc_seconds(this: &Date) -> u32;
+impl PartialEq for Symbol {
+    fn eq(&self, other: &Symbol) -> bool {
+        Object::is(self.as_ref(), other.as_ref())
+    }
+}
 
-    /// Creates a JavaScript `Date` instance that represents
-    /// a single moment in time. `Date` objects are based on a time value that is
-    /// the number of milliseconds since 1 January 1970 UTC.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date)
-    #[wasm_bindgen(constructor)]
-    pub fn new(init: &JsValue) -> Date;
+impl Eq for Symbol {}
 
-    /// Creates a JavaScript `Date` instance that represents the current moment in
-    /// time.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date)
-    #[wasm_bindgen(constructor)]
-    pub fn new_0() -> Date;
+/// A Rust-side map keyed by [`Symbol`] identity rather than description,
+/// built on [`Symbol::id_hash`].
+///
+/// Two symbols created with identical descriptions are still distinct keys
+/// here -- exactly as they are in JS -- because the key is each symbol's
+/// stable identity, not its `description` string.
+pub struct SymbolMap<V> {
+    entries: RefCell<BTreeMap<u64, (Symbol, V)>>,
+}
 
-    /// Creates a JavaScript `Date` instance that represents
-    /// a single moment in time. `Date` objects are based on a time value that is
-    /// the number of milliseconds since 1 January 1970 UTC.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date)
-    #[wasm_bindgen(constructor)]
-    pub fn new_with_year_month(year: u32, month: i32) -> Date;
+impl<V> SymbolMap<V> {
+    /// Creates a new, empty map.
+    pub fn new() -> SymbolMap<V> {
+        SymbolMap {
+            entries: RefCell::new(BTreeMap::new()),
+        }
+    }
 
-    /// Creates a JavaScript `Date` instance that represents
-    /// a single moment in time. `Date` objects are based on a time value that is
-    /// the number of milliseconds since 1 January 1970 UTC.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date)
-    #[wasm_bindgen(constructor)]
-    pub fn new_with_year_month_day(year: u32, month: i32, day: i32) -> Date;
+    /// Inserts `value` under `key`'s identity, returning the previously
+    /// stored value for that symbol, if any.
+    pub fn insert(&self, key: Symbol, value: V) -> Option<V> {
+        let id = key.id_hash();
+        self.entries
+            .borrow_mut()
+            .insert(id, (key, value))
+            .map(|(_, value)| value)
+    }
 
-    /// Creates a JavaScript `Date` instance that represents
-    /// a single moment in time. `Date` objects are based on a time value that is
-    /// the number of milliseconds since 1 January 1970 UTC.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date)
-    #[wasm_bindgen(constructor)]
-    pub fn new_with_year_month_day_hr(year: u32, month: i32, day: i32, hr: i32) -> Date;
+    /// Removes and returns the value stored for `key`'s identity, if any.
+    pub fn remove(&self, key: &Symbol) -> Option<V> {
+        self.entries
+            .borrow_mut()
+            .remove(&key.id_hash())
+            .map(|(_, value)| value)
+    }
 
-    /// Creates a JavaScript `Date` instance that represents
-    /// a single moment in time. `Date` objects are based on a time value that is
-    /// the number of milliseconds since 1 January 1970 UTC.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date)
-    #[wasm_bindgen(constructor)]
-    pub fn new_with_year_month_day_hr_min(
-        year: u32,
-        month: i32,
-        day: i32,
-        hr: i32,
-        min: i32,
-    ) -> Date;
+    /// Returns the number of entries in the map.
+    pub fn len(&self) -> usize {
+        self.entries.borrow().len()
+    }
 
-    /// Creates a JavaScript `Date` instance that represents
-    /// a single moment in time. `Date` objects are based on a time value that is
-    /// the number of milliseconds since 1 January 1970 UTC.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date)
-    #[wasm_bindgen(constructor)]
-    pub fn new_with_year_month_day_hr_min_sec(
-        year: u32,
-        month: i32,
-        day: i32,
-        hr: i32,
-        min: i32,
-        sec: i32,
-    ) -> Date;
+    /// Returns whether the map has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.borrow().is_empty()
+    }
+}
 
-    /// Creates a JavaScript `Date` instance that represents
-    /// a single moment in time. `Date` objects are based on a time value that is
-    /// the number of milliseconds since 1 January 1970 UTC.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date)
-    #[wasm_bindgen(constructor)]
-    pub fn new_with_year_month_day_hr_min_sec_milli(
-        year: u32,
-        month: i32,
-        day: i32,
-        hr: i32,
-        min: i32,
-        sec: i32,
-        milli: i32,
-    ) -> Date;
+impl<V: Clone> SymbolMap<V> {
+    /// Returns a clone of the value stored for `key`'s identity, if any.
+    pub fn get(&self, key: &Symbol) -> Option<V> {
+        self.entries
+            .borrow()
+            .get(&key.id_hash())
+            .map(|(_, value)| value.clone())
+    }
+}
 
-    /// The `Date.now()` method returns the number of milliseconds
-    /// elapsed since January 1, 1970 00:00:00 UTC.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/now)
-    #[wasm_bindgen(static_method_of = Date)]
-    pub fn now() -> f64;
+impl<V> Default for SymbolMap<V> {
+    fn default() -> SymbolMap<V> {
+        SymbolMap::new()
+    }
+}
 
-    /// The `Date.parse()` method parses a string representation of a date, and returns the number of milliseconds
-    /// since January 1, 1970, 00:00:00 UTC or `NaN` if the string is unrecognized or, in some cases,
-    /// contains illegal date values (e.g. 2015-02-31).
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/parse)
-    #[wasm_bindgen(static_method_of = Date)]
-    pub fn parse(date: &str) -> f64;
+impl Object {
+    /// Installs `check` as `target`'s `[Symbol.hasInstance]` method, so that
+    /// `value instanceof target` (and [`instance::is_instance_of_value`])
+    /// delegates to it instead of the default prototype-chain walk.
+    ///
+    /// The property is defined non-enumerable and non-writable, matching how
+    /// `Symbol.hasInstance` is installed on built-in constructors, but
+    /// configurable so it can be replaced later.
+    ///
+    /// The closure is leaked (via [`Closure::forget`]) for the lifetime of
+    /// the program, the same tradeoff documented on [`Promise::detach`] for
+    /// any fire-and-forget callback handed to JS.
+    pub fn define_has_instance(
+        target: &Object,
+        mut check: impl FnMut(JsValue) -> bool + 'static,
+    ) -> Result<(), JsValue> {
+        let closure =
+            Closure::wrap(Box::new(move |value: JsValue| check(value)) as Box<dyn FnMut(JsValue) -> bool>);
+        let function: &Function = closure.as_ref().unchecked_ref();
+
+        let descriptor = Object::new();
+        Reflect::set(descriptor.as_ref(), &JsValue::from_str("value"), function)?;
+        Reflect::set(
+            descriptor.as_ref(),
+            &JsValue::from_str("configurable"),
+            &JsValue::TRUE,
+        )?;
+        Reflect::define_property(target, Symbol::has_instance().as_ref(), &descriptor)?;
+
+        closure.forget();
+        Ok(())
+    }
+
+    /// Returns the value stored under the Symbol-keyed property `key`,
+    /// downcast to `T`. Returns `None` if the property is absent
+    /// (`undefined`) or holds a value that isn't a `T`.
+    pub fn get_symbol<T: JsCast>(&self, key: &Symbol) -> Option<T> {
+        let value = Reflect::get(self.as_ref(), key.as_ref()).ok()?;
+        if value.is_undefined() {
+            None
+        } else {
+            value.dyn_into::<T>().ok()
+        }
+    }
+
+    /// Stores `value` under the Symbol-keyed property `key`.
+    pub fn set_symbol<T: JsCast>(&self, key: &Symbol, value: &T) -> Result<bool, JsValue> {
+        Reflect::set(self.as_ref(), key.as_ref(), value.as_ref())
+    }
+
+    /// Removes the Symbol-keyed property `key`. Returns whether the
+    /// property existed and was successfully deleted.
+    pub fn delete_symbol(&self, key: &Symbol) -> Result<bool, JsValue> {
+        Reflect::delete_property(self, key.as_ref())
+    }
+
+    /// Returns whether the Symbol-keyed property `key` is present on this
+    /// object (or its prototype chain).
+    pub fn has_symbol(&self, key: &Symbol) -> Result<bool, JsValue> {
+        Reflect::has(self.as_ref(), key.as_ref())
+    }
+}
 
-    /// The `setDate()` method sets the day of the Date object relative to the beginning of the currently set month.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setDate)
-    #[wasm_bindgen(method, js_name = setDate)]
-    pub fn set_date(this: &Date, day: u32) -> f64;
+/// A [`Symbol`] paired with the Rust type `T` it's expected to store,
+/// so that [`Object::get_key`]/[`Object::set_key`] are type-safe at the
+/// call site without repeating the type at every call. Purely a Rust-side
+/// pairing -- the phantom type has no runtime representation in JS.
+///
+/// ```no_run
+/// # use js_sys::{Number, Object, Symbol, SymbolKey};
+/// let my_key: SymbolKey<Number> = SymbolKey::new(Symbol::new_private("my-lib:cache"));
+/// let obj = Object::new();
+/// obj.set_key(&my_key, &Number::from(42));
+/// assert_eq!(obj.get_key(&my_key).map(|n| n.value_of()), Some(42.0));
+/// ```
+pub struct SymbolKey<T> {
+    symbol: Symbol,
+    marker: core::marker::PhantomData<T>,
+}
 
-    /// The `setFullYear()` method sets the full year for a specified date according to local time.
-    /// Returns new timestamp.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setFullYear)
-    #[wasm_bindgen(method, js_name = setFullYear)]
-    pub fn set_full_year(this: &Date, year: u32) -> f64;
+impl<T> SymbolKey<T> {
+    /// Wraps `symbol` as a typed key for values of type `T`.
+    pub fn new(symbol: Symbol) -> Self {
+        SymbolKey {
+            symbol,
+            marker: core::marker::PhantomData,
+        }
+    }
 
-    /// The `setFullYear()` method sets the full year for a specified date according to local time.
-    /// Returns new timestamp.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setFullYear)
-    #[wasm_bindgen(method, js_name = setFullYear)]
-    pub fn set_full_year_with_month(this: &Date, year: u32, month: i32) -> f64;
+    /// Returns the underlying untyped `Symbol`.
+    pub fn symbol(&self) -> &Symbol {
+        &self.symbol
+    }
+}
 
-    /// The `setFullYear()` method sets the full year for a specified date according to local time.
-    /// Returns new timestamp.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setFullYear)
-    #[wasm_bindgen(method, js_name = setFullYear)]
-    pub fn set_full_year_with_month_date(this: &Date, year: u32, month: i32, date: i32) -> f64;
+impl<T> Clone for SymbolKey<T> {
+    fn clone(&self) -> Self {
+        SymbolKey::new(self.symbol.clone())
+    }
+}
 
-    /// The `setHours()` method sets the hours for a specified date according to local time,
-    /// and returns the number of milliseconds since January 1, 1970 00:00:00 UTC until the time represented
-    /// by the updated Date instance.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setHours)
-    #[wasm_bindgen(method, js_name = setHours)]
-    pub fn set_hours(this: &Date, hours: u32) -> f64;
+impl Object {
+    /// Type-safe sugar for [`Object::get_symbol`] given a [`SymbolKey`].
+    pub fn get_key<T: JsCast>(&self, key: &SymbolKey<T>) -> Option<T> {
+        self.get_symbol(&key.symbol)
+    }
 
-    /// The `setMilliseconds()` method sets the milliseconds for a specified date according to local time.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setMilliseconds)
-    #[wasm_bindgen(method, js_name = setMilliseconds)]
-    pub fn set_milliseconds(this: &Date, milliseconds: u32) -> f64;
+    /// Type-safe sugar for [`Object::set_symbol`] given a [`SymbolKey`].
+    pub fn set_key<T: JsCast>(&self, key: &SymbolKey<T>, value: &T) -> Result<bool, JsValue> {
+        self.set_symbol(&key.symbol, value)
+    }
 
-    /// The `setMinutes()` method sets the minutes for a specified date according to local time.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setMinutes)
-    #[wasm_bindgen(method, js_name = setMinutes)]
-    pub fn set_minutes(this: &Date, minutes: u32) -> f64;
+    /// Type-safe sugar for [`Object::delete_symbol`] given a [`SymbolKey`].
+    pub fn delete_key<T>(&self, key: &SymbolKey<T>) -> Result<bool, JsValue> {
+        self.delete_symbol(&key.symbol)
+    }
 
-    /// The `setMonth()` method sets the month for a specified date according to the currently set year.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setMonth)
-    #[wasm_bindgen(method, js_name = setMonth)]
-    pub fn set_month(this: &Date, month: u32) -> f64;
+    /// Type-safe sugar for [`Object::has_symbol`] given a [`SymbolKey`].
+    pub fn has_key<T>(&self, key: &SymbolKey<T>) -> Result<bool, JsValue> {
+        self.has_symbol(&key.symbol)
+    }
+}
 
-    /// The `setSeconds()` method sets the seconds for a specified date according to local time.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setSeconds)
-    #[wasm_bindgen(method, js_name = setSeconds)]
-    pub fn set_seconds(this: &Date, seconds: u32) -> f64;
+/// A nominal-typing helper for branding plain objects, since `instanceof`
+/// alone can't distinguish objects that merely look alike.
+///
+/// [`Brand::apply`] marks an object as belonging to this brand by adding it
+/// to an internal [`WeakSet`]; [`Brand::check`] tests membership. Unlike
+/// [`Object::define_has_instance`], a `Brand` doesn't touch the object's
+/// `[Symbol.hasInstance]` or prototype at all, so it composes with any
+/// existing class hierarchy.
+#[derive(Debug)]
+pub struct Brand {
+    name: String,
+    marked: WeakSet,
+}
 
-    /// The `setTime()` method sets the Date object to the time represented by a number of milliseconds
-    /// since January 1, 1970, 00:00:00 UTC.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setTime)
-    #[wasm_bindgen(method, js_name = setTime)]
-    pub fn set_time(this: &Date, time: f64) -> f64;
+impl Brand {
+    /// Creates a new, empty brand identified by `name` (used only for
+    /// [`Debug`](core::fmt::Debug) output).
+    pub fn new(name: &str) -> Brand {
+        Brand {
+            name: String::from(name),
+            marked: WeakSet::new(),
+        }
+    }
 
-    /// The `setUTCDate()` method sets the day of the month for a specified date
-    /// according to universal time.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setUTCDate)
-    #[wasm_bindgen(method, js_name = setUTCDate)]
-    pub fn set_utc_date(this: &Date, day: u32) -> f64;
+    /// Returns this brand's name, as given to [`Brand::new`].
+    pub fn name(&self) -> &str {
+        &self.name
+    }
 
-    /// The `setUTCFullYear()` method sets the full year for a specified date according to universal time.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setUTCFullYear)
-    #[wasm_bindgen(method, js_name = setUTCFullYear)]
-    pub fn set_utc_full_year(this: &Date, year: u32) -> f64;
+    /// Marks `obj` as belonging to this brand.
+    pub fn apply(&self, obj: &Object) {
+        self.marked.add(obj);
+    }
 
-    /// The `setUTCFullYear()` method sets the full year for a specified date according to universal time.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setUTCFullYear)
-    #[wasm_bindgen(method, js_name = setUTCFullYear)]
-    pub fn set_utc_full_year_with_month(this: &Date, year: u32, month: i32) -> f64;
+    /// Returns whether `value` was previously marked with [`Brand::apply`].
+    pub fn check(&self, value: &JsValue) -> bool {
+        match value.dyn_ref::<Object>() {
+            Some(obj) => self.marked.has(obj),
+            None => false,
+        }
+    }
+}
 
-    /// The `setUTCFullYear()` method sets the full year for a specified date according to universal time.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setUTCFullYear)
-    #[wasm_bindgen(method, js_name = setUTCFullYear)]
-    pub fn set_utc_full_year_with_month_date(this: &Date, year: u32, month: i32, date: i32) -> f64;
+/// Helpers for the `instanceof` operator that the built-in operator syntax
+/// can't offer: a fallible version usable on values that might not be
+/// objects or callable.
+pub mod instance {
+    use super::*;
 
-    /// The `setUTCHours()` method sets the hour for a specified date according to universal time,
-    /// and returns the number of milliseconds since  January 1, 1970 00:00:00 UTC until the time
-    /// represented by the updated Date instance.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setUTCHours)
-    #[wasm_bindgen(method, js_name = setUTCHours)]
-    pub fn set_utc_hours(this: &Date, hours: u32) -> f64;
+    /// Mirrors the `instanceof` operator: `is_instance_of_value(value,
+    /// constructor_like)` is equivalent to `value instanceof
+    /// constructor_like`, including respecting a custom
+    /// `[Symbol.hasInstance]` installed by [`Object::define_has_instance`].
+    ///
+    /// Returns `Err` instead of throwing when `constructor_like` has no
+    /// callable `[Symbol.hasInstance]`, e.g. because it is not an object at
+    /// all.
+    pub fn is_instance_of_value(value: &JsValue, constructor_like: &JsValue) -> Result<bool, JsValue> {
+        let has_instance = Reflect::get(constructor_like, Symbol::has_instance().as_ref())?;
+        let has_instance: &Function = has_instance
+            .dyn_ref()
+            .ok_or_else(|| JsValue::from_str("Symbol.hasInstance is not callable"))?;
+        let result = has_instance.call1(constructor_like, value)?;
+        Ok(result.is_truthy())
+    }
+}
 
-    /// The `setUTCMilliseconds()` method sets the milliseconds for a specified date
-    /// according to universal time.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setUTCMilliseconds)
-    #[wasm_bindgen(method, js_name = setUTCMilliseconds)]
-    pub fn set_utc_milliseconds(this: &Date, milliseconds: u32) -> f64;
+/// No-`std`-friendly debug formatting for arbitrary JS values.
+///
+/// [`Map`], [`Set`], and [`Iterator`] already derive
+/// [`Debug`](core::fmt::Debug) (it just prints the wrapped `JsValue`), so
+/// this is a separate, deeper formatter rather than a replacement `impl`.
+pub mod debug {
+    use super::*;
 
-    /// The `setUTCMinutes()` method sets the minutes for a specified date according to universal time.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setUTCMinutes)
-    #[wasm_bindgen(method, js_name = setUTCMinutes)]
-    pub fn set_utc_minutes(this: &Date, minutes: u32) -> f64;
+    /// Renders `value` as an indented tree of its own enumerable
+    /// properties, special-casing [`Array`], [`Map`], and [`Set`] so their
+    /// elements show up instead of their (mostly empty) own properties.
+    ///
+    /// Descends at most `max_depth` levels and shows at most `max_items`
+    /// children per level, appending a `... (N more)` marker past that
+    /// limit. Cycles (a value reachable from itself) are detected and
+    /// rendered as `<cycle>` instead of recursing forever. An error thrown
+    /// while reading a property or an array element (e.g. by a hostile
+    /// getter) is rendered inline as `<error: ...>`; an error raised by a
+    /// `Proxy` in any other way (another trap, a revoked proxy, a `Map` or
+    /// `Set` method called on a proxy) propagates to the caller.
+    pub fn debug_tree(value: &JsValue, max_depth: usize, max_items: usize) -> String {
+        let seen = Set::new(&JsValue::UNDEFINED);
+        let mut out = String::new();
+        write_node(value, 0, max_depth, max_items, &seen, &mut out);
+        out
+    }
 
-    /// The `setUTCMonth()` method sets the month for a specified date according to universal time.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setUTCMonth)
-    #[wasm_bindgen(method, js_name = setUTCMonth)]
-    pub fn set_utc_month(this: &Date, month: u32) -> f64;
+    fn write_indent(out: &mut String, depth: usize) {
+        for _ in 0..depth {
+            out.push_str("  ");
+        }
+    }
 
-    /// The `setUTCSeconds()` method sets the seconds for a specified date according to universal time.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setUTCSeconds)
-    #[wasm_bindgen(method, js_name = setUTCSeconds)]
-    pub fn set_utc_seconds(this: &Date, seconds: u32) -> f64;
+    fn write_node(
+        value: &JsValue,
+        depth: usize,
+        max_depth: usize,
+        max_items: usize,
+        seen: &Set,
+        out: &mut String,
+    ) {
+        if value.is_null() {
+            out.push_str("null");
+            return;
+        }
+        if value.is_undefined() {
+            out.push_str("undefined");
+            return;
+        }
+        if let Some(s) = value.as_string() {
+            out.push_str(&alloc::format!("{:?}", s));
+            return;
+        }
+        if let Some(n) = value.as_f64() {
+            out.push_str(&alloc::format!("{}", n));
+            return;
+        }
+        if let Some(b) = value.as_bool() {
+            out.push_str(if b { "true" } else { "false" });
+            return;
+        }
+        if value.is_function() {
+            out.push_str("<function>");
+            return;
+        }
 
-    /// The `toDateString()` method returns the date portion of a Date object
-    /// in human readable form in American English.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toDateString)
-    #[wasm_bindgen(method, js_name = toDateString)]
-    pub fn to_date_string(this: &Date) -> JsString;
+        // Past here, `value` is an object: detect cycles via the ancestor
+        // path before recursing into it.
+        if seen.has(value) {
+            out.push_str("<cycle>");
+            return;
+        }
+        if depth >= max_depth {
+            out.push_str("<...>");
+            return;
+        }
+        seen.add(value);
+
+        if let Some(array) = value.dyn_ref::<Array>() {
+            out.push_str("[\n");
+            let len = array.length() as usize;
+            let shown = len.min(max_items);
+            for i in 0..shown {
+                write_indent(out, depth + 1);
+                match Reflect::get(value, &JsValue::from_f64(i as f64)) {
+                    Ok(v) => write_node(&v, depth + 1, max_depth, max_items, seen, out),
+                    Err(e) => out.push_str(&alloc::format!("<error: {:?}>", e)),
+                }
+                out.push('\n');
+            }
+            if len > shown {
+                write_indent(out, depth + 1);
+                out.push_str(&alloc::format!("... ({} more)\n", len - shown));
+            }
+            write_indent(out, depth);
+            out.push(']');
+        } else if let Some(map) = value.dyn_ref::<Map>() {
+            out.push_str("Map {\n");
+            let mut remaining = max_items;
+            map.for_each(&mut |v, k| {
+                if remaining == 0 {
+                    return;
+                }
+                remaining -= 1;
+                write_indent(out, depth + 1);
+                write_node(&k, depth + 1, max_depth, max_items, seen, out);
+                out.push_str(" => ");
+                write_node(&v, depth + 1, max_depth, max_items, seen, out);
+                out.push('\n');
+            });
+            if map.size() as usize > max_items {
+                write_indent(out, depth + 1);
+                out.push_str(&alloc::format!("... ({} more)\n", map.size() as usize - max_items));
+            }
+            write_indent(out, depth);
+            out.push('}');
+        } else if let Some(set) = value.dyn_ref::<Set>() {
+            out.push_str("Set {\n");
+            let mut remaining = max_items;
+            set.for_each(&mut |v, _, _| {
+                if remaining == 0 {
+                    return;
+                }
+                remaining -= 1;
+                write_indent(out, depth + 1);
+                write_node(&v, depth + 1, max_depth, max_items, seen, out);
+                out.push('\n');
+            });
+            if set.size() as usize > max_items {
+                write_indent(out, depth + 1);
+                out.push_str(&alloc::format!("... ({} more)\n", set.size() as usize - max_items));
+            }
+            write_indent(out, depth);
+            out.push('}');
+        } else if let Some(object) = value.dyn_ref::<Object>() {
+            out.push_str("{\n");
+            let keys = Object::keys(object);
+            let len = keys.length() as usize;
+            let shown = len.min(max_items);
+            for i in 0..shown {
+                let key = keys.get(i as u32);
+                write_indent(out, depth + 1);
+                out.push_str(&key.as_string().unwrap_or_default());
+                out.push_str(": ");
+                match Reflect::get(value, &key) {
+                    Ok(v) => write_node(&v, depth + 1, max_depth, max_items, seen, out),
+                    Err(e) => out.push_str(&alloc::format!("<error: {:?}>", e)),
+                }
+                out.push('\n');
+            }
+            if len > shown {
+                write_indent(out, depth + 1);
+                out.push_str(&alloc::format!("... ({} more)\n", len - shown));
+            }
+            write_indent(out, depth);
+            out.push('}');
+        } else {
+            out.push_str("<unknown>");
+        }
 
-    /// The `toISOString()` method returns a string in simplified extended ISO format (ISO
-    /// 8601), which is always 24 or 27 characters long (YYYY-MM-DDTHH:mm:ss.sssZ or
-    /// ±YYYYYY-MM-DDTHH:mm:ss.sssZ, respectively). The timezone is always zero UTC offset,
-    /// as denoted by the suffix "Z"
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toISOString)
-    #[wasm_bindgen(method, js_name = toISOString)]
-    pub fn to_iso_string(this: &Date) -> JsString;
+        seen.delete(value);
+    }
+}
 
-    /// The `toJSON()` method returns a string representation of the Date object.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toJSON)
-    #[wasm_bindgen(method, js_name = toJSON)]
-    pub fn to_json(this: &Date) -> JsString;
+#[allow(non_snake_case)]
+pub mod Intl {
+    use super::*;
 
-    /// The `toLocaleDateString()` method returns a string with a language sensitive
-    /// representation of the date portion of this date. The new locales and options
-    /// arguments let applications specify the language whose formatting conventions
-    /// should be used and allow to customize the behavior of the function.
-    /// In older implementations, which ignore the locales and options arguments,
-    /// the locale used and the form of the string
-    /// returned are entirely implementation dependent.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toLocaleDateString)
-    #[wasm_bindgen(method, js_name = toLocaleDateString)]
-    pub fn to_locale_date_string(this: &Date, locale: &str, options: &JsValue) -> JsString;
+    // Intl
+    #[wasm_bindgen]
+    extern "C" {
+        /// The `Intl.getCanonicalLocales()` method returns an array containing
+        /// the canonical locale names. Duplicates will be omitted and elements
+        /// will be validated as structurally valid language tags.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/getCanonicalLocales)
+        #[wasm_bindgen(js_name = getCanonicalLocales, js_namespace = Intl)]
+        pub fn get_canonical_locales(s: &JsValue) -> Array;
 
-    /// The `toLocaleString()` method returns a string with a language sensitive
-    /// representation of this date. The new locales and options arguments
-    /// let applications specify the language whose formatting conventions
-    /// should be used and customize the behavior of the function.
-    /// In older implementations, which ignore the locales
-    /// and options arguments, the locale used and the form of the string
-    /// returned are entirely implementation dependent.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toLocaleString)
-    #[wasm_bindgen(method, js_name = toLocaleString)]
-    pub fn to_locale_string(this: &Date, locale: &str, options: &JsValue) -> JsString;
+        /// The `Intl.supportedValuesOf()` method returns a sorted array
+        /// containing the supported unique calendar, collation, currency,
+        /// numbering system, time zone, or unit values supported by the
+        /// implementation, for the given `key`. Throws a `RangeError` if
+        /// `key` isn't one of `"calendar"`, `"collation"`, `"currency"`,
+        /// `"numberingSystem"`, `"timeZone"`, or `"unit"`, hence `catch`.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/supportedValuesOf)
+        #[wasm_bindgen(js_name = supportedValuesOf, js_namespace = Intl, catch)]
+        pub fn supported_values_of(key: &str) -> Result<Array, JsValue>;
+    }
 
-    /// The `toLocaleTimeString()` method returns a string with a language sensitive
-    /// representation of the time portion of this date. The new locales and options
-    /// arguments let applications specify the language whose formatting conventions should be
-    /// used and customize the behavior of the function. In older implementations, which ignore
-    /// the locales and options arguments, the locale used and the form of the string
-    /// returned are entirely implementation dependent.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toLocaleTimeString)
-    #[wasm_bindgen(method, js_name = toLocaleTimeString)]
-    pub fn to_locale_time_string(this: &Date, locale: &str) -> JsString;
+    /// Feature-detection helpers for newer `Intl` constructors, so
+    /// downstream code can branch on availability without `try`/`catch`
+    /// noise at every call site. Each check is a `Reflect` lookup on the
+    /// global `Intl` object, cached per-thread after the first call.
+    pub mod features {
+        use super::*;
+
+        fn has_global(name: &str) -> bool {
+            let intl = Reflect::get(&crate::global(), &JsValue::from_str("Intl")).unwrap_or(JsValue::UNDEFINED);
+            Reflect::get(&intl, &JsValue::from_str(name))
+                .map(|ctor| !ctor.is_undefined())
+                .unwrap_or(false)
+        }
 
-    /// The `toString()` method returns a string representing
-    /// the specified Date object.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toString)
-    #[wasm_bindgen(method, js_name = toString)]
-    pub fn to_string(this: &Date) -> JsString;
+        macro_rules! cached_feature {
+            ($fn_name:ident, $intl_name:literal) => {
+                #[doc = concat!(
+                    "Returns whether `Intl.",
+                    $intl_name,
+                    "` is available in this environment. Cached per-thread after the first call."
+                )]
+                pub fn $fn_name() -> bool {
+                    #[cfg(feature = "std")]
+                    {
+                        thread_local!(static CACHED: bool = has_global($intl_name));
+                        return CACHED.with(|cached| *cached);
+                    }
+                    #[cfg(not(feature = "std"))]
+                    {
+                        use once_cell::unsync::Lazy;
+
+                        struct Wrapper(Lazy<bool>);
+
+                        #[cfg(not(target_feature = "atomics"))]
+                        unsafe impl Sync for Wrapper {}
+                        #[cfg(not(target_feature = "atomics"))]
+                        unsafe impl Send for Wrapper {}
+
+                        #[cfg_attr(target_feature = "atomics", thread_local)]
+                        static CACHED: Wrapper = Wrapper(Lazy::new(|| has_global($intl_name)));
+
+                        return *CACHED.0;
+                    }
+                }
+            };
+        }
 
-    /// The `toTimeString()` method returns the time portion of a Date object in human
-    /// readable form in American English.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toTimeString)
-    #[wasm_bindgen(method, js_name = toTimeString)]
-    pub fn to_time_string(this: &Date) -> JsString;
+        cached_feature!(has_segmenter, "Segmenter");
+        cached_feature!(has_duration_format, "DurationFormat");
+        cached_feature!(has_relative_time_format, "RelativeTimeFormat");
+        cached_feature!(has_list_format, "ListFormat");
+    }
 
-    /// The `toUTCString()` method converts a date to a string,
-    /// using the UTC time zone.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toUTCString)
-    #[wasm_bindgen(method, js_name = toUTCString)]
-    pub fn to_utc_string(this: &Date) -> JsString;
+    // Intl.Collator
+    #[wasm_bindgen]
+    extern "C" {
+        /// The `Intl.Collator` object is a constructor for collators, objects
+        /// that enable language sensitive string comparison.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Collator)
+        #[wasm_bindgen(extends = Object, js_namespace = Intl, typescript_type = "Intl.Collator")]
+        #[derive(Clone, Debug)]
+        pub type Collator;
 
-    /// The `Date.UTC()` method accepts the same parameters as the
-    /// longest form of the constructor, and returns the number of
-    /// milliseconds in a `Date` object since January 1, 1970,
-    /// 00:00:00, universal time.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/UTC)
-    #[wasm_bindgen(static_method_of = Date, js_name = UTC)]
-    pub fn utc(year: f64, month: f64) -> f64;
+        /// The `Intl.Collator` object is a constructor for collators, objects
+        /// that enable language sensitive string comparison.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Collator)
+        #[wasm_bindgen(constructor, js_namespace = Intl)]
+        pub fn new(locales: &Array, options: &Object) -> Collator;
 
-    /// The `valueOf()` method  returns the primitive value of
-    /// a Date object.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/valueOf)
-    #[wasm_bindgen(method, js_name = valueOf)]
-    pub fn value_of(this: &Date) -> f64;
-}
+        /// The Intl.Collator.prototype.compare property returns a function that
+        /// compares two strings according to the sort order of this Collator
+        /// object.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Collator/compare)
+        #[wasm_bindgen(method, getter, js_class = "Intl.Collator")]
+        pub fn compare(this: &Collator) -> Function;
 
-// Object.
-#[wasm_bindgen]
-extern "C" {
-    #[wasm_bindgen(typescript_type = "object")]
-    #[derive(Clone, Debug)]
-    pub type Object;
+        /// The `Intl.Collator.prototype.resolvedOptions()` method returns a new
+        /// object with properties reflecting the locale and collation options
+        /// computed during initialization of this Collator object.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Collator/resolvedOptions)
+        #[wasm_bindgen(method, js_namespace = Intl, js_name = resolvedOptions)]
+        pub fn resolved_options(this: &Collator) -> Object;
 
-    /// The `Object.assign()` method is used to copy the values of all enumerable
-    /// own properties from one or more source objects to a target object. It
-    /// will return the target object.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/assign)
-    #[wasm_bindgen(static_method_of = Object)]
-    pub fn assign(target: &Object, source: &Object) -> Object;
+        /// The `Intl.Collator.supportedLocalesOf()` method returns an array
+        /// containing those of the provided locales that are supported in
+        /// collation without having to fall back to the runtime's default
+        /// locale.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Collator/supportedLocalesOf)
+        #[wasm_bindgen(static_method_of = Collator, js_namespace = Intl, js_name = supportedLocalesOf)]
+        pub fn supported_locales_of(locales: &Array, options: &Object) -> Array;
+    }
 
-    /// The `Object.assign()` method is used to copy the values of all enumerable
-    /// own properties from one or more source objects to a target object. It
-    /// will return the target object.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/assign)
-    #[wasm_bindgen(static_method_of = Object, js_name = assign)]
-    pub fn assign2(target: &Object, source1: &Object, source2: &Object) -> Object;
+    impl Default for Collator {
+        fn default() -> Self {
+            Self::new(
+                &JsValue::UNDEFINED.unchecked_into(),
+                &JsValue::UNDEFINED.unchecked_into(),
+            )
+        }
+    }
 
-    /// The `Object.assign()` method is used to copy the values of all enumerable
-    /// own properties from one or more source objects to a target object. It
-    /// will return the target object.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/assign)
-    #[wasm_bindgen(static_method_of = Object, js_name = assign)]
-    pub fn assign3(target: &Object, source1: &Object, source2: &Object, source3: &Object)
-        -> Object;
+    /// Typed view of the object returned by [`Collator::resolved_options`],
+    /// reading the commonly used fields without a manual `Reflect::get`
+    /// per field.
+    #[derive(Clone, Debug)]
+    pub struct CollatorResolvedOptions {
+        /// The resolved BCP 47 locale, e.g. `"de-DE"`.
+        pub locale: String,
+        /// The collation usage, e.g. `"sort"` or `"search"`.
+        pub usage: String,
+        /// The sensitivity, e.g. `"variant"` or `"base"`.
+        pub sensitivity: String,
+        /// Whether numeric collation is enabled (e.g. `"2" < "10"`).
+        pub numeric: bool,
+        /// Whether upper- or lowercase sorts first (`"upper"`, `"lower"`,
+        /// or `"false"`).
+        pub case_first: String,
+        /// Whether punctuation is ignored during comparison.
+        pub ignore_punctuation: bool,
+    }
 
-    /// The constructor property returns a reference to the `Object` constructor
-    /// function that created the instance object.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/constructor)
-    #[wasm_bindgen(method, getter)]
-    pub fn constructor(this: &Object) -> Function;
+    fn ordering_from_compare_result(result: f64) -> Ordering {
+        if result < 0.0 {
+            Ordering::Less
+        } else if result > 0.0 {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    }
 
-    /// The `Object.create()` method creates a new object, using an existing
-    /// object to provide the newly created object's prototype.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/create)
-    #[wasm_bindgen(static_method_of = Object)]
-    pub fn create(prototype: &Object) -> Object;
+    impl Collator {
+        /// Compares `a` and `b` according to this collator's locale rules,
+        /// returning an [`Ordering`] instead of the raw signed number
+        /// `compare()` gives back. Each call makes two JS calls: one to read
+        /// the bound `compare` function and one to call it.
+        pub fn compare_str(&self, a: &str, b: &str) -> Ordering {
+            let result = self
+                .compare()
+                .call2(&JsValue::UNDEFINED, &JsValue::from_str(a), &JsValue::from_str(b))
+                .ok()
+                .and_then(|v| v.as_f64())
+                .unwrap_or(0.0);
+            ordering_from_compare_result(result)
+        }
 
-    /// The static method `Object.defineProperty()` defines a new
-    /// property directly on an object, or modifies an existing
-    /// property on an object, and returns the object.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/defineProperty)
-    #[wasm_bindgen(static_method_of = Object, js_name = defineProperty)]
-    pub fn define_property(obj: &Object, prop: &JsValue, descriptor: &Object) -> Object;
+        /// Returns a typed view of [`Collator::resolved_options`]'s most
+        /// commonly used fields.
+        pub fn resolved_options_typed(&self) -> Result<CollatorResolvedOptions, FieldError> {
+            let options = self.resolved_options();
+            Ok(CollatorResolvedOptions {
+                locale: field_string(&options, "locale")?,
+                usage: field_string(&options, "usage")?,
+                sensitivity: field_string(&options, "sensitivity")?,
+                numeric: field_bool(&options, "numeric")?,
+                case_first: field_string(&options, "caseFirst")?,
+                ignore_punctuation: field_bool(&options, "ignorePunctuation")?,
+            })
+        }
 
-    /// The `Object.defineProperties()` method defines new or modifies
-    /// existing properties directly on an object, returning the
-    /// object.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/defineProperties)
-    #[wasm_bindgen(static_method_of = Object, js_name = defineProperties)]
-    pub fn define_properties(obj: &Object, props: &Object) -> Object;
+        /// Returns a Rust closure that calls this collator's `compare()`
+        /// once per invocation, suitable for `slice::sort_by`. Crossing
+        /// into JS once per comparison is fine for small inputs, but for
+        /// large ones prefer [`Collator::sort_strings`], which round-trips
+        /// through a single JS array sort instead.
+        pub fn as_rust_comparator(&self) -> impl Fn(&str, &str) -> Ordering + '_ {
+            let compare = self.compare();
+            move |a: &str, b: &str| {
+                let result = compare
+                    .call2(&JsValue::UNDEFINED, &JsValue::from_str(a), &JsValue::from_str(b))
+                    .ok()
+                    .and_then(|v| v.as_f64())
+                    .unwrap_or(0.0);
+                ordering_from_compare_result(result)
+            }
+        }
 
-    /// The `Object.entries()` method returns an array of a given
-    /// object's own enumerable property [key, value] pairs, in the
-    /// same order as that provided by a for...in loop (the difference
-    /// being that a for-in loop enumerates properties in the
-    /// prototype chain as well).
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/entries)
-    #[wasm_bindgen(static_method_of = Object)]
-    pub fn entries(object: &Object) -> Array;
+        /// Sorts `v` in place using this collator's locale rules, making a
+        /// single round trip through a JS array sort instead of one JS
+        /// call per comparison done by [`Collator::as_rust_comparator`] —
+        /// worth it for large inputs.
+        pub fn sort_strings(&self, v: &mut Vec<String>) {
+            let array = Array::new();
+            for s in v.iter() {
+                array.push(&JsValue::from_str(s));
+            }
+            // Hand the collator's own bound `compare` function to the JS
+            // sort, so no comparison calls back into Rust.
+            let compare = self.compare();
+            let sort = Reflect::get(array.as_ref(), &JsValue::from_str("sort"))
+                .ok()
+                .and_then(|f| f.dyn_into::<Function>().ok());
+            if let Some(sort) = sort {
+                let _ = sort.call1(array.as_ref(), compare.as_ref());
+            }
+            *v = array
+                .iter()
+                .map(|value| value.as_string().unwrap_or_default())
+                .collect();
+        }
+    }
 
-    /// The `Object.freeze()` method freezes an object: that is, prevents new
-    /// properties from being added to it; prevents existing properties from
-    /// being removed; and prevents existing properties, or their enumerability,
-    /// configurability, or writability, from being changed, it also prevents
-    /// the prototype from being changed. The method returns the passed object.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/freeze)
-    #[wasm_bindgen(static_method_of = Object)]
-    pub fn freeze(value: &Object) -> Object;
+    // Intl.DateTimeFormat
+    #[wasm_bindgen]
+    extern "C" {
+        /// The `Intl.DateTimeFormat` object is a constructor for objects
+        /// that enable language-sensitive date and time formatting.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DateTimeFormat)
+        #[wasm_bindgen(extends = Object, js_namespace = Intl, typescript_type = "Intl.DateTimeFormat")]
+        #[derive(Clone, Debug)]
+        pub type DateTimeFormat;
 
-    /// The `Object.fromEntries()` method transforms a list of key-value pairs
-    /// into an object.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/fromEntries)
-    #[wasm_bindgen(static_method_of = Object, catch, js_name = fromEntries)]
-    pub fn from_entries(iterable: &JsValue) -> Result<Object, JsValue>;
+        /// The `Intl.DateTimeFormat` object is a constructor for objects
+        /// that enable language-sensitive date and time formatting.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DateTimeFormat)
+        #[wasm_bindgen(constructor, js_namespace = Intl)]
+        pub fn new(locales: &Array, options: &Object) -> DateTimeFormat;
 
-    /// The `Object.getOwnPropertyDescriptor()` method returns a
-    /// property descriptor for an own property (that is, one directly
-    /// present on an object and not in the object's prototype chain)
-    /// of a given object.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/getOwnPropertyDescriptor)
-    #[wasm_bindgen(static_method_of = Object, js_name = getOwnPropertyDescriptor)]
-    pub fn get_own_property_descriptor(obj: &Object, prop: &JsValue) -> JsValue;
+        /// The Intl.DateTimeFormat.prototype.format property returns a getter function that
+        /// formats a date according to the locale and formatting options of this
+        /// Intl.DateTimeFormat object.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DateTimeFormat/format)
+        #[wasm_bindgen(method, getter, js_class = "Intl.DateTimeFormat")]
+        pub fn format(this: &DateTimeFormat) -> Function;
 
-    /// The `Object.getOwnPropertyDescriptors()` method returns all own
-    /// property descriptors of a given object.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/getOwnPropertyDescriptors)
-    #[wasm_bindgen(static_method_of = Object, js_name = getOwnPropertyDescriptors)]
-    pub fn get_own_property_descriptors(obj: &Object) -> JsValue;
+        /// The `Intl.DateTimeFormat.prototype.formatToParts()` method allows locale-aware
+        /// formatting of strings produced by DateTimeFormat formatters.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DateTimeFormat/formatToParts)
+        #[wasm_bindgen(method, js_class = "Intl.DateTimeFormat", js_name = formatToParts)]
+        pub fn format_to_parts(this: &DateTimeFormat, date: &Date) -> Array;
 
-    /// The `Object.getOwnPropertyNames()` method returns an array of
-    /// all properties (including non-enumerable properties except for
-    /// those which use Symbol) found directly upon a given object.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/getOwnPropertyNames)
-    #[wasm_bindgen(static_method_of = Object, js_name = getOwnPropertyNames)]
-    pub fn get_own_property_names(obj: &Object) -> Array;
+        /// The `Intl.DateTimeFormat.prototype.resolvedOptions()` method returns a new
+        /// object with properties reflecting the locale and date and time formatting
+        /// options computed during initialization of this DateTimeFormat object.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DateTimeFormat/resolvedOptions)
+        #[wasm_bindgen(method, js_namespace = Intl, js_name = resolvedOptions)]
+        pub fn resolved_options(this: &DateTimeFormat) -> Object;
 
-    /// The `Object.getOwnPropertySymbols()` method returns an array of
-    /// all symbol properties found directly upon a given object.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/getOwnPropertySymbols)
-    #[wasm_bindgen(static_method_of = Object, js_name = getOwnPropertySymbols)]
-    pub fn get_own_property_symbols(obj: &Object) -> Array;
+        /// The `Intl.DateTimeFormat.supportedLocalesOf()` method returns an array
+        /// containing those of the provided locales that are supported in date
+        /// and time formatting without having to fall back to the runtime's default
+        /// locale.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DateTimeFormat/supportedLocalesOf)
+        #[wasm_bindgen(static_method_of = DateTimeFormat, js_namespace = Intl, js_name = supportedLocalesOf)]
+        pub fn supported_locales_of(locales: &Array, options: &Object) -> Array;
+    }
 
-    /// The `Object.getPrototypeOf()` method returns the prototype
-    /// (i.e. the value of the internal [[Prototype]] property) of the
-    /// specified object.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/getPrototypeOf)
-    #[wasm_bindgen(static_method_of = Object, js_name = getPrototypeOf)]
-    pub fn get_prototype_of(obj: &JsValue) -> Object;
+    impl Default for DateTimeFormat {
+        fn default() -> Self {
+            Self::new(
+                &JsValue::UNDEFINED.unchecked_into(),
+                &JsValue::UNDEFINED.unchecked_into(),
+            )
+        }
+    }
 
-    /// The `hasOwnProperty()` method returns a boolean indicating whether the
-    /// object has the specified property as its own property (as opposed to
-    /// inheriting it).
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/hasOwnProperty)
-    #[wasm_bindgen(method, js_name = hasOwnProperty)]
-    pub fn has_own_property(this: &Object, property: &JsValue) -> bool;
+    // Intl.NumberFormat
+    #[wasm_bindgen]
+    extern "C" {
+        /// The `Intl.NumberFormat` object is a constructor for objects
+        /// that enable language sensitive number formatting.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/NumberFormat)
+        #[wasm_bindgen(extends = Object, js_namespace = Intl, typescript_type = "Intl.NumberFormat")]
+        #[derive(Clone, Debug)]
+        pub type NumberFormat;
+
+        /// The `Intl.NumberFormat` object is a constructor for objects
+        /// that enable language sensitive number formatting.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/NumberFormat)
+        #[wasm_bindgen(constructor, js_namespace = Intl)]
+        pub fn new(locales: &Array, options: &Object) -> NumberFormat;
 
-    /// The `Object.hasOwn()` method returns a boolean indicating whether the
-    /// object passed in has the specified property as its own property (as
-    /// opposed to inheriting it).
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/hasOwn)
-    #[wasm_bindgen(static_method_of = Object, js_name = hasOwn)]
-    pub fn has_own(instance: &Object, property: &JsValue) -> bool;
+        /// The Intl.NumberFormat.prototype.format property returns a getter function that
+        /// formats a number according to the locale and formatting options of this
+        /// NumberFormat object.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/NumberFormat/format)
+        #[wasm_bindgen(method, getter, js_class = "Intl.NumberFormat")]
+        pub fn format(this: &NumberFormat) -> Function;
 
-    /// The `Object.is()` method determines whether two values are the same value.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/is)
-    #[wasm_bindgen(static_method_of = Object)]
-    pub fn is(value_1: &JsValue, value_2: &JsValue) -> bool;
+        /// The `Intl.Numberformat.prototype.formatToParts()` method allows locale-aware
+        /// formatting of strings produced by NumberTimeFormat formatters.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/NumberFormat/formatToParts)
+        #[wasm_bindgen(method, js_class = "Intl.NumberFormat", js_name = formatToParts)]
+        pub fn format_to_parts(this: &NumberFormat, number: f64) -> Array;
 
-    /// The `Object.isExtensible()` method determines if an object is extensible
-    /// (whether it can have new properties added to it).
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/isExtensible)
-    #[wasm_bindgen(static_method_of = Object, js_name = isExtensible)]
-    pub fn is_extensible(object: &Object) -> bool;
+        /// The `Intl.NumberFormat.prototype.resolvedOptions()` method returns a new
+        /// object with properties reflecting the locale and number formatting
+        /// options computed during initialization of this NumberFormat object.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/NumberFormat/resolvedOptions)
+        #[wasm_bindgen(method, js_namespace = Intl, js_name = resolvedOptions)]
+        pub fn resolved_options(this: &NumberFormat) -> Object;
 
-    /// The `Object.isFrozen()` determines if an object is frozen.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/isFrozen)
-    #[wasm_bindgen(static_method_of = Object, js_name = isFrozen)]
-    pub fn is_frozen(object: &Object) -> bool;
+        /// The `Intl.NumberFormat.supportedLocalesOf()` method returns an array
+        /// containing those of the provided locales that are supported in number
+        /// formatting without having to fall back to the runtime's default locale.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/NumberFormat/supportedLocalesOf)
+        #[wasm_bindgen(static_method_of = NumberFormat, js_namespace = Intl, js_name = supportedLocalesOf)]
+        pub fn supported_locales_of(locales: &Array, options: &Object) -> Array;
+    }
 
-    /// The `Object.isSealed()` method determines if an object is sealed.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/isSealed)
-    #[wasm_bindgen(static_method_of = Object, js_name = isSealed)]
-    pub fn is_sealed(object: &Object) -> bool;
+    impl Default for NumberFormat {
+        fn default() -> Self {
+            Self::new(
+                &JsValue::UNDEFINED.unchecked_into(),
+                &JsValue::UNDEFINED.unchecked_into(),
+            )
+        }
+    }
 
-    /// The `isPrototypeOf()` method checks if an object exists in another
-    /// object's prototype chain.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/isPrototypeOf)
-    #[wasm_bindgen(method, js_name = isPrototypeOf)]
-    pub fn is_prototype_of(this: &Object, value: &JsValue) -> bool;
+    #[wasm_bindgen]
+    extern "C" {
+        /// One part of the breakdown returned by
+        /// [`NumberFormat::format_to_parts_typed`]: a span of the
+        /// formatted string together with the kind of thing it
+        /// represents (currency symbol, group separator, digits, ...).
+        #[wasm_bindgen(extends = Object, typescript_type = "Intl.NumberFormatPart")]
+        #[derive(Clone, Debug)]
+        pub type NumberFormatPart;
+
+        /// The kind of this part, e.g. `"currency"`, `"group"`,
+        /// `"integer"`, `"decimal"`, `"fraction"`, or `"literal"`.
+        #[wasm_bindgen(method, getter, structural, js_name = "type")]
+        pub fn part_type(this: &NumberFormatPart) -> JsString;
+
+        /// The substring of the formatted string this part covers.
+        #[wasm_bindgen(method, getter, structural)]
+        pub fn value(this: &NumberFormatPart) -> JsString;
+
+        /// The object returned by
+        /// [`NumberFormat::resolved_options_typed`]: the locale and
+        /// number formatting options the engine actually picked, after
+        /// applying its own defaults and locale negotiation.
+        #[wasm_bindgen(extends = Object, typescript_type = "Intl.ResolvedNumberFormatOptions")]
+        #[derive(Clone, Debug)]
+        pub type NumberFormatResolvedOptions;
 
-    /// The `Object.keys()` method returns an array of a given object's property
-    /// names, in the same order as we get with a normal loop.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/keys)
-    #[wasm_bindgen(static_method_of = Object)]
-    pub fn keys(object: &Object) -> Array;
+        #[wasm_bindgen(method, getter, structural)]
+        pub fn locale(this: &NumberFormatResolvedOptions) -> JsString;
 
-    /// The [`Object`] constructor creates an object wrapper.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object)
-    #[wasm_bindgen(constructor)]
-    pub fn new() -> Object;
+        #[wasm_bindgen(method, getter, structural, js_name = numberingSystem)]
+        pub fn numbering_system(this: &NumberFormatResolvedOptions) -> JsString;
 
-    /// The `Object.preventExtensions()` method prevents new properties from
-    /// ever being added to an object (i.e. prevents future extensions to the
-    /// object).
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/preventExtensions)
-    #[wasm_bindgen(static_method_of = Object, js_name = preventExtensions)]
-    pub fn prevent_extensions(object: &Object);
+        #[wasm_bindgen(method, getter, structural)]
+        pub fn style(this: &NumberFormatResolvedOptions) -> JsString;
 
-    /// The `propertyIsEnumerable()` method returns a Boolean indicating
-    /// whether the specified property is enumerable.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/propertyIsEnumerable)
-    #[wasm_bindgen(method, js_name = propertyIsEnumerable)]
-    pub fn property_is_enumerable(this: &Object, property: &JsValue) -> bool;
+        /// The resolved currency code, or `None` when `style` isn't
+        /// `"currency"`.
+        #[wasm_bindgen(method, getter, structural)]
+        pub fn currency(this: &NumberFormatResolvedOptions) -> Option<JsString>;
 
-    /// The `Object.seal()` method seals an object, preventing new properties
-    /// from being added to it and marking all existing properties as
-    /// non-configurable.  Values of present properties can still be changed as
-    /// long as they are writable.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/seal)
-    #[wasm_bindgen(static_method_of = Object)]
-    pub fn seal(value: &Object) -> Object;
+        #[wasm_bindgen(method, getter, structural, js_name = minimumFractionDigits)]
+        pub fn minimum_fraction_digits(this: &NumberFormatResolvedOptions) -> u32;
 
-    /// The `Object.setPrototypeOf()` method sets the prototype (i.e., the
-    /// internal `[[Prototype]]` property) of a specified object to another
-    /// object or `null`.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/setPrototypeOf)
-    #[wasm_bindgen(static_method_of = Object, js_name = setPrototypeOf)]
-    pub fn set_prototype_of(object: &Object, prototype: &Object) -> Object;
+        #[wasm_bindgen(method, getter, structural, js_name = maximumFractionDigits)]
+        pub fn maximum_fraction_digits(this: &NumberFormatResolvedOptions) -> u32;
 
-    /// The `toLocaleString()` method returns a string representing the object.
-    /// This method is meant to be overridden by derived objects for
-    /// locale-specific purposes.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/toLocaleString)
-    #[wasm_bindgen(method, js_name = toLocaleString)]
-    pub fn to_locale_string(this: &Object) -> JsString;
+        #[wasm_bindgen(method, getter, structural)]
+        pub fn notation(this: &NumberFormatResolvedOptions) -> JsString;
 
-    /// The `toString()` method returns a string representing the object.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/toString)
-    #[wasm_bindgen(method, js_name = toString)]
-    pub fn to_string(this: &Object) -> JsString;
+        #[wasm_bindgen(method, getter, structural, js_name = roundingMode)]
+        pub fn rounding_mode(this: &NumberFormatResolvedOptions) -> JsString;
+    }
 
-    /// The `valueOf()` method returns the primitive value of the
-    /// specified object.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/valueOf)
-    #[wasm_bindgen(method, js_name = valueOf)]
-    pub fn value_of(this: &Object) -> Object;
+    impl NumberFormat {
+        /// Like [`format_to_parts`](Self::format_to_parts), but the
+        /// elements are typed as [`NumberFormatPart`] instead of plain
+        /// `Object`s, giving direct `part_type()`/`value()` accessors
+        /// instead of a `Reflect::get` round trip per field.
+        pub fn format_to_parts_typed(&self, number: f64) -> Vec<NumberFormatPart> {
+            self.format_to_parts(number)
+                .iter()
+                .map(|part| part.unchecked_into())
+                .collect()
+        }
 
-    /// The `Object.values()` method returns an array of a given object's own
-    /// enumerable property values, in the same order as that provided by a
-    /// `for...in` loop (the difference being that a for-in loop enumerates
-    /// properties in the prototype chain as well).
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/values)
-    #[wasm_bindgen(static_method_of = Object)]
-    pub fn values(object: &Object) -> Array;
-}
+        /// Formats `v` the way [`format`](Self::format) formats a number,
+        /// but without `f64`'s limited integer precision -- useful for
+        /// values outside `Number.MAX_SAFE_INTEGER`.
+        pub fn format_bigint(&self, v: &BigInt) -> Result<JsString, JsValue> {
+            self.format()
+                .call1(&JsValue::UNDEFINED, v.as_ref())?
+                .dyn_into()
+                .map_err(|_| JsValue::from_str("Intl.NumberFormat.format did not return a string"))
+        }
 
-impl Object {
-    /// Returns the `Object` value of this JS value if it's an instance of an
-    /// object.
-    ///
-    /// If this JS value is not an instance of an object then this returns
-    /// `None`.
-    pub fn try_from(val: &JsValue) -> Option<&Object> {
-        if val.is_object() {
-            Some(val.unchecked_ref())
-        } else {
-            None
+        /// Formats the decimal number spelled out in `decimal` (e.g.
+        /// `"123456789012345678901234567890.5"`) the way
+        /// `Intl.NumberFormat.prototype.format` does when given a numeric
+        /// string, preserving precision beyond what `f64` can represent.
+        pub fn format_js_string(&self, decimal: &str) -> Result<JsString, JsValue> {
+            self.format()
+                .call1(&JsValue::UNDEFINED, &JsValue::from_str(decimal))?
+                .dyn_into()
+                .map_err(|_| JsValue::from_str("Intl.NumberFormat.format did not return a string"))
         }
-    }
-}
 
-impl PartialEq for Object {
-    #[inline]
-    fn eq(&self, other: &Object) -> bool {
-        Object::is(self.as_ref(), other.as_ref())
+        /// Like [`resolved_options`](Self::resolved_options), but returns a
+        /// [`NumberFormatResolvedOptions`] with typed accessors for the
+        /// commonly-needed fields instead of a plain `Object`.
+        pub fn resolved_options_typed(&self) -> NumberFormatResolvedOptions {
+            self.resolved_options().unchecked_into()
+        }
     }
-}
 
-impl Eq for Object {}
+    // Intl.PluralRules
+    #[wasm_bindgen]
+    extern "C" {
+        /// The `Intl.PluralRules` object is a constructor for objects
+        /// that enable plural sensitive formatting and plural language rules.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/PluralRules)
+        #[wasm_bindgen(extends = Object, js_namespace = Intl, typescript_type = "Intl.PluralRules")]
+        #[derive(Clone, Debug)]
+        pub type PluralRules;
 
-impl Default for Object {
-    fn default() -> Self {
-        Self::new()
-    }
-}
+        /// The `Intl.PluralRules` object is a constructor for objects
+        /// that enable plural sensitive formatting and plural language rules.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/PluralRules)
+        #[wasm_bindgen(constructor, js_namespace = Intl)]
+        pub fn new(locales: &Array, options: &Object) -> PluralRules;
 
-// Proxy
-#[wasm_bindgen]
-extern "C" {
-    #[wasm_bindgen(typescript_type = "ProxyConstructor")]
-    #[derive(Clone, Debug)]
-    pub type Proxy;
+        /// The `Intl.PluralRules.prototype.resolvedOptions()` method returns a new
+        /// object with properties reflecting the locale and plural formatting
+        /// options computed during initialization of this PluralRules object.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/PluralRules/resolvedOptions)
+        #[wasm_bindgen(method, js_namespace = Intl, js_name = resolvedOptions)]
+        pub fn resolved_options(this: &PluralRules) -> Object;
 
-    /// The [`Proxy`] object is used to define custom behavior for fundamental
-    /// operations (e.g. property lookup, assignment, enumeration, function
-    /// invocation, etc).
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Proxy)
-    #[wasm_bindgen(constructor)]
-    pub fn new(target: &JsValue, handler: &Object) -> Proxy;
+        /// The `Intl.PluralRules.prototype.select()` method returns a String indicating
+        /// which plural rule to use for locale-aware formatting.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/PluralRules/select)
+        #[wasm_bindgen(method, js_namespace = Intl)]
+        pub fn select(this: &PluralRules, number: f64) -> JsString;
 
-    /// The `Proxy.revocable()` method is used to create a revocable [`Proxy`]
-    /// object.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Proxy/revocable)
-    #[wasm_bindgen(static_method_of = Proxy)]
-    pub fn revocable(target: &JsValue, handler: &Object) -> Object;
-}
+        /// The `Intl.PluralRules.supportedLocalesOf()` method returns an array
+        /// containing those of the provided locales that are supported in plural
+        /// formatting without having to fall back to the runtime's default locale.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/PluralRules/supportedLocalesOf)
+        #[wasm_bindgen(static_method_of = PluralRules, js_namespace = Intl, js_name = supportedLocalesOf)]
+        pub fn supported_locales_of(locales: &Array, options: &Object) -> Array;
+    }
 
-// RangeError
-#[wasm_bindgen]
-extern "C" {
-    /// The `RangeError` object indicates an error when a value is not in the set
-    /// or range of allowed values.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RangeError)
-    #[wasm_bindgen(extends = Error, extends = Object, typescript_type = "RangeError")]
-    #[derive(Clone, Debug, PartialEq, Eq)]
-    pub type RangeError;
+    impl Default for PluralRules {
+        fn default() -> Self {
+            Self::new(
+                &JsValue::UNDEFINED.unchecked_into(),
+                &JsValue::UNDEFINED.unchecked_into(),
+            )
+        }
+    }
 
-    /// The `RangeError` object indicates an error when a value is not in the set
-    /// or range of allowed values.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RangeError)
-    #[wasm_bindgen(constructor)]
-    pub fn new(message: &str) -> RangeError;
-}
+    /// The plural category returned by [`PluralRules::select_typed`],
+    /// parsed from the raw string [`PluralRules::select`] gives back.
+    /// Unrecognized strings (future CLDR categories, or an engine bug)
+    /// fall back to [`Other`](Self::Other) rather than erroring, since
+    /// `"other"` is required to exist for every locale.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    pub enum PluralCategory {
+        /// CLDR `zero` category.
+        Zero,
+        /// CLDR `one` category (not necessarily the English singular).
+        One,
+        /// CLDR `two` category.
+        Two,
+        /// CLDR `few` category.
+        Few,
+        /// CLDR `many` category.
+        Many,
+        /// CLDR `other` category, also used as the fallback for any
+        /// string not recognized above.
+        Other,
+    }
+
+    impl PluralCategory {
+        fn from_str(s: &str) -> Self {
+            match s {
+                "zero" => PluralCategory::Zero,
+                "one" => PluralCategory::One,
+                "two" => PluralCategory::Two,
+                "few" => PluralCategory::Few,
+                "many" => PluralCategory::Many,
+                _ => PluralCategory::Other,
+            }
+        }
+    }
 
-// ReferenceError
-#[wasm_bindgen]
-extern "C" {
-    /// The `ReferenceError` object represents an error when a non-existent
-    /// variable is referenced.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ReferenceError)
-    #[wasm_bindgen(extends = Error, extends = Object, typescript_type = "ReferenceError")]
-    #[derive(Clone, Debug, PartialEq, Eq)]
-    pub type ReferenceError;
+    /// Typed view of the object returned by
+    /// [`PluralRules::resolved_options`], reading the commonly used fields
+    /// without a manual `Reflect::get` per field.
+    #[derive(Clone, Debug)]
+    pub struct PluralRulesResolvedOptions {
+        /// The resolved BCP 47 locale, e.g. `"en-US"`.
+        pub locale: String,
+        /// `"cardinal"` or `"ordinal"`.
+        pub ty: String,
+        /// The minimum number of fraction digits used when pluralizing.
+        pub minimum_fraction_digits: f64,
+        /// The maximum number of fraction digits used when pluralizing.
+        pub maximum_fraction_digits: f64,
+        /// The plural categories this `PluralRules`' locale distinguishes
+        /// between, e.g. `["one", "other"]` for English cardinals or
+        /// `["one", "few", "many", "other"]` for Polish.
+        pub plural_categories: Vec<String>,
+    }
 
-    /// The `ReferenceError` object represents an error when a non-existent
-    /// variable is referenced.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ReferenceError)
-    #[wasm_bindgen(constructor)]
-    pub fn new(message: &str) -> ReferenceError;
-}
+    impl PluralRules {
+        /// Like [`select`](Self::select), but parses the result into a
+        /// [`PluralCategory`] instead of leaving it as a raw [`JsString`].
+        pub fn select_typed(&self, number: f64) -> PluralCategory {
+            PluralCategory::from_str(&String::from(self.select(number)))
+        }
 
-#[allow(non_snake_case)]
-pub mod Reflect {
-    use super::*;
+        /// Returns a typed view of [`PluralRules::resolved_options`]'s most
+        /// commonly used fields.
+        pub fn resolved_options_typed(&self) -> Result<PluralRulesResolvedOptions, FieldError> {
+            let options = self.resolved_options();
+            Ok(PluralRulesResolvedOptions {
+                locale: field_string(&options, "locale")?,
+                ty: field_string(&options, "type")?,
+                minimum_fraction_digits: field_f64(&options, "minimumFractionDigits")?,
+                maximum_fraction_digits: field_f64(&options, "maximumFractionDigits")?,
+                plural_categories: field_array(&options, "pluralCategories")?,
+            })
+        }
+    }
 
-    // Reflect
+    // Intl.RelativeTimeFormat
     #[wasm_bindgen]
     extern "C" {
-        /// The static `Reflect.apply()` method calls a target function with
-        /// arguments as specified.
+        /// The `Intl.RelativeTimeFormat` object is a constructor for objects
+        /// that enable language-sensitive relative time formatting.
         ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/apply)
-        #[wasm_bindgen(js_namespace = Reflect, catch)]
-        pub fn apply(
-            target: &Function,
-            this_argument: &JsValue,
-            arguments_list: &Array,
-        ) -> Result<JsValue, JsValue>;
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/RelativeTimeFormat)
+        #[wasm_bindgen(extends = Object, js_namespace = Intl, typescript_type = "Intl.RelativeTimeFormat")]
+        #[derive(Clone, Debug)]
+        pub type RelativeTimeFormat;
 
-        /// The static `Reflect.construct()` method acts like the new operator, but
-        /// as a function.  It is equivalent to calling `new target(...args)`. It
-        /// gives also the added option to specify a different prototype.
+        /// The `Intl.RelativeTimeFormat` object is a constructor for objects
+        /// that enable language-sensitive relative time formatting.
         ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/construct)
-        #[wasm_bindgen(js_namespace = Reflect, catch)]
-        pub fn construct(target: &Function, arguments_list: &Array) -> Result<JsValue, JsValue>;
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/RelativeTimeFormat)
+        #[wasm_bindgen(constructor, js_namespace = Intl)]
+        pub fn new(locales: &Array, options: &Object) -> RelativeTimeFormat;
 
-        /// The static `Reflect.construct()` method acts like the new operator, but
-        /// as a function.  It is equivalent to calling `new target(...args)`. It
-        /// gives also the added option to specify a different prototype.
+        /// The `Intl.RelativeTimeFormat.prototype.format` method formats a `value` and `unit`
+        /// according to the locale and formatting options of this Intl.RelativeTimeFormat object.
         ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/construct)
-        #[wasm_bindgen(js_namespace = Reflect, js_name = construct, catch)]
-        pub fn construct_with_new_target(
-            target: &Function,
-            arguments_list: &Array,
-            new_target: &Function,
-        ) -> Result<JsValue, JsValue>;
+        /// Returns a `RangeError` as `Err` if `unit` is not a valid relative time unit.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/RelativeTimeFormat/format)
+        #[wasm_bindgen(method, js_class = "Intl.RelativeTimeFormat", catch)]
+        pub fn format(this: &RelativeTimeFormat, value: f64, unit: &str) -> Result<JsString, JsValue>;
 
-        /// The static `Reflect.defineProperty()` method is like
-        /// `Object.defineProperty()` but returns a `Boolean`.
+        /// The `Intl.RelativeTimeFormat.prototype.formatToParts()` method returns an array of
+        /// objects representing the relative time format in parts that can be used for custom locale-aware formatting.
         ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/defineProperty)
-        #[wasm_bindgen(js_namespace = Reflect, js_name = defineProperty, catch)]
-        pub fn define_property(
-            target: &Object,
-            property_key: &JsValue,
-            attributes: &Object,
-        ) -> Result<bool, JsValue>;
+        /// Returns a `RangeError` as `Err` if `unit` is not a valid relative time unit.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/RelativeTimeFormat/formatToParts)
+        #[wasm_bindgen(method, js_class = "Intl.RelativeTimeFormat", js_name = formatToParts, catch)]
+        pub fn format_to_parts(this: &RelativeTimeFormat, value: f64, unit: &str) -> Result<Array, JsValue>;
 
-        /// The static `Reflect.deleteProperty()` method allows to delete
-        /// properties.  It is like the `delete` operator as a function.
+        /// The `Intl.RelativeTimeFormat.prototype.resolvedOptions()` method returns a new
+        /// object with properties reflecting the locale and relative time formatting
+        /// options computed during initialization of this RelativeTimeFormat object.
         ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/deleteProperty)
-        #[wasm_bindgen(js_namespace = Reflect, js_name = deleteProperty, catch)]
-        pub fn delete_property(target: &Object, key: &JsValue) -> Result<bool, JsValue>;
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/RelativeTimeFormat/resolvedOptions)
+        #[wasm_bindgen(method, js_namespace = Intl, js_name = resolvedOptions)]
+        pub fn resolved_options(this: &RelativeTimeFormat) -> Object;
 
-        /// The static `Reflect.get()` method works like getting a property from
-        /// an object (`target[propertyKey]`) as a function.
+        /// The `Intl.RelativeTimeFormat.supportedLocalesOf()` method returns an array
+        /// containing those of the provided locales that are supported in date and time
+        /// formatting without having to fall back to the runtime's default locale.
         ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/get)
-        #[wasm_bindgen(js_namespace = Reflect, catch)]
-        pub fn get(target: &JsValue, key: &JsValue) -> Result<JsValue, JsValue>;
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RelativeTimeFormat/supportedLocalesOf)
+        #[wasm_bindgen(static_method_of = RelativeTimeFormat, js_namespace = Intl, js_name = supportedLocalesOf)]
+        pub fn supported_locales_of(locales: &Array, options: &Object) -> Array;
+    }
 
-        /// The same as [`get`](fn.get.html)
-        /// except the key is an `f64`, which is slightly faster.
-        #[wasm_bindgen(js_namespace = Reflect, js_name = "get", catch)]
-        pub fn get_f64(target: &JsValue, key: f64) -> Result<JsValue, JsValue>;
+    // Intl.RelativeTimeFormatOptions
+    #[wasm_bindgen]
+    extern "C" {
+        /// The options dictionary accepted by
+        /// [`RelativeTimeFormat::new_with_options`].
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/RelativeTimeFormat/RelativeTimeFormat)
+        #[wasm_bindgen(extends = Object, typescript_type = "Intl.RelativeTimeFormatOptions")]
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub type RelativeTimeFormatOptions;
+    }
 
-        /// The same as [`get`](fn.get.html)
-        /// except the key is a `u32`, which is slightly faster.
-        #[wasm_bindgen(js_namespace = Reflect, js_name = "get", catch)]
-        pub fn get_u32(target: &JsValue, key: u32) -> Result<JsValue, JsValue>;
+    impl RelativeTimeFormatOptions {
+        /// Creates a new, empty `Intl.RelativeTimeFormat` options dictionary.
+        pub fn new() -> Self {
+            Object::new().unchecked_into()
+        }
 
-        /// The static `Reflect.getOwnPropertyDescriptor()` method is similar to
-        /// `Object.getOwnPropertyDescriptor()`. It returns a property descriptor
-        /// of the given property if it exists on the object, `undefined` otherwise.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/getOwnPropertyDescriptor)
-        #[wasm_bindgen(js_namespace = Reflect, js_name = getOwnPropertyDescriptor, catch)]
-        pub fn get_own_property_descriptor(
-            target: &Object,
-            property_key: &JsValue,
-        ) -> Result<JsValue, JsValue>;
+        /// Sets the `numeric` option, `"always"` or `"auto"`.
+        pub fn numeric(&mut self, value: &str) -> &mut Self {
+            let r = Reflect::set(
+                self.as_ref(),
+                &JsValue::from_str("numeric"),
+                &JsValue::from_str(value),
+            );
+            debug_assert!(r.is_ok(), "setting numeric property should never fail");
+            self
+        }
 
-        /// The static `Reflect.getPrototypeOf()` method is almost the same
-        /// method as `Object.getPrototypeOf()`. It returns the prototype
-        /// (i.e. the value of the internal `[[Prototype]]` property) of
-        /// the specified object.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/getPrototypeOf)
-        #[wasm_bindgen(js_namespace = Reflect, js_name = getPrototypeOf, catch)]
-        pub fn get_prototype_of(target: &JsValue) -> Result<Object, JsValue>;
+        /// Sets the `style` option, one of `"long"`, `"short"`, or `"narrow"`.
+        pub fn style(&mut self, value: &str) -> &mut Self {
+            let r = Reflect::set(
+                self.as_ref(),
+                &JsValue::from_str("style"),
+                &JsValue::from_str(value),
+            );
+            debug_assert!(r.is_ok(), "setting style property should never fail");
+            self
+        }
+    }
 
-        /// The static `Reflect.has()` method works like the in operator as a
-        /// function.
+    impl Default for RelativeTimeFormatOptions {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    impl RelativeTimeFormat {
+        /// An `Intl.RelativeTimeFormat` constructed with a typed
+        /// [`RelativeTimeFormatOptions`] dictionary instead of a bare
+        /// `Object`.
+        pub fn new_with_options(
+            locales: &Array,
+            options: &RelativeTimeFormatOptions,
+        ) -> RelativeTimeFormat {
+            Self::new(locales, options.as_ref())
+        }
+
+        /// Formats `delta_ms`, a duration in milliseconds relative to now,
+        /// by picking the largest unit (seconds through years) for which
+        /// the magnitude is at least `1`, then delegating to
+        /// [`RelativeTimeFormat::format`].
+        pub fn format_millis_delta(&self, delta_ms: f64) -> Result<JsString, JsValue> {
+            const MS_PER_SECOND: f64 = 1000.0;
+            const UNITS: [(&str, f64); 6] = [
+                ("years", MS_PER_SECOND * 60.0 * 60.0 * 24.0 * 365.0),
+                ("months", MS_PER_SECOND * 60.0 * 60.0 * 24.0 * 30.0),
+                ("days", MS_PER_SECOND * 60.0 * 60.0 * 24.0),
+                ("hours", MS_PER_SECOND * 60.0 * 60.0),
+                ("minutes", MS_PER_SECOND * 60.0),
+                ("seconds", MS_PER_SECOND),
+            ];
+            let &(unit, unit_ms) = UNITS
+                .iter()
+                .find(|&&(_, unit_ms)| delta_ms.abs() >= unit_ms)
+                .unwrap_or(&UNITS[UNITS.len() - 1]);
+            self.format(delta_ms / unit_ms, unit)
+        }
+    }
+
+    impl Default for RelativeTimeFormat {
+        fn default() -> Self {
+            Self::new(
+                &JsValue::UNDEFINED.unchecked_into(),
+                &JsValue::UNDEFINED.unchecked_into(),
+            )
+        }
+    }
+
+    // Intl.DisplayNames
+    #[wasm_bindgen]
+    extern "C" {
+        /// The `Intl.DisplayNames` object enables the consistent translation
+        /// of language, region, script, currency, calendar, or date-time
+        /// field display names.
         ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/has)
-        #[wasm_bindgen(js_namespace = Reflect, catch)]
-        pub fn has(target: &JsValue, property_key: &JsValue) -> Result<bool, JsValue>;
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DisplayNames)
+        #[wasm_bindgen(extends = Object, js_namespace = Intl, typescript_type = "Intl.DisplayNames")]
+        #[derive(Clone, Debug)]
+        pub type DisplayNames;
 
-        /// The static `Reflect.isExtensible()` method determines if an object is
-        /// extensible (whether it can have new properties added to it). It is
-        /// similar to `Object.isExtensible()`, but with some differences.
+        /// The `Intl.DisplayNames` constructor. `options` must at least set
+        /// the `type` field (`"language"`, `"region"`, `"script"`,
+        /// `"currency"`, `"calendar"`, or `"dateTimeField"`); the
+        /// constructor throws a `TypeError` if it's missing.
         ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/isExtensible)
-        #[wasm_bindgen(js_namespace = Reflect, js_name = isExtensible, catch)]
-        pub fn is_extensible(target: &Object) -> Result<bool, JsValue>;
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DisplayNames/DisplayNames)
+        #[wasm_bindgen(catch, constructor, js_namespace = Intl)]
+        pub fn new(locales: &Array, options: &Object) -> Result<DisplayNames, JsValue>;
 
-        /// The static `Reflect.ownKeys()` method returns an array of the
-        /// target object's own property keys.
+        /// The `Intl.DisplayNames.prototype.of()` method receives a code
+        /// and returns a string based on the locale and options provided
+        /// when instantiating this `Intl.DisplayNames` object.
         ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/ownKeys)
-        #[wasm_bindgen(js_namespace = Reflect, js_name = ownKeys, catch)]
-        pub fn own_keys(target: &JsValue) -> Result<Array, JsValue>;
+        /// Returns a `RangeError` as `Err` if `code` isn't well-formed for
+        /// this `DisplayNames`' `type`. Returns `Ok(None)` (JS `undefined`)
+        /// if `code` is well-formed but unrecognized and `fallback` was set
+        /// to `"none"`.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DisplayNames/of)
+        #[wasm_bindgen(method, js_class = "Intl.DisplayNames", catch)]
+        pub fn of(this: &DisplayNames, code: &str) -> Result<Option<JsString>, JsValue>;
+
+        /// The `Intl.DisplayNames.prototype.resolvedOptions()` method
+        /// returns a new object with properties reflecting the locale and
+        /// display name formatting options computed during initialization
+        /// of this `DisplayNames` object.
+        ///
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DisplayNames/resolvedOptions)
+        #[wasm_bindgen(method, js_namespace = Intl, js_name = resolvedOptions)]
+        pub fn resolved_options(this: &DisplayNames) -> Object;
 
-        /// The static `Reflect.preventExtensions()` method prevents new
-        /// properties from ever being added to an object (i.e. prevents
-        /// future extensions to the object). It is similar to
-        /// `Object.preventExtensions()`, but with some differences.
+        /// The `Intl.DisplayNames.supportedLocalesOf()` method returns an
+        /// array containing those of the provided locales that are
+        /// supported in display names formatting without having to fall
+        /// back to the runtime's default locale.
         ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/preventExtensions)
-        #[wasm_bindgen(js_namespace = Reflect, js_name = preventExtensions, catch)]
-        pub fn prevent_extensions(target: &Object) -> Result<bool, JsValue>;
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DisplayNames/supportedLocalesOf)
+        #[wasm_bindgen(static_method_of = DisplayNames, js_namespace = Intl, js_name = supportedLocalesOf)]
+        pub fn supported_locales_of(locales: &Array, options: &Object) -> Array;
+    }
 
-        /// The static `Reflect.set()` method works like setting a
-        /// property on an object.
+    // Intl.DisplayNamesOptions
+    #[wasm_bindgen]
+    extern "C" {
+        /// The options dictionary accepted by
+        /// [`DisplayNames::new_with_options`].
         ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/set)
-        #[wasm_bindgen(js_namespace = Reflect, catch)]
-        pub fn set(
-            target: &JsValue,
-            property_key: &JsValue,
-            value: &JsValue,
-        ) -> Result<bool, JsValue>;
+        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DisplayNames/DisplayNames)
+        #[wasm_bindgen(extends = Object, typescript_type = "Intl.DisplayNamesOptions")]
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub type DisplayNamesOptions;
+    }
 
-        /// The same as [`set`](fn.set.html)
-        /// except the key is an `f64`, which is slightly faster.
-        #[wasm_bindgen(js_namespace = Reflect, js_name = "set", catch)]
-        pub fn set_f64(
-            target: &JsValue,
-            property_key: f64,
-            value: &JsValue,
-        ) -> Result<bool, JsValue>;
+    impl DisplayNamesOptions {
+        /// Creates a new `Intl.DisplayNames` options dictionary with the
+        /// required `type` field set to one of `"language"`, `"region"`,
+        /// `"script"`, `"currency"`, `"calendar"`, or `"dateTimeField"`.
+        pub fn new(ty: &str) -> Self {
+            let mut options: Self = Object::new().unchecked_into();
+            options.type_(ty);
+            options
+        }
 
-        /// The same as [`set`](fn.set.html)
-        /// except the key is a `u32`, which is slightly faster.
-        #[wasm_bindgen(js_namespace = Reflect, js_name = "set", catch)]
-        pub fn set_u32(
-            target: &JsValue,
-            property_key: u32,
-            value: &JsValue,
-        ) -> Result<bool, JsValue>;
+        /// Sets the `type` option.
+        pub fn type_(&mut self, value: &str) -> &mut Self {
+            let r = Reflect::set(
+                self.as_ref(),
+                &JsValue::from_str("type"),
+                &JsValue::from_str(value),
+            );
+            debug_assert!(r.is_ok(), "setting type property should never fail");
+            self
+        }
 
-        /// The static `Reflect.set()` method works like setting a
-        /// property on an object.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/set)
-        #[wasm_bindgen(js_namespace = Reflect, js_name = set, catch)]
-        pub fn set_with_receiver(
-            target: &JsValue,
-            property_key: &JsValue,
-            value: &JsValue,
-            receiver: &JsValue,
-        ) -> Result<bool, JsValue>;
+        /// Sets the `style` option, one of `"narrow"`, `"short"`, or `"long"`.
+        pub fn style(&mut self, value: &str) -> &mut Self {
+            let r = Reflect::set(
+                self.as_ref(),
+                &JsValue::from_str("style"),
+                &JsValue::from_str(value),
+            );
+            debug_assert!(r.is_ok(), "setting style property should never fail");
+            self
+        }
 
-        /// The static `Reflect.setPrototypeOf()` method is the same
-        /// method as `Object.setPrototypeOf()`. It sets the prototype
-        /// (i.e., the internal `[[Prototype]]` property) of a specified
-        /// object to another object or to null.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/setPrototypeOf)
-        #[wasm_bindgen(js_namespace = Reflect, js_name = setPrototypeOf, catch)]
-        pub fn set_prototype_of(target: &Object, prototype: &JsValue) -> Result<bool, JsValue>;
-    }
-}
+        /// Sets the `fallback` option, `"code"` or `"none"`.
+        pub fn fallback(&mut self, value: &str) -> &mut Self {
+            let r = Reflect::set(
+                self.as_ref(),
+                &JsValue::from_str("fallback"),
+                &JsValue::from_str(value),
+            );
+            debug_assert!(r.is_ok(), "setting fallback property should never fail");
+            self
+        }
 
-// RegExp
-#[wasm_bindgen]
-extern "C" {
-    #[wasm_bindgen(extends = Object, typescript_type = "RegExp")]
-    #[derive(Clone, Debug, PartialEq, Eq)]
-    pub type RegExp;
+        /// Sets the `languageDisplay` option, `"dialect"` or `"standard"`
+        /// (only used when `type` is `"language"`).
+        pub fn language_display(&mut self, value: &str) -> &mut Self {
+            let r = Reflect::set(
+                self.as_ref(),
+                &JsValue::from_str("languageDisplay"),
+                &JsValue::from_str(value),
+            );
+            debug_assert!(
+                r.is_ok(),
+                "setting languageDisplay property should never fail"
+            );
+            self
+        }
+    }
 
-    /// The `exec()` method executes a search for a match in a specified
-    /// string. Returns a result array, or null.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/exec)
-    #[wasm_bindgen(method)]
-    pub fn exec(this: &RegExp, text: &str) -> Option<Array>;
+    impl DisplayNames {
+        /// An `Intl.DisplayNames` constructed with a typed
+        /// [`DisplayNamesOptions`] dictionary instead of a bare `Object`.
+        pub fn new_with_options(
+            locales: &Array,
+            options: &DisplayNamesOptions,
+        ) -> Result<DisplayNames, JsValue> {
+            Self::new(locales, options.as_ref())
+        }
+    }
+}
 
-    /// The flags property returns a string consisting of the flags of
-    /// the current regular expression object.
+// Promise
+#[wasm_bindgen]
+extern "C" {
+    /// The `Promise` object represents the eventual completion (or failure) of
+    /// an asynchronous operation, and its resulting value.
     ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/flags)
-    #[wasm_bindgen(method, getter)]
-    pub fn flags(this: &RegExp) -> JsString;
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise)
+    #[must_use]
+    #[wasm_bindgen(extends = Object, typescript_type = "Promise<any>")]
+    #[derive(Clone, Debug)]
+    pub type Promise;
 
-    /// The global property indicates whether or not the "g" flag is
-    /// used with the regular expression. global is a read-only
-    /// property of an individual regular expression instance.
+    /// Creates a new `Promise` with the provided executor `cb`
     ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/global)
-    #[wasm_bindgen(method, getter)]
-    pub fn global(this: &RegExp) -> bool;
-
-    /// The ignoreCase property indicates whether or not the "i" flag
-    /// is used with the regular expression. ignoreCase is a read-only
-    /// property of an individual regular expression instance.
+    /// The `cb` is a function that is passed with the arguments `resolve` and
+    /// `reject`. The `cb` function is executed immediately by the `Promise`
+    /// implementation, passing `resolve` and `reject` functions (the executor
+    /// is called before the `Promise` constructor even returns the created
+    /// object). The `resolve` and `reject` functions, when called, resolve or
+    /// reject the promise, respectively. The executor normally initiates
+    /// some asynchronous work, and then, once that completes, either calls
+    /// the `resolve` function to resolve the promise or else rejects it if an
+    /// error occurred.
     ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/ignoreCase)
-    #[wasm_bindgen(method, getter, js_name = ignoreCase)]
-    pub fn ignore_case(this: &RegExp) -> bool;
-
-    /// The non-standard input property is a static property of
-    /// regular expressions that contains the string against which a
-    /// regular expression is matched. RegExp.$_ is an alias for this
-    /// property.
+    /// If an error is thrown in the executor function, the promise is rejected.
+    /// The return value of the executor is ignored.
     ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/input)
-    #[wasm_bindgen(static_method_of = RegExp, getter)]
-    pub fn input() -> JsString;
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise)
+    #[wasm_bindgen(constructor)]
+    pub fn new(cb: &mut dyn FnMut(Function, Function)) -> Promise;
 
-    /// The lastIndex is a read/write integer property of regular expression
-    /// instances that specifies the index at which to start the next match.
+    /// The `Promise.all(iterable)` method returns a single `Promise` that
+    /// resolves when all of the promises in the iterable argument have resolved
+    /// or when the iterable argument contains no promises. It rejects with the
+    /// reason of the first promise that rejects.
     ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/lastIndex)
-    #[wasm_bindgen(structural, getter = lastIndex, method)]
-    pub fn last_index(this: &RegExp) -> u32;
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/all)
+    #[wasm_bindgen(static_method_of = Promise)]
+    pub fn all(obj: &JsValue) -> Promise;
 
-    /// The lastIndex is a read/write integer property of regular expression
-    /// instances that specifies the index at which to start the next match.
+    /// The `Promise.allSettled(iterable)` method returns a single `Promise` that
+    /// resolves when all of the promises in the iterable argument have either
+    /// fulfilled or rejected or when the iterable argument contains no promises.
     ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/lastIndex)
-    #[wasm_bindgen(structural, setter = lastIndex, method)]
-    pub fn set_last_index(this: &RegExp, index: u32);
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/allSettled)
+    #[wasm_bindgen(static_method_of = Promise, js_name = allSettled)]
+    pub fn all_settled(obj: &JsValue) -> Promise;
 
-    /// The non-standard lastMatch property is a static and read-only
-    /// property of regular expressions that contains the last matched
-    /// characters. `RegExp.$&` is an alias for this property.
+    /// The `Promise.any(iterable)` method returns a single `Promise` that
+    /// resolves when any of the promises in the iterable argument have resolved
+    /// or when the iterable argument contains no promises. It rejects with an
+    /// `AggregateError` if all promises in the iterable rejected.
     ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/lastMatch)
-    #[wasm_bindgen(static_method_of = RegExp, getter, js_name = lastMatch)]
-    pub fn last_match() -> JsString;
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/any)
+    #[wasm_bindgen(static_method_of = Promise)]
+    pub fn any(obj: &JsValue) -> Promise;
 
-    /// The non-standard lastParen property is a static and read-only
-    /// property of regular expressions that contains the last
-    /// parenthesized substring match, if any. `RegExp.$+` is an alias
-    /// for this property.
+    /// The `Promise.race(iterable)` method returns a promise that resolves or
+    /// rejects as soon as one of the promises in the iterable resolves or
+    /// rejects, with the value or reason from that promise.
     ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/lastParen)
-    #[wasm_bindgen(static_method_of = RegExp, getter, js_name = lastParen)]
-    pub fn last_paren() -> JsString;
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/race)
+    #[wasm_bindgen(static_method_of = Promise)]
+    pub fn race(obj: &JsValue) -> Promise;
 
-    /// The non-standard leftContext property is a static and
-    /// read-only property of regular expressions that contains the
-    /// substring preceding the most recent match. `RegExp.$`` is an
-    /// alias for this property.
+    /// The `Promise.reject(reason)` method returns a `Promise` object that is
+    /// rejected with the given reason.
     ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/leftContext)
-    #[wasm_bindgen(static_method_of = RegExp, getter, js_name = leftContext)]
-    pub fn left_context() -> JsString;
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/reject)
+    #[wasm_bindgen(static_method_of = Promise)]
+    pub fn reject(obj: &JsValue) -> Promise;
 
-    /// The multiline property indicates whether or not the "m" flag
-    /// is used with the regular expression. multiline is a read-only
-    /// property of an individual regular expression instance.
+    /// The `Promise.resolve(value)` method returns a `Promise` object that is
+    /// resolved with the given value. If the value is a promise, that promise
+    /// is returned; if the value is a thenable (i.e. has a "then" method), the
+    /// returned promise will "follow" that thenable, adopting its eventual
+    /// state; otherwise the returned promise will be fulfilled with the value.
     ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/multiline)
-    #[wasm_bindgen(method, getter)]
-    pub fn multiline(this: &RegExp) -> bool;
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/resolve)
+    #[wasm_bindgen(static_method_of = Promise)]
+    pub fn resolve(obj: &JsValue) -> Promise;
 
-    /// The non-standard $1, $2, $3, $4, $5, $6, $7, $8, $9 properties
-    /// are static and read-only properties of regular expressions
-    /// that contain parenthesized substring matches.
+    /// The `catch()` method returns a `Promise` and deals with rejected cases
+    /// only.  It behaves the same as calling `Promise.prototype.then(undefined,
+    /// onRejected)` (in fact, calling `obj.catch(onRejected)` internally calls
+    /// `obj.then(undefined, onRejected)`).
     ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/n)
-    #[wasm_bindgen(static_method_of = RegExp, getter, js_name = "$1")]
-    pub fn n1() -> JsString;
-    #[wasm_bindgen(static_method_of = RegExp, getter, js_name = "$2")]
-    pub fn n2() -> JsString;
-    #[wasm_bindgen(static_method_of = RegExp, getter, js_name = "$3")]
-    pub fn n3() -> JsString;
-    #[wasm_bindgen(static_method_of = RegExp, getter, js_name = "$4")]
-    pub fn n4() -> JsString;
-    #[wasm_bindgen(static_method_of = RegExp, getter, js_name = "$5")]
-    pub fn n5() -> JsString;
-    #[wasm_bindgen(static_method_of = RegExp, getter, js_name = "$6")]
-    pub fn n6() -> JsString;
-    #[wasm_bindgen(static_method_of = RegExp, getter, js_name = "$7")]
-    pub fn n7() -> JsString;
-    #[wasm_bindgen(static_method_of = RegExp, getter, js_name = "$8")]
-    pub fn n8() -> JsString;
-    #[wasm_bindgen(static_method_of = RegExp, getter, js_name = "$9")]
-    pub fn n9() -> JsString;
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/catch)
+    #[wasm_bindgen(method)]
+    pub fn catch(this: &Promise, cb: &Closure<dyn FnMut(JsValue)>) -> Promise;
 
-    /// The `RegExp` constructor creates a regular expression object for matching text with a pattern.
+    /// The `then()` method returns a `Promise`. It takes up to two arguments:
+    /// callback functions for the success and failure cases of the `Promise`.
     ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp)
-    #[wasm_bindgen(constructor)]
-    pub fn new(pattern: &str, flags: &str) -> RegExp;
-    #[wasm_bindgen(constructor)]
-    pub fn new_regexp(pattern: &RegExp, flags: &str) -> RegExp;
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/then)
+    #[wasm_bindgen(method)]
+    pub fn then(this: &Promise, cb: &Closure<dyn FnMut(JsValue)>) -> Promise;
 
-    /// The non-standard rightContext property is a static and
-    /// read-only property of regular expressions that contains the
-    /// substring following the most recent match. `RegExp.$'` is an
-    /// alias for this property.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/rightContext)
-    #[wasm_bindgen(static_method_of = RegExp, getter, js_name = rightContext)]
-    pub fn right_context() -> JsString;
+    /// Same as `then`, only with both arguments provided.
+    #[wasm_bindgen(method, js_name = then)]
+    pub fn then2(
+        this: &Promise,
+        resolve: &Closure<dyn FnMut(JsValue)>,
+        reject: &Closure<dyn FnMut(JsValue)>,
+    ) -> Promise;
 
-    /// The source property returns a String containing the source
-    /// text of the regexp object, and it doesn't contain the two
-    /// forward slashes on both sides and any flags.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/source)
-    #[wasm_bindgen(method, getter)]
-    pub fn source(this: &RegExp) -> JsString;
+    /// Same as `then`, but for a callback that produces a value (or a
+    /// thenable for `then`'s usual chaining behavior) rather than just
+    /// running a side effect. See [`Promise::map_js`] and
+    /// [`Promise::and_then_js`].
+    #[wasm_bindgen(method, js_name = then)]
+    pub fn then_map(this: &Promise, cb: &Closure<dyn FnMut(JsValue) -> JsValue>) -> Promise;
 
-    /// The sticky property reflects whether or not the search is
-    /// sticky (searches in strings only from the index indicated by
-    /// the lastIndex property of this regular expression). sticky is
-    /// a read-only property of an individual regular expression
-    /// object.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/sticky)
-    #[wasm_bindgen(method, getter)]
-    pub fn sticky(this: &RegExp) -> bool;
+    /// Same as `then_map`, but with both the fulfillment and rejection
+    /// handlers provided, each producing a value (or thenable) rather
+    /// than running a side effect. See [`Promise::map_both_js`].
+    #[wasm_bindgen(method, js_name = then)]
+    pub fn then2_map(
+        this: &Promise,
+        resolve: &Closure<dyn FnMut(JsValue) -> JsValue>,
+        reject: &Closure<dyn FnMut(JsValue) -> JsValue>,
+    ) -> Promise;
 
-    /// The `test()` method executes a search for a match between a
-    /// regular expression and a specified string. Returns true or
-    /// false.
+    /// The `finally()` method returns a `Promise`. When the promise is settled,
+    /// whether fulfilled or rejected, the specified callback function is
+    /// executed. This provides a way for code that must be executed once the
+    /// `Promise` has been dealt with to be run whether the promise was
+    /// fulfilled successfully or rejected.
     ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/test)
+    /// This lets you avoid duplicating code in both the promise's `then()` and
+    /// `catch()` handlers.
+    ///
+    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/finally)
     #[wasm_bindgen(method)]
-    pub fn test(this: &RegExp, text: &str) -> bool;
+    pub fn finally(this: &Promise, cb: &Closure<dyn FnMut()>) -> Promise;
+}
 
-    /// The `toString()` method returns a string representing the
-    /// regular expression.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/toString)
-    #[wasm_bindgen(method, js_name = toString)]
-    pub fn to_string(this: &RegExp) -> JsString;
+impl Promise {
+    /// Attaches a no-op rejection handler so this promise's eventual
+    /// rejection (if any) is marked handled, silencing "unhandled
+    /// rejection" warnings for fire-and-forget promises nobody awaits.
+    ///
+    /// The attached closure is deliberately leaked (via
+    /// [`Closure::forget`]) since there's nothing left to drop it once this
+    /// function returns; that's fine here since it's a tiny no-op.
+    pub fn detach(&self) {
+        let closure = Closure::wrap(Box::new(|_reason: JsValue| {}) as Box<dyn FnMut(JsValue)>);
+        let _ = self.catch(&closure);
+        closure.forget();
+    }
+
+    /// Attaches a rejection handler that stringifies the reason with
+    /// [`coerce::to_js_string`] (falling back to `Debug` if that itself
+    /// throws), prefixes it with `prefix`, and records it in a per-thread
+    /// log drained by [`promise::take_logged_rejections`]. The log is a
+    /// plain `Vec` with no capacity limit: nothing is dropped from it until
+    /// it is drained. Kept environment-agnostic on purpose: it doesn't
+    /// assume a `console`.
+    pub fn catch_log(&self, prefix: &str) {
+        let prefix = String::from(prefix);
+        let closure = Closure::wrap(Box::new(move |reason: JsValue| {
+            let text = coerce::to_js_string(&reason)
+                .ok()
+                .and_then(|s| s.as_string())
+                .unwrap_or_else(|| alloc::format!("{:?}", reason));
+            promise::record_rejection(alloc::format!("{}{}", prefix, text));
+        }) as Box<dyn FnMut(JsValue)>);
+        let _ = self.catch(&closure);
+        closure.forget();
+    }
+
+    /// Attaches `on_resolve`/`on_reject` as observers of this promise's
+    /// settlement and returns a clone of `self`, not the derived promise
+    /// `then` creates (which is discarded), so calls can be chained.
+    pub fn tap(
+        &self,
+        on_resolve: &Closure<dyn FnMut(JsValue)>,
+        on_reject: &Closure<dyn FnMut(JsValue)>,
+    ) -> Promise {
+        let _ = self.then2(on_resolve, on_reject);
+        self.clone()
+    }
+
+    /// Maps this promise's resolution value through `f`, like
+    /// `.then(value => f(value))`, without hand-building a [`Closure`] at
+    /// the call site. `f` runs at most once; if this promise rejects
+    /// instead of resolving, `f` never runs and, held by the forgotten
+    /// closure, is never dropped.
+    pub fn map_js(&self, f: impl FnOnce(JsValue) -> JsValue + 'static) -> Promise {
+        let mut f = Some(f);
+        let closure = Closure::once(Box::new(move |value: JsValue| {
+            f.take().expect("Promise::map_js closure invoked twice")(value)
+        }) as Box<dyn FnOnce(JsValue) -> JsValue>);
+        let result = self.then_map(&closure);
+        closure.forget();
+        result
+    }
+
+    /// Chains this promise's resolution value into `f`, which itself
+    /// returns a `Promise`, like `.then(value => f(value))` where `f`'s
+    /// result is a thenable -- the returned promise follows `f`'s promise
+    /// instead of resolving to it directly.
+    pub fn and_then_js(&self, f: impl FnOnce(JsValue) -> Promise + 'static) -> Promise {
+        let mut f = Some(f);
+        let closure = Closure::once(Box::new(move |value: JsValue| -> JsValue {
+            f.take().expect("Promise::and_then_js closure invoked twice")(value).into()
+        }) as Box<dyn FnOnce(JsValue) -> JsValue>);
+        let result = self.then_map(&closure);
+        closure.forget();
+        result
+    }
+
+    /// Like [`Promise::map_js`], but also maps a rejection through
+    /// `on_rejected`, converging both branches on the same kind of
+    /// result: the returned promise resolves with whichever handler ran,
+    /// and only rejects if that handler throws. If this promise never
+    /// settles, neither does the returned one.
+    ///
+    /// This differs from `.then(on_fulfilled).catch(on_rejected)`:
+    /// here, exactly like passing both handlers to a single JS `then()`
+    /// call, `on_rejected` only runs if *this* promise rejects -- not if
+    /// `on_fulfilled` itself throws while handling a fulfillment.
+    pub fn map_both_js(
+        &self,
+        on_fulfilled: impl FnOnce(JsValue) -> JsValue + 'static,
+        on_rejected: impl FnOnce(JsValue) -> JsValue + 'static,
+    ) -> Promise {
+        let mut on_fulfilled = Some(on_fulfilled);
+        let resolve_closure = Closure::once(Box::new(move |value: JsValue| {
+            on_fulfilled
+                .take()
+                .expect("Promise::map_both_js fulfilled closure invoked twice")(value)
+        }) as Box<dyn FnOnce(JsValue) -> JsValue>);
+
+        let mut on_rejected = Some(on_rejected);
+        let reject_closure = Closure::once(Box::new(move |reason: JsValue| {
+            on_rejected
+                .take()
+                .expect("Promise::map_both_js rejected closure invoked twice")(reason)
+        }) as Box<dyn FnOnce(JsValue) -> JsValue>);
+
+        let result = self.then2_map(&resolve_closure, &reject_closure);
+        resolve_closure.forget();
+        reject_closure.forget();
+        result
+    }
+
+    /// Maps this promise's rejection reason through `f`, leaving a
+    /// successful resolution untouched. Unlike a `.then(x => x, f)`-style
+    /// mapping (which would turn a handled rejection into a *resolved*
+    /// promise), the promise returned here stays rejected, now with `f`'s
+    /// mapped reason, so a later `.catch()` still runs.
+    pub fn map_err_js(&self, f: impl FnOnce(JsValue) -> JsValue + 'static) -> Promise {
+        let this = self.clone();
+        let mut f = Some(f);
+        let mut executor = move |resolve: Function, reject: Function| {
+            let resolve_closure = Closure::once(Box::new(move |value: JsValue| {
+                let _ = resolve.call1(&JsValue::UNDEFINED, &value);
+            }) as Box<dyn FnOnce(JsValue)>);
+
+            let f = f.take();
+            let reject = reject.clone();
+            let reject_closure = Closure::once(Box::new(move |reason: JsValue| {
+                let mapped = f.expect("Promise::map_err_js closure invoked twice")(reason);
+                let _ = reject.call1(&JsValue::UNDEFINED, &mapped);
+            }) as Box<dyn FnOnce(JsValue)>);
+
+            let _ = this.then2(&resolve_closure, &reject_closure);
+            resolve_closure.forget();
+            reject_closure.forget();
+        };
+        Promise::new(&mut executor)
+    }
+
+    /// Converts this `Promise` into a Rust [`core::future::Future`] that
+    /// resolves to `Ok(value)` when the promise fulfills, or `Err(reason)`
+    /// when it rejects.
+    ///
+    /// This is a minimal, `js-sys`-only bridge -- the same role
+    /// `wasm-bindgen-futures`' `JsFuture` plays -- for crates that want to
+    /// `.await` a single `Promise` without taking on that crate's
+    /// dependency. It doesn't provide an executor; something still has to
+    /// drive the returned future to completion.
+    pub fn into_future(self) -> JsFuture {
+        JsFuture::from(self)
+    }
+}
+
+struct JsFutureState {
+    result: Option<Result<JsValue, JsValue>>,
+    waker: Option<core::task::Waker>,
+}
+
+/// A Rust [`core::future::Future`] that resolves when the wrapped
+/// [`Promise`] settles. See [`Promise::into_future`].
+#[must_use = "futures do nothing unless awaited or polled"]
+pub struct JsFuture {
+    state: Rc<RefCell<JsFutureState>>,
+    // Kept alive for as long as the future is, since the promise holds
+    // only a reference to them; dropping the future detaches these.
+    _closures: (Closure<dyn FnMut(JsValue)>, Closure<dyn FnMut(JsValue)>),
+}
+
+impl From<Promise> for JsFuture {
+    fn from(promise: Promise) -> JsFuture {
+        let state = Rc::new(RefCell::new(JsFutureState {
+            result: None,
+            waker: None,
+        }));
+
+        let resolve_state = state.clone();
+        let resolve = Closure::wrap(Box::new(move |value: JsValue| {
+            let mut state = resolve_state.borrow_mut();
+            state.result = Some(Ok(value));
+            if let Some(waker) = state.waker.take() {
+                waker.wake();
+            }
+        }) as Box<dyn FnMut(JsValue)>);
+
+        let reject_state = state.clone();
+        let reject = Closure::wrap(Box::new(move |reason: JsValue| {
+            let mut state = reject_state.borrow_mut();
+            state.result = Some(Err(reason));
+            if let Some(waker) = state.waker.take() {
+                waker.wake();
+            }
+        }) as Box<dyn FnMut(JsValue)>);
+
+        let _ = promise.then2(&resolve, &reject);
+
+        JsFuture {
+            state,
+            _closures: (resolve, reject),
+        }
+    }
+}
 
-    /// The unicode property indicates whether or not the "u" flag is
-    /// used with a regular expression. unicode is a read-only
-    /// property of an individual regular expression instance.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/unicode)
-    #[wasm_bindgen(method, getter)]
-    pub fn unicode(this: &RegExp) -> bool;
+impl core::future::Future for JsFuture {
+    type Output = Result<JsValue, JsValue>;
+
+    fn poll(
+        self: core::pin::Pin<&mut Self>,
+        cx: &mut core::task::Context<'_>,
+    ) -> core::task::Poll<Self::Output> {
+        let mut state = self.state.borrow_mut();
+        match state.result.take() {
+            Some(result) => core::task::Poll::Ready(result),
+            None => {
+                state.waker = Some(cx.waker().clone());
+                core::task::Poll::Pending
+            }
+        }
+    }
 }
 
-// Set
-#[wasm_bindgen]
-extern "C" {
-    #[wasm_bindgen(extends = Object, typescript_type = "Set<any>")]
-    #[derive(Clone, Debug, PartialEq, Eq)]
-    pub type Set;
+/// A per-thread log of rejection reasons recorded by [`Promise::catch_log`].
+pub mod promise {
+    use super::*;
 
-    /// The `add()` method appends a new element with a specified value to the
-    /// end of a [`Set`] object.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/add)
-    #[wasm_bindgen(method)]
-    pub fn add(this: &Set, value: &JsValue) -> Set;
+    fn with_log<R>(f: impl FnOnce(&mut Vec<String>) -> R) -> R {
+        #[cfg(feature = "std")]
+        {
+            thread_local! {
+                static LOG: core::cell::RefCell<Vec<String>> = core::cell::RefCell::new(Vec::new());
+            }
+            LOG.with(|log| f(&mut log.borrow_mut()))
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            struct Wrapper(core::cell::RefCell<Vec<String>>);
 
-    /// The `clear()` method removes all elements from a [`Set`] object.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/clear)
-    #[wasm_bindgen(method)]
-    pub fn clear(this: &Set);
+            #[cfg(not(target_feature = "atomics"))]
+            unsafe impl Sync for Wrapper {}
+            #[cfg(not(target_feature = "atomics"))]
+            unsafe impl Send for Wrapper {}
 
-    /// The `delete()` method removes the specified element from a [`Set`]
-    /// object.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/delete)
-    #[wasm_bindgen(method)]
-    pub fn delete(this: &Set, value: &JsValue) -> bool;
+            #[cfg_attr(target_feature = "atomics", thread_local)]
+            static LOG: Wrapper = Wrapper(core::cell::RefCell::new(Vec::new()));
 
-    /// The `forEach()` method executes a provided function once for each value
-    /// in the Set object, in insertion order.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/forEach)
-    #[wasm_bindgen(method, js_name = forEach)]
-    pub fn for_each(this: &Set, callback: &mut dyn FnMut(JsValue, JsValue, Set));
+            f(&mut LOG.0.borrow_mut())
+        }
+    }
 
-    /// The `has()` method returns a boolean indicating whether an element with
-    /// the specified value exists in a [`Set`] object or not.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/has)
-    #[wasm_bindgen(method)]
-    pub fn has(this: &Set, value: &JsValue) -> bool;
+    pub(super) fn record_rejection(message: String) {
+        with_log(|log| log.push(message));
+    }
 
-    /// The [`Set`] object lets you store unique values of any type, whether
-    /// primitive values or object references.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set)
-    #[wasm_bindgen(constructor)]
-    pub fn new(init: &JsValue) -> Set;
+    /// Drains and returns every rejection reason recorded by
+    /// [`Promise::catch_log`] on this thread so far.
+    pub fn take_logged_rejections() -> Vec<String> {
+        with_log(core::mem::take)
+    }
 
-    /// The size accessor property returns the number of elements in a [`Set`]
-    /// object.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/de/docs/Web/JavaScript/Reference/Global_Objects/Set/size)
-    #[wasm_bindgen(method, getter, structural)]
-    pub fn size(this: &Set) -> u32;
-}
+    /// Defers `f` to run on the microtask queue, portably and without
+    /// `web_sys::queue_microtask`: schedules it as a `.then()` callback on
+    /// an already-resolved promise. The closure is kept alive (via
+    /// [`Closure::forget`]) only until it runs, at which point dropping it
+    /// is handled by `wasm-bindgen`'s callback machinery, unlike the
+    /// permanently-leaked closures elsewhere in this module.
+    ///
+    /// This is microtask timing, not macrotask (`setTimeout`) timing: `f`
+    /// runs after the current synchronous section finishes but before any
+    /// subsequent task (timer, I/O callback, etc.).
+    pub fn queue_microtask(f: impl FnOnce() + 'static) {
+        let mut f = Some(f);
+        let closure = Closure::once(Box::new(move |_: JsValue| {
+            if let Some(f) = f.take() {
+                f();
+            }
+        }) as Box<dyn FnOnce(JsValue)>);
+        let _ = Promise::resolve(&JsValue::UNDEFINED).then(&closure);
+        closure.forget();
+    }
 
-impl Default for Set {
-    fn default() -> Self {
-        Self::new(&JsValue::UNDEFINED)
+    /// Returns an already-resolved `Promise<undefined>` that callers can
+    /// `.await` to yield to the microtask queue once.
+    pub fn next_microtask() -> Promise {
+        Promise::resolve(&JsValue::UNDEFINED)
     }
-}
 
-// SetIterator
-#[wasm_bindgen]
-extern "C" {
-    /// The `entries()` method returns a new Iterator object that contains an
-    /// array of [value, value] for each element in the Set object, in insertion
-    /// order. For Set objects there is no key like in Map objects. However, to
-    /// keep the API similar to the Map object, each entry has the same value
-    /// for its key and value here, so that an array [value, value] is returned.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/entries)
-    #[wasm_bindgen(method)]
-    pub fn entries(set: &Set) -> Iterator;
+    /// Schedules every closure in `fs` via [`queue_microtask`], in order.
+    /// Since microtasks run strictly FIFO, this guarantees `fs[0]` runs
+    /// before `fs[1]`, and so on.
+    pub fn microtask_batch(fs: Vec<Box<dyn FnOnce()>>) {
+        for f in fs {
+            queue_microtask(f);
+        }
+    }
 
-    /// The `keys()` method is an alias for this method (for similarity with
-    /// Map objects); it behaves exactly the same and returns values
-    /// of Set elements.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/values)
-    #[wasm_bindgen(method)]
-    pub fn keys(set: &Set) -> Iterator;
+    /// Like `Promise.race`, but the result identifies which entry of
+    /// `promises` settled first: resolves with a two-element
+    /// `[index, value]` array if that entry resolved, or rejects with a
+    /// two-element `[index, reason]` array if it rejected.
+    ///
+    /// Unlike [`Promise::race`], the returned promise never itself rejects
+    /// with a bare reason, since the index is always attached.
+    pub fn select_all(promises: &Array) -> Promise {
+        let wrapped = Array::new();
+        for i in 0..promises.length() {
+            let promise: Promise = promises.get(i).unchecked_into();
+            wrapped.push(&tag_settlement(&promise, i));
+        }
+        Promise::race(wrapped.as_ref())
+    }
 
-    /// The `values()` method returns a new Iterator object that contains the
-    /// values for each element in the Set object in insertion order.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/values)
-    #[wasm_bindgen(method)]
-    pub fn values(set: &Set) -> Iterator;
-}
+    fn tag_settlement(promise: &Promise, index: u32) -> Promise {
+        let promise = promise.clone();
+        Promise::new(&mut move |resolve, reject| {
+            let resolve = resolve.clone();
+            let reject = reject.clone();
+            let on_resolve = Closure::wrap(Box::new(move |value: JsValue| {
+                let tagged = Array::new();
+                tagged.push(&JsValue::from_f64(index as f64));
+                tagged.push(&value);
+                let _ = resolve.call1(&JsValue::UNDEFINED, tagged.as_ref());
+            }) as Box<dyn FnMut(JsValue)>);
+            let on_reject = Closure::wrap(Box::new(move |reason: JsValue| {
+                let tagged = Array::new();
+                tagged.push(&JsValue::from_f64(index as f64));
+                tagged.push(&reason);
+                let _ = reject.call1(&JsValue::UNDEFINED, tagged.as_ref());
+            }) as Box<dyn FnMut(JsValue)>);
+            let _ = promise.then2(&on_resolve, &on_reject);
+            on_resolve.forget();
+            on_reject.forget();
+        })
+    }
 
-// SyntaxError
-#[wasm_bindgen]
-extern "C" {
-    /// A `SyntaxError` is thrown when the JavaScript engine encounters tokens or
-    /// token order that does not conform to the syntax of the language when
-    /// parsing code.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/SyntaxError)
-    #[wasm_bindgen(extends = Error, extends = Object, typescript_type = "SyntaxError")]
-    #[derive(Clone, Debug, PartialEq, Eq)]
-    pub type SyntaxError;
+    /// A typed-in-spirit wrapper around `Promise.allSettled`: waits for
+    /// every promise in `promises` to settle and resolves with an `Array`
+    /// of `{status, value}` / `{status, reason}` result objects, in the
+    /// same order as `promises`, per the spec.
+    pub fn collect_settled_in_order(promises: &Array) -> Promise {
+        Promise::all_settled(promises.as_ref())
+    }
 
-    /// A `SyntaxError` is thrown when the JavaScript engine encounters tokens or
-    /// token order that does not conform to the syntax of the language when
-    /// parsing code.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/SyntaxError)
-    #[wasm_bindgen(constructor)]
-    pub fn new(message: &str) -> SyntaxError;
-}
+    struct RunLimitedState {
+        thunks: Array,
+        results: Array,
+        next_index: u32,
+        remaining: u32,
+        fail_fast: bool,
+        stopped: bool,
+        resolve: Function,
+        reject: Function,
+    }
 
-// TypeError
-#[wasm_bindgen]
-extern "C" {
-    /// The `TypeError` object represents an error when a value is not of the
-    /// expected type.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypeError)
-    #[wasm_bindgen(extends = Error, extends = Object, typescript_type = "TypeError")]
-    #[derive(Clone, Debug, PartialEq, Eq)]
-    pub type TypeError;
+    fn make_settled_result(ok: bool, value: &JsValue) -> Object {
+        let result = Object::new();
+        if ok {
+            let _ = Reflect::set(result.as_ref(), &JsValue::from_str("status"), &JsValue::from_str("fulfilled"));
+            let _ = Reflect::set(result.as_ref(), &JsValue::from_str("value"), value);
+        } else {
+            let _ = Reflect::set(result.as_ref(), &JsValue::from_str("status"), &JsValue::from_str("rejected"));
+            let _ = Reflect::set(result.as_ref(), &JsValue::from_str("reason"), value);
+        }
+        result
+    }
 
-    /// The `TypeError` object represents an error when a value is not of the
-    /// expected type.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypeError)
-    #[wasm_bindgen(constructor)]
-    pub fn new(message: &str) -> TypeError;
-}
+    fn record_settlement(state: &Rc<RefCell<RunLimitedState>>, index: u32, ok: bool, value: JsValue) {
+        enum Next {
+            None,
+            Resolve(Function, Array),
+            Reject(Function, JsValue),
+        }
 
-// URIError
-#[wasm_bindgen]
-extern "C" {
-    /// The `URIError` object represents an error when a global URI handling
-    /// function was used in a wrong way.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/URIError)
-    #[wasm_bindgen(extends = Error, extends = Object, js_name = URIError, typescript_type = "URIError")]
-    #[derive(Clone, Debug, PartialEq, Eq)]
-    pub type UriError;
+        let next = {
+            let mut s = state.borrow_mut();
+            if s.stopped {
+                return;
+            }
 
-    /// The `URIError` object represents an error when a global URI handling
-    /// function was used in a wrong way.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/URIError)
-    #[wasm_bindgen(constructor, js_class = "URIError")]
-    pub fn new(message: &str) -> UriError;
-}
+            s.results.set(index, make_settled_result(ok, &value).into());
+            s.remaining -= 1;
+
+            if s.fail_fast && !ok {
+                s.stopped = true;
+                Next::Reject(s.reject.clone(), value)
+            } else if s.remaining == 0 {
+                s.stopped = true;
+                Next::Resolve(s.resolve.clone(), s.results.clone())
+            } else {
+                Next::None
+            }
+        };
 
-// WeakMap
-#[wasm_bindgen]
-extern "C" {
-    #[wasm_bindgen(extends = Object, typescript_type = "WeakMap<object, any>")]
-    #[derive(Clone, Debug, PartialEq, Eq)]
-    pub type WeakMap;
+        match next {
+            Next::None => {}
+            Next::Reject(reject, reason) => {
+                let _ = reject.call1(&JsValue::UNDEFINED, &reason);
+            }
+            Next::Resolve(resolve, results) => {
+                let _ = resolve.call1(&JsValue::UNDEFINED, results.as_ref());
+            }
+        }
+    }
 
-    /// The [`WeakMap`] object is a collection of key/value pairs in which the
-    /// keys are weakly referenced.  The keys must be objects and the values can
-    /// be arbitrary values.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap)
-    #[wasm_bindgen(constructor)]
-    pub fn new() -> WeakMap;
+    fn launch_next(state: Rc<RefCell<RunLimitedState>>) {
+        let started = {
+            let mut s = state.borrow_mut();
+            if s.stopped || s.next_index >= s.thunks.length() {
+                None
+            } else {
+                let index = s.next_index;
+                s.next_index += 1;
+                Some((index, s.thunks.get(index)))
+            }
+        };
 
-    /// The `set()` method sets the value for the key in the [`WeakMap`] object.
-    /// Returns the [`WeakMap`] object.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap/set)
-    #[wasm_bindgen(method, js_class = "WeakMap")]
-    pub fn set(this: &WeakMap, key: &Object, value: &JsValue) -> WeakMap;
+        let (index, thunk) = match started {
+            Some(pair) => pair,
+            None => return,
+        };
 
-    /// The `get()` method returns a specified by key element
-    /// from a [`WeakMap`] object.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap/get)
-    #[wasm_bindgen(method)]
-    pub fn get(this: &WeakMap, key: &Object) -> JsValue;
+        let outcome = match thunk.dyn_ref::<Function>() {
+            Some(f) => f.call0(&JsValue::UNDEFINED),
+            None => Err(Error::new("thunk is not callable").into()),
+        };
 
-    /// The `has()` method returns a boolean indicating whether an element with
-    /// the specified key exists in the [`WeakMap`] object or not.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap/has)
-    #[wasm_bindgen(method)]
-    pub fn has(this: &WeakMap, key: &Object) -> bool;
+        match outcome {
+            Err(thrown) => {
+                record_settlement(&state, index, false, thrown);
+                queue_microtask(move || launch_next(state));
+            }
+            Ok(returned) => match returned.dyn_into::<Promise>() {
+                Ok(promise) => {
+                    let on_resolve_state = Rc::clone(&state);
+                    let on_reject_state = Rc::clone(&state);
+                    let on_resolve = Closure::wrap(Box::new(move |value: JsValue| {
+                        record_settlement(&on_resolve_state, index, true, value);
+                        launch_next(Rc::clone(&on_resolve_state));
+                    }) as Box<dyn FnMut(JsValue)>);
+                    let on_reject = Closure::wrap(Box::new(move |reason: JsValue| {
+                        record_settlement(&on_reject_state, index, false, reason);
+                        launch_next(Rc::clone(&on_reject_state));
+                    }) as Box<dyn FnMut(JsValue)>);
+                    let _ = promise.then2(&on_resolve, &on_reject);
+                    on_resolve.forget();
+                    on_reject.forget();
+                }
+                Err(immediate) => {
+                    record_settlement(&state, index, true, immediate);
+                    queue_microtask(move || launch_next(state));
+                }
+            },
+        }
+    }
 
-    /// The `delete()` method removes the specified element from a [`WeakMap`]
-    /// object.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap/delete)
-    #[wasm_bindgen(method)]
-    pub fn delete(this: &WeakMap, key: &Object) -> bool;
-}
+    fn run_limited_impl(thunks: &Array, limit: u32, fail_fast: bool) -> Promise {
+        let thunks = thunks.clone();
+        let len = thunks.length();
+        let results = Array::new();
+        results.set_length(len);
 
-impl Default for WeakMap {
-    fn default() -> Self {
-        Self::new()
+        let mut executor = move |resolve: Function, reject: Function| {
+            if len == 0 {
+                let _ = resolve.call1(&JsValue::UNDEFINED, results.as_ref());
+                return;
+            }
+
+            let state = Rc::new(RefCell::new(RunLimitedState {
+                thunks: thunks.clone(),
+                results: results.clone(),
+                next_index: 0,
+                remaining: len,
+                fail_fast,
+                stopped: false,
+                resolve,
+                reject,
+            }));
+
+            for _ in 0..limit.clamp(1, len) {
+                launch_next(Rc::clone(&state));
+            }
+        };
+        Promise::new(&mut executor)
     }
-}
 
-// WeakSet
-#[wasm_bindgen]
-extern "C" {
-    #[wasm_bindgen(extends = Object, typescript_type = "WeakSet<object>")]
-    #[derive(Clone, Debug, PartialEq, Eq)]
-    pub type WeakSet;
+    /// Runs every thunk in `thunks` (a zero-argument function returning a
+    /// `Promise`, or any other value -- treated as already settled),
+    /// starting at most `limit` at a time and starting the next as each
+    /// one settles. Resolves with an `Array` of `{status, value}` /
+    /// `{status, reason}` result objects -- the same shape
+    /// `Promise.allSettled` produces -- in the same order as `thunks`, and
+    /// never itself rejects: a thunk that throws synchronously counts as a
+    /// `"rejected"` result like a rejected promise would.
+    ///
+    /// Continuations run through [`queue_microtask`]/promise `.then()`
+    /// chaining rather than direct Rust recursion, so there's no recursion
+    /// depth concern even for a long, synchronously-resolving `thunks`.
+    pub fn run_limited(thunks: &Array, limit: u32) -> Promise {
+        run_limited_impl(thunks, limit, false)
+    }
 
-    /// The `WeakSet` object lets you store weakly held objects in a collection.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakSet)
-    #[wasm_bindgen(constructor)]
-    pub fn new() -> WeakSet;
+    /// Like [`run_limited`], but rejects as soon as any thunk throws or its
+    /// promise rejects, with that reason, and stops starting thunks that
+    /// haven't already been started (already-started ones still run to
+    /// completion, but their results are discarded).
+    pub fn run_limited_fail_fast(thunks: &Array, limit: u32) -> Promise {
+        run_limited_impl(thunks, limit, true)
+    }
+}
 
-    /// The `has()` method returns a boolean indicating whether an object exists
-    /// in a WeakSet or not.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakSet/has)
-    #[wasm_bindgen(method)]
-    pub fn has(this: &WeakSet, value: &Object) -> bool;
+/// Returns a handle to the global scope object.
+///
+/// This allows access to the global properties and global names by accessing
+/// the `Object` returned.
+pub fn global() -> Object {
+    #[cfg(feature = "std")]
+    {
+        thread_local!(static GLOBAL: Object = get_global_object());
+        return GLOBAL.with(|g| g.clone());
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        use once_cell::unsync::Lazy;
 
-    /// The `add()` method appends a new object to the end of a WeakSet object.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakSet/add)
-    #[wasm_bindgen(method)]
-    pub fn add(this: &WeakSet, value: &Object) -> WeakSet;
+        struct Wrapper<T>(Lazy<T>);
 
-    /// The `delete()` method removes the specified element from a WeakSet
-    /// object.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakSet/delete)
-    #[wasm_bindgen(method)]
-    pub fn delete(this: &WeakSet, value: &Object) -> bool;
-}
+        #[cfg(not(target_feature = "atomics"))]
+        unsafe impl<T> Sync for Wrapper<T> {}
 
-impl Default for WeakSet {
-    fn default() -> Self {
-        Self::new()
+        #[cfg(not(target_feature = "atomics"))]
+        unsafe impl<T> Send for Wrapper<T> {}
+
+        #[cfg_attr(target_feature = "atomics", thread_local)]
+        static GLOBAL: Wrapper<Object> = Wrapper(Lazy::new(get_global_object));
+
+        return GLOBAL.0.clone();
     }
-}
 
-#[cfg(js_sys_unstable_apis)]
-#[allow(non_snake_case)]
-pub mod Temporal;
+    fn get_global_object() -> Object {
+        // Accessing the global object is not an easy thing to do, and what we
+        // basically want is `globalThis` but we can't rely on that existing
+        // everywhere. In the meantime we've got the fallbacks mentioned in:
+        //
+        // https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/globalThis
+        //
+        // Note that this is pretty heavy code-size wise but it at least gets
+        // the job largely done for now and avoids the `Function` constructor at
+        // the end which triggers CSP errors.
+        #[wasm_bindgen]
+        extern "C" {
+            type Global;
 
-#[allow(non_snake_case)]
-pub mod WebAssembly {
-    use super::*;
+            #[wasm_bindgen(thread_local_v2, js_name = globalThis)]
+            static GLOBAL_THIS: Option<Object>;
 
-    // WebAssembly
-    #[wasm_bindgen]
-    extern "C" {
-        /// The `WebAssembly.compile()` function compiles a `WebAssembly.Module`
-        /// from WebAssembly binary code.  This function is useful if it is
-        /// necessary to a compile a module before it can be instantiated
-        /// (otherwise, the `WebAssembly.instantiate()` function should be used).
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WebAssembly/compile)
-        #[wasm_bindgen(js_namespace = WebAssembly)]
-        pub fn compile(buffer_source: &JsValue) -> Promise;
+            #[wasm_bindgen(thread_local_v2, js_name = self)]
+            static SELF: Option<Object>;
 
-        /// The `WebAssembly.compileStreaming()` function compiles a
-        /// `WebAssembly.Module` module directly from a streamed underlying
-        /// source. This function is useful if it is necessary to a compile a
-        /// module before it can be instantiated (otherwise, the
-        /// `WebAssembly.instantiateStreaming()` function should be used).
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WebAssembly/compileStreaming)
-        #[wasm_bindgen(js_namespace = WebAssembly, js_name = compileStreaming)]
-        pub fn compile_streaming(response: &Promise) -> Promise;
+            #[wasm_bindgen(thread_local_v2, js_name = window)]
+            static WINDOW: Option<Object>;
 
-        /// The `WebAssembly.instantiate()` function allows you to compile and
-        /// instantiate WebAssembly code.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WebAssembly/instantiate)
-        #[wasm_bindgen(js_namespace = WebAssembly, js_name = instantiate)]
-        pub fn instantiate_buffer(buffer: &[u8], imports: &Object) -> Promise;
+            #[wasm_bindgen(thread_local_v2, js_name = global)]
+            static GLOBAL: Option<Object>;
+        }
 
-        /// The `WebAssembly.instantiate()` function allows you to compile and
-        /// instantiate WebAssembly code.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WebAssembly/instantiate)
-        #[wasm_bindgen(js_namespace = WebAssembly, js_name = instantiate)]
-        pub fn instantiate_module(module: &Module, imports: &Object) -> Promise;
+        // The order is important: in Firefox Extension Content Scripts `globalThis`
+        // is a Sandbox (not Window), so `globalThis` must be checked after `window`.
+        let static_object = SELF
+            .with(Option::clone)
+            .or_else(|| WINDOW.with(Option::clone))
+            .or_else(|| GLOBAL_THIS.with(Option::clone))
+            .or_else(|| GLOBAL.with(Option::clone));
+        if let Some(obj) = static_object {
+            if !obj.is_undefined() {
+                return obj;
+            }
+        }
 
-        /// The `WebAssembly.instantiateStreaming()` function compiles and
-        /// instantiates a WebAssembly module directly from a streamed
-        /// underlying source. This is the most efficient, optimized way to load
-        /// Wasm code.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WebAssembly/instantiateStreaming)
-        #[wasm_bindgen(js_namespace = WebAssembly, js_name = instantiateStreaming)]
-        pub fn instantiate_streaming(response: &Promise, imports: &Object) -> Promise;
+        // According to StackOverflow you can access the global object via:
+        //
+        //      const global = Function('return this')();
+        //
+        // I think that's because the manufactured function isn't in "strict" mode.
+        // It also turns out that non-strict functions will ignore `undefined`
+        // values for `this` when using the `apply` function.
+        //
+        // As a result we use the equivalent of this snippet to get a handle to the
+        // global object in a sort of roundabout way that should hopefully work in
+        // all contexts like ESM, node, browsers, etc.
+        let this = Function::new_no_args("return this")
+            .call0(&JsValue::undefined())
+            .ok();
 
-        /// The `WebAssembly.validate()` function validates a given typed
-        /// array of WebAssembly binary code, returning whether the bytes
-        /// form a valid Wasm module (`true`) or not (`false`).
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WebAssembly/validate)
-        #[wasm_bindgen(js_namespace = WebAssembly, catch)]
-        pub fn validate(buffer_source: &JsValue) -> Result<bool, JsValue>;
+        // Note that we avoid `unwrap()` on `call0` to avoid code size bloat, we
+        // just handle the `Err` case as returning a different object.
+        debug_assert!(this.is_some());
+        match this {
+            Some(this) => this.unchecked_into(),
+            None => JsValue::undefined().unchecked_into(),
+        }
     }
+}
 
-    // WebAssembly.CompileError
-    #[wasm_bindgen]
-    extern "C" {
-        /// The `WebAssembly.CompileError()` constructor creates a new
-        /// WebAssembly `CompileError` object, which indicates an error during
-        /// WebAssembly decoding or validation.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WebAssembly/CompileError)
-        #[wasm_bindgen(extends = Error, js_namespace = WebAssembly, typescript_type = "WebAssembly.CompileError")]
-        #[derive(Clone, Debug, PartialEq, Eq)]
-        pub type CompileError;
+/// Trait for Rust numeric primitives that can be converted into a typed
+/// array's element type using the same coercion semantics JavaScript's
+/// `TypedArray.prototype.set()` applies to array-like sources (e.g. the
+/// `ToInt8`/`ToUint8`/`ToUint8Clamp` abstract operations), so that
+/// `set_converting` behaves as if the source values had been assigned
+/// through JS rather than truncated or saturated the Rust way.
+pub trait PrimCast: Copy {
+    /// Converts `self` into an `f64`, the common pivot type used by the JS
+    /// numeric coercion abstract operations. The conversion is exact for
+    /// every type except `i64` and `u64`, whose values beyond 2^53 are
+    /// rounded to the nearest `f64`.
+    fn to_f64_lossy(self) -> f64;
+}
 
-        /// The `WebAssembly.CompileError()` constructor creates a new
-        /// WebAssembly `CompileError` object, which indicates an error during
-        /// WebAssembly decoding or validation.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WebAssembly/CompileError)
-        #[wasm_bindgen(constructor, js_namespace = WebAssembly)]
-        pub fn new(message: &str) -> CompileError;
+macro_rules! prim_cast_impl {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl PrimCast for $ty {
+                #[inline]
+                fn to_f64_lossy(self) -> f64 {
+                    self as f64
+                }
+            }
+        )*
+    };
+}
+
+prim_cast_impl!(i8, i16, i32, i64, u8, u16, u32, u64, f32, f64);
+
+/// Implements the `ToIntegerOrInfinity` followed by modulo-`2^bits` wrapping
+/// that the `ToInt8`/`ToUint8`/`ToInt16`/etc. abstract operations perform
+/// when JS coerces a number into a typed array element.
+fn js_wrap_integer(value: f64, bits: u32, signed: bool) -> i64 {
+    if !value.is_finite() {
+        return 0;
+    }
+    let truncated = Math::trunc(value);
+    let modulus = match bits {
+        8 => 256.0,
+        16 => 65536.0,
+        32 => 4294967296.0,
+        64 => 18446744073709551616.0,
+        _ => core::unreachable!(),
+    };
+    let mut wrapped = truncated % modulus;
+    if signed {
+        // Move straight into `[-modulus / 2, modulus / 2)`: each step stays
+        // exact in `f64` even for 64 bits, and `as i64` cannot saturate.
+        if wrapped >= modulus / 2.0 {
+            wrapped -= modulus;
+        } else if wrapped < -modulus / 2.0 {
+            wrapped += modulus;
+        }
+        return wrapped as i64;
+    }
+    if wrapped < 0.0 {
+        wrapped += modulus;
     }
+    wrapped as i64
+}
 
-    // WebAssembly.Instance
-    #[wasm_bindgen]
-    extern "C" {
-        /// A `WebAssembly.Instance` object is a stateful, executable instance
-        /// of a `WebAssembly.Module`. Instance objects contain all the exported
-        /// WebAssembly functions that allow calling into WebAssembly code from
-        /// JavaScript.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WebAssembly/Instance)
-        #[wasm_bindgen(extends = Object, js_namespace = WebAssembly, typescript_type = "WebAssembly.Instance")]
-        #[derive(Clone, Debug, PartialEq, Eq)]
-        pub type Instance;
+fn js_wrap_i8(value: f64) -> i8 {
+    js_wrap_integer(value, 8, true) as i8
+}
 
-        /// The `WebAssembly.Instance()` constructor function can be called to
-        /// synchronously instantiate a given `WebAssembly.Module`
-        /// object. However, the primary way to get an `Instance` is through the
-        /// asynchronous `WebAssembly.instantiateStreaming()` function.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WebAssembly/Instance)
-        #[wasm_bindgen(catch, constructor, js_namespace = WebAssembly)]
-        pub fn new(module: &Module, imports: &Object) -> Result<Instance, JsValue>;
+fn js_wrap_u8(value: f64) -> u8 {
+    js_wrap_integer(value, 8, false) as u8
+}
 
-        /// The `exports` readonly property of the `WebAssembly.Instance` object
-        /// prototype returns an object containing as its members all the
-        /// functions exported from the WebAssembly module instance, to allow
-        /// them to be accessed and used by JavaScript.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WebAssembly/Instance/exports)
-        #[wasm_bindgen(getter, method, js_namespace = WebAssembly)]
-        pub fn exports(this: &Instance) -> Object;
-    }
+fn js_wrap_i16(value: f64) -> i16 {
+    js_wrap_integer(value, 16, true) as i16
+}
 
-    // WebAssembly.LinkError
-    #[wasm_bindgen]
-    extern "C" {
-        /// The `WebAssembly.LinkError()` constructor creates a new WebAssembly
-        /// LinkError object, which indicates an error during module
-        /// instantiation (besides traps from the start function).
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WebAssembly/LinkError)
-        #[wasm_bindgen(extends = Error, js_namespace = WebAssembly, typescript_type = "WebAssembly.LinkError")]
-        #[derive(Clone, Debug, PartialEq, Eq)]
-        pub type LinkError;
+fn js_wrap_u16(value: f64) -> u16 {
+    js_wrap_integer(value, 16, false) as u16
+}
 
-        /// The `WebAssembly.LinkError()` constructor creates a new WebAssembly
-        /// LinkError object, which indicates an error during module
-        /// instantiation (besides traps from the start function).
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WebAssembly/LinkError)
-        #[wasm_bindgen(constructor, js_namespace = WebAssembly)]
-        pub fn new(message: &str) -> LinkError;
-    }
+fn js_wrap_i32(value: f64) -> i32 {
+    js_wrap_integer(value, 32, true) as i32
+}
 
-    // WebAssembly.RuntimeError
-    #[wasm_bindgen]
-    extern "C" {
-        /// The `WebAssembly.RuntimeError()` constructor creates a new WebAssembly
-        /// `RuntimeError` object — the type that is thrown whenever WebAssembly
-        /// specifies a trap.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WebAssembly/RuntimeError)
-        #[wasm_bindgen(extends = Error, js_namespace = WebAssembly, typescript_type = "WebAssembly.RuntimeError")]
-        #[derive(Clone, Debug, PartialEq, Eq)]
-        pub type RuntimeError;
+fn js_wrap_u32(value: f64) -> u32 {
+    js_wrap_integer(value, 32, false) as u32
+}
 
-        /// The `WebAssembly.RuntimeError()` constructor creates a new WebAssembly
-        /// `RuntimeError` object — the type that is thrown whenever WebAssembly
-        /// specifies a trap.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WebAssembly/RuntimeError)
-        #[wasm_bindgen(constructor, js_namespace = WebAssembly)]
-        pub fn new(message: &str) -> RuntimeError;
-    }
+fn js_wrap_i64(value: f64) -> i64 {
+    js_wrap_integer(value, 64, true)
+}
 
-    // WebAssembly.Module
-    #[wasm_bindgen]
-    extern "C" {
-        /// A `WebAssembly.Module` object contains stateless WebAssembly code
-        /// that has already been compiled by the browser and can be
-        /// efficiently shared with Workers, and instantiated multiple times.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WebAssembly/Module)
-        #[wasm_bindgen(js_namespace = WebAssembly, extends = Object, typescript_type = "WebAssembly.Module")]
-        #[derive(Clone, Debug, PartialEq, Eq)]
-        pub type Module;
+fn js_wrap_u64(value: f64) -> u64 {
+    // The signed wrap is the same value mod 2^64; `as u64` reinterprets it.
+    js_wrap_integer(value, 64, true) as u64
+}
+
+fn js_wrap_f32(value: f64) -> f32 {
+    value as f32
+}
+
+fn js_identity_f64(value: f64) -> f64 {
+    value
+}
 
-        /// A `WebAssembly.Module` object contains stateless WebAssembly code
-        /// that has already been compiled by the browser and can be
-        /// efficiently shared with Workers, and instantiated multiple times.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WebAssembly/Module)
-        #[wasm_bindgen(constructor, js_namespace = WebAssembly, catch)]
-        pub fn new(buffer_source: &JsValue) -> Result<Module, JsValue>;
+/// Implements the `ToUint8Clamp` abstract operation: clamp to `[0, 255]`
+/// and round halfway values to the nearest even integer.
+fn js_clamp_u8(value: f64) -> u8 {
+    if value.is_nan() || value <= 0.0 {
+        return 0;
+    }
+    if value >= 255.0 {
+        return 255;
+    }
+    let floor = Math::floor(value);
+    let diff = value - floor;
+    let rounded = if diff < 0.5 {
+        floor
+    } else if diff > 0.5 {
+        floor + 1.0
+    } else if (floor as i64) % 2 == 0 {
+        floor
+    } else {
+        floor + 1.0
+    };
+    rounded as u8
+}
 
-        /// The `WebAssembly.customSections()` function returns a copy of the
-        /// contents of all custom sections in the given module with the given
-        /// string name.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WebAssembly/Module/customSections)
-        #[wasm_bindgen(static_method_of = Module, js_namespace = WebAssembly, js_name = customSections)]
-        pub fn custom_sections(module: &Module, sectionName: &str) -> Array;
+/// The error returned by a typed array's `_checked`/`try_*` accessors when
+/// its backing `ArrayBuffer` has been detached, in place of the silent
+/// zero/empty result the unchecked accessors give in that case.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Detached;
+
+/// The error returned by a typed array's `from_bytes_view` when the given
+/// `Uint8Array`'s byte length isn't a multiple of the target element size.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AlignmentError {
+    byte_length: u32,
+    element_size: usize,
+}
 
-        /// The `WebAssembly.exports()` function returns an array containing
-        /// descriptions of all the declared exports of the given `Module`.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WebAssembly/Module/exports)
-        #[wasm_bindgen(static_method_of = Module, js_namespace = WebAssembly)]
-        pub fn exports(module: &Module) -> Array;
+impl fmt::Display for AlignmentError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "byte length {} is not a multiple of the element size {}",
+            self.byte_length, self.element_size
+        )
+    }
+}
 
-        /// The `WebAssembly.imports()` function returns an array containing
-        /// descriptions of all the declared imports of the given `Module`.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WebAssembly/Module/imports)
-        #[wasm_bindgen(static_method_of = Module, js_namespace = WebAssembly)]
-        pub fn imports(module: &Module) -> Array;
+#[cfg(feature = "std")]
+impl std::error::Error for AlignmentError {}
+
+/// Maps a Rust [`Ordering`] to the negative/zero/positive number a JS
+/// comparator is expected to return.
+fn ordering_to_f64(ordering: Ordering) -> f64 {
+    match ordering {
+        Ordering::Less => -1.0,
+        Ordering::Equal => 0.0,
+        Ordering::Greater => 1.0,
     }
+}
 
-    // WebAssembly.Table
-    #[wasm_bindgen]
-    extern "C" {
-        /// The `WebAssembly.Table()` constructor creates a new `Table` object
-        /// of the given size and element type.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WebAssembly/Table)
-        #[wasm_bindgen(js_namespace = WebAssembly, extends = Object, typescript_type = "WebAssembly.Table")]
-        #[derive(Clone, Debug, PartialEq, Eq)]
-        pub type Table;
+macro_rules! arrays {
+    ($(#[doc = $ctor:literal] #[doc = $mdn:literal] $name:ident: $ty:ident via $conv:ident,)*) => ($(
+        #[wasm_bindgen]
+        extern "C" {
+            #[wasm_bindgen(extends = Object, typescript_type = $name)]
+            #[derive(Clone, Debug)]
+            pub type $name;
 
-        /// The `WebAssembly.Table()` constructor creates a new `Table` object
-        /// of the given size and element type.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WebAssembly/Table)
-        #[wasm_bindgen(constructor, js_namespace = WebAssembly, catch)]
-        pub fn new(table_descriptor: &Object) -> Result<Table, JsValue>;
+            /// The
+            #[doc = $ctor]
+            /// constructor creates a new array.
+            ///
+            /// [MDN documentation](
+            #[doc = $mdn]
+            /// )
+            #[wasm_bindgen(constructor)]
+            pub fn new(constructor_arg: &JsValue) -> $name;
 
-        /// The length prototype property of the `WebAssembly.Table` object
-        /// returns the length of the table, i.e. the number of elements in the
-        /// table.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WebAssembly/Table/length)
-        #[wasm_bindgen(method, getter, js_namespace = WebAssembly)]
-        pub fn length(this: &Table) -> u32;
+            /// An
+            #[doc = $ctor]
+            /// which creates an array with an internal buffer large
+            /// enough for `length` elements.
+            ///
+            /// [MDN documentation](
+            #[doc = $mdn]
+            /// )
+            #[wasm_bindgen(constructor)]
+            pub fn new_with_length(length: u32) -> $name;
 
-        /// The `get()` prototype method of the `WebAssembly.Table()` object
-        /// retrieves a function reference stored at a given index.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WebAssembly/Table/get)
-        #[wasm_bindgen(method, catch, js_namespace = WebAssembly)]
-        pub fn get(this: &Table, index: u32) -> Result<Function, JsValue>;
+            /// An
+            #[doc = $ctor]
+            /// which creates an array with the given buffer but is a
+            /// view starting at `byte_offset`.
+            ///
+            /// [MDN documentation](
+            #[doc = $mdn]
+            /// )
+            #[wasm_bindgen(constructor)]
+            pub fn new_with_byte_offset(buffer: &JsValue, byte_offset: u32) -> $name;
 
-        /// The `grow()` prototype method of the `WebAssembly.Table` object
-        /// increases the size of the `Table` instance by a specified number of
-        /// elements.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WebAssembly/Table/grow)
-        #[wasm_bindgen(method, catch, js_namespace = WebAssembly)]
-        pub fn grow(this: &Table, additional_capacity: u32) -> Result<u32, JsValue>;
+            /// An
+            #[doc = $ctor]
+            /// which creates an array with the given buffer but is a
+            /// view starting at `byte_offset` for `length` elements.
+            ///
+            /// [MDN documentation](
+            #[doc = $mdn]
+            /// )
+            #[wasm_bindgen(constructor)]
+            pub fn new_with_byte_offset_and_length(
+                buffer: &JsValue,
+                byte_offset: u32,
+                length: u32,
+            ) -> $name;
 
-        /// The `set()` prototype method of the `WebAssembly.Table` object mutates a
-        /// reference stored at a given index to a different value.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WebAssembly/Table/set)
-        #[wasm_bindgen(method, catch, js_namespace = WebAssembly)]
-        pub fn set(this: &Table, index: u32, function: &Function) -> Result<(), JsValue>;
-    }
+            /// The `fill()` method fills all the elements of an array from a start index
+            /// to an end index with a static value. The end index is not included.
+            ///
+            /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/fill)
+            #[wasm_bindgen(method)]
+            pub fn fill(this: &$name, value: $ty, start: u32, end: u32) -> $name;
 
-    // WebAssembly.Tag
-    #[wasm_bindgen]
-    extern "C" {
-        /// The `WebAssembly.Tag()` constructor creates a new `Tag` object
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WebAssembly/Tag)
-        #[wasm_bindgen(js_namespace = WebAssembly, extends = Object, typescript_type = "WebAssembly.Tag")]
-        #[derive(Clone, Debug, PartialEq, Eq)]
-        pub type Tag;
+            /// The buffer accessor property represents the `ArrayBuffer` referenced
+            /// by a `TypedArray` at construction time.
+            #[wasm_bindgen(getter, method)]
+            pub fn buffer(this: &$name) -> ArrayBuffer;
 
-        /// The `WebAssembly.Tag()` constructor creates a new `Tag` object
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WebAssembly/Tag)
-        #[wasm_bindgen(constructor, js_namespace = WebAssembly, catch)]
-        pub fn new(tag_descriptor: &Object) -> Result<Tag, JsValue>;
-    }
+            /// The `subarray()` method returns a new `TypedArray` on the same
+            /// `ArrayBuffer` store and with the same element types as for this
+            /// `TypedArray` object.
+            #[wasm_bindgen(method)]
+            pub fn subarray(this: &$name, begin: u32, end: u32) -> $name;
 
-    // WebAssembly.Exception
-    #[wasm_bindgen]
-    extern "C" {
-        /// The `WebAssembly.Exception()` constructor creates a new `Exception` object
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WebAssembly/Exception)
-        #[wasm_bindgen(js_namespace = WebAssembly, extends = Object, typescript_type = "WebAssembly.Exception")]
-        #[derive(Clone, Debug, PartialEq, Eq)]
-        pub type Exception;
+            /// The `slice()` method returns a shallow copy of a portion of a typed
+            /// array into a new typed array object. This method has the same algorithm
+            /// as `Array.prototype.slice()`.
+            #[wasm_bindgen(method)]
+            pub fn slice(this: &$name, begin: u32, end: u32) -> $name;
 
-        /// The `WebAssembly.Exception()` constructor creates a new `Exception` object
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WebAssembly/Exception)
-        #[wasm_bindgen(constructor, js_namespace = WebAssembly, catch)]
-        pub fn new(tag: &Tag, payload: &Array) -> Result<Exception, JsValue>;
+            /// The `forEach()` method executes a provided function once per array
+            /// element. This method has the same algorithm as
+            /// `Array.prototype.forEach()`. `TypedArray` is one of the typed array
+            /// types here.
+            #[wasm_bindgen(method, js_name = forEach)]
+            pub fn for_each(this: &$name, callback: &mut dyn FnMut($ty, u32, $name));
 
-        /// The `WebAssembly.Exception()` constructor creates a new `Exception` object
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WebAssembly/Exception)
-        #[wasm_bindgen(constructor, js_namespace = WebAssembly, catch)]
-        pub fn new_with_options(
-            tag: &Tag,
-            payload: &Array,
-            options: &Object,
-        ) -> Result<Exception, JsValue>;
+            /// The length accessor property represents the length (in elements) of a
+            /// typed array.
+            #[wasm_bindgen(method, getter)]
+            pub fn length(this: &$name) -> u32;
 
-        /// The `is()` prototype method of the `WebAssembly.Exception` can be used to
-        /// test if the Exception matches a given tag.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WebAssembly/Exception/is)
-        #[wasm_bindgen(method, js_namespace = WebAssembly)]
-        pub fn is(this: &Exception, tag: &Tag) -> bool;
+            /// The byteLength accessor property represents the length (in bytes) of a
+            /// typed array.
+            #[wasm_bindgen(method, getter, js_name = byteLength)]
+            pub fn byte_length(this: &$name) -> u32;
 
-        /// The `getArg()` prototype method of the `WebAssembly.Exception` can be used
-        /// to get the value of a specified item in the exception's data arguments
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WebAssembly/Exception/getArg)
-        #[wasm_bindgen(method, js_namespace = WebAssembly, js_name = getArg, catch)]
-        pub fn get_arg(this: &Exception, tag: &Tag, index: u32) -> Result<JsValue, JsValue>;
-    }
+            /// The byteOffset accessor property represents the offset (in bytes) of a
+            /// typed array from the start of its `ArrayBuffer`.
+            #[wasm_bindgen(method, getter, js_name = byteOffset)]
+            pub fn byte_offset(this: &$name) -> u32;
 
-    // WebAssembly.Global
-    #[wasm_bindgen]
-    extern "C" {
-        /// The `WebAssembly.Global()` constructor creates a new `Global` object
-        /// of the given type and value.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WebAssembly/Global)
-        #[wasm_bindgen(js_namespace = WebAssembly, extends = Object, typescript_type = "WebAssembly.Global")]
-        #[derive(Clone, Debug, PartialEq, Eq)]
-        pub type Global;
+            /// The `set()` method stores multiple values in the typed array, reading
+            /// input values from a specified array.
+            #[wasm_bindgen(method)]
+            pub fn set(this: &$name, src: &JsValue, offset: u32);
 
-        /// The `WebAssembly.Global()` constructor creates a new `Global` object
-        /// of the given type and value.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WebAssembly/Global)
-        #[wasm_bindgen(constructor, js_namespace = WebAssembly, catch)]
-        pub fn new(global_descriptor: &Object, value: &JsValue) -> Result<Global, JsValue>;
+            /// Gets the value at `idx`, counting from the end if negative.
+            #[wasm_bindgen(method)]
+            pub fn at(this: &$name, idx: i32) -> Option<$ty>;
 
-        /// The value prototype property of the `WebAssembly.Global` object
-        /// returns the value of the global.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WebAssembly/Global)
-        #[wasm_bindgen(method, getter, structural, js_namespace = WebAssembly)]
-        pub fn value(this: &Global) -> JsValue;
-        #[wasm_bindgen(method, setter = value, structural, js_namespace = WebAssembly)]
-        pub fn set_value(this: &Global, value: &JsValue);
-    }
+            /// The `copyWithin()` method shallow copies part of a typed array to another
+            /// location in the same typed array and returns it, without modifying its size.
+            ///
+            /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/copyWithin)
+            #[wasm_bindgen(method, js_name = copyWithin)]
+            pub fn copy_within(this: &$name, target: i32, start: i32, end: i32) -> $name;
 
-    // WebAssembly.Memory
-    #[wasm_bindgen]
-    extern "C" {
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WebAssembly/Memory)
-        #[wasm_bindgen(js_namespace = WebAssembly, extends = Object, typescript_type = "WebAssembly.Memory")]
-        #[derive(Clone, Debug, PartialEq, Eq)]
-        pub type Memory;
+            /// Gets the value at `idx`, equivalent to the javascript `my_var = arr[idx]`.
+            #[wasm_bindgen(method, structural, indexing_getter)]
+            pub fn get_index(this: &$name, idx: u32) -> $ty;
 
-        /// The `WebAssembly.Memory()` constructor creates a new `Memory` object
-        /// which is a resizable `ArrayBuffer` that holds the raw bytes of
-        /// memory accessed by a WebAssembly `Instance`.
-        ///
-        /// A memory created by JavaScript or in WebAssembly code will be
-        /// accessible and mutable from both JavaScript and WebAssembly.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WebAssembly/Memory)
-        #[wasm_bindgen(constructor, js_namespace = WebAssembly, catch)]
-        pub fn new(descriptor: &Object) -> Result<Memory, JsValue>;
+            /// Sets the value at `idx`, equivalent to the javascript `arr[idx] = value`.
+            #[wasm_bindgen(method, structural, indexing_setter)]
+            pub fn set_index(this: &$name, idx: u32, value: $ty);
 
-        /// An accessor property that returns the buffer contained in the
-        /// memory.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WebAssembly/Memory/buffer)
-        #[wasm_bindgen(method, getter, js_namespace = WebAssembly)]
-        pub fn buffer(this: &Memory) -> JsValue;
+            /// The `sort()` method sorts the elements of a typed array
+            /// in place and returns the array, sorted in ascending
+            /// numeric order. Unlike `Array.prototype.sort()`, this is
+            /// numeric by default, not lexicographic, so there's no need
+            /// to re-sort the result in Rust.
+            #[wasm_bindgen(method)]
+            pub fn sort(this: &$name) -> $name;
+
+            /// Like [`sort`](Self::sort), but orders elements according
+            /// to `compare` (returning a negative, zero, or positive
+            /// number) instead of ascending numeric order.
+            #[wasm_bindgen(method, js_name = sort)]
+            fn sort_with_f64(this: &$name, compare: &mut dyn FnMut($ty, $ty) -> f64) -> $name;
+
+            /// The `toSorted()` method is the copying counterpart of
+            /// [`sort`](Self::sort): it returns a new typed array sorted
+            /// in ascending numeric order, leaving `this` untouched.
+            #[wasm_bindgen(method, js_name = toSorted)]
+            pub fn to_sorted(this: &$name) -> $name;
+
+            /// Like [`to_sorted`](Self::to_sorted), but orders elements
+            /// according to `compare` instead of ascending numeric order.
+            #[wasm_bindgen(method, js_name = toSorted)]
+            fn to_sorted_with_f64(this: &$name, compare: &mut dyn FnMut($ty, $ty) -> f64) -> $name;
+
+            /// The `indexOf()` method returns the first index at which a
+            /// given element can be found in the array, or -1 if it is not
+            /// present. Uses strict equality, so unlike
+            /// [`includes`](Self::includes), a search for `NaN` never
+            /// matches.
+            #[wasm_bindgen(method, js_name = indexOf)]
+            pub fn index_of(this: &$name, value: $ty, from_index: i32) -> i32;
+
+            /// The `lastIndexOf()` method returns the last index at which a
+            /// given element can be found in the array, searching
+            /// backwards from `from_index`, or -1 if it is not present.
+            #[wasm_bindgen(method, js_name = lastIndexOf)]
+            pub fn last_index_of(this: &$name, value: $ty, from_index: i32) -> i32;
+
+            /// The `includes()` method determines whether a typed array
+            /// includes a certain value, using `SameValueZero` semantics --
+            /// unlike [`index_of`](Self::index_of), a search for `NaN`
+            /// finds a `NaN` element.
+            #[wasm_bindgen(method)]
+            pub fn includes(this: &$name, value: $ty, from_index: i32) -> bool;
 
-        /// The `grow()` prototype method of the `Memory` object increases the
-        /// size of the memory instance by a specified number of WebAssembly
-        /// pages.
-        ///
-        /// Takes the number of pages to grow (64KiB in size) and returns the
-        /// previous size of memory, in pages.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WebAssembly/Memory/grow)
-        #[wasm_bindgen(method, js_namespace = WebAssembly)]
-        pub fn grow(this: &Memory, pages: u32) -> u32;
-    }
-}
+            /// The `join()` method joins all elements of a typed array into
+            /// a string, separated by `separator`.
+            #[wasm_bindgen(method, js_name = join)]
+            pub fn join(this: &$name, separator: &str) -> JsString;
+        }
 
-/// The `JSON` object contains methods for parsing [JavaScript Object
-/// Notation (JSON)](https://json.org/) and converting values to JSON. It
-/// can't be called or constructed, and aside from its two method
-/// properties, it has no interesting functionality of its own.
-#[allow(non_snake_case)]
-pub mod JSON {
-    use super::*;
+        impl $name {
+            /// Creates a JS typed array which is a view into wasm's linear
+            /// memory at the slice specified.
+            ///
+            /// This function returns a new typed array which is a view into
+            /// wasm's memory. This view does not copy the underlying data.
+            ///
+            /// # Safety
+            ///
+            /// Views into WebAssembly memory are only valid so long as the
+            /// backing buffer isn't resized in JS. Once this function is called
+            /// any future calls to `Box::new` (or malloc of any form) may cause
+            /// the returned value here to be invalidated. Use with caution!
+            ///
+            /// Additionally the returned object can be safely mutated but the
+            /// input slice isn't guaranteed to be mutable.
+            ///
+            /// Finally, the returned object is disconnected from the input
+            /// slice's lifetime, so there's no guarantee that the data is read
+            /// at the right time.
+            pub unsafe fn view(rust: &[$ty]) -> $name {
+                let buf = wasm_bindgen::memory();
+                let mem = buf.unchecked_ref::<WebAssembly::Memory>();
+                $name::new_with_byte_offset_and_length(
+                    &mem.buffer(),
+                    rust.as_ptr() as u32,
+                    rust.len() as u32,
+                )
+            }
 
-    // JSON
-    #[wasm_bindgen]
-    extern "C" {
-        /// The `JSON.parse()` method parses a JSON string, constructing the
-        /// JavaScript value or object described by the string.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/JSON/parse)
-        #[wasm_bindgen(catch, js_namespace = JSON)]
-        pub fn parse(text: &str) -> Result<JsValue, JsValue>;
+            /// Creates a JS typed array which is a view into wasm's linear
+            /// memory at the specified pointer with specified length.
+            ///
+            /// This function returns a new typed array which is a view into
+            /// wasm's memory. This view does not copy the underlying data.
+            ///
+            /// # Safety
+            ///
+            /// Views into WebAssembly memory are only valid so long as the
+            /// backing buffer isn't resized in JS. Once this function is called
+            /// any future calls to `Box::new` (or malloc of any form) may cause
+            /// the returned value here to be invalidated. Use with caution!
+            ///
+            /// Additionally the returned object can be safely mutated,
+            /// the changes are guaranteed to be reflected in the input array.
+            pub unsafe fn view_mut_raw(ptr: *mut $ty, length: usize) -> $name {
+                let buf = wasm_bindgen::memory();
+                let mem = buf.unchecked_ref::<WebAssembly::Memory>();
+                $name::new_with_byte_offset_and_length(
+                    &mem.buffer(),
+                    ptr as u32,
+                    length as u32
+                )
+            }
 
-        /// The `JSON.stringify()` method converts a JavaScript value to a JSON string.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/JSON/stringify)
-        #[wasm_bindgen(catch, js_namespace = JSON)]
-        pub fn stringify(obj: &JsValue) -> Result<JsString, JsValue>;
 
-        /// The `JSON.stringify()` method converts a JavaScript value to a JSON string.
-        ///
-        /// The `replacer` argument is a function that alters the behavior of the stringification
-        /// process, or an array of String and Number objects that serve as a whitelist
-        /// for selecting/filtering the properties of the value object to be included
-        /// in the JSON string. If this value is null or not provided, all properties
-        /// of the object are included in the resulting JSON string.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/JSON/stringify)
-        #[wasm_bindgen(catch, js_namespace = JSON, js_name = stringify)]
-        pub fn stringify_with_replacer(
-            obj: &JsValue,
-            replacer: &JsValue,
-        ) -> Result<JsString, JsValue>;
+            /// Copy the contents of this JS typed array into the destination
+            /// Rust pointer.
+            ///
+            /// This function will efficiently copy the memory from a typed
+            /// array into this Wasm module's own linear memory, initializing
+            /// the memory destination provided.
+            ///
+            /// # Safety
+            ///
+            /// This function requires `dst` to point to a buffer
+            /// large enough to fit this array's contents.
+            pub unsafe fn raw_copy_to_ptr(&self, dst: *mut $ty) {
+                #[cfg(feature = "call-metrics")]
+                metrics::record(metrics::Category::BulkCopy);
+                let buf = wasm_bindgen::memory();
+                let mem = buf.unchecked_ref::<WebAssembly::Memory>();
+                let all_wasm_memory = $name::new(&mem.buffer());
+                let offset = dst as usize / mem::size_of::<$ty>();
+                all_wasm_memory.set(self, offset as u32);
+            }
 
-        /// The `JSON.stringify()` method converts a JavaScript value to a JSON string.
-        ///
-        /// The `replacer` argument is a function that alters the behavior of the stringification
-        /// process, or an array of String and Number objects that serve as a whitelist
-        /// for selecting/filtering the properties of the value object to be included
-        /// in the JSON string. If this value is null or not provided, all properties
-        /// of the object are included in the resulting JSON string.
-        ///
-        /// The `space` argument is a String or Number object that's used to insert white space into
-        /// the output JSON string for readability purposes. If this is a Number, it
-        /// indicates the number of space characters to use as white space; this number
-        /// is capped at 10 (if it is greater, the value is just 10). Values less than
-        /// 1 indicate that no space should be used. If this is a String, the string
-        /// (or the first 10 characters of the string, if it's longer than that) is
-        /// used as white space. If this parameter is not provided (or is null), no
-        /// white space is used.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/JSON/stringify)
-        #[wasm_bindgen(catch, js_namespace = JSON, js_name = stringify)]
-        pub fn stringify_with_replacer_and_space(
-            obj: &JsValue,
-            replacer: &JsValue,
-            space: &JsValue,
-        ) -> Result<JsString, JsValue>;
+            /// Copy the contents of this JS typed array into the destination
+            /// Rust slice.
+            ///
+            /// This function will efficiently copy the memory from a typed
+            /// array into this Wasm module's own linear memory, initializing
+            /// the memory destination provided.
+            ///
+            /// # Panics
+            ///
+            /// This function will panic if this typed array's length is
+            /// different than the length of the provided `dst` array.
+            pub fn copy_to(&self, dst: &mut [$ty]) {
+                core::assert_eq!(self.length() as usize, dst.len());
+                unsafe { self.raw_copy_to_ptr(dst.as_mut_ptr()); }
+            }
 
-    }
-}
+            /// Copy the contents of the source Rust slice into this
+            /// JS typed array.
+            ///
+            /// This function will efficiently copy the memory from within
+            /// the Wasm module's own linear memory to this typed array.
+            ///
+            /// # Panics
+            ///
+            /// This function will panic if this typed array's length is
+            /// different than the length of the provided `src` array.
+            pub fn copy_from(&self, src: &[$ty]) {
+                core::assert_eq!(self.length() as usize, src.len());
+                // This is safe because the `set` function copies from its TypedArray argument
+                unsafe { self.set(&$name::view(src), 0) }
+            }
 
-// JsString
-#[wasm_bindgen]
-extern "C" {
-    #[wasm_bindgen(js_name = String, extends = Object, is_type_of = JsValue::is_string, typescript_type = "string")]
-    #[derive(Clone, PartialEq, Eq)]
-    pub type JsString;
+            /// Efficiently copies the contents of this JS typed array into a new Vec.
+            pub fn to_vec(&self) -> Vec<$ty> {
+                let mut output = Vec::with_capacity(self.length() as usize);
+                unsafe {
+                    self.raw_copy_to_ptr(output.as_mut_ptr());
+                    output.set_len(self.length() as usize);
+                }
+                output
+            }
 
-    /// The length property of a String object indicates the length of a string,
-    /// in UTF-16 code units.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/length)
-    #[wasm_bindgen(method, getter, structural)]
-    pub fn length(this: &JsString) -> u32;
+            /// Returns `true` if this view's backing `ArrayBuffer` has been
+            /// detached, which otherwise silently makes `length()` and
+            /// `byte_length()` read as `0` instead of signaling anything.
+            pub fn is_detached(&self) -> bool {
+                self.buffer().detached()
+            }
 
-    /// The 'at()' method returns a new string consisting of the single UTF-16
-    /// code unit located at the specified offset into the string, counting from
-    /// the end if it's negative.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/at)
-    #[wasm_bindgen(method, js_class = "String")]
-    pub fn at(this: &JsString, index: i32) -> Option<JsString>;
+            /// Like [`length`](Self::length), but returns [`Detached`]
+            /// instead of silently returning `0` when the backing buffer
+            /// has been detached.
+            pub fn length_checked(&self) -> Result<u32, Detached> {
+                if self.is_detached() {
+                    Err(Detached)
+                } else {
+                    Ok(self.length())
+                }
+            }
 
-    /// The String object's `charAt()` method returns a new string consisting of
-    /// the single UTF-16 code unit located at the specified offset into the
-    /// string.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/charAt)
-    #[wasm_bindgen(method, js_class = "String", js_name = charAt)]
-    pub fn char_at(this: &JsString, index: u32) -> JsString;
+            /// Like [`byte_length`](Self::byte_length), but returns
+            /// [`Detached`] instead of silently returning `0` when the
+            /// backing buffer has been detached.
+            pub fn byte_length_checked(&self) -> Result<u32, Detached> {
+                if self.is_detached() {
+                    Err(Detached)
+                } else {
+                    Ok(self.byte_length())
+                }
+            }
+
+            /// Like [`to_vec`](Self::to_vec), but returns [`Detached`]
+            /// instead of an empty `Vec` when the backing buffer has been
+            /// detached.
+            pub fn try_to_vec(&self) -> Result<Vec<$ty>, Detached> {
+                if self.is_detached() {
+                    Err(Detached)
+                } else {
+                    Ok(self.to_vec())
+                }
+            }
+
+            /// Like [`copy_to`](Self::copy_to), but returns [`Detached`]
+            /// instead of panicking on the resulting length mismatch when
+            /// the backing buffer has been detached.
+            pub fn try_copy_to(&self, dst: &mut [$ty]) -> Result<(), Detached> {
+                if self.is_detached() {
+                    Err(Detached)
+                } else {
+                    self.copy_to(dst);
+                    Ok(())
+                }
+            }
 
-    /// The `charCodeAt()` method returns an integer between 0 and 65535
-    /// representing the UTF-16 code unit at the given index (the UTF-16 code
-    /// unit matches the Unicode code point for code points representable in a
-    /// single UTF-16 code unit, but might also be the first code unit of a
-    /// surrogate pair for code points not representable in a single UTF-16 code
-    /// unit, e.g. Unicode code points > 0x10000).  If you want the entire code
-    /// point value, use `codePointAt()`.
-    ///
-    /// Returns `NaN` if index is out of range.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/charCodeAt)
-    #[wasm_bindgen(method, js_class = "String", js_name = charCodeAt)]
-    pub fn char_code_at(this: &JsString, index: u32) -> f64;
+            /// Like [`sort`](Self::sort), but sorts in place according to
+            /// `cmp` instead of ascending numeric order.
+            pub fn sort_by(&self, cmp: &mut dyn FnMut($ty, $ty) -> Ordering) -> $name {
+                self.sort_with_f64(&mut |a, b| ordering_to_f64(cmp(a, b)))
+            }
 
-    /// The `codePointAt()` method returns a non-negative integer that is the
-    /// Unicode code point value.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/codePointAt)
-    #[wasm_bindgen(method, js_class = "String", js_name = codePointAt)]
-    pub fn code_point_at(this: &JsString, pos: u32) -> JsValue;
+            /// Like [`to_sorted`](Self::to_sorted), but sorts according to
+            /// `cmp` instead of ascending numeric order.
+            pub fn to_sorted_by(&self, cmp: &mut dyn FnMut($ty, $ty) -> Ordering) -> $name {
+                self.to_sorted_with_f64(&mut |a, b| ordering_to_f64(cmp(a, b)))
+            }
 
-    /// The `concat()` method concatenates the string arguments to the calling
-    /// string and returns a new string.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/concat)
-    #[wasm_bindgen(method, js_class = "String")]
-    pub fn concat(this: &JsString, string_2: &JsValue) -> JsString;
+            /// Gets the element at `idx`, accepting either a `u32` (from
+            /// the start) or an `i32` (negative-from-end, like
+            /// [`at`](Self::at)) via [`JsIndex`]. Returns `None` if out of
+            /// bounds.
+            pub fn get_at(&self, idx: impl Into<JsIndex>) -> Option<$ty> {
+                let len = self.length();
+                idx.into().resolve(len).map(|i| self.get_index(i))
+            }
 
-    /// The `endsWith()` method determines whether a string ends with the characters of a
-    /// specified string, returning true or false as appropriate.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/endsWith)
-    #[wasm_bindgen(method, js_class = "String", js_name = endsWith)]
-    pub fn ends_with(this: &JsString, search_string: &str, length: i32) -> bool;
+            /// Sets the element at `idx`, accepting either a `u32` or a
+            /// negative-from-end `i32` via [`JsIndex`]. Returns `false`
+            /// without modifying the array if `idx` is out of bounds.
+            pub fn set_at(&self, idx: impl Into<JsIndex>, value: $ty) -> bool {
+                let len = self.length();
+                match idx.into().resolve(len) {
+                    Some(i) => {
+                        self.set_index(i, value);
+                        true
+                    }
+                    None => false,
+                }
+            }
 
-    /// The static `String.fromCharCode()` method returns a string created from
-    /// the specified sequence of UTF-16 code units.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/fromCharCode)
-    ///
-    /// # Notes
-    ///
-    /// There are a few bindings to `from_char_code` in `js-sys`: `from_char_code1`, `from_char_code2`, etc...
-    /// with different arities.
-    ///
-    /// Additionally, this function accepts `u16` for character codes, but
-    /// fixing others requires a breaking change release
-    /// (see https://github.com/rustwasm/wasm-bindgen/issues/1460 for details).
-    #[wasm_bindgen(static_method_of = JsString, js_class = "String", js_name = fromCharCode, variadic)]
-    pub fn from_char_code(char_codes: &[u16]) -> JsString;
+            /// Returns `true` if this array's first elements equal `prefix`,
+            /// element by element.
+            ///
+            /// Only the compared prefix is copied out of the array, via a
+            /// single bulk [`slice`](Self::slice) call.
+            pub fn starts_with(&self, prefix: &[$ty]) -> bool {
+                if prefix.len() as u64 > self.length() as u64 {
+                    return false;
+                }
+                self.slice(0, prefix.len() as u32).to_vec() == prefix
+            }
 
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/fromCharCode)
-    #[wasm_bindgen(static_method_of = JsString, js_class = "String", js_name = fromCharCode)]
-    pub fn from_char_code1(a: u32) -> JsString;
+            /// Returns `true` if this array's last elements equal `suffix`,
+            /// element by element.
+            ///
+            /// Only the compared suffix is copied out of the array, via a
+            /// single bulk [`slice`](Self::slice) call.
+            pub fn ends_with(&self, suffix: &[$ty]) -> bool {
+                let len = self.length();
+                if suffix.len() as u64 > len as u64 {
+                    return false;
+                }
+                self.slice(len - suffix.len() as u32, len).to_vec() == suffix
+            }
 
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/fromCharCode)
-    #[wasm_bindgen(static_method_of = JsString, js_class = "String", js_name = fromCharCode)]
-    pub fn from_char_code2(a: u32, b: u32) -> JsString;
+            /// Returns `true` if this array has the same length and elements
+            /// as `other`.
+            pub fn eq_slice(&self, other: &[$ty]) -> bool {
+                self.length() as usize == other.len() && self.to_vec() == other
+            }
 
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/fromCharCode)
-    #[wasm_bindgen(static_method_of = JsString, js_class = "String", js_name = fromCharCode)]
-    pub fn from_char_code3(a: u32, b: u32, c: u32) -> JsString;
+            /// Returns the index of the first occurrence of `needle` in this
+            /// array, or `None` if it doesn't occur.
+            ///
+            /// An empty `needle` is found at index `0`, matching the usual
+            /// convention for substring/subslice search.
+            ///
+            /// This does a naive search, but copies the haystack out of the
+            /// array in one bulk [`to_vec`](Self::to_vec) call rather than
+            /// one JS round trip per comparison.
+            pub fn find_subslice(&self, needle: &[$ty]) -> Option<u32> {
+                if needle.is_empty() {
+                    return Some(0);
+                }
+                let haystack = self.to_vec();
+                haystack
+                    .windows(needle.len())
+                    .position(|window| window == needle)
+                    .map(|i| i as u32)
+            }
 
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/fromCharCode)
-    #[wasm_bindgen(static_method_of = JsString, js_class = "String", js_name = fromCharCode)]
-    pub fn from_char_code4(a: u32, b: u32, c: u32, d: u32) -> JsString;
+            /// Like [`index_of`](Self::index_of), but returns `None`
+            /// instead of JS's `-1` sentinel when `value` isn't found.
+            pub fn find_index_of(&self, value: $ty, from_index: i32) -> Option<u32> {
+                match self.index_of(value, from_index) {
+                    -1 => None,
+                    i => Some(i as u32),
+                }
+            }
 
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/fromCharCode)
-    #[wasm_bindgen(static_method_of = JsString, js_class = "String", js_name = fromCharCode)]
-    pub fn from_char_code5(a: u32, b: u32, c: u32, d: u32, e: u32) -> JsString;
+            /// Like [`last_index_of`](Self::last_index_of), but returns
+            /// `None` instead of JS's `-1` sentinel when `value` isn't
+            /// found.
+            pub fn find_last_index_of(&self, value: $ty, from_index: i32) -> Option<u32> {
+                match self.last_index_of(value, from_index) {
+                    -1 => None,
+                    i => Some(i as u32),
+                }
+            }
 
-    /// The static `String.fromCodePoint()` method returns a string created by
-    /// using the specified sequence of code points.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/fromCodePoint)
-    ///
-    /// # Exceptions
-    ///
-    /// A RangeError is thrown if an invalid Unicode code point is given
-    ///
-    /// # Notes
-    ///
-    /// There are a few bindings to `from_code_point` in `js-sys`: `from_code_point1`, `from_code_point2`, etc...
-    /// with different arities.
-    #[wasm_bindgen(catch, static_method_of = JsString, js_class = "String", js_name = fromCodePoint, variadic)]
-    pub fn from_code_point(code_points: &[u32]) -> Result<JsString, JsValue>;
+            /// Returns a `Uint8Array` view over this array's underlying
+            /// bytes -- the same buffer and the same
+            /// [`byte_offset`](Self::byte_offset)..`byte_offset +
+            /// byte_length` range -- rather than a copy.
+            ///
+            /// # Aliasing hazard
+            ///
+            /// The returned view shares a buffer with `self`: writing
+            /// through one is visible through the other. If the buffer is
+            /// a `SharedArrayBuffer`, writes from another agent can also
+            /// be observed through either view without synchronization.
+            pub fn bytes_view(&self) -> Uint8Array {
+                Uint8Array::new_with_byte_offset_and_length(&self.buffer(), self.byte_offset(), self.byte_length())
+            }
 
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/fromCodePoint)
-    #[wasm_bindgen(catch, static_method_of = JsString, js_class = "String", js_name = fromCodePoint)]
-    pub fn from_code_point1(a: u32) -> Result<JsString, JsValue>;
+            /// The inverse of [`bytes_view`](Self::bytes_view): reinterprets
+            /// `bytes` as a view of this element type over the same buffer
+            /// and byte range.
+            ///
+            /// Errors with [`AlignmentError`] if `bytes`'s byte length
+            /// isn't a multiple of this type's element size; unlike the
+            /// aliasing hazard above, this is a genuine error rather than
+            /// something the caller is expected to reason about, since a
+            /// misaligned view would silently truncate the final partial
+            /// element.
+            pub fn from_bytes_view(bytes: &Uint8Array) -> Result<$name, AlignmentError> {
+                let element_size = mem::size_of::<$ty>();
+                let byte_length = bytes.byte_length();
+                if byte_length as usize % element_size != 0 {
+                    return Err(AlignmentError {
+                        byte_length,
+                        element_size,
+                    });
+                }
+                Ok($name::new_with_byte_offset_and_length(
+                    &bytes.buffer(),
+                    bytes.byte_offset(),
+                    byte_length / element_size as u32,
+                ))
+            }
 
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/fromCodePoint)
-    #[wasm_bindgen(catch, static_method_of = JsString, js_class = "String", js_name = fromCodePoint)]
-    pub fn from_code_point2(a: u32, b: u32) -> Result<JsString, JsValue>;
+            /// Streams this array through `f` in chunks of at most
+            /// `chunk_len` elements, bulk-copying each chunk into a single
+            /// reused Rust buffer (a [`subarray`](Self::subarray) view per
+            /// chunk, copied out with [`copy_to`](Self::copy_to)) rather than
+            /// collecting the whole array with
+            /// [`to_vec`](Self::to_vec) first -- useful for bounding peak
+            /// memory use on a very large typed array.
+            ///
+            /// `f` is called with each chunk's starting element offset and
+            /// its contents; returning [`ControlFlow::Break`] stops
+            /// iteration early and `process_chunks` returns that value.
+            pub fn process_chunks<R>(
+                &self,
+                chunk_len: u32,
+                mut f: impl FnMut(u64, &[$ty]) -> ControlFlow<R>,
+            ) -> Option<R> {
+                let len = self.length();
+                let mut buf = alloc::vec![$conv(0.0); chunk_len as usize];
+                let mut offset = 0u32;
+
+                while offset < len {
+                    let this_chunk_len = core::cmp::min(chunk_len, len - offset);
+                    let chunk = &mut buf[..this_chunk_len as usize];
+                    self.subarray(offset, offset + this_chunk_len).copy_to(chunk);
+
+                    match f(offset as u64, chunk) {
+                        ControlFlow::Continue(()) => {}
+                        ControlFlow::Break(r) => return Some(r),
+                    }
+
+                    offset += this_chunk_len;
+                }
 
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/fromCodePoint)
-    #[wasm_bindgen(catch, static_method_of = JsString, js_class = "String", js_name = fromCodePoint)]
-    pub fn from_code_point3(a: u32, b: u32, c: u32) -> Result<JsString, JsValue>;
+                None
+            }
 
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/fromCodePoint)
-    #[wasm_bindgen(catch, static_method_of = JsString, js_class = "String", js_name = fromCodePoint)]
-    pub fn from_code_point4(a: u32, b: u32, c: u32, d: u32) -> Result<JsString, JsValue>;
+            /// Copies the elements in `src_range` into `dst`, without
+            /// creating an intermediate [`subarray`](Self::subarray) JS
+            /// object -- worth it for small ranges pulled out of a much
+            /// larger array.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `src_range`'s length doesn't equal `dst.len()`, or
+            /// if `src_range`'s end is past this array's length.
+            pub fn copy_to_range(&self, src_range: Range<u32>, dst: &mut [$ty]) {
+                let range_len = src_range.end.saturating_sub(src_range.start);
+                core::assert_eq!(range_len as usize, dst.len());
+                core::assert!(src_range.end <= self.length());
+
+                for (i, slot) in dst.iter_mut().enumerate() {
+                    *slot = self.get_index(src_range.start + i as u32);
+                }
+            }
+        }
 
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/fromCodePoint)
-    #[wasm_bindgen(catch, static_method_of = JsString, js_class = "String", js_name = fromCodePoint)]
-    pub fn from_code_point5(a: u32, b: u32, c: u32, d: u32, e: u32) -> Result<JsString, JsValue>;
+        impl<'a> From<&'a [$ty]> for $name {
+            #[inline]
+            fn from(slice: &'a [$ty]) -> $name {
+                // This is safe because the `new` function makes a copy if its argument is a TypedArray
+                unsafe { $name::new(&$name::view(slice)) }
+            }
+        }
 
-    /// The `includes()` method determines whether one string may be found
-    /// within another string, returning true or false as appropriate.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/includes)
-    #[wasm_bindgen(method, js_class = "String")]
-    pub fn includes(this: &JsString, search_string: &str, position: i32) -> bool;
+        impl Default for $name {
+            fn default() -> Self {
+                Self::new(&JsValue::UNDEFINED.unchecked_into())
+            }
+        }
+    )*);
+}
 
-    /// The `indexOf()` method returns the index within the calling String
-    /// object of the first occurrence of the specified value, starting the
-    /// search at fromIndex.  Returns -1 if the value is not found.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/indexOf)
-    #[wasm_bindgen(method, js_class = "String", js_name = indexOf)]
-    pub fn index_of(this: &JsString, search_value: &str, from_index: i32) -> i32;
+arrays! {
+    /// `Int8Array()`
+    /// https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Int8Array
+    Int8Array: i8 via js_wrap_i8,
 
-    /// The `lastIndexOf()` method returns the index within the calling String
-    /// object of the last occurrence of the specified value, searching
-    /// backwards from fromIndex.  Returns -1 if the value is not found.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/lastIndexOf)
-    #[wasm_bindgen(method, js_class = "String", js_name = lastIndexOf)]
-    pub fn last_index_of(this: &JsString, search_value: &str, from_index: i32) -> i32;
+    /// `Int16Array()`
+    /// https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Int16Array
+    Int16Array: i16 via js_wrap_i16,
 
-    /// The `localeCompare()` method returns a number indicating whether
-    /// a reference string comes before or after or is the same as
-    /// the given string in sort order.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/localeCompare)
-    #[wasm_bindgen(method, js_class = "String", js_name = localeCompare)]
-    pub fn locale_compare(
-        this: &JsString,
-        compare_string: &str,
-        locales: &Array,
-        options: &Object,
-    ) -> i32;
+    /// `Int32Array()`
+    /// https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Int32Array
+    Int32Array: i32 via js_wrap_i32,
 
-    /// The `match()` method retrieves the matches when matching a string against a regular expression.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/match)
-    #[wasm_bindgen(method, js_class = "String", js_name = match)]
-    pub fn match_(this: &JsString, pattern: &RegExp) -> Option<Object>;
+    /// `Uint8Array()`
+    /// https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Uint8Array
+    Uint8Array: u8 via js_wrap_u8,
 
-    /// The `match_all()` method is similar to `match()`, but gives an iterator of `exec()` arrays, which preserve capture groups.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/matchAll)
-    #[wasm_bindgen(method, js_class = "String", js_name = matchAll)]
-    pub fn match_all(this: &JsString, pattern: &RegExp) -> Iterator;
+    /// `Uint8ClampedArray()`
+    /// https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Uint8ClampedArray
+    Uint8ClampedArray: u8 via js_clamp_u8,
 
-    /// The `normalize()` method returns the Unicode Normalization Form
-    /// of a given string (if the value isn't a string, it will be converted to one first).
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/normalize)
-    #[wasm_bindgen(method, js_class = "String")]
-    pub fn normalize(this: &JsString, form: &str) -> JsString;
+    /// `Uint16Array()`
+    /// https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Uint16Array
+    Uint16Array: u16 via js_wrap_u16,
 
-    /// The `padEnd()` method pads the current string with a given string
-    /// (repeated, if needed) so that the resulting string reaches a given
-    /// length. The padding is applied from the end (right) of the current
-    /// string.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/padEnd)
-    #[wasm_bindgen(method, js_class = "String", js_name = padEnd)]
-    pub fn pad_end(this: &JsString, target_length: u32, pad_string: &str) -> JsString;
+    /// `Uint32Array()`
+    /// https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Uint32Array
+    Uint32Array: u32 via js_wrap_u32,
 
-    /// The `padStart()` method pads the current string with another string
-    /// (repeated, if needed) so that the resulting string reaches the given
-    /// length. The padding is applied from the start (left) of the current
-    /// string.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/padStart)
-    #[wasm_bindgen(method, js_class = "String", js_name = padStart)]
-    pub fn pad_start(this: &JsString, target_length: u32, pad_string: &str) -> JsString;
+    /// `Float32Array()`
+    /// https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Float32Array
+    Float32Array: f32 via js_wrap_f32,
 
-    /// The `repeat()` method constructs and returns a new string which contains the specified
-    /// number of copies of the string on which it was called, concatenated together.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/repeat)
-    #[wasm_bindgen(method, js_class = "String")]
-    pub fn repeat(this: &JsString, count: i32) -> JsString;
+    /// `Float64Array()`
+    /// https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Float64Array
+    Float64Array: f64 via js_identity_f64,
 
-    /// The `replace()` method returns a new string with some or all matches of a pattern
-    /// replaced by a replacement. The pattern can be a string or a RegExp, and
-    /// the replacement can be a string or a function to be called for each match.
-    ///
-    /// Note: The original string will remain unchanged.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/replace)
-    #[wasm_bindgen(method, js_class = "String")]
-    pub fn replace(this: &JsString, pattern: &str, replacement: &str) -> JsString;
+    /// `BigInt64Array()`
+    /// https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/BigInt64Array
+    BigInt64Array: i64 via js_wrap_i64,
 
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/replace)
-    #[wasm_bindgen(method, js_class = "String", js_name = replace)]
-    pub fn replace_with_function(
-        this: &JsString,
-        pattern: &str,
-        replacement: &Function,
-    ) -> JsString;
+    /// `BigUint64Array()`
+    /// https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/BigUint64Array
+    BigUint64Array: u64 via js_wrap_u64,
+}
+
+// Despite their element being a JS `BigInt`, `BigInt64Array` and
+// `BigUint64Array` get `to_vec`, `copy_to`, `copy_from`, `From<&[i64/u64]>`,
+// and `get_index`/`set_index` for free from the `arrays!` macro above. The
+// first four go through the typed array's raw bytes in Wasm linear memory
+// rather than boxing each element, so there's no per-element `BigInt`
+// allocation; `get_index`/`set_index` convert the one element they touch
+// between `BigInt` and `i64`/`u64` per call. No separate hand-written
+// implementation is needed.
+
+macro_rules! set_converting_impl {
+    ($($name:ident: $ty:ident via $conv:ident,)*) => ($(
+        impl $name {
+            /// Stores `src` into this typed array starting at `offset`,
+            /// converting each element into this array's element type using
+            /// the same coercion rules JS applies when `set()` is given a
+            /// plain array of a different numeric type (wrapping for most
+            /// typed arrays, clamping with round-half-to-even for
+            /// `Uint8ClampedArray`).
+            ///
+            /// The converted values are written through a single temporary
+            /// typed array, so the number of JS boundary calls is the same
+            /// regardless of `src`'s length.
+            ///
+            /// Returns a `RangeError` if `offset + src.len()` would write
+            /// past the end of this array.
+            pub fn set_converting<S: PrimCast>(
+                &self,
+                src: &[S],
+                offset: u32,
+            ) -> Result<(), RangeError> {
+                if (offset as u64) + (src.len() as u64) > self.length() as u64 {
+                    return Err(RangeError::new(
+                        "set_converting: offset + src.len() exceeds the typed array's length",
+                    ));
+                }
+                let converted: Vec<$ty> = src.iter().map(|v| $conv(v.to_f64_lossy())).collect();
+                let view: $name = (&converted[..]).into();
+                self.set(&view, offset);
+                Ok(())
+            }
+        }
+    )*);
+}
 
-    #[wasm_bindgen(method, js_class = "String", js_name = replace)]
-    pub fn replace_by_pattern(this: &JsString, pattern: &RegExp, replacement: &str) -> JsString;
+// `BigInt64Array` and `BigUint64Array` are deliberately left out here: JS's
+// `TypedArray.prototype.set()` doesn't apply `ToNumber` coercion to them at
+// all (it requires actual `BigInt` sources, raising a `TypeError` on a
+// plain number), so there's no `ToNumber`-style coercion for
+// `set_converting` to emulate in the first place. Piping a Rust `i64`/`u64`
+// source through the `f64` pivot `PrimCast::to_f64_lossy` uses for every
+// other element type would also silently lose precision above 2^53, which
+// compounds the mismatch rather than approximating it.
+set_converting_impl! {
+    Int8Array: i8 via js_wrap_i8,
+    Int16Array: i16 via js_wrap_i16,
+    Int32Array: i32 via js_wrap_i32,
+    Uint8Array: u8 via js_wrap_u8,
+    Uint8ClampedArray: u8 via js_clamp_u8,
+    Uint16Array: u16 via js_wrap_u16,
+    Uint32Array: u32 via js_wrap_u32,
+    Float32Array: f32 via js_wrap_f32,
+    Float64Array: f64 via js_identity_f64,
+}
 
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/replace)
-    #[wasm_bindgen(method, js_class = "String", js_name = replace)]
-    pub fn replace_by_pattern_with_function(
-        this: &JsString,
-        pattern: &RegExp,
-        replacement: &Function,
-    ) -> JsString;
+impl Float32Array {
+    /// Copies every `stride`-th element of this array into `dst`, starting
+    /// at `offset`, e.g. extracting the `x` component out of packed
+    /// `(x, y, z, u, v)` vertex data without a hand-rolled strided loop at
+    /// each call site. Returns the number of elements copied, which is
+    /// `min(dst.len(), elements available at that stride from offset)`.
+    ///
+    /// Done with a single bulk [`to_vec`](Self::to_vec) followed by a
+    /// Rust-side strided read, rather than one JS call per element.
+    pub fn strided_copy_to(&self, dst: &mut [f32], offset: u32, stride: u32) -> Result<usize, RangeError> {
+        if stride == 0 {
+            return Err(RangeError::new("strided_copy_to: stride must be nonzero"));
+        }
+        let source = self.to_vec();
+        let mut count = 0;
+        let mut i = offset as usize;
+        while i < source.len() && count < dst.len() {
+            dst[count] = source[i];
+            count += 1;
+            i += stride as usize;
+        }
+        Ok(count)
+    }
 
-    /// The `replace_all()` method returns a new string with all matches of a pattern
-    /// replaced by a replacement. The pattern can be a string or a global RegExp, and
-    /// the replacement can be a string or a function to be called for each match.
-    ///
-    /// Note: The original string will remain unchanged.
+    /// Writes `src` into this array at every `stride`-th position starting
+    /// at `offset`, leaving the elements in between untouched. Returns the
+    /// number of elements written.
     ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/replaceAll)
-    #[wasm_bindgen(method, js_class = "String", js_name = replaceAll)]
-    pub fn replace_all(this: &JsString, pattern: &str, replacement: &str) -> JsString;
+    /// Done with a single bulk [`to_vec`](Self::to_vec)/
+    /// [`copy_from`](Self::copy_from) round trip and a Rust-side strided
+    /// write in between, rather than one JS call per element.
+    pub fn strided_copy_from(&self, src: &[f32], offset: u32, stride: u32) -> Result<usize, RangeError> {
+        if stride == 0 {
+            return Err(RangeError::new("strided_copy_from: stride must be nonzero"));
+        }
+        let mut dest = self.to_vec();
+        let mut count = 0;
+        let mut i = offset as usize;
+        while i < dest.len() && count < src.len() {
+            dest[i] = src[count];
+            count += 1;
+            i += stride as usize;
+        }
+        self.copy_from(&dest);
+        Ok(count)
+    }
 
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/replaceAll)
-    #[wasm_bindgen(method, js_class = "String", js_name = replaceAll)]
-    pub fn replace_all_with_function(
-        this: &JsString,
-        pattern: &str,
-        replacement: &Function,
-    ) -> JsString;
+    /// Splits this array into `stride` separate arrays, one per
+    /// interleaved channel, e.g. turning packed `(x, y, z)` vertex data
+    /// into three arrays of `x`, `y`, and `z` values.
+    ///
+    /// Errors if `stride` is zero or doesn't evenly divide this array's
+    /// length. Done with a single bulk [`to_vec`](Self::to_vec) followed by
+    /// a Rust-side demux.
+    pub fn deinterleave(&self, stride: u32) -> Result<Vec<Float32Array>, RangeError> {
+        if stride == 0 {
+            return Err(RangeError::new("deinterleave: stride must be nonzero"));
+        }
+        let source = self.to_vec();
+        let stride = stride as usize;
+        if source.len() % stride != 0 {
+            return Err(RangeError::new("deinterleave: length is not a multiple of stride"));
+        }
+        let channel_len = source.len() / stride;
+        let mut channels: Vec<Vec<f32>> = alloc::vec![Vec::with_capacity(channel_len); stride];
+        for (i, value) in source.into_iter().enumerate() {
+            channels[i % stride].push(value);
+        }
+        Ok(channels.into_iter().map(|channel| Float32Array::from(&channel[..])).collect())
+    }
 
-    #[wasm_bindgen(method, js_class = "String", js_name = replaceAll)]
-    pub fn replace_all_by_pattern(this: &JsString, pattern: &RegExp, replacement: &str)
-        -> JsString;
+    /// The inverse of [`deinterleave`](Self::deinterleave): packs equal-length
+    /// `parts` into a single array, interleaving element `i` of each part in
+    /// order.
+    ///
+    /// Errors if `parts` isn't empty and its slices don't all have the same
+    /// length.
+    pub fn interleave(parts: &[&[f32]]) -> Result<Float32Array, RangeError> {
+        let len = match parts.first() {
+            Some(first) => first.len(),
+            None => return Ok(Float32Array::new_with_length(0)),
+        };
+        if parts.iter().any(|part| part.len() != len) {
+            return Err(RangeError::new("interleave: all parts must have equal length"));
+        }
+        let mut out = Vec::with_capacity(len * parts.len());
+        for i in 0..len {
+            for part in parts {
+                out.push(part[i]);
+            }
+        }
+        Ok(Float32Array::from(&out[..]))
+    }
+}
 
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/replaceAll)
-    #[wasm_bindgen(method, js_class = "String", js_name = replaceAll)]
-    pub fn replace_all_by_pattern_with_function(
-        this: &JsString,
-        pattern: &RegExp,
-        replacement: &Function,
-    ) -> JsString;
+impl Uint16Array {
+    /// Copies every `stride`-th element of this array into `dst`, starting
+    /// at `offset`, e.g. extracting one index channel out of packed index
+    /// buffer data. Returns the number of elements copied, which is
+    /// `min(dst.len(), elements available at that stride from offset)`.
+    ///
+    /// Done with a single bulk [`to_vec`](Self::to_vec) followed by a
+    /// Rust-side strided read, rather than one JS call per element.
+    pub fn strided_copy_to(&self, dst: &mut [u16], offset: u32, stride: u32) -> Result<usize, RangeError> {
+        if stride == 0 {
+            return Err(RangeError::new("strided_copy_to: stride must be nonzero"));
+        }
+        let source = self.to_vec();
+        let mut count = 0;
+        let mut i = offset as usize;
+        while i < source.len() && count < dst.len() {
+            dst[count] = source[i];
+            count += 1;
+            i += stride as usize;
+        }
+        Ok(count)
+    }
 
-    /// The `search()` method executes a search for a match between
-    /// a regular expression and this String object.
+    /// Writes `src` into this array at every `stride`-th position starting
+    /// at `offset`, leaving the elements in between untouched. Returns the
+    /// number of elements written.
     ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/search)
-    #[wasm_bindgen(method, js_class = "String")]
-    pub fn search(this: &JsString, pattern: &RegExp) -> i32;
+    /// Done with a single bulk [`to_vec`](Self::to_vec)/
+    /// [`copy_from`](Self::copy_from) round trip and a Rust-side strided
+    /// write in between, rather than one JS call per element.
+    pub fn strided_copy_from(&self, src: &[u16], offset: u32, stride: u32) -> Result<usize, RangeError> {
+        if stride == 0 {
+            return Err(RangeError::new("strided_copy_from: stride must be nonzero"));
+        }
+        let mut dest = self.to_vec();
+        let mut count = 0;
+        let mut i = offset as usize;
+        while i < dest.len() && count < src.len() {
+            dest[i] = src[count];
+            count += 1;
+            i += stride as usize;
+        }
+        self.copy_from(&dest);
+        Ok(count)
+    }
 
-    /// The `slice()` method extracts a section of a string and returns it as a
-    /// new string, without modifying the original string.
+    /// Splits this array into `stride` separate arrays, one per
+    /// interleaved channel.
     ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/slice)
-    #[wasm_bindgen(method, js_class = "String")]
-    pub fn slice(this: &JsString, start: u32, end: u32) -> JsString;
+    /// Errors if `stride` is zero or doesn't evenly divide this array's
+    /// length. Done with a single bulk [`to_vec`](Self::to_vec) followed by
+    /// a Rust-side demux.
+    pub fn deinterleave(&self, stride: u32) -> Result<Vec<Uint16Array>, RangeError> {
+        if stride == 0 {
+            return Err(RangeError::new("deinterleave: stride must be nonzero"));
+        }
+        let source = self.to_vec();
+        let stride = stride as usize;
+        if source.len() % stride != 0 {
+            return Err(RangeError::new("deinterleave: length is not a multiple of stride"));
+        }
+        let channel_len = source.len() / stride;
+        let mut channels: Vec<Vec<u16>> = alloc::vec![Vec::with_capacity(channel_len); stride];
+        for (i, value) in source.into_iter().enumerate() {
+            channels[i % stride].push(value);
+        }
+        Ok(channels.into_iter().map(|channel| Uint16Array::from(&channel[..])).collect())
+    }
 
-    /// The `split()` method splits a String object into an array of strings by separating the string
-    /// into substrings, using a specified separator string to determine where to make each split.
+    /// The inverse of [`deinterleave`](Self::deinterleave): packs equal-length
+    /// `parts` into a single array, interleaving element `i` of each part in
+    /// order.
     ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/split)
-    #[wasm_bindgen(method, js_class = "String")]
-    pub fn split(this: &JsString, separator: &str) -> Array;
+    /// Errors if `parts` isn't empty and its slices don't all have the same
+    /// length.
+    pub fn interleave(parts: &[&[u16]]) -> Result<Uint16Array, RangeError> {
+        let len = match parts.first() {
+            Some(first) => first.len(),
+            None => return Ok(Uint16Array::new_with_length(0)),
+        };
+        if parts.iter().any(|part| part.len() != len) {
+            return Err(RangeError::new("interleave: all parts must have equal length"));
+        }
+        let mut out = Vec::with_capacity(len * parts.len());
+        for i in 0..len {
+            for part in parts {
+                out.push(part[i]);
+            }
+        }
+        Ok(Uint16Array::from(&out[..]))
+    }
+}
 
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/split)
-    #[wasm_bindgen(method, js_class = "String", js_name = split)]
-    pub fn split_limit(this: &JsString, separator: &str, limit: u32) -> Array;
+/// Options controlling the layout of [`Uint8Array::hexdump`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HexdumpOptions {
+    bytes_per_line: usize,
+    max_bytes: usize,
+}
 
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/split)
-    #[wasm_bindgen(method, js_class = "String", js_name = split)]
-    pub fn split_by_pattern(this: &JsString, pattern: &RegExp) -> Array;
+impl HexdumpOptions {
+    /// Starts a new builder: 16 bytes per line, no truncation.
+    pub fn new() -> Self {
+        HexdumpOptions {
+            bytes_per_line: 16,
+            max_bytes: usize::MAX,
+        }
+    }
 
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/split)
-    #[wasm_bindgen(method, js_class = "String", js_name = split)]
-    pub fn split_by_pattern_limit(this: &JsString, pattern: &RegExp, limit: u32) -> Array;
+    /// Sets how many bytes are shown per line. Panics if `count` is zero.
+    pub fn bytes_per_line(mut self, count: usize) -> Self {
+        assert_ne!(count, 0, "bytes_per_line must be nonzero");
+        self.bytes_per_line = count;
+        self
+    }
 
-    /// The `startsWith()` method determines whether a string begins with the
-    /// characters of a specified string, returning true or false as
-    /// appropriate.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/startsWith)
-    #[wasm_bindgen(method, js_class = "String", js_name = startsWith)]
-    pub fn starts_with(this: &JsString, search_string: &str, position: u32) -> bool;
+    /// Stops after `count` bytes, appending a final `... (N bytes total)`
+    /// line if the array is longer than that.
+    pub fn max_bytes(mut self, count: usize) -> Self {
+        self.max_bytes = count;
+        self
+    }
+}
 
-    /// The `substring()` method returns the part of the string between the
-    /// start and end indexes, or to the end of the string.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/substring)
-    #[wasm_bindgen(method, js_class = "String")]
-    pub fn substring(this: &JsString, index_start: u32, index_end: u32) -> JsString;
+impl Default for HexdumpOptions {
+    fn default() -> Self {
+        HexdumpOptions::new()
+    }
+}
 
-    /// The `substr()` method returns the part of a string between
-    /// the start index and a number of characters after it.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/substr)
-    #[wasm_bindgen(method, js_class = "String")]
-    pub fn substr(this: &JsString, start: i32, length: i32) -> JsString;
+/// Why [`Uint8Array::from_hex_str`] failed to parse its input.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HexParseError {
+    /// Byte offset into the input string where parsing failed.
+    pub position: usize,
+}
 
-    /// The `toLocaleLowerCase()` method returns the calling string value converted to lower case,
-    /// according to any locale-specific case mappings.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/toLocaleLowerCase)
-    #[wasm_bindgen(method, js_class = "String", js_name = toLocaleLowerCase)]
-    pub fn to_locale_lower_case(this: &JsString, locale: Option<&str>) -> JsString;
+impl fmt::Display for HexParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid hex digit at byte offset {}", self.position)
+    }
+}
 
-    /// The `toLocaleUpperCase()` method returns the calling string value converted to upper case,
-    /// according to any locale-specific case mappings.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/ja/docs/Web/JavaScript/Reference/Global_Objects/String/toLocaleUpperCase)
-    #[wasm_bindgen(method, js_class = "String", js_name = toLocaleUpperCase)]
-    pub fn to_locale_upper_case(this: &JsString, locale: Option<&str>) -> JsString;
+fn hex_digit(byte: u8) -> Option<u8> {
+    match byte {
+        b'0'..=b'9' => Some(byte - b'0'),
+        b'a'..=b'f' => Some(byte - b'a' + 10),
+        b'A'..=b'F' => Some(byte - b'A' + 10),
+        _ => None,
+    }
+}
 
-    /// The `toLowerCase()` method returns the calling string value
-    /// converted to lower case.
+impl Uint8Array {
+    /// Renders this array as a classic offset/hex/ASCII-gutter hex dump,
+    /// e.g.:
     ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/toLowerCase)
-    #[wasm_bindgen(method, js_class = "String", js_name = toLowerCase)]
-    pub fn to_lower_case(this: &JsString) -> JsString;
-
-    /// The `toString()` method returns a string representing the specified
-    /// object.
+    /// ```text
+    /// 00000000  48 65 6c 6c 6f 2c 20 77  6f 72 6c 64 21             Hello, world!
+    /// ```
     ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/toString)
-    #[wasm_bindgen(method, js_class = "String", js_name = toString)]
-    pub fn to_string(this: &JsString) -> JsString;
+    /// Unprintable bytes (outside `0x20..=0x7e`) show as `.` in the ASCII
+    /// gutter. Done with a single bulk [`to_vec`](Self::to_vec) followed by
+    /// Rust-side formatting, rather than one JS call per byte.
+    pub fn hexdump(&self, opts: &HexdumpOptions) -> String {
+        let bytes = self.to_vec();
+        let total = bytes.len();
+        let shown = bytes.len().min(opts.max_bytes);
+        let mut out = String::new();
+        for (line_start, line) in bytes[..shown].chunks(opts.bytes_per_line).enumerate() {
+            if line_start > 0 {
+                out.push('\n');
+            }
+            let _ = write!(out, "{:08x}  ", line_start * opts.bytes_per_line);
+            for (i, byte) in line.iter().enumerate() {
+                let _ = write!(out, "{:02x} ", byte);
+                if i % 8 == 7 {
+                    out.push(' ');
+                }
+            }
+            let full_width = opts.bytes_per_line * 3 + opts.bytes_per_line / 8;
+            let written = line.len() * 3 + line.len() / 8;
+            for _ in written..full_width {
+                out.push(' ');
+            }
+            out.push_str("  ");
+            for byte in line {
+                let ch = if (0x20..=0x7e).contains(byte) { *byte as char } else { '.' };
+                out.push(ch);
+            }
+        }
+        if shown < total {
+            if shown > 0 {
+                out.push('\n');
+            }
+            let _ = write!(out, "... ({} bytes total)", total);
+        }
+        out
+    }
 
-    /// The `toUpperCase()` method returns the calling string value converted to
-    /// uppercase (the value will be converted to a string if it isn't one).
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/toUpperCase)
-    #[wasm_bindgen(method, js_class = "String", js_name = toUpperCase)]
-    pub fn to_upper_case(this: &JsString) -> JsString;
+    /// Encodes this array's bytes as a lowercase hex string, two characters
+    /// per byte, with no separators.
+    pub fn to_hex_string(&self) -> String {
+        let bytes = self.to_vec();
+        let mut out = String::with_capacity(bytes.len() * 2);
+        for byte in bytes {
+            let _ = write!(out, "{:02x}", byte);
+        }
+        out
+    }
 
-    /// The `trim()` method removes whitespace from both ends of a string.
-    /// Whitespace in this context is all the whitespace characters (space, tab,
-    /// no-break space, etc.) and all the line terminator characters (LF, CR,
-    /// etc.).
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/trim)
-    #[wasm_bindgen(method, js_class = "String")]
-    pub fn trim(this: &JsString) -> JsString;
+    /// Parses a string of hex digits (as produced by
+    /// [`to_hex_string`](Self::to_hex_string)) into a new [`Uint8Array`].
+    /// `s` must have an even number of hex digit characters; whitespace is
+    /// not permitted between them.
+    pub fn from_hex_str(s: &str) -> Result<Uint8Array, HexParseError> {
+        let bytes = s.as_bytes();
+        if bytes.len() % 2 != 0 {
+            return Err(HexParseError { position: bytes.len() - 1 });
+        }
+        let mut out = Vec::with_capacity(bytes.len() / 2);
+        for (i, pair) in bytes.chunks(2).enumerate() {
+            let hi = hex_digit(pair[0]).ok_or(HexParseError { position: i * 2 })?;
+            let lo = hex_digit(pair[1]).ok_or(HexParseError { position: i * 2 + 1 })?;
+            out.push(hi << 4 | lo);
+        }
+        Ok(Uint8Array::from(&out[..]))
+    }
+}
 
-    /// The `trimEnd()` method removes whitespace from the end of a string.
-    /// `trimRight()` is an alias of this method.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/trimEnd)
-    #[wasm_bindgen(method, js_class = "String", js_name = trimEnd)]
-    pub fn trim_end(this: &JsString) -> JsString;
+impl fmt::LowerHex for Uint8Array {
+    /// Formats up to the first 64 bytes as lowercase hex, appending `...`
+    /// if there are more.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const LIMIT: usize = 64;
+        let bytes = self.to_vec();
+        for byte in bytes.iter().take(LIMIT) {
+            write!(f, "{:02x}", byte)?;
+        }
+        if bytes.len() > LIMIT {
+            f.write_str("...")?;
+        }
+        Ok(())
+    }
+}
 
-    /// The `trimEnd()` method removes whitespace from the end of a string.
-    /// `trimRight()` is an alias of this method.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/trimEnd)
-    #[wasm_bindgen(method, js_class = "String", js_name = trimRight)]
-    pub fn trim_right(this: &JsString) -> JsString;
+/// A `application/x-www-form-urlencoded` query-string codec that doesn't
+/// need `web_sys::UrlSearchParams`: splitting, `+`-for-space handling, and
+/// percent-decode error reporting all happen here in Rust, with only the
+/// percent-encoding of individual components delegated to the existing
+/// [`encode_uri_component`] global.
+///
+/// Pairs are represented the same way `Map`/`Array` already do for
+/// key-value data in this crate: a key/value [`Map`] (string keys and
+/// values), or an [`Array`] of two-element `[key, value]` `Array`s when
+/// repeated keys need to be preserved.
+pub mod query {
+    use super::*;
 
-    /// The `trimStart()` method removes whitespace from the beginning of a
-    /// string. `trimLeft()` is an alias of this method.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/trimStart)
-    #[wasm_bindgen(method, js_class = "String", js_name = trimStart)]
-    pub fn trim_start(this: &JsString) -> JsString;
+    /// A percent-escape in the input couldn't be decoded: either it was
+    /// truncated (a `%` not followed by two hex digits) or the decoded
+    /// bytes weren't valid UTF-8. `position` is the byte offset of the
+    /// offending `%` (or invalid byte) within the string passed to
+    /// [`decode`].
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct QueryDecodeError {
+        pub position: usize,
+    }
 
-    /// The `trimStart()` method removes whitespace from the beginning of a
-    /// string. `trimLeft()` is an alias of this method.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/trimStart)
-    #[wasm_bindgen(method, js_class = "String", js_name = trimLeft)]
-    pub fn trim_left(this: &JsString) -> JsString;
+    impl fmt::Display for QueryDecodeError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(
+                f,
+                "invalid percent-encoding at byte offset {}",
+                self.position
+            )
+        }
+    }
 
-    /// The `valueOf()` method returns the primitive value of a `String` object.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/valueOf)
-    #[wasm_bindgen(method, js_class = "String", js_name = valueOf)]
-    pub fn value_of(this: &JsString) -> JsString;
+    #[cfg(feature = "std")]
+    impl std::error::Error for QueryDecodeError {}
 
-    /// The static `raw()` method is a tag function of template literals,
-    /// similar to the `r` prefix in Python or the `@` prefix in C# for string literals.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/raw)
-    #[wasm_bindgen(catch, variadic, static_method_of = JsString, js_class = "String")]
-    pub fn raw(call_site: &Object, substitutions: &Array) -> Result<JsString, JsValue>;
+    fn encode_component(s: &str, space_as_plus: bool) -> String {
+        let encoded = String::from(encode_uri_component(s));
+        if space_as_plus {
+            encoded.replace("%20", "+")
+        } else {
+            encoded
+        }
+    }
+
+    /// Encodes `pairs` (a `Map` of string keys to string values) as a
+    /// query string. When `space_as_plus` is set, spaces are encoded as
+    /// `+` rather than `%20`, matching the historical
+    /// `application/x-www-form-urlencoded` convention used by HTML forms.
+    pub fn encode(pairs: &Map, space_as_plus: bool) -> JsString {
+        let mut out = String::new();
+        pairs.for_each(&mut |value, key| {
+            if !out.is_empty() {
+                out.push('&');
+            }
+            out.push_str(&encode_component(&key.as_string().unwrap_or_default(), space_as_plus));
+            out.push('=');
+            out.push_str(&encode_component(&value.as_string().unwrap_or_default(), space_as_plus));
+        });
+        JsString::from(out)
+    }
+
+    /// Same as [`encode`], but takes an `Array` of `[key, value]` pairs
+    /// instead of a `Map`, so repeated keys survive encoding.
+    pub fn encode_multi(pairs: &Array, space_as_plus: bool) -> JsString {
+        let mut out = String::new();
+        for entry in pairs.iter() {
+            let pair: Array = entry.unchecked_into();
+            if !out.is_empty() {
+                out.push('&');
+            }
+            out.push_str(&encode_component(&pair.get(0).as_string().unwrap_or_default(), space_as_plus));
+            out.push('=');
+            out.push_str(&encode_component(&pair.get(1).as_string().unwrap_or_default(), space_as_plus));
+        }
+        JsString::from(out)
+    }
+
+    fn byte_offset(outer: &str, inner: &str) -> usize {
+        inner.as_ptr() as usize - outer.as_ptr() as usize
+    }
+
+    fn percent_decode(s: &str, plus_as_space: bool) -> Result<String, QueryDecodeError> {
+        let bytes = s.as_bytes();
+        let mut out: Vec<u8> = Vec::with_capacity(bytes.len());
+        let mut positions: Vec<usize> = Vec::with_capacity(bytes.len());
+        let mut i = 0;
+        while i < bytes.len() {
+            match bytes[i] {
+                b'+' if plus_as_space => {
+                    out.push(b' ');
+                    positions.push(i);
+                    i += 1;
+                }
+                b'%' => {
+                    if i + 2 >= bytes.len() {
+                        return Err(QueryDecodeError { position: i });
+                    }
+                    let hi = hex_digit(bytes[i + 1]).ok_or(QueryDecodeError { position: i + 1 })?;
+                    let lo = hex_digit(bytes[i + 2]).ok_or(QueryDecodeError { position: i + 2 })?;
+                    out.push((hi << 4) | lo);
+                    positions.push(i);
+                    i += 3;
+                }
+                b => {
+                    out.push(b);
+                    positions.push(i);
+                    i += 1;
+                }
+            }
+        }
+        String::from_utf8(out)
+            .map_err(|e| QueryDecodeError { position: positions[e.utf8_error().valid_up_to()] })
+    }
+
+    /// Decodes a query string (without a leading `?`) into an `Array` of
+    /// `[key, value]` pairs, in order, preserving repeated keys. A key
+    /// without a following `=` decodes to an empty-string value. Both `+`
+    /// and `%20` decode to a space.
+    pub fn decode(s: &str) -> Result<Array, QueryDecodeError> {
+        let out = Array::new();
+        for segment in s.split('&') {
+            if segment.is_empty() {
+                continue;
+            }
+            let (raw_key, raw_value) = match segment.find('=') {
+                Some(idx) => (&segment[..idx], &segment[idx + 1..]),
+                None => (segment, ""),
+            };
+            let key = percent_decode(raw_key, true)
+                .map_err(|e| QueryDecodeError { position: e.position + byte_offset(s, raw_key) })?;
+            let value = percent_decode(raw_value, true)
+                .map_err(|e| QueryDecodeError { position: e.position + byte_offset(s, raw_value) })?;
+            let pair = Array::new();
+            pair.push(&JsValue::from_str(&key));
+            pair.push(&JsValue::from_str(&value));
+            out.push(&pair);
+        }
+        Ok(out)
+    }
+}
 
-    /// The static `raw()` method is a tag function of template literals,
-    /// similar to the `r` prefix in Python or the `@` prefix in C# for string literals.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/raw)
-    #[wasm_bindgen(catch, static_method_of = JsString, js_class = "String", js_name = raw)]
-    pub fn raw_0(call_site: &Object) -> Result<JsString, JsValue>;
+/// Diagnostics for values that claim to be iterable (via
+/// `Symbol.iterator`) but violate the iteration protocol, so a caller gets
+/// more than an opaque `TypeError` thrown deep inside [`try_iter`].
+pub mod iterator {
+    use super::*;
 
-    /// The static `raw()` method is a tag function of template literals,
-    /// similar to the `r` prefix in Python or the `@` prefix in C# for string literals.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/raw)
-    #[wasm_bindgen(catch, static_method_of = JsString, js_class = "String", js_name = raw)]
-    pub fn raw_1(call_site: &Object, substitutions_1: &str) -> Result<JsString, JsValue>;
+    /// What's wrong (or not) with a value's conformance to the JS
+    /// iteration protocol, as produced by [`diagnose`].
+    #[derive(Debug)]
+    pub enum IterDiagnosis {
+        /// `value[Symbol.iterator]` is `undefined`/`null` (or couldn't be
+        /// read at all).
+        NoSymbolIterator,
+        /// `value[Symbol.iterator]` exists but isn't callable.
+        SymbolIteratorNotCallable,
+        /// Calling `value[Symbol.iterator]()`, or calling `.next()` on the
+        /// iterator it returned, threw. Carries the thrown value.
+        IteratorCallThrew(JsValue),
+        /// The returned iterator has no `next` property.
+        NextMissing,
+        /// The returned iterator's `next` property exists but isn't
+        /// callable.
+        NextNotCallable,
+        /// `iterator.next()` didn't return an object (e.g. returned
+        /// `false` or `undefined`).
+        FirstResultNotObject,
+        /// The protocol looks sound. `reports_done_immediately` notes
+        /// whether the very first `next()` call already reported `done`
+        /// (an empty iterable, which is valid but worth flagging to a
+        /// caller who expected elements).
+        Ok { reports_done_immediately: bool },
+    }
 
-    /// The static `raw()` method is a tag function of template literals,
-    /// similar to the `r` prefix in Python or the `@` prefix in C# for string literals.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/raw)
-    #[wasm_bindgen(catch, static_method_of = JsString, js_class = "String", js_name = raw)]
-    pub fn raw_2(
-        call_site: &Object,
-        substitutions_1: &str,
-        substitutions_2: &str,
-    ) -> Result<JsString, JsValue>;
+    /// Probes `val` for iteration-protocol conformance, identifying
+    /// exactly which step fails (if any) instead of the opaque
+    /// `TypeError` a malformed iterator would otherwise throw from deep
+    /// inside [`try_iter`].
+    ///
+    /// Obtains its own iterator via `val[Symbol.iterator]()` (a fresh one,
+    /// independent of anything a caller is already iterating) and pulls
+    /// at most one element from it to check the shape of the result --
+    /// cautious, but not side-effect-free for iterators that have
+    /// observable effects on `next()`.
+    pub fn diagnose(val: &JsValue) -> IterDiagnosis {
+        let iter_sym = Symbol::iterator();
+        let iter_fn_val = match Reflect::get(val, iter_sym.as_ref()) {
+            Ok(v) => v,
+            Err(_) => return IterDiagnosis::NoSymbolIterator,
+        };
+        if iter_fn_val.is_undefined() || iter_fn_val.is_null() {
+            return IterDiagnosis::NoSymbolIterator;
+        }
+        let iter_fn: Function = match iter_fn_val.dyn_into() {
+            Ok(f) => f,
+            Err(_) => return IterDiagnosis::SymbolIteratorNotCallable,
+        };
 
-    /// The static `raw()` method is a tag function of template literals,
-    /// similar to the `r` prefix in Python or the `@` prefix in C# for string literals.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/raw)
-    #[wasm_bindgen(catch, static_method_of = JsString, js_class = "String", js_name = raw)]
-    pub fn raw_3(
-        call_site: &Object,
-        substitutions_1: &str,
-        substitutions_2: &str,
-        substitutions_3: &str,
-    ) -> Result<JsString, JsValue>;
+        let iterator = match iter_fn.call0(val) {
+            Ok(it) => it,
+            Err(e) => return IterDiagnosis::IteratorCallThrew(e),
+        };
 
-    /// The static `raw()` method is a tag function of template literals,
-    /// similar to the `r` prefix in Python or the `@` prefix in C# for string literals.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/raw)
-    #[wasm_bindgen(catch, static_method_of = JsString, js_class = "String", js_name = raw)]
-    pub fn raw_4(
-        call_site: &Object,
-        substitutions_1: &str,
-        substitutions_2: &str,
-        substitutions_3: &str,
-        substitutions_4: &str,
-    ) -> Result<JsString, JsValue>;
+        let next_val = match Reflect::get(&iterator, &JsValue::from_str("next")) {
+            Ok(v) => v,
+            Err(_) => return IterDiagnosis::NextMissing,
+        };
+        if next_val.is_undefined() {
+            return IterDiagnosis::NextMissing;
+        }
+        let next_fn: Function = match next_val.dyn_into() {
+            Ok(f) => f,
+            Err(_) => return IterDiagnosis::NextNotCallable,
+        };
 
-    /// The static `raw()` method is a tag function of template literals,
-    /// similar to the `r` prefix in Python or the `@` prefix in C# for string literals.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/raw)
-    #[wasm_bindgen(catch, static_method_of = JsString, js_class = "String", js_name = raw)]
-    pub fn raw_5(
-        call_site: &Object,
-        substitutions_1: &str,
-        substitutions_2: &str,
-        substitutions_3: &str,
-        substitutions_4: &str,
-        substitutions_5: &str,
-    ) -> Result<JsString, JsValue>;
+        let first = match next_fn.call0(&iterator) {
+            Ok(v) => v,
+            Err(e) => return IterDiagnosis::IteratorCallThrew(e),
+        };
+        if !first.is_object() {
+            return IterDiagnosis::FirstResultNotObject;
+        }
 
-    /// The static `raw()` method is a tag function of template literals,
-    /// similar to the `r` prefix in Python or the `@` prefix in C# for string literals.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/raw)
-    #[wasm_bindgen(catch, static_method_of = JsString, js_class = "String", js_name = raw)]
-    pub fn raw_6(
-        call_site: &Object,
-        substitutions_1: &str,
-        substitutions_2: &str,
-        substitutions_3: &str,
-        substitutions_4: &str,
-        substitutions_5: &str,
-        substitutions_6: &str,
-    ) -> Result<JsString, JsValue>;
+        let reports_done_immediately = Reflect::get(&first, &JsValue::from_str("done"))
+            .map(|d| d.is_truthy())
+            .unwrap_or(false);
+        IterDiagnosis::Ok { reports_done_immediately }
+    }
 
-    /// The static `raw()` method is a tag function of template literals,
-    /// similar to the `r` prefix in Python or the `@` prefix in C# for string literals.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/raw)
-    #[wasm_bindgen(catch, static_method_of = JsString, js_class = "String", js_name = raw)]
-    pub fn raw_7(
-        call_site: &Object,
-        substitutions_1: &str,
-        substitutions_2: &str,
-        substitutions_3: &str,
-        substitutions_4: &str,
-        substitutions_5: &str,
-        substitutions_6: &str,
-        substitutions_7: &str,
-    ) -> Result<JsString, JsValue>;
+    /// Like [`try_iter`], but on failure runs [`diagnose`] to explain
+    /// exactly what about `val` isn't a well-formed iterable, instead of
+    /// just returning `None`/the raw thrown value.
+    pub fn try_iter_diagnosed(val: &JsValue) -> Result<IntoIter, IterDiagnosis> {
+        match try_iter(val) {
+            Ok(Some(iter)) => Ok(iter),
+            Ok(None) => Err(diagnose(val)),
+            Err(_) => Err(diagnose(val)),
+        }
+    }
 }
 
-impl JsString {
-    /// Returns the `JsString` value of this JS value if it's an instance of a
-    /// string.
-    ///
-    /// If this JS value is not an instance of a string then this returns
-    /// `None`.
-    #[deprecated(note = "recommended to use dyn_ref instead which is now equivalent")]
-    pub fn try_from(val: &JsValue) -> Option<&JsString> {
-        val.dyn_ref()
-    }
+/// Finding which [`ArrayBuffer`]s within a value are transferable, ahead
+/// of a `structuredClone(value, { transfer: [...] })` call or a post-to-
+/// worker. Within this crate's scope, `ArrayBuffer` is the only
+/// transferable kind (`MessagePort` and friends live in `web_sys`).
+pub mod transfer {
+    use super::*;
 
-    /// Returns whether this string is a valid UTF-16 string.
-    ///
-    /// This is useful for learning whether `String::from(..)` will return a
-    /// lossless representation of the JS string. If this string contains
-    /// unpaired surrogates then `String::from` will succeed but it will be a
-    /// lossy representation of the JS string because unpaired surrogates will
-    /// become replacement characters.
-    ///
-    /// If this function returns `false` then to get a lossless representation
-    /// of the string you'll need to manually use the `iter` method (or the
-    /// `char_code_at` accessor) to view the raw character codes.
-    ///
-    /// For more information, see the documentation on [JS strings vs Rust
-    /// strings][docs]
-    ///
-    /// [docs]: https://rustwasm.github.io/docs/wasm-bindgen/reference/types/str.html
-    pub fn is_valid_utf16(&self) -> bool {
-        core::char::decode_utf16(self.iter()).all(|i| i.is_ok())
+    /// Is `value` itself directly transferable -- i.e. an [`ArrayBuffer`]?
+    /// Doesn't look inside objects/arrays/typed-array views; see
+    /// [`collect_transferable_buffers`] for that.
+    pub fn is_plain_transferable(value: &JsValue) -> bool {
+        value.dyn_ref::<ArrayBuffer>().is_some()
     }
 
-    /// Returns an iterator over the `u16` character codes that make up this JS
-    /// string.
-    ///
-    /// This method will call `char_code_at` for each code in this JS string,
-    /// returning an iterator of the codes in sequence.
-    pub fn iter(
-        &self,
-    ) -> impl ExactSizeIterator<Item = u16> + DoubleEndedIterator<Item = u16> + '_ {
-        (0..self.length()).map(move |i| self.char_code_at(i) as u16)
+    /// Walks `value` (through plain objects, `Array`s, `Map`s, `Set`s, and
+    /// typed-array/`DataView` `.buffer` properties) and collects every
+    /// distinct, non-detached `ArrayBuffer` reachable from it, up to
+    /// `max_depth` levels deep. Buffers reachable through more than one
+    /// path (e.g. two typed-array views over the same backing buffer)
+    /// appear only once, compared by identity via [`Object::is`].
+    ///
+    /// Safe against cycles: a value already on the current path is not
+    /// walked again, though it may still be visited again via a
+    /// different, non-cyclic path.
+    pub fn collect_transferable_buffers(value: &JsValue, max_depth: usize) -> Array {
+        let buffers = Array::new();
+        let seen_buffers: Vec<ArrayBuffer> = Vec::new();
+        let visiting: Vec<JsValue> = Vec::new();
+        let mut state = (buffers, seen_buffers, visiting);
+        walk_transferables(value, max_depth, &mut state);
+        state.0
     }
 
-    /// If this string consists of a single Unicode code point, then this method
-    /// converts it into a Rust `char` without doing any allocations.
-    ///
-    /// If this JS value is not a valid UTF-8 or consists of more than a single
-    /// codepoint, then this returns `None`.
-    ///
-    /// Note that a single Unicode code point might be represented as more than
-    /// one code unit on the JavaScript side. For example, a JavaScript string
-    /// `"\uD801\uDC37"` is actually a single Unicode code point U+10437 which
-    /// corresponds to a character '𐐷'.
-    pub fn as_char(&self) -> Option<char> {
-        let len = self.length();
+    type WalkState = (Array, Vec<ArrayBuffer>, Vec<JsValue>);
 
-        if len == 0 || len > 2 {
-            return None;
+    fn walk_transferables(value: &JsValue, depth: usize, state: &mut WalkState) {
+        if depth == 0 {
+            return;
         }
 
-        // This will be simplified when definitions are fixed:
-        // https://github.com/rustwasm/wasm-bindgen/issues/1362
-        let cp = self.code_point_at(0).as_f64().unwrap_throw() as u32;
+        if let Some(buffer) = value.dyn_ref::<ArrayBuffer>() {
+            record_buffer(buffer, state);
+            return;
+        }
 
-        let c = core::char::from_u32(cp)?;
+        if !value.is_object() {
+            return;
+        }
+        if state.2.iter().any(|v| Object::is(v, value)) {
+            return;
+        }
+        state.2.push(value.clone());
 
-        if c.len_utf16() as u32 == len {
-            Some(c)
-        } else {
-            None
+        if let Ok(buffer_val) = Reflect::get(value, &JsValue::from_str("buffer")) {
+            if let Some(buffer) = buffer_val.dyn_ref::<ArrayBuffer>() {
+                record_buffer(buffer, state);
+                state.2.pop();
+                return;
+            }
+        }
+
+        if let Some(array) = value.dyn_ref::<Array>() {
+            for item in array.iter() {
+                walk_transferables(&item, depth - 1, state);
+            }
+        } else if let Some(map) = value.dyn_ref::<Map>() {
+            let entries: Vec<(JsValue, JsValue)> = map.entries_page(0, map.size());
+            for (key, entry_value) in entries {
+                walk_transferables(&key, depth - 1, state);
+                walk_transferables(&entry_value, depth - 1, state);
+            }
+        } else if let Some(set) = value.dyn_ref::<Set>() {
+            for item in set.values_page(0, set.size()) {
+                walk_transferables(&item, depth - 1, state);
+            }
+        } else if let Ok(object) = value.clone().dyn_into::<Object>() {
+            for key in Object::keys(&object).iter() {
+                if let Ok(child) = Reflect::get(&object, &key) {
+                    walk_transferables(&child, depth - 1, state);
+                }
+            }
         }
+
+        state.2.pop();
     }
-}
 
-impl PartialEq<str> for JsString {
-    #[allow(clippy::cmp_owned)] // prevent infinite recursion
-    fn eq(&self, other: &str) -> bool {
-        String::from(self) == other
+    fn record_buffer(buffer: &ArrayBuffer, state: &mut WalkState) {
+        if buffer.byte_length() == 0 {
+            // Either a genuinely empty buffer or an already-detached one;
+            // neither is worth (or able to be) transferred again.
+            return;
+        }
+        if !state.1.iter().any(|seen| Object::is(seen.as_ref(), buffer.as_ref())) {
+            state.1.push(buffer.clone());
+            state.0.push(buffer.as_ref());
+        }
     }
-}
 
-impl<'a> PartialEq<&'a str> for JsString {
-    fn eq(&self, other: &&'a str) -> bool {
-        <JsString as PartialEq<str>>::eq(self, other)
+    /// Like the global `structuredClone`, but automatically transfers
+    /// (rather than copies) every [`ArrayBuffer`] found via
+    /// [`collect_transferable_buffers`] -- after this call, the sources
+    /// are detached and only the clone holds the data.
+    pub fn clone_with_auto_transfer(value: &JsValue) -> Result<JsValue, JsValue> {
+        let buffers = collect_transferable_buffers(value, 32);
+        let clone_fn = structured_clone_fn()?;
+        let options = Object::new();
+        Reflect::set(options.as_ref(), &JsValue::from_str("transfer"), buffers.as_ref())?;
+        clone_fn.call2(&JsValue::UNDEFINED, value, options.as_ref())
     }
 }
 
-impl PartialEq<String> for JsString {
-    fn eq(&self, other: &String) -> bool {
-        <JsString as PartialEq<str>>::eq(self, other)
+/// Rust-side decoding for `%XX`-escaped URI components, as an alternative
+/// to [`decode_uri`] and [`decode_uri_component`] for callers who need
+/// input that never throws, or an error that points at the failing offset.
+pub mod uri {
+    use super::*;
+
+    /// Percent-decodes `s`, the same escaping `decodeURIComponent` expects,
+    /// except that malformed input is never an error: a truncated `%`
+    /// escape, a non-hex-digit following `%`, or bytes that don't form
+    /// valid UTF-8 are all replaced with the Unicode replacement character
+    /// (`U+FFFD`) instead of throwing, so this function always returns a
+    /// `String`.
+    pub fn try_decode_component_lossy(s: &str) -> String {
+        let bytes = s.as_bytes();
+        let mut decoded: Vec<u8> = Vec::with_capacity(bytes.len());
+        let mut i = 0;
+        while i < bytes.len() {
+            if bytes[i] == b'%' {
+                let hi = bytes.get(i + 1).copied().and_then(hex_digit);
+                let lo = bytes.get(i + 2).copied().and_then(hex_digit);
+                match (hi, lo) {
+                    (Some(hi), Some(lo)) => {
+                        decoded.push((hi << 4) | lo);
+                        i += 3;
+                    }
+                    _ => {
+                        let mut buf = [0u8; 4];
+                        let encoded = core::char::REPLACEMENT_CHARACTER.encode_utf8(&mut buf);
+                        decoded.extend_from_slice(encoded.as_bytes());
+                        i += 1;
+                    }
+                }
+            } else {
+                decoded.push(bytes[i]);
+                i += 1;
+            }
+        }
+        // `from_utf8_lossy` puts one replacement character in place of each
+        // invalid sequence and keeps every valid byte around it.
+        String::from_utf8(decoded).unwrap_or_else(|e| String::from_utf8_lossy(e.as_bytes()).into_owned())
     }
-}
 
-impl<'a> PartialEq<&'a String> for JsString {
-    fn eq(&self, other: &&'a String) -> bool {
-        <JsString as PartialEq<str>>::eq(self, other)
+    /// Returns the byte offset of the first percent-escape in `s` that
+    /// either is truncated, isn't followed by two hex digits, or decodes
+    /// (together with any escapes that follow it) to bytes that aren't
+    /// valid UTF-8 -- i.e. the first escape [`try_decode_component_lossy`]
+    /// had to paper over with a replacement character. Returns `None` if
+    /// `s` would decode cleanly.
+    pub fn find_invalid_sequence(s: &str) -> Option<usize> {
+        let bytes = s.as_bytes();
+        let mut decoded: Vec<u8> = Vec::with_capacity(bytes.len());
+        let mut positions: Vec<usize> = Vec::with_capacity(bytes.len());
+        let mut i = 0;
+        while i < bytes.len() {
+            if bytes[i] == b'%' {
+                let hi = bytes.get(i + 1).copied().and_then(hex_digit);
+                let lo = bytes.get(i + 2).copied().and_then(hex_digit);
+                match (hi, lo) {
+                    (Some(hi), Some(lo)) => {
+                        decoded.push((hi << 4) | lo);
+                        positions.push(i);
+                        i += 3;
+                    }
+                    _ => {
+                        // An escape decoded earlier may already be invalid
+                        // UTF-8 (or a sequence this bad escape cuts short);
+                        // that one comes first.
+                        return Some(match core::str::from_utf8(&decoded) {
+                            Ok(_) => i,
+                            Err(e) => positions[e.valid_up_to()],
+                        });
+                    }
+                }
+            } else {
+                decoded.push(bytes[i]);
+                positions.push(i);
+                i += 1;
+            }
+        }
+        match String::from_utf8(decoded) {
+            Ok(_) => None,
+            Err(e) => Some(positions[e.utf8_error().valid_up_to()]),
+        }
     }
 }
 
-impl<'a> From<&'a str> for JsString {
-    fn from(s: &'a str) -> Self {
-        JsString::unchecked_from_js(JsValue::from_str(s))
+/// A single-producer/single-consumer byte ring buffer backed by a
+/// `SharedArrayBuffer`, for passing framed messages between workers
+/// without per-message allocation on the JS side.
+///
+/// The buffer is laid out as an 8-byte control region (two `i32` slots --
+/// a head index at element 0 and a tail index at element 1, both counted
+/// modulo the data region's length) followed by the data region itself.
+/// Producer and consumer each hold their own [`SpscRing`] (built via
+/// [`SpscRing::with_capacity`] and [`SpscRing::from_shared`] respectively)
+/// wrapping views onto the *same* underlying `SharedArrayBuffer`.
+///
+/// Every push/pop touches the head and tail indices with
+/// [`Atomics::load`]/[`Atomics::store`], which the JS spec gives,
+/// respectively, acquire and release ordering -- the same guarantee
+/// `core::sync::atomic::Ordering::Acquire`/`Release` give in Rust, so a
+/// consumer that observes a new tail value is guaranteed to also see the
+/// bytes the producer wrote before updating it.
+pub mod sync {
+    use super::*;
+
+    const HEADER_ELEMENTS: u32 = 2;
+    const HEADER_BYTES: u32 = 8;
+    const FRAME_PREFIX_BYTES: u32 = 4;
+
+    /// Returned by [`SpscRing::try_push`] when there isn't enough free
+    /// space in the ring for the message (plus its 4-byte length prefix)
+    /// right now.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct Full;
+
+    impl fmt::Display for Full {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "ring buffer has no room for this message")
+        }
     }
-}
 
-impl From<String> for JsString {
-    fn from(s: String) -> Self {
-        From::from(&*s)
+    #[cfg(feature = "std")]
+    impl std::error::Error for Full {}
+
+    /// Returned by [`SpscRing::try_pop`] when the ring has no message
+    /// waiting.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct Empty;
+
+    impl fmt::Display for Empty {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "ring buffer is empty")
+        }
     }
-}
 
-impl From<char> for JsString {
-    #[inline]
-    fn from(c: char) -> Self {
-        JsString::from_code_point1(c as u32).unwrap_throw()
+    #[cfg(feature = "std")]
+    impl std::error::Error for Empty {}
+
+    /// Returned by [`SpscRing::from_shared`] when the given
+    /// `SharedArrayBuffer` is too small to hold even the control region
+    /// plus a non-empty data region.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct LayoutError {
+        pub byte_length: u32,
     }
-}
 
-impl<'a> From<&'a JsString> for String {
-    fn from(s: &'a JsString) -> Self {
-        s.obj.as_string().unwrap_throw()
-    }
-}
+    impl fmt::Display for LayoutError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(
+                f,
+                "SharedArrayBuffer of {} bytes is too small for an SpscRing (needs more than {} bytes)",
+                self.byte_length, HEADER_BYTES
+            )
+        }
+    }
+
+    #[cfg(feature = "std")]
+    impl std::error::Error for LayoutError {}
+
+    /// A single-producer/single-consumer ring buffer of framed byte
+    /// messages, backed by a `SharedArrayBuffer`. See the [module
+    /// documentation](self) for the wire layout and memory-ordering
+    /// guarantees.
+    #[derive(Clone, Debug)]
+    pub struct SpscRing {
+        control: Int32Array,
+        data: Uint8Array,
+        capacity: u32,
+    }
+
+    impl SpscRing {
+        /// Allocates a new `SharedArrayBuffer` with `bytes` of ring
+        /// capacity (plus the fixed 8-byte control header) and returns a
+        /// ring attached to it. Share [`SpscRing::shared_buffer`]'s result
+        /// with the other side (e.g. by `postMessage`ing it to a worker),
+        /// which attaches its own `SpscRing` to the same memory via
+        /// [`SpscRing::from_shared`].
+        pub fn with_capacity(bytes: usize) -> Result<SpscRing, JsValue> {
+            let bytes: u32 = bytes
+                .try_into()
+                .map_err(|_| JsValue::from_str("SpscRing capacity overflows u32"))?;
+            if bytes == 0 {
+                return Err(JsValue::from_str("SpscRing capacity must be at least 1 byte"));
+            }
+            let buffer = SharedArrayBuffer::new(HEADER_BYTES + bytes);
+            let control =
+                Int32Array::new_with_byte_offset_and_length(&JsValue::from(buffer.clone()), 0, HEADER_ELEMENTS);
+            let data = Uint8Array::new_with_byte_offset_and_length(&JsValue::from(buffer), HEADER_BYTES, bytes);
+            Ok(SpscRing { control, data, capacity: bytes })
+        }
+
+        /// Attaches to an existing ring's `SharedArrayBuffer`, as the
+        /// other side of a [`SpscRing::with_capacity`] pair.
+        pub fn from_shared(buf: &SharedArrayBuffer) -> Result<SpscRing, LayoutError> {
+            let byte_length = buf.byte_length();
+            if byte_length <= HEADER_BYTES {
+                return Err(LayoutError { byte_length });
+            }
+            let capacity = byte_length - HEADER_BYTES;
+            let control =
+                Int32Array::new_with_byte_offset_and_length(&JsValue::from(buf.clone()), 0, HEADER_ELEMENTS);
+            let data = Uint8Array::new_with_byte_offset_and_length(&JsValue::from(buf.clone()), HEADER_BYTES, capacity);
+            Ok(SpscRing { control, data, capacity })
+        }
+
+        /// Returns the underlying `SharedArrayBuffer`, to hand to the
+        /// other side of the pair.
+        pub fn shared_buffer(&self) -> SharedArrayBuffer {
+            self.data.buffer().unchecked_into()
+        }
+
+        fn load(&self, index: u32) -> u32 {
+            Atomics::load(JsValue::as_ref(&self.control), index).unwrap_throw() as u32
+        }
+
+        fn store(&self, index: u32, value: u32) {
+            Atomics::store(JsValue::as_ref(&self.control), index, value as i32).unwrap_throw();
+        }
+
+        /// Bytes available to write without overtaking `head`, always
+        /// leaving one byte unused so `head == tail` can mean only
+        /// "empty", never ambiguously "full".
+        fn free_space(&self, head: u32, tail: u32) -> u32 {
+            // `tail.wrapping_sub(head) % self.capacity` is only correct when
+            // `capacity` is a power of two -- `with_capacity` accepts any
+            // byte count, so compute the circular distance directly instead
+            // of relying on 32-bit wraparound lining up with it.
+            let used = if tail >= head {
+                tail - head
+            } else {
+                self.capacity - head + tail
+            };
+            self.capacity - 1 - used
+        }
+
+        fn write_bytes(&self, mut pos: u32, bytes: &[u8]) -> u32 {
+            for &byte in bytes {
+                self.data.set_index(pos, byte);
+                pos = (pos + 1) % self.capacity;
+            }
+            pos
+        }
+
+        fn read_bytes(&self, mut pos: u32, len: u32, out: &mut Vec<u8>) -> u32 {
+            for _ in 0..len {
+                out.push(self.data.get_index(pos));
+                pos = (pos + 1) % self.capacity;
+            }
+            pos
+        }
+
+        /// Pushes `data` onto the ring as a single framed message, or
+        /// returns [`Full`] if there isn't room for it (plus its 4-byte
+        /// length prefix) right now.
+        pub fn try_push(&self, data: &[u8]) -> Result<(), Full> {
+            let needed = FRAME_PREFIX_BYTES + data.len() as u32;
+            let head = self.load(0);
+            let tail = self.load(1);
+            if self.free_space(head, tail) < needed {
+                return Err(Full);
+            }
+            let mut pos = tail;
+            pos = self.write_bytes(pos, &(data.len() as u32).to_le_bytes());
+            pos = self.write_bytes(pos, data);
+            self.store(1, pos);
+            let _ = Atomics::notify(&self.control, 1);
+            Ok(())
+        }
 
-impl From<JsString> for String {
-    fn from(s: JsString) -> Self {
-        From::from(&s)
-    }
-}
+        /// Pops the next framed message off the ring, appending its bytes
+        /// to `out` (which is not cleared first) and returning its
+        /// length, or returns [`Empty`] if the ring has no message
+        /// waiting. Also wakes any producer parked in
+        /// [`SpscRing::wait_producer`], since popping frees space.
+        pub fn try_pop(&self, out: &mut Vec<u8>) -> Result<usize, Empty> {
+            let head = self.load(0);
+            let tail = self.load(1);
+            if head == tail {
+                return Err(Empty);
+            }
+            let mut len_bytes = [0u8; 4];
+            let mut pos = head;
+            for b in &mut len_bytes {
+                *b = self.data.get_index(pos);
+                pos = (pos + 1) % self.capacity;
+            }
+            let len = u32::from_le_bytes(len_bytes);
+            pos = self.read_bytes(pos, len, out);
+            self.store(0, pos);
+            let _ = Atomics::notify(&self.control, 0);
+            Ok(len as usize)
+        }
 
-impl fmt::Debug for JsString {
-    #[inline]
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        fmt::Debug::fmt(&String::from(self), f)
-    }
-}
+        /// Wakes agents parked in [`SpscRing::wait_consumer`]. [`try_push`](Self::try_push)
+        /// already calls this itself after a successful push, so this is
+        /// only needed to wake a consumer after pushing through some other
+        /// path (e.g. writing the control indices directly).
+        pub fn notify_consumer(&self) -> Result<u32, JsValue> {
+            Atomics::notify(&self.control, 1)
+        }
 
-impl fmt::Display for JsString {
-    #[inline]
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        fmt::Display::fmt(&String::from(self), f)
-    }
-}
+        /// Sleeps (cooperatively, via `Atomics.wait`) until the ring's
+        /// head index changes -- meaning the consumer freed some space --
+        /// or `timeout_ms` elapses, whichever comes first. Intended for a
+        /// producer that just got [`Full`] back from [`SpscRing::try_push`].
+        ///
+        /// Like the underlying `Atomics.wait`, this isn't available on the
+        /// main thread -- only call it from a worker.
+        pub fn wait_producer(&self, timeout_ms: f64) -> Result<JsString, JsValue> {
+            let head = self.load(0);
+            Atomics::wait_with_timeout(&self.control, 0, head as i32, timeout_ms)
+        }
 
-impl str::FromStr for JsString {
-    type Err = convert::Infallible;
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(JsString::from(s))
+        /// Sleeps (cooperatively, via `Atomics.wait`) until the ring's
+        /// tail index changes -- meaning the producer pushed a new
+        /// message -- or `timeout_ms` elapses, whichever comes first.
+        /// Intended for a consumer that just got [`Empty`] back from
+        /// [`SpscRing::try_pop`]; woken automatically by
+        /// [`SpscRing::try_push`], or manually via
+        /// [`SpscRing::notify_consumer`].
+        ///
+        /// Like the underlying `Atomics.wait`, this isn't available on the
+        /// main thread -- only call it from a worker.
+        pub fn wait_consumer(&self, timeout_ms: f64) -> Result<JsString, JsValue> {
+            let tail = self.load(1);
+            Atomics::wait_with_timeout(&self.control, 1, tail as i32, timeout_ms)
+        }
     }
 }
 
-// Symbol
-#[wasm_bindgen]
-extern "C" {
-    #[wasm_bindgen(is_type_of = JsValue::is_symbol, typescript_type = "Symbol")]
+/// A small, fast, deterministic pseudo-random number generator, for tests
+/// and simulations that need repeatable "randomness" -- unlike
+/// `Math.random`, [`rng::SeededRng`] can be seeded, so the same seed always
+/// produces the same sequence of outputs. This is a splitmix64 generator:
+/// simple, free of weak seeds, and good enough for non-cryptographic use,
+/// but not suitable for anything security-sensitive (it never touches
+/// `crypto.getRandomValues`, so it works the same in every JS environment).
+pub mod rng {
+    use super::*;
+
+    /// A splitmix64-based seeded random number generator.
     #[derive(Clone, Debug)]
-    pub type Symbol;
+    pub struct SeededRng {
+        state: u64,
+    }
 
-    /// The `Symbol.hasInstance` well-known symbol is used to determine
-    /// if a constructor object recognizes an object as its instance.
-    /// The `instanceof` operator's behavior can be customized by this symbol.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol/hasInstance)
-    #[wasm_bindgen(static_method_of = Symbol, getter, structural, js_name = hasInstance)]
-    pub fn has_instance() -> Symbol;
+    impl SeededRng {
+        /// Creates a new generator seeded with `seed`.
+        pub fn new(seed: u64) -> SeededRng {
+            SeededRng { state: seed }
+        }
 
-    /// The `Symbol.isConcatSpreadable` well-known symbol is used to configure
-    /// if an object should be flattened to its array elements when using the
-    /// `Array.prototype.concat()` method.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol/isConcatSpreadable)
-    #[wasm_bindgen(static_method_of = Symbol, getter, structural, js_name = isConcatSpreadable)]
-    pub fn is_concat_spreadable() -> Symbol;
+        fn next_u64(&mut self) -> u64 {
+            self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+            let mut z = self.state;
+            z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+            z ^ (z >> 31)
+        }
 
-    /// The `Symbol.asyncIterator` well-known symbol specifies the default AsyncIterator for an object.
-    /// If this property is set on an object, it is an async iterable and can be used in a `for await...of` loop.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol/asyncIterator)
-    #[wasm_bindgen(static_method_of = Symbol, getter, structural, js_name = asyncIterator)]
-    pub fn async_iterator() -> Symbol;
+        /// Returns the next random `u32`.
+        pub fn next_u32(&mut self) -> u32 {
+            (self.next_u64() >> 32) as u32
+        }
 
-    /// The `Symbol.iterator` well-known symbol specifies the default iterator
-    /// for an object.  Used by `for...of`.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol/iterator)
-    #[wasm_bindgen(static_method_of = Symbol, getter, structural)]
-    pub fn iterator() -> Symbol;
+        /// Returns the next random `f64` in `[0, 1)`, matching the range
+        /// semantics of `Math.random()`.
+        pub fn next_f64(&mut self) -> f64 {
+            // 53 bits of randomness -- the precision of an f64 mantissa --
+            // scaled into [0, 1), the same way most engines implement
+            // Math.random.
+            let bits = self.next_u64() >> 11;
+            (bits as f64) * (1.0 / (1u64 << 53) as f64)
+        }
 
-    /// The `Symbol.match` well-known symbol specifies the matching of a regular
-    /// expression against a string. This function is called by the
-    /// `String.prototype.match()` method.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol/match)
-    #[wasm_bindgen(static_method_of = Symbol, getter, structural, js_name = match)]
-    pub fn match_() -> Symbol;
+        /// Fills `arr` with random bytes, built up in a Rust `Vec` and
+        /// copied over in a single crossing into JS.
+        pub fn fill_uint8array(&mut self, arr: &Uint8Array) {
+            let len = arr.length() as usize;
+            let mut bytes = Vec::with_capacity(len);
 
-    /// The `Symbol.replace` well-known symbol specifies the method that
-    /// replaces matched substrings of a string.  This function is called by the
-    /// `String.prototype.replace()` method.
-    ///
-    /// For more information, see `RegExp.prototype[@@replace]()` and
-    /// `String.prototype.replace()`.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol/replace)
-    #[wasm_bindgen(static_method_of = Symbol, getter, structural)]
-    pub fn replace() -> Symbol;
+            while bytes.len() < len {
+                bytes.extend_from_slice(&self.next_u64().to_le_bytes());
+            }
+            bytes.truncate(len);
 
-    /// The `Symbol.search` well-known symbol specifies the method that returns
-    /// the index within a string that matches the regular expression.  This
-    /// function is called by the `String.prototype.search()` method.
-    ///
-    /// For more information, see `RegExp.prototype[@@search]()` and
-    /// `String.prototype.search()`.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol/search)
-    #[wasm_bindgen(static_method_of = Symbol, getter, structural)]
-    pub fn search() -> Symbol;
+            arr.copy_from(&bytes);
+        }
 
-    /// The well-known symbol `Symbol.species` specifies a function-valued
-    /// property that the constructor function uses to create derived objects.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol/species)
-    #[wasm_bindgen(static_method_of = Symbol, getter, structural)]
-    pub fn species() -> Symbol;
+        /// Shuffles `arr` in place using the Fisher-Yates algorithm.
+        pub fn shuffle_array(&mut self, arr: &Array) {
+            let len = arr.length();
 
-    /// The `Symbol.split` well-known symbol specifies the method that splits a
-    /// string at the indices that match a regular expression.  This function is
-    /// called by the `String.prototype.split()` method.
-    ///
-    /// For more information, see `RegExp.prototype[@@split]()` and
-    /// `String.prototype.split()`.
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol/split)
-    #[wasm_bindgen(static_method_of = Symbol, getter, structural)]
-    pub fn split() -> Symbol;
+            for i in (1..len).rev() {
+                let j = self.next_u32() % (i + 1);
+                let a = arr.get(i);
+                let b = arr.get(j);
+                arr.set(i, b);
+                arr.set(j, a);
+            }
+        }
 
-    /// The `Symbol.toPrimitive` is a symbol that specifies a function valued
-    /// property that is called to convert an object to a corresponding
-    /// primitive value.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol/toPrimitive)
-    #[wasm_bindgen(static_method_of = Symbol, getter, structural, js_name = toPrimitive)]
-    pub fn to_primitive() -> Symbol;
+        /// Returns a new array of `n` random numbers in `[0, 1)`.
+        pub fn random_js_values(&mut self, n: u32) -> Array {
+            let out = Array::new();
 
-    /// The `Symbol.toStringTag` well-known symbol is a string valued property
-    /// that is used in the creation of the default string description of an
-    /// object.  It is accessed internally by the `Object.prototype.toString()`
-    /// method.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol/toString)
-    #[wasm_bindgen(static_method_of = Symbol, getter, structural, js_name = toStringTag)]
-    pub fn to_string_tag() -> Symbol;
+            for _ in 0..n {
+                out.push(&JsValue::from_f64(self.next_f64()));
+            }
 
-    /// The `Symbol.for(key)` method searches for existing symbols in a runtime-wide symbol registry with
-    /// the given key and returns it if found.
-    /// Otherwise a new symbol gets created in the global symbol registry with this key.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol/for)
-    #[wasm_bindgen(static_method_of = Symbol, js_name = for)]
-    pub fn for_(key: &str) -> Symbol;
+            out
+        }
+    }
+}
 
-    /// The `Symbol.keyFor(sym)` method retrieves a shared symbol key from the global symbol registry for the given symbol.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol/keyFor)
-    #[wasm_bindgen(static_method_of = Symbol, js_name = keyFor)]
-    pub fn key_for(sym: &Symbol) -> JsValue;
+/// Helpers for making a plain object appear, from JS's perspective, like an
+/// instance of a given class -- without running that class's constructor.
+pub mod inherit {
+    use super::*;
 
-    /// The `toString()` method returns a string representing the specified Symbol object.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol/toString)
-    #[wasm_bindgen(method, js_name = toString)]
-    pub fn to_string(this: &Symbol) -> JsString;
+    /// Builds a new object whose prototype is `class_fn.prototype` (so
+    /// `instanceof class_fn` and a prototype-chain walk both see it as an
+    /// instance) and which carries the own, enumerable properties of
+    /// `own_props`, without ever calling `class_fn` itself -- so none of
+    /// its constructor side effects run.
+    pub fn make_instance_of(class_fn: &Function, own_props: &Object) -> Result<Object, JsValue> {
+        let prototype = Reflect::get(class_fn.as_ref(), &JsValue::from_str("prototype"))?
+            .dyn_into::<Object>()?;
+        let instance = Object::create(&prototype);
+
+        for key in Object::keys(own_props).iter() {
+            let value = Reflect::get(own_props.as_ref(), &key)?;
+            Reflect::set(instance.as_ref(), &key, &value)?;
+        }
 
-    /// The `Symbol.unscopables` well-known symbol is used to specify an object
-    /// value of whose own and inherited property names are excluded from the
-    /// with environment bindings of the associated object.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol/unscopables)
-    #[wasm_bindgen(static_method_of = Symbol, getter, structural)]
-    pub fn unscopables() -> Symbol;
+        Ok(instance)
+    }
 
-    /// The `valueOf()` method returns the primitive value of a Symbol object.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol/valueOf)
-    #[wasm_bindgen(method, js_name = valueOf)]
-    pub fn value_of(this: &Symbol) -> Symbol;
+    /// Binds `Object.prototype.isPrototypeOf`: returns `true` if `proto`
+    /// appears anywhere in `value`'s prototype chain.
+    pub fn is_prototype_of(proto: &Object, value: &JsValue) -> bool {
+        Object::is_prototype_of(proto, value)
+    }
 }
 
-#[allow(non_snake_case)]
-pub mod Intl {
+/// A more configurable alternative to [`JSON::stringify`] for diagnostic
+/// dumps of mixed JS structures, where the default JSON encoding of some
+/// values is lossy or simply throws (a `Map` serializes to `{}`, a
+/// `BigInt` throws).
+pub mod export {
     use super::*;
 
-    // Intl
-    #[wasm_bindgen]
-    extern "C" {
-        /// The `Intl.getCanonicalLocales()` method returns an array containing
-        /// the canonical locale names. Duplicates will be omitted and elements
-        /// will be validated as structurally valid language tags.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/getCanonicalLocales)
-        #[wasm_bindgen(js_name = getCanonicalLocales, js_namespace = Intl)]
-        pub fn get_canonical_locales(s: &JsValue) -> Array;
+    /// How [`to_json_string`] encodes a [`Map`].
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum MapEncoding {
+        /// `{"key": value, ...}` -- each key is coerced to a property name
+        /// (strings and numbers convert naturally; anything else falls
+        /// back to its `Debug` text).
+        Object,
+        /// `[[key, value], ...]` -- preserves non-string keys exactly.
+        PairsArray,
     }
 
-    // Intl.Collator
-    #[wasm_bindgen]
-    extern "C" {
-        /// The `Intl.Collator` object is a constructor for collators, objects
-        /// that enable language sensitive string comparison.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Collator)
-        #[wasm_bindgen(extends = Object, js_namespace = Intl, typescript_type = "Intl.Collator")]
-        #[derive(Clone, Debug)]
-        pub type Collator;
+    /// How [`to_json_string`] encodes `undefined` found as an object
+    /// property or `Map` value. `undefined` array elements and the root
+    /// value always encode as `null`, matching `JSON.stringify`.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum UndefinedEncoding {
+        /// Encode as `null`.
+        Null,
+        /// Omit the property entirely.
+        Skip,
+    }
 
-        /// The `Intl.Collator` object is a constructor for collators, objects
-        /// that enable language sensitive string comparison.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Collator)
-        #[wasm_bindgen(constructor, js_namespace = Intl)]
-        pub fn new(locales: &Array, options: &Object) -> Collator;
+    /// How [`to_json_string`] encodes typed arrays.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum TypedArrayEncoding {
+        /// One JSON element per entry, the same as spreading the view into
+        /// a plain array.
+        Array,
+        /// A single string, base64-encoding the view's raw bytes.
+        Base64,
+    }
 
-        /// The Intl.Collator.prototype.compare property returns a function that
-        /// compares two strings according to the sort order of this Collator
-        /// object.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Collator/compare)
-        #[wasm_bindgen(method, getter, js_class = "Intl.Collator")]
-        pub fn compare(this: &Collator) -> Function;
+    /// Configures how [`to_json_string`] handles values that don't have an
+    /// unambiguous JSON representation.
+    #[derive(Clone, Copy, Debug)]
+    pub struct ExportOptions {
+        pub map_encoding: MapEncoding,
+        pub undefined_encoding: UndefinedEncoding,
+        pub typed_array_encoding: TypedArrayEncoding,
+        /// Object/array/map/set nesting deeper than this many levels is
+        /// replaced with a `"[Truncated]"` marker string instead of being
+        /// traversed further.
+        pub max_depth: usize,
+        /// Number of spaces per indentation level; `0` produces compact
+        /// output with no extra whitespace.
+        pub indent: usize,
+    }
 
-        /// The `Intl.Collator.prototype.resolvedOptions()` method returns a new
-        /// object with properties reflecting the locale and collation options
-        /// computed during initialization of this Collator object.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Collator/resolvedOptions)
-        #[wasm_bindgen(method, js_namespace = Intl, js_name = resolvedOptions)]
-        pub fn resolved_options(this: &Collator) -> Object;
+    impl Default for ExportOptions {
+        fn default() -> ExportOptions {
+            ExportOptions {
+                map_encoding: MapEncoding::Object,
+                undefined_encoding: UndefinedEncoding::Null,
+                typed_array_encoding: TypedArrayEncoding::Array,
+                max_depth: 32,
+                indent: 0,
+            }
+        }
+    }
 
-        /// The `Intl.Collator.supportedLocalesOf()` method returns an array
-        /// containing those of the provided locales that are supported in
-        /// collation without having to fall back to the runtime's default
-        /// locale.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Collator/supportedLocalesOf)
-        #[wasm_bindgen(static_method_of = Collator, js_namespace = Intl, js_name = supportedLocalesOf)]
-        pub fn supported_locales_of(locales: &Array, options: &Object) -> Array;
+    const TRUNCATED_MARKER: &str = "\"[Truncated]\"";
+    const BASE64_ALPHABET: &[u8; 64] =
+        b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    fn quote(s: &str) -> Result<String, JsValue> {
+        JSON::stringify(&JsValue::from_str(s)).map(String::from)
+    }
+
+    fn is_cycle(visiting: &[JsValue], value: &JsValue) -> bool {
+        visiting.iter().any(|v| Object::is(v, value))
+    }
+
+    fn write_indent(out: &mut String, level: usize, opts: &ExportOptions) {
+        if opts.indent > 0 {
+            out.push('\n');
+            for _ in 0..level * opts.indent {
+                out.push(' ');
+            }
+        }
+    }
+
+    fn base64_encode(bytes: &[u8]) -> String {
+        let mut out = String::with_capacity((bytes.len() + 2) / 3 * 4);
+
+        for chunk in bytes.chunks(3) {
+            let b0 = chunk[0];
+            let b1 = *chunk.get(1).unwrap_or(&0);
+            let b2 = *chunk.get(2).unwrap_or(&0);
+
+            out.push(BASE64_ALPHABET[(b0 >> 2) as usize] as char);
+            out.push(BASE64_ALPHABET[(((b0 & 0x03) << 4) | (b1 >> 4)) as usize] as char);
+            out.push(if chunk.len() > 1 {
+                BASE64_ALPHABET[(((b1 & 0x0f) << 2) | (b2 >> 6)) as usize] as char
+            } else {
+                '='
+            });
+            out.push(if chunk.len() > 2 {
+                BASE64_ALPHABET[(b2 & 0x3f) as usize] as char
+            } else {
+                '='
+            });
+        }
+
+        out
+    }
+
+    fn typed_array_bytes(value: &JsValue) -> Result<Vec<u8>, JsValue> {
+        let buffer = Reflect::get(value, &JsValue::from_str("buffer"))?.dyn_into::<ArrayBuffer>()?;
+        let byte_offset = Reflect::get(value, &JsValue::from_str("byteOffset"))?
+            .as_f64()
+            .unwrap_or(0.0) as u32;
+        let byte_length = Reflect::get(value, &JsValue::from_str("byteLength"))?
+            .as_f64()
+            .unwrap_or(0.0) as u32;
+
+        let view = Uint8Array::new_with_byte_offset_and_length(buffer.as_ref(), byte_offset, byte_length);
+        Ok(view.to_vec())
+    }
+
+    fn map_key_to_property_name(key: &JsValue) -> String {
+        if let Some(s) = key.as_string() {
+            s
+        } else if let Some(n) = key.as_f64() {
+            alloc::format!("{}", n)
+        } else {
+            alloc::format!("{:?}", key)
+        }
+    }
+
+    fn write_value(
+        value: &JsValue,
+        depth: usize,
+        level: usize,
+        opts: &ExportOptions,
+        visiting: &mut Vec<JsValue>,
+        out: &mut String,
+    ) -> Result<(), JsValue> {
+        if value.is_undefined() {
+            out.push_str("null");
+            return Ok(());
+        }
+        if value.is_null()
+            || value.as_bool().is_some()
+            || value.as_f64().is_some()
+            || value.is_string()
+        {
+            out.push_str(&String::from(JSON::stringify(value)?));
+            return Ok(());
+        }
+        if let Some(bigint) = value.dyn_ref::<BigInt>() {
+            let digits = bigint.to_string(10).map_err(JsValue::from)?;
+            out.push_str(&quote(&alloc::format!("{}n", String::from(digits)))?);
+            return Ok(());
+        }
+        if let Some(date) = value.dyn_ref::<Date>() {
+            out.push_str(&quote(&String::from(date.to_iso_string()))?);
+            return Ok(());
+        }
+        if !value.is_object() {
+            return Err(Error::new("value has no JSON representation").into());
+        }
+        if is_cycle(visiting, value) {
+            return Err(Error::new("cycle detected while exporting to JSON").into());
+        }
+        if depth >= opts.max_depth {
+            out.push_str(TRUNCATED_MARKER);
+            return Ok(());
+        }
+
+        visiting.push(value.clone());
+        let result = write_container(value, depth, level, opts, visiting, out);
+        visiting.pop();
+        result
     }
 
-    impl Default for Collator {
-        fn default() -> Self {
-            Self::new(
-                &JsValue::UNDEFINED.unchecked_into(),
-                &JsValue::UNDEFINED.unchecked_into(),
-            )
+    fn write_container(
+        value: &JsValue,
+        depth: usize,
+        level: usize,
+        opts: &ExportOptions,
+        visiting: &mut Vec<JsValue>,
+        out: &mut String,
+    ) -> Result<(), JsValue> {
+        if ArrayBuffer::is_view(value) && value.dyn_ref::<DataView>().is_none() {
+            return match opts.typed_array_encoding {
+                TypedArrayEncoding::Base64 => {
+                    out.push_str(&quote(&base64_encode(&typed_array_bytes(value)?))?);
+                    Ok(())
+                }
+                TypedArrayEncoding::Array => {
+                    let elements = Array::from(value);
+                    write_array(elements.iter(), depth, level, opts, visiting, out)
+                }
+            };
         }
-    }
 
-    // Intl.DateTimeFormat
-    #[wasm_bindgen]
-    extern "C" {
-        /// The `Intl.DateTimeFormat` object is a constructor for objects
-        /// that enable language-sensitive date and time formatting.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DateTimeFormat)
-        #[wasm_bindgen(extends = Object, js_namespace = Intl, typescript_type = "Intl.DateTimeFormat")]
-        #[derive(Clone, Debug)]
-        pub type DateTimeFormat;
+        if let Some(array) = value.dyn_ref::<Array>() {
+            return write_array(array.iter(), depth, level, opts, visiting, out);
+        }
 
-        /// The `Intl.DateTimeFormat` object is a constructor for objects
-        /// that enable language-sensitive date and time formatting.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DateTimeFormat)
-        #[wasm_bindgen(constructor, js_namespace = Intl)]
-        pub fn new(locales: &Array, options: &Object) -> DateTimeFormat;
+        if let Some(set) = value.dyn_ref::<Set>() {
+            return write_array(set.iter_snapshot(), depth, level, opts, visiting, out);
+        }
 
-        /// The Intl.DateTimeFormat.prototype.format property returns a getter function that
-        /// formats a date according to the locale and formatting options of this
-        /// Intl.DateTimeFormat object.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DateTimeFormat/format)
-        #[wasm_bindgen(method, getter, js_class = "Intl.DateTimeFormat")]
-        pub fn format(this: &DateTimeFormat) -> Function;
+        if let Some(map) = value.dyn_ref::<Map>() {
+            return write_map(map, depth, level, opts, visiting, out);
+        }
 
-        /// The `Intl.DateTimeFormat.prototype.formatToParts()` method allows locale-aware
-        /// formatting of strings produced by DateTimeFormat formatters.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DateTimeFormat/formatToParts)
-        #[wasm_bindgen(method, js_class = "Intl.DateTimeFormat", js_name = formatToParts)]
-        pub fn format_to_parts(this: &DateTimeFormat, date: &Date) -> Array;
+        write_object(value.unchecked_ref::<Object>(), depth, level, opts, visiting, out)
+    }
 
-        /// The `Intl.DateTimeFormat.prototype.resolvedOptions()` method returns a new
-        /// object with properties reflecting the locale and date and time formatting
-        /// options computed during initialization of this DateTimeFormat object.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DateTimeFormat/resolvedOptions)
-        #[wasm_bindgen(method, js_namespace = Intl, js_name = resolvedOptions)]
-        pub fn resolved_options(this: &DateTimeFormat) -> Object;
+    fn write_array(
+        items: impl core::iter::Iterator<Item = JsValue>,
+        depth: usize,
+        level: usize,
+        opts: &ExportOptions,
+        visiting: &mut Vec<JsValue>,
+        out: &mut String,
+    ) -> Result<(), JsValue> {
+        out.push('[');
+        let mut first = true;
+
+        for item in items {
+            if !first {
+                out.push(',');
+            }
+            write_indent(out, level + 1, opts);
+            write_value(&item, depth + 1, level + 1, opts, visiting, out)?;
+            first = false;
+        }
 
-        /// The `Intl.DateTimeFormat.supportedLocalesOf()` method returns an array
-        /// containing those of the provided locales that are supported in date
-        /// and time formatting without having to fall back to the runtime's default
-        /// locale.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DateTimeFormat/supportedLocalesOf)
-        #[wasm_bindgen(static_method_of = DateTimeFormat, js_namespace = Intl, js_name = supportedLocalesOf)]
-        pub fn supported_locales_of(locales: &Array, options: &Object) -> Array;
+        if !first {
+            write_indent(out, level, opts);
+        }
+        out.push(']');
+
+        Ok(())
     }
 
-    impl Default for DateTimeFormat {
-        fn default() -> Self {
-            Self::new(
-                &JsValue::UNDEFINED.unchecked_into(),
-                &JsValue::UNDEFINED.unchecked_into(),
-            )
+    fn write_object(
+        object: &Object,
+        depth: usize,
+        level: usize,
+        opts: &ExportOptions,
+        visiting: &mut Vec<JsValue>,
+        out: &mut String,
+    ) -> Result<(), JsValue> {
+        out.push('{');
+        let mut first = true;
+
+        for key in Object::keys(object).iter() {
+            let value = Reflect::get(object.as_ref(), &key)?;
+
+            if value.is_undefined() && opts.undefined_encoding == UndefinedEncoding::Skip {
+                continue;
+            }
+
+            if !first {
+                out.push(',');
+            }
+            write_indent(out, level + 1, opts);
+            out.push_str(&quote(&key.as_string().unwrap_or_default())?);
+            out.push(':');
+            if opts.indent > 0 {
+                out.push(' ');
+            }
+            write_value(&value, depth + 1, level + 1, opts, visiting, out)?;
+            first = false;
         }
-    }
 
-    // Intl.NumberFormat
-    #[wasm_bindgen]
-    extern "C" {
-        /// The `Intl.NumberFormat` object is a constructor for objects
-        /// that enable language sensitive number formatting.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/NumberFormat)
-        #[wasm_bindgen(extends = Object, js_namespace = Intl, typescript_type = "Intl.NumberFormat")]
-        #[derive(Clone, Debug)]
-        pub type NumberFormat;
+        if !first {
+            write_indent(out, level, opts);
+        }
+        out.push('}');
 
-        /// The `Intl.NumberFormat` object is a constructor for objects
-        /// that enable language sensitive number formatting.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/NumberFormat)
-        #[wasm_bindgen(constructor, js_namespace = Intl)]
-        pub fn new(locales: &Array, options: &Object) -> NumberFormat;
+        Ok(())
+    }
 
-        /// The Intl.NumberFormat.prototype.format property returns a getter function that
-        /// formats a number according to the locale and formatting options of this
-        /// NumberFormat object.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/NumberFormat/format)
-        #[wasm_bindgen(method, getter, js_class = "Intl.NumberFormat")]
-        pub fn format(this: &NumberFormat) -> Function;
+    fn write_map(
+        map: &Map,
+        depth: usize,
+        level: usize,
+        opts: &ExportOptions,
+        visiting: &mut Vec<JsValue>,
+        out: &mut String,
+    ) -> Result<(), JsValue> {
+        let entries = map.iter_snapshot();
+
+        match opts.map_encoding {
+            MapEncoding::PairsArray => {
+                out.push('[');
+                let mut first = true;
+
+                for (key, value) in entries {
+                    if !first {
+                        out.push(',');
+                    }
+                    write_indent(out, level + 1, opts);
+                    out.push('[');
+                    write_value(&key, depth + 2, level + 1, opts, visiting, out)?;
+                    out.push(',');
+                    if opts.indent > 0 {
+                        out.push(' ');
+                    }
+                    write_value(&value, depth + 2, level + 1, opts, visiting, out)?;
+                    out.push(']');
+                    first = false;
+                }
 
-        /// The `Intl.Numberformat.prototype.formatToParts()` method allows locale-aware
-        /// formatting of strings produced by NumberTimeFormat formatters.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/NumberFormat/formatToParts)
-        #[wasm_bindgen(method, js_class = "Intl.NumberFormat", js_name = formatToParts)]
-        pub fn format_to_parts(this: &NumberFormat, number: f64) -> Array;
+                if !first {
+                    write_indent(out, level, opts);
+                }
+                out.push(']');
+            }
+            MapEncoding::Object => {
+                out.push('{');
+                let mut first = true;
+
+                for (key, value) in entries {
+                    if value.is_undefined() && opts.undefined_encoding == UndefinedEncoding::Skip {
+                        continue;
+                    }
+
+                    if !first {
+                        out.push(',');
+                    }
+                    write_indent(out, level + 1, opts);
+                    out.push_str(&quote(&map_key_to_property_name(&key))?);
+                    out.push(':');
+                    if opts.indent > 0 {
+                        out.push(' ');
+                    }
+                    write_value(&value, depth + 1, level + 1, opts, visiting, out)?;
+                    first = false;
+                }
 
-        /// The `Intl.NumberFormat.prototype.resolvedOptions()` method returns a new
-        /// object with properties reflecting the locale and number formatting
-        /// options computed during initialization of this NumberFormat object.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/NumberFormat/resolvedOptions)
-        #[wasm_bindgen(method, js_namespace = Intl, js_name = resolvedOptions)]
-        pub fn resolved_options(this: &NumberFormat) -> Object;
+                if !first {
+                    write_indent(out, level, opts);
+                }
+                out.push('}');
+            }
+        }
 
-        /// The `Intl.NumberFormat.supportedLocalesOf()` method returns an array
-        /// containing those of the provided locales that are supported in number
-        /// formatting without having to fall back to the runtime's default locale.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/NumberFormat/supportedLocalesOf)
-        #[wasm_bindgen(static_method_of = NumberFormat, js_namespace = Intl, js_name = supportedLocalesOf)]
-        pub fn supported_locales_of(locales: &Array, options: &Object) -> Array;
+        Ok(())
     }
 
-    impl Default for NumberFormat {
-        fn default() -> Self {
-            Self::new(
-                &JsValue::UNDEFINED.unchecked_into(),
-                &JsValue::UNDEFINED.unchecked_into(),
-            )
-        }
+    /// Serializes `value` to a JSON string, using `opts` to resolve the
+    /// ambiguities `JSON.stringify` doesn't handle: `Map`s, `Set`s,
+    /// `BigInt`s, `Date`s, and typed arrays. A reference cycle returns an
+    /// error instead of throwing a raw `RangeError` from exhausting the
+    /// call stack; nesting beyond `opts.max_depth` is truncated in place
+    /// rather than erroring.
+    pub fn to_json_string(value: &JsValue, opts: &ExportOptions) -> Result<String, JsValue> {
+        let mut out = String::new();
+        write_value(value, 0, 0, opts, &mut Vec::new(), &mut out)?;
+        Ok(out)
     }
+}
 
-    // Intl.PluralRules
-    #[wasm_bindgen]
-    extern "C" {
-        /// The `Intl.PluralRules` object is a constructor for objects
-        /// that enable plural sensitive formatting and plural language rules.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/PluralRules)
-        #[wasm_bindgen(extends = Object, js_namespace = Intl, typescript_type = "Intl.PluralRules")]
-        #[derive(Clone, Debug)]
-        pub type PluralRules;
+/// Which element type a [`JsType::TypedArray`] view has.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TypedArrayKind {
+    Int8,
+    Uint8,
+    Uint8Clamped,
+    Int16,
+    Uint16,
+    Int32,
+    Uint32,
+    Float32,
+    Float64,
+    BigInt64,
+    BigUint64,
+}
 
-        /// The `Intl.PluralRules` object is a constructor for objects
-        /// that enable plural sensitive formatting and plural language rules.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/PluralRules)
-        #[wasm_bindgen(constructor, js_namespace = Intl)]
-        pub fn new(locales: &Array, options: &Object) -> PluralRules;
+/// Classifies the runtime type of a [`JsValue`], for dispatch code that
+/// would otherwise be a long chain of `is_*`/`dyn_ref` checks. Returned by
+/// [`JsType::of`]; see [`value::visit`] for a trait-based alternative that
+/// also hands back the narrowed value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum JsType {
+    Undefined,
+    Null,
+    Boolean,
+    Number,
+    BigInt,
+    String,
+    Symbol,
+    Function,
+    Array,
+    TypedArray(TypedArrayKind),
+    ArrayBuffer,
+    Map,
+    Set,
+    Date,
+    RegExp,
+    Error,
+    Promise,
+    PlainObject,
+    OtherObject,
+}
 
-        /// The `Intl.PluralRules.prototype.resolvedOptions()` method returns a new
-        /// object with properties reflecting the locale and plural formatting
-        /// options computed during initialization of this PluralRules object.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/PluralRules/resolvedOptions)
-        #[wasm_bindgen(method, js_namespace = Intl, js_name = resolvedOptions)]
-        pub fn resolved_options(this: &PluralRules) -> Object;
+impl JsType {
+    /// Classifies `value`'s runtime type, checking the cheapest and least
+    /// ambiguous cases first -- e.g. [`Array::is_array`] before the generic
+    /// object fallback, so an array is never misreported as a plain
+    /// object. Values from another realm (a different iframe or worker)
+    /// still classify correctly for the brand-checked cases (`Array` and
+    /// `Error`), since those use engine-level brand checks rather than an
+    /// `instanceof` test against this realm's constructors. A typed array
+    /// from another realm passes the [`ArrayBuffer::is_view`] brand check,
+    /// but its element kind is found with `instanceof`, so it classifies
+    /// as [`JsType::OtherObject`].
+    pub fn of(value: &JsValue) -> JsType {
+        if value.is_undefined() {
+            return JsType::Undefined;
+        }
+        if value.is_null() {
+            return JsType::Null;
+        }
+        if value.as_bool().is_some() {
+            return JsType::Boolean;
+        }
+        if value.as_f64().is_some() {
+            return JsType::Number;
+        }
+        if value.is_bigint() {
+            return JsType::BigInt;
+        }
+        if value.is_string() {
+            return JsType::String;
+        }
+        if value.is_symbol() {
+            return JsType::Symbol;
+        }
+        if value.is_function() {
+            return JsType::Function;
+        }
+        if Array::is_array(value) {
+            return JsType::Array;
+        }
+        if let Some(kind) = typed_array_kind(value) {
+            return JsType::TypedArray(kind);
+        }
+        if value.dyn_ref::<ArrayBuffer>().is_some() {
+            return JsType::ArrayBuffer;
+        }
+        if value.dyn_ref::<Map>().is_some() {
+            return JsType::Map;
+        }
+        if value.dyn_ref::<Set>().is_some() {
+            return JsType::Set;
+        }
+        if value.dyn_ref::<Date>().is_some() {
+            return JsType::Date;
+        }
+        if value.dyn_ref::<RegExp>().is_some() {
+            return JsType::RegExp;
+        }
+        if Error::is_error(value) {
+            return JsType::Error;
+        }
+        if value.dyn_ref::<Promise>().is_some() {
+            return JsType::Promise;
+        }
+        if is_plain_object(value) {
+            JsType::PlainObject
+        } else {
+            JsType::OtherObject
+        }
+    }
+}
 
-        /// The `Intl.PluralRules.prototype.select()` method returns a String indicating
-        /// which plural rule to use for locale-aware formatting.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/PluralRules/select)
-        #[wasm_bindgen(method, js_namespace = Intl)]
-        pub fn select(this: &PluralRules, number: f64) -> JsString;
+fn typed_array_kind(value: &JsValue) -> Option<TypedArrayKind> {
+    if !ArrayBuffer::is_view(value) || value.dyn_ref::<DataView>().is_some() {
+        return None;
+    }
+    if value.dyn_ref::<Int8Array>().is_some() {
+        Some(TypedArrayKind::Int8)
+    } else if value.dyn_ref::<Uint8ClampedArray>().is_some() {
+        Some(TypedArrayKind::Uint8Clamped)
+    } else if value.dyn_ref::<Uint8Array>().is_some() {
+        Some(TypedArrayKind::Uint8)
+    } else if value.dyn_ref::<Int16Array>().is_some() {
+        Some(TypedArrayKind::Int16)
+    } else if value.dyn_ref::<Uint16Array>().is_some() {
+        Some(TypedArrayKind::Uint16)
+    } else if value.dyn_ref::<Int32Array>().is_some() {
+        Some(TypedArrayKind::Int32)
+    } else if value.dyn_ref::<Uint32Array>().is_some() {
+        Some(TypedArrayKind::Uint32)
+    } else if value.dyn_ref::<Float32Array>().is_some() {
+        Some(TypedArrayKind::Float32)
+    } else if value.dyn_ref::<Float64Array>().is_some() {
+        Some(TypedArrayKind::Float64)
+    } else if value.dyn_ref::<BigInt64Array>().is_some() {
+        Some(TypedArrayKind::BigInt64)
+    } else if value.dyn_ref::<BigUint64Array>().is_some() {
+        Some(TypedArrayKind::BigUint64)
+    } else {
+        None
+    }
+}
 
-        /// The `Intl.PluralRules.supportedLocalesOf()` method returns an array
-        /// containing those of the provided locales that are supported in plural
-        /// formatting without having to fall back to the runtime's default locale.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/PluralRules/supportedLocalesOf)
-        #[wasm_bindgen(static_method_of = PluralRules, js_namespace = Intl, js_name = supportedLocalesOf)]
-        pub fn supported_locales_of(locales: &Array, options: &Object) -> Array;
+/// Returns `true` if `value`'s prototype is exactly `Object.prototype` or
+/// `null` -- i.e. it's a literal-like object (`{}`, `Object.create(null)`,
+/// or a structure built only from those) rather than an instance of some
+/// other class.
+fn is_plain_object(value: &JsValue) -> bool {
+    let proto = Object::get_prototype_of(value);
+    let proto_value: &JsValue = proto.as_ref();
+    if proto_value.is_null() {
+        return true;
     }
+    let object_prototype = Object::get_prototype_of(Object::new().as_ref());
+    Object::is(proto_value, object_prototype.as_ref())
+}
 
-    impl Default for PluralRules {
-        fn default() -> Self {
-            Self::new(
-                &JsValue::UNDEFINED.unchecked_into(),
-                &JsValue::UNDEFINED.unchecked_into(),
-            )
+/// Trait-based dispatch on a [`JsValue`]'s runtime type, as an alternative
+/// to matching on [`JsType::of`] -- implement only the variants you care
+/// about; the rest default to doing nothing.
+pub mod value {
+    use super::*;
+
+    /// Visits exactly one of these methods per [`visit`] call, with the
+    /// value already narrowed to its concrete type. Every method defaults
+    /// to a no-op, so implementors only override what they need.
+    #[allow(unused_variables)]
+    pub trait JsVisitor {
+        fn visit_undefined(&mut self) {}
+        fn visit_null(&mut self) {}
+        fn visit_boolean(&mut self, value: bool) {}
+        fn visit_number(&mut self, value: f64) {}
+        fn visit_bigint(&mut self, value: &BigInt) {}
+        fn visit_string(&mut self, value: &JsString) {}
+        fn visit_symbol(&mut self, value: &Symbol) {}
+        fn visit_function(&mut self, value: &Function) {}
+        fn visit_array(&mut self, value: &Array) {}
+        fn visit_typed_array(&mut self, value: &JsValue, kind: TypedArrayKind) {}
+        fn visit_array_buffer(&mut self, value: &ArrayBuffer) {}
+        fn visit_map(&mut self, value: &Map) {}
+        fn visit_set(&mut self, value: &Set) {}
+        fn visit_date(&mut self, value: &Date) {}
+        fn visit_regexp(&mut self, value: &RegExp) {}
+        fn visit_error(&mut self, value: &Error) {}
+        fn visit_promise(&mut self, value: &Promise) {}
+        fn visit_plain_object(&mut self, value: &Object) {}
+        fn visit_other_object(&mut self, value: &Object) {}
+    }
+
+    /// Classifies `value` via [`JsType::of`] and dispatches to the matching
+    /// [`JsVisitor`] method, with `value` already cast to the concrete
+    /// type that method expects.
+    pub fn visit(value: &JsValue, visitor: &mut impl JsVisitor) {
+        match JsType::of(value) {
+            JsType::Undefined => visitor.visit_undefined(),
+            JsType::Null => visitor.visit_null(),
+            JsType::Boolean => visitor.visit_boolean(value.as_bool().unwrap_throw()),
+            JsType::Number => visitor.visit_number(value.as_f64().unwrap_throw()),
+            JsType::BigInt => visitor.visit_bigint(value.unchecked_ref()),
+            JsType::String => visitor.visit_string(value.unchecked_ref()),
+            JsType::Symbol => visitor.visit_symbol(value.unchecked_ref()),
+            JsType::Function => visitor.visit_function(value.unchecked_ref()),
+            JsType::Array => visitor.visit_array(value.unchecked_ref()),
+            JsType::TypedArray(kind) => visitor.visit_typed_array(value, kind),
+            JsType::ArrayBuffer => visitor.visit_array_buffer(value.unchecked_ref()),
+            JsType::Map => visitor.visit_map(value.unchecked_ref()),
+            JsType::Set => visitor.visit_set(value.unchecked_ref()),
+            JsType::Date => visitor.visit_date(value.unchecked_ref()),
+            JsType::RegExp => visitor.visit_regexp(value.unchecked_ref()),
+            JsType::Error => visitor.visit_error(value.unchecked_ref()),
+            JsType::Promise => visitor.visit_promise(value.unchecked_ref()),
+            JsType::PlainObject => visitor.visit_plain_object(value.unchecked_ref()),
+            JsType::OtherObject => visitor.visit_other_object(value.unchecked_ref()),
         }
     }
+}
 
-    // Intl.RelativeTimeFormat
-    #[wasm_bindgen]
-    extern "C" {
-        /// The `Intl.RelativeTimeFormat` object is a constructor for objects
-        /// that enable language-sensitive relative time formatting.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/RelativeTimeFormat)
-        #[wasm_bindgen(extends = Object, js_namespace = Intl, typescript_type = "Intl.RelativeTimeFormat")]
-        #[derive(Clone, Debug)]
-        pub type RelativeTimeFormat;
+/// Calling `Array.prototype` methods on array-like objects (anything with
+/// a `length` and indexed properties, but not necessarily a real `Array`
+/// -- `arguments` objects, `NodeList`s, and similar host objects) by
+/// borrowing the method and invoking it with the array-like as `this`,
+/// rather than first copying it into a real `Array` with [`Array::from`].
+pub mod array_like {
+    use super::*;
 
-        /// The `Intl.RelativeTimeFormat` object is a constructor for objects
-        /// that enable language-sensitive relative time formatting.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/RelativeTimeFormat)
-        #[wasm_bindgen(constructor, js_namespace = Intl)]
-        pub fn new(locales: &Array, options: &Object) -> RelativeTimeFormat;
+    /// Looks up and caches `Array.prototype[name]` the first time it's
+    /// needed, the same dual-path thread-local/`once_cell` pattern used by
+    /// [`own_key_count_fn`] elsewhere in this crate.
+    fn cached_array_prototype_method(name: &'static str, init: impl FnOnce() -> Function) -> Function {
+        #[cfg(feature = "std")]
+        {
+            thread_local! {
+                static CACHE: RefCell<BTreeMap<&'static str, Function>> = RefCell::new(BTreeMap::new());
+            }
+            CACHE.with(|cache| {
+                cache
+                    .borrow_mut()
+                    .entry(name)
+                    .or_insert_with(init)
+                    .clone()
+            })
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            use once_cell::unsync::Lazy;
 
-        /// The `Intl.RelativeTimeFormat.prototype.format` method formats a `value` and `unit`
-        /// according to the locale and formatting options of this Intl.RelativeTimeFormat object.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/RelativeTimeFormat/format)
-        #[wasm_bindgen(method, js_class = "Intl.RelativeTimeFormat")]
-        pub fn format(this: &RelativeTimeFormat, value: f64, unit: &str) -> JsString;
+            struct Wrapper(Lazy<RefCell<BTreeMap<&'static str, Function>>>);
 
-        /// The `Intl.RelativeTimeFormat.prototype.formatToParts()` method returns an array of
-        /// objects representing the relative time format in parts that can be used for custom locale-aware formatting.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/RelativeTimeFormat/formatToParts)
-        #[wasm_bindgen(method, js_class = "Intl.RelativeTimeFormat", js_name = formatToParts)]
-        pub fn format_to_parts(this: &RelativeTimeFormat, value: f64, unit: &str) -> Array;
+            #[cfg(not(target_feature = "atomics"))]
+            unsafe impl Sync for Wrapper {}
 
-        /// The `Intl.RelativeTimeFormat.prototype.resolvedOptions()` method returns a new
-        /// object with properties reflecting the locale and relative time formatting
-        /// options computed during initialization of this RelativeTimeFormat object.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/RelativeTimeFormat/resolvedOptions)
-        #[wasm_bindgen(method, js_namespace = Intl, js_name = resolvedOptions)]
-        pub fn resolved_options(this: &RelativeTimeFormat) -> Object;
+            #[cfg(not(target_feature = "atomics"))]
+            unsafe impl Send for Wrapper {}
 
-        /// The `Intl.RelativeTimeFormat.supportedLocalesOf()` method returns an array
-        /// containing those of the provided locales that are supported in date and time
-        /// formatting without having to fall back to the runtime's default locale.
-        ///
-        /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RelativeTimeFormat/supportedLocalesOf)
-        #[wasm_bindgen(static_method_of = RelativeTimeFormat, js_namespace = Intl, js_name = supportedLocalesOf)]
-        pub fn supported_locales_of(locales: &Array, options: &Object) -> Array;
+            #[cfg_attr(target_feature = "atomics", thread_local)]
+            static CACHE: Wrapper = Wrapper(Lazy::new(|| RefCell::new(BTreeMap::new())));
+
+            CACHE
+                .0
+                .borrow_mut()
+                .entry(name)
+                .or_insert_with(init)
+                .clone()
+        }
+    }
+
+    fn array_prototype_method(name: &'static str) -> Function {
+        fn lookup(name: &str) -> Function {
+            let prototype = Object::get_prototype_of(Array::new().as_ref());
+            Reflect::get(prototype.as_ref(), &JsValue::from_str(name))
+                .ok()
+                .and_then(|f| f.dyn_into::<Function>().ok())
+                .unwrap_throw()
+        }
+
+        cached_array_prototype_method(name, || lookup(name))
+    }
+
+    /// Borrows `Array.prototype.slice` and calls it on `value`, equivalent
+    /// to `Array.prototype.slice.call(value, start, end)`.
+    pub fn slice_of(value: &Object, start: i32, end: i32) -> Result<Array, JsValue> {
+        let result = array_prototype_method("slice").call2(
+            value.as_ref(),
+            &JsValue::from_f64(start as f64),
+            &JsValue::from_f64(end as f64),
+        )?;
+        Ok(result.unchecked_into())
+    }
+
+    /// Borrows `Array.prototype.indexOf` and calls it on `value`,
+    /// equivalent to `Array.prototype.indexOf.call(value, needle)`.
+    pub fn index_of_in(value: &Object, needle: &JsValue) -> Result<i32, JsValue> {
+        let result = array_prototype_method("indexOf").call1(value.as_ref(), needle)?;
+        Ok(result.as_f64().unwrap_or(-1.0) as i32)
     }
 
-    impl Default for RelativeTimeFormat {
-        fn default() -> Self {
-            Self::new(
-                &JsValue::UNDEFINED.unchecked_into(),
-                &JsValue::UNDEFINED.unchecked_into(),
-            )
+    /// Visits every element of `value` by index, in the same order
+    /// `Array.prototype.forEach` would.
+    ///
+    /// Unlike [`slice_of`] and [`index_of_in`], this drives the iteration
+    /// from Rust via [`length_of`] and `Reflect::get` rather than handing
+    /// a closure to a borrowed JS `forEach` -- the same trade-off
+    /// [`Array::for_each_rust`] makes over [`Array::for_each`]: one JS
+    /// round trip per element instead of per callback invocation, but no
+    /// JS-visible callback value needs to be built at all.
+    pub fn for_each_of(value: &Object, cb: &mut dyn FnMut(JsValue, u32)) {
+        let len = length_of(value).unwrap_or(0);
+        for index in 0..len {
+            let item = Reflect::get(value.as_ref(), &JsValue::from_f64(index as f64)).unwrap_throw();
+            cb(item, index);
+        }
+    }
+
+    /// Reads `value`'s `length` property with `ToLength` semantics, capped
+    /// to `u32`: the property goes through [`coerce::to_number`] (so a
+    /// numeric string such as `"3"` reads as `3`), a missing, negative or
+    /// `NaN` length reads as `0`, a non-integer length is truncated, and a
+    /// length above `u32::MAX` (`Infinity` included) reads as `u32::MAX`.
+    /// Returns `Err` if reading or converting the property throws.
+    pub fn length_of(value: &Object) -> Result<u32, JsValue> {
+        let raw = Reflect::get(value.as_ref(), &JsValue::from_str("length"))?;
+        let len = coerce::to_number(&raw)?;
+        if len.is_nan() || len <= 0.0 {
+            Ok(0)
+        } else {
+            // `as u32` truncates the fractional part and saturates at
+            // `u32::MAX`.
+            Ok(len as u32)
         }
     }
 }
 
-// Promise
-#[wasm_bindgen]
-extern "C" {
-    /// The `Promise` object represents the eventual completion (or failure) of
-    /// an asynchronous operation, and its resulting value.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise)
-    #[must_use]
-    #[wasm_bindgen(extends = Object, typescript_type = "Promise<any>")]
-    #[derive(Clone, Debug)]
-    pub type Promise;
+/// Lightweight instrumentation for counting how often this crate's own
+/// Rust-implemented helpers cross the wasm/JS boundary, enabled by the
+/// `call-metrics` cargo feature. With the feature off, every call site
+/// that would record a count is compiled out entirely (see the
+/// `#[cfg(feature = "call-metrics")]` guards at each call site), so
+/// there's no runtime cost at all, not even an empty function call.
+#[cfg(feature = "call-metrics")]
+pub mod metrics {
+    use super::*;
 
-    /// Creates a new `Promise` with the provided executor `cb`
-    ///
-    /// The `cb` is a function that is passed with the arguments `resolve` and
-    /// `reject`. The `cb` function is executed immediately by the `Promise`
-    /// implementation, passing `resolve` and `reject` functions (the executor
-    /// is called before the `Promise` constructor even returns the created
-    /// object). The `resolve` and `reject` functions, when called, resolve or
-    /// reject the promise, respectively. The executor normally initiates
-    /// some asynchronous work, and then, once that completes, either calls
-    /// the `resolve` function to resolve the promise or else rejects it if an
-    /// error occurred.
-    ///
-    /// If an error is thrown in the executor function, the promise is rejected.
-    /// The return value of the executor is ignored.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise)
-    #[wasm_bindgen(constructor)]
-    pub fn new(cb: &mut dyn FnMut(Function, Function)) -> Promise;
+    /// A category of instrumented boundary crossing.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    pub enum Category {
+        /// A single-element read, e.g. `ArrayIter::next`, `Map` entry
+        /// iteration.
+        ElementGet,
+        /// A bulk copy covering many elements in one call, e.g.
+        /// `to_vec`/`copy_to` on a typed array.
+        BulkCopy,
+        /// A JS-visible closure being invoked.
+        ClosureInvoke,
+        /// A JS constructor call.
+        Constructor,
+        /// Anything not covered by the other categories.
+        Other,
+    }
 
-    /// The `Promise.all(iterable)` method returns a single `Promise` that
-    /// resolves when all of the promises in the iterable argument have resolved
-    /// or when the iterable argument contains no promises. It rejects with the
-    /// reason of the first promise that rejects.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/all)
-    #[wasm_bindgen(static_method_of = Promise)]
-    pub fn all(obj: &JsValue) -> Promise;
+    /// A snapshot of per-category call counts.
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    pub struct CallMetrics {
+        pub element_get: u64,
+        pub bulk_copy: u64,
+        pub closure_invoke: u64,
+        pub constructor: u64,
+        pub other: u64,
+    }
 
-    /// The `Promise.allSettled(iterable)` method returns a single `Promise` that
-    /// resolves when all of the promises in the iterable argument have either
-    /// fulfilled or rejected or when the iterable argument contains no promises.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/allSettled)
-    #[wasm_bindgen(static_method_of = Promise, js_name = allSettled)]
-    pub fn all_settled(obj: &JsValue) -> Promise;
+    impl CallMetrics {
+        fn get_mut(&mut self, category: Category) -> &mut u64 {
+            match category {
+                Category::ElementGet => &mut self.element_get,
+                Category::BulkCopy => &mut self.bulk_copy,
+                Category::ClosureInvoke => &mut self.closure_invoke,
+                Category::Constructor => &mut self.constructor,
+                Category::Other => &mut self.other,
+            }
+        }
 
-    /// The `Promise.any(iterable)` method returns a single `Promise` that
-    /// resolves when any of the promises in the iterable argument have resolved
-    /// or when the iterable argument contains no promises. It rejects with an
-    /// `AggregateError` if all promises in the iterable rejected.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/any)
-    #[wasm_bindgen(static_method_of = Promise)]
-    pub fn any(obj: &JsValue) -> Promise;
+        fn saturating_delta(&self, earlier: &CallMetrics) -> CallMetrics {
+            CallMetrics {
+                element_get: self.element_get.saturating_sub(earlier.element_get),
+                bulk_copy: self.bulk_copy.saturating_sub(earlier.bulk_copy),
+                closure_invoke: self.closure_invoke.saturating_sub(earlier.closure_invoke),
+                constructor: self.constructor.saturating_sub(earlier.constructor),
+                other: self.other.saturating_sub(earlier.other),
+            }
+        }
+    }
 
-    /// The `Promise.race(iterable)` method returns a promise that resolves or
-    /// rejects as soon as one of the promises in the iterable resolves or
-    /// rejects, with the value or reason from that promise.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/race)
-    #[wasm_bindgen(static_method_of = Promise)]
-    pub fn race(obj: &JsValue) -> Promise;
+    fn with_counts<R>(f: impl FnOnce(&mut CallMetrics) -> R) -> R {
+        #[cfg(feature = "std")]
+        {
+            thread_local!(static COUNTS: RefCell<CallMetrics> = RefCell::new(CallMetrics::default()));
+            COUNTS.with(|c| f(&mut c.borrow_mut()))
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            use once_cell::unsync::Lazy;
 
-    /// The `Promise.reject(reason)` method returns a `Promise` object that is
-    /// rejected with the given reason.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/reject)
-    #[wasm_bindgen(static_method_of = Promise)]
-    pub fn reject(obj: &JsValue) -> Promise;
+            struct Wrapper(Lazy<RefCell<CallMetrics>>);
 
-    /// The `Promise.resolve(value)` method returns a `Promise` object that is
-    /// resolved with the given value. If the value is a promise, that promise
-    /// is returned; if the value is a thenable (i.e. has a "then" method), the
-    /// returned promise will "follow" that thenable, adopting its eventual
-    /// state; otherwise the returned promise will be fulfilled with the value.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/resolve)
-    #[wasm_bindgen(static_method_of = Promise)]
-    pub fn resolve(obj: &JsValue) -> Promise;
+            #[cfg(not(target_feature = "atomics"))]
+            unsafe impl Sync for Wrapper {}
 
-    /// The `catch()` method returns a `Promise` and deals with rejected cases
-    /// only.  It behaves the same as calling `Promise.prototype.then(undefined,
-    /// onRejected)` (in fact, calling `obj.catch(onRejected)` internally calls
-    /// `obj.then(undefined, onRejected)`).
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/catch)
-    #[wasm_bindgen(method)]
-    pub fn catch(this: &Promise, cb: &Closure<dyn FnMut(JsValue)>) -> Promise;
+            #[cfg(not(target_feature = "atomics"))]
+            unsafe impl Send for Wrapper {}
 
-    /// The `then()` method returns a `Promise`. It takes up to two arguments:
-    /// callback functions for the success and failure cases of the `Promise`.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/then)
-    #[wasm_bindgen(method)]
-    pub fn then(this: &Promise, cb: &Closure<dyn FnMut(JsValue)>) -> Promise;
+            #[cfg_attr(target_feature = "atomics", thread_local)]
+            static COUNTS: Wrapper = Wrapper(Lazy::new(|| RefCell::new(CallMetrics::default())));
 
-    /// Same as `then`, only with both arguments provided.
-    #[wasm_bindgen(method, js_name = then)]
-    pub fn then2(
-        this: &Promise,
-        resolve: &Closure<dyn FnMut(JsValue)>,
-        reject: &Closure<dyn FnMut(JsValue)>,
-    ) -> Promise;
+            f(&mut COUNTS.0.borrow_mut())
+        }
+    }
 
-    /// The `finally()` method returns a `Promise`. When the promise is settled,
-    /// whether fulfilled or rejected, the specified callback function is
-    /// executed. This provides a way for code that must be executed once the
-    /// `Promise` has been dealt with to be run whether the promise was
-    /// fulfilled successfully or rejected.
-    ///
-    /// This lets you avoid duplicating code in both the promise's `then()` and
-    /// `catch()` handlers.
-    ///
-    /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/finally)
-    #[wasm_bindgen(method)]
-    pub fn finally(this: &Promise, cb: &Closure<dyn FnMut()>) -> Promise;
-}
+    fn with_scopes<R>(f: impl FnOnce(&mut Vec<(String, CallMetrics)>) -> R) -> R {
+        #[cfg(feature = "std")]
+        {
+            thread_local!(static SCOPES: RefCell<Vec<(String, CallMetrics)>> = RefCell::new(Vec::new()));
+            SCOPES.with(|s| f(&mut s.borrow_mut()))
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            use once_cell::unsync::Lazy;
 
-/// Returns a handle to the global scope object.
-///
-/// This allows access to the global properties and global names by accessing
-/// the `Object` returned.
-pub fn global() -> Object {
-    #[cfg(feature = "std")]
-    {
-        thread_local!(static GLOBAL: Object = get_global_object());
-        return GLOBAL.with(|g| g.clone());
-    }
-    #[cfg(not(feature = "std"))]
-    {
-        use once_cell::unsync::Lazy;
+            struct Wrapper(Lazy<RefCell<Vec<(String, CallMetrics)>>>);
 
-        struct Wrapper<T>(Lazy<T>);
+            #[cfg(not(target_feature = "atomics"))]
+            unsafe impl Sync for Wrapper {}
 
-        #[cfg(not(target_feature = "atomics"))]
-        unsafe impl<T> Sync for Wrapper<T> {}
+            #[cfg(not(target_feature = "atomics"))]
+            unsafe impl Send for Wrapper {}
 
-        #[cfg(not(target_feature = "atomics"))]
-        unsafe impl<T> Send for Wrapper<T> {}
+            #[cfg_attr(target_feature = "atomics", thread_local)]
+            static SCOPES: Wrapper = Wrapper(Lazy::new(|| RefCell::new(Vec::new())));
 
-        #[cfg_attr(target_feature = "atomics", thread_local)]
-        static GLOBAL: Wrapper<Object> = Wrapper(Lazy::new(get_global_object));
+            f(&mut SCOPES.0.borrow_mut())
+        }
+    }
 
-        return GLOBAL.0.clone();
+    /// Increments the count for `category`. Called at each instrumented
+    /// site in the crate; has no effect outside of this module's own
+    /// bookkeeping.
+    pub fn record(category: Category) {
+        with_counts(|counts| *counts.get_mut(category) += 1);
     }
 
-    fn get_global_object() -> Object {
-        // Accessing the global object is not an easy thing to do, and what we
-        // basically want is `globalThis` but we can't rely on that existing
-        // everywhere. In the meantime we've got the fallbacks mentioned in:
-        //
-        // https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/globalThis
-        //
-        // Note that this is pretty heavy code-size wise but it at least gets
-        // the job largely done for now and avoids the `Function` constructor at
-        // the end which triggers CSP errors.
-        #[wasm_bindgen]
-        extern "C" {
-            type Global;
+    /// Returns the current total counts, since the last [`reset`].
+    pub fn snapshot() -> CallMetrics {
+        with_counts(|counts| *counts)
+    }
 
-            #[wasm_bindgen(thread_local_v2, js_name = globalThis)]
-            static GLOBAL_THIS: Option<Object>;
+    /// Zeroes every counter and clears the recorded scope results.
+    pub fn reset() {
+        with_counts(|counts| *counts = CallMetrics::default());
+        with_scopes(|scopes| scopes.clear());
+    }
 
-            #[wasm_bindgen(thread_local_v2, js_name = self)]
-            static SELF: Option<Object>;
+    /// Runs `f`, recording the delta in counts caused by running it under
+    /// `name`, appending `(name, delta)` to the list returned by
+    /// [`scoped_results`]. Nested `scoped` calls each get their own entry;
+    /// an outer scope's delta includes everything a nested scope did,
+    /// since both measure against the same running totals.
+    pub fn scoped<R>(name: &str, f: impl FnOnce() -> R) -> R {
+        let before = snapshot();
+        let result = f();
+        let after = snapshot();
+        with_scopes(|scopes| scopes.push((String::from(name), after.saturating_delta(&before))));
+        result
+    }
 
-            #[wasm_bindgen(thread_local_v2, js_name = window)]
-            static WINDOW: Option<Object>;
+    /// Returns every `(name, delta)` pair recorded by [`scoped`] so far,
+    /// in the order the scopes completed.
+    pub fn scoped_results() -> Vec<(String, CallMetrics)> {
+        with_scopes(|scopes| scopes.clone())
+    }
+}
 
-            #[wasm_bindgen(thread_local_v2, js_name = global)]
-            static GLOBAL: Option<Object>;
-        }
+/// Choosing the right plural form of a message given an
+/// [`Intl::PluralRules`] category, without hand-writing the `match` over
+/// [`Intl::PluralCategory`] at every call site.
+pub mod plural {
+    use super::*;
 
-        // The order is important: in Firefox Extension Content Scripts `globalThis`
-        // is a Sandbox (not Window), so `globalThis` must be checked after `window`.
-        let static_object = SELF
-            .with(Option::clone)
-            .or_else(|| WINDOW.with(Option::clone))
-            .or_else(|| GLOBAL_THIS.with(Option::clone))
-            .or_else(|| GLOBAL.with(Option::clone));
-        if let Some(obj) = static_object {
-            if !obj.is_undefined() {
-                return obj;
+    /// The per-category strings for one message, e.g. `{one: "1 item",
+    /// other: "{} items"}`. `other` is required since every locale's
+    /// `PluralRules` falls back to it; the rest are optional because most
+    /// locales (English included) don't distinguish all six categories.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct PluralForms<'a> {
+        /// The form used when [`Intl::PluralRules::select_typed`] returns
+        /// [`Zero`](Intl::PluralCategory::Zero).
+        pub zero: Option<&'a str>,
+        /// The form used for [`One`](Intl::PluralCategory::One).
+        pub one: Option<&'a str>,
+        /// The form used for [`Two`](Intl::PluralCategory::Two).
+        pub two: Option<&'a str>,
+        /// The form used for [`Few`](Intl::PluralCategory::Few).
+        pub few: Option<&'a str>,
+        /// The form used for [`Many`](Intl::PluralCategory::Many).
+        pub many: Option<&'a str>,
+        /// The fallback form, used for
+        /// [`Other`](Intl::PluralCategory::Other) and for any other
+        /// category whose specific form wasn't provided.
+        pub other: &'a str,
+    }
+
+    impl<'a> PluralForms<'a> {
+        /// Builds a `PluralForms` with only the required `other` form set;
+        /// use the `zero`/`one`/`two`/`few`/`many` fields to fill in the
+        /// rest.
+        pub fn new(other: &'a str) -> Self {
+            PluralForms {
+                zero: None,
+                one: None,
+                two: None,
+                few: None,
+                many: None,
+                other,
             }
         }
+    }
 
-        // According to StackOverflow you can access the global object via:
-        //
-        //      const global = Function('return this')();
-        //
-        // I think that's because the manufactured function isn't in "strict" mode.
-        // It also turns out that non-strict functions will ignore `undefined`
-        // values for `this` when using the `apply` function.
-        //
-        // As a result we use the equivalent of this snippet to get a handle to the
-        // global object in a sort of roundabout way that should hopefully work in
-        // all contexts like ESM, node, browsers, etc.
-        let this = Function::new_no_args("return this")
-            .call0(&JsValue::undefined())
-            .ok();
-
-        // Note that we avoid `unwrap()` on `call0` to avoid code size bloat, we
-        // just handle the `Err` case as returning a different object.
-        debug_assert!(this.is_some());
-        match this {
-            Some(this) => this.unchecked_into(),
-            None => JsValue::undefined().unchecked_into(),
-        }
+    /// Picks the form of `forms` matching `n`'s plural category under
+    /// `rules`, falling back to `forms.other` when the matching category
+    /// wasn't provided.
+    pub fn choose<'a>(rules: &Intl::PluralRules, n: f64, forms: &PluralForms<'a>) -> &'a str {
+        let category = match rules.select_typed(n) {
+            Intl::PluralCategory::Zero => forms.zero,
+            Intl::PluralCategory::One => forms.one,
+            Intl::PluralCategory::Two => forms.two,
+            Intl::PluralCategory::Few => forms.few,
+            Intl::PluralCategory::Many => forms.many,
+            Intl::PluralCategory::Other => None,
+        };
+        category.unwrap_or(forms.other)
     }
 }
 
-macro_rules! arrays {
-    ($(#[doc = $ctor:literal] #[doc = $mdn:literal] $name:ident: $ty:ident,)*) => ($(
-        #[wasm_bindgen]
-        extern "C" {
-            #[wasm_bindgen(extends = Object, typescript_type = $name)]
-            #[derive(Clone, Debug)]
-            pub type $name;
-
-            /// The
-            #[doc = $ctor]
-            /// constructor creates a new array.
-            ///
-            /// [MDN documentation](
-            #[doc = $mdn]
-            /// )
-            #[wasm_bindgen(constructor)]
-            pub fn new(constructor_arg: &JsValue) -> $name;
+/// String codecs layered on top of [`DataView`], for binary formats that
+/// embed UTF-8 text as a fixed length, a `0`-terminated run, or a
+/// length-prefixed run. Every read bulk-copies its byte range out of the
+/// view once (via a [`Uint8Array`] sub-view of the same buffer) and
+/// validates it as UTF-8 in Rust, rather than decoding byte-by-byte.
+pub mod dataview {
+    use super::*;
 
-            /// An
-            #[doc = $ctor]
-            /// which creates an array with an internal buffer large
-            /// enough for `length` elements.
-            ///
-            /// [MDN documentation](
-            #[doc = $mdn]
-            /// )
-            #[wasm_bindgen(constructor)]
-            pub fn new_with_length(length: u32) -> $name;
+    /// Why a [`Cursor`] string read failed.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum Utf8OrBoundsError {
+        /// The read would have needed more bytes than remain in the view.
+        OutOfBounds {
+            /// The cursor's byte offset when the read was attempted.
+            position: usize,
+            /// The number of bytes the read needed.
+            requested: usize,
+            /// The number of bytes actually left in the view.
+            remaining: usize,
+        },
+        /// The bytes read were in-bounds but weren't valid UTF-8.
+        InvalidUtf8 {
+            /// The view-relative byte offset of the first byte that isn't
+            /// valid UTF-8 (or isn't part of a complete sequence).
+            valid_up_to: usize,
+        },
+        /// [`Cursor::read_utf8_nul`] reached the end of the view without
+        /// finding a `0` byte.
+        MissingTerminator,
+    }
 
-            /// An
-            #[doc = $ctor]
-            /// which creates an array with the given buffer but is a
-            /// view starting at `byte_offset`.
-            ///
-            /// [MDN documentation](
-            #[doc = $mdn]
-            /// )
-            #[wasm_bindgen(constructor)]
-            pub fn new_with_byte_offset(buffer: &JsValue, byte_offset: u32) -> $name;
+    impl fmt::Display for Utf8OrBoundsError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                Utf8OrBoundsError::OutOfBounds {
+                    position,
+                    requested,
+                    remaining,
+                } => write!(
+                    f,
+                    "read of {} bytes at offset {} needs more than the {} bytes remaining",
+                    requested, position, remaining
+                ),
+                Utf8OrBoundsError::InvalidUtf8 { valid_up_to } => {
+                    write!(f, "invalid UTF-8 at byte offset {}", valid_up_to)
+                }
+                Utf8OrBoundsError::MissingTerminator => {
+                    write!(f, "no nul terminator before the end of the view")
+                }
+            }
+        }
+    }
 
-            /// An
-            #[doc = $ctor]
-            /// which creates an array with the given buffer but is a
-            /// view starting at `byte_offset` for `length` elements.
-            ///
-            /// [MDN documentation](
-            #[doc = $mdn]
-            /// )
-            #[wasm_bindgen(constructor)]
-            pub fn new_with_byte_offset_and_length(
-                buffer: &JsValue,
-                byte_offset: u32,
-                length: u32,
-            ) -> $name;
+    #[cfg(feature = "std")]
+    impl std::error::Error for Utf8OrBoundsError {}
 
-            /// The `fill()` method fills all the elements of an array from a start index
-            /// to an end index with a static value. The end index is not included.
-            ///
-            /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/fill)
-            #[wasm_bindgen(method)]
-            pub fn fill(this: &$name, value: $ty, start: u32, end: u32) -> $name;
+    /// A length-prefix integer usable with
+    /// [`Cursor::read_len_prefixed_utf8`] and
+    /// [`Cursor::write_len_prefixed_utf8`]: implemented for `u8`, `u16`,
+    /// and `u32`.
+    pub trait LenPrefix: Copy {
+        /// The prefix's size in bytes.
+        const SIZE: usize;
 
-            /// The buffer accessor property represents the `ArrayBuffer` referenced
-            /// by a `TypedArray` at construction time.
-            #[wasm_bindgen(getter, method)]
-            pub fn buffer(this: &$name) -> ArrayBuffer;
+        /// Reads the prefix at `view`'s byte offset `pos`.
+        fn read(view: &DataView, pos: usize, little_endian: bool) -> Self;
 
-            /// The `subarray()` method returns a new `TypedArray` on the same
-            /// `ArrayBuffer` store and with the same element types as for this
-            /// `TypedArray` object.
-            #[wasm_bindgen(method)]
-            pub fn subarray(this: &$name, begin: u32, end: u32) -> $name;
+        /// Writes `self` as the prefix at `view`'s byte offset `pos`.
+        fn write(self, view: &DataView, pos: usize, little_endian: bool);
 
-            /// The `slice()` method returns a shallow copy of a portion of a typed
-            /// array into a new typed array object. This method has the same algorithm
-            /// as `Array.prototype.slice()`.
-            #[wasm_bindgen(method)]
-            pub fn slice(this: &$name, begin: u32, end: u32) -> $name;
+        /// This prefix value as a byte length.
+        fn to_len(self) -> usize;
 
-            /// The `forEach()` method executes a provided function once per array
-            /// element. This method has the same algorithm as
-            /// `Array.prototype.forEach()`. `TypedArray` is one of the typed array
-            /// types here.
-            #[wasm_bindgen(method, js_name = forEach)]
-            pub fn for_each(this: &$name, callback: &mut dyn FnMut($ty, u32, $name));
+        /// `len` as a prefix value, saturating to the prefix type's max
+        /// rather than overflowing if `len` doesn't fit.
+        fn from_len(len: usize) -> Self;
+    }
 
-            /// The length accessor property represents the length (in elements) of a
-            /// typed array.
-            #[wasm_bindgen(method, getter)]
-            pub fn length(this: &$name) -> u32;
+    impl LenPrefix for u8 {
+        const SIZE: usize = 1;
 
-            /// The byteLength accessor property represents the length (in bytes) of a
-            /// typed array.
-            #[wasm_bindgen(method, getter, js_name = byteLength)]
-            pub fn byte_length(this: &$name) -> u32;
+        fn read(view: &DataView, pos: usize, _little_endian: bool) -> Self {
+            view.get_uint8(pos)
+        }
 
-            /// The byteOffset accessor property represents the offset (in bytes) of a
-            /// typed array from the start of its `ArrayBuffer`.
-            #[wasm_bindgen(method, getter, js_name = byteOffset)]
-            pub fn byte_offset(this: &$name) -> u32;
+        fn write(self, view: &DataView, pos: usize, _little_endian: bool) {
+            view.set_uint8(pos, self);
+        }
 
-            /// The `set()` method stores multiple values in the typed array, reading
-            /// input values from a specified array.
-            #[wasm_bindgen(method)]
-            pub fn set(this: &$name, src: &JsValue, offset: u32);
+        fn to_len(self) -> usize {
+            self as usize
+        }
 
-            /// Gets the value at `idx`, counting from the end if negative.
-            #[wasm_bindgen(method)]
-            pub fn at(this: &$name, idx: i32) -> Option<$ty>;
+        fn from_len(len: usize) -> Self {
+            len.min(u8::MAX as usize) as u8
+        }
+    }
 
-            /// The `copyWithin()` method shallow copies part of a typed array to another
-            /// location in the same typed array and returns it, without modifying its size.
-            ///
-            /// [MDN documentation](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/copyWithin)
-            #[wasm_bindgen(method, js_name = copyWithin)]
-            pub fn copy_within(this: &$name, target: i32, start: i32, end: i32) -> $name;
+    impl LenPrefix for u16 {
+        const SIZE: usize = 2;
 
-            /// Gets the value at `idx`, equivalent to the javascript `my_var = arr[idx]`.
-            #[wasm_bindgen(method, structural, indexing_getter)]
-            pub fn get_index(this: &$name, idx: u32) -> $ty;
+        fn read(view: &DataView, pos: usize, little_endian: bool) -> Self {
+            view.get_uint16_endian(pos, little_endian)
+        }
 
-            /// Sets the value at `idx`, equivalent to the javascript `arr[idx] = value`.
-            #[wasm_bindgen(method, structural, indexing_setter)]
-            pub fn set_index(this: &$name, idx: u32, value: $ty);
+        fn write(self, view: &DataView, pos: usize, little_endian: bool) {
+            view.set_uint16_endian(pos, self, little_endian);
         }
 
-        impl $name {
-            /// Creates a JS typed array which is a view into wasm's linear
-            /// memory at the slice specified.
-            ///
-            /// This function returns a new typed array which is a view into
-            /// wasm's memory. This view does not copy the underlying data.
-            ///
-            /// # Safety
-            ///
-            /// Views into WebAssembly memory are only valid so long as the
-            /// backing buffer isn't resized in JS. Once this function is called
-            /// any future calls to `Box::new` (or malloc of any form) may cause
-            /// the returned value here to be invalidated. Use with caution!
-            ///
-            /// Additionally the returned object can be safely mutated but the
-            /// input slice isn't guaranteed to be mutable.
-            ///
-            /// Finally, the returned object is disconnected from the input
-            /// slice's lifetime, so there's no guarantee that the data is read
-            /// at the right time.
-            pub unsafe fn view(rust: &[$ty]) -> $name {
-                let buf = wasm_bindgen::memory();
-                let mem = buf.unchecked_ref::<WebAssembly::Memory>();
-                $name::new_with_byte_offset_and_length(
-                    &mem.buffer(),
-                    rust.as_ptr() as u32,
-                    rust.len() as u32,
-                )
-            }
+        fn to_len(self) -> usize {
+            self as usize
+        }
 
-            /// Creates a JS typed array which is a view into wasm's linear
-            /// memory at the specified pointer with specified length.
-            ///
-            /// This function returns a new typed array which is a view into
-            /// wasm's memory. This view does not copy the underlying data.
-            ///
-            /// # Safety
-            ///
-            /// Views into WebAssembly memory are only valid so long as the
-            /// backing buffer isn't resized in JS. Once this function is called
-            /// any future calls to `Box::new` (or malloc of any form) may cause
-            /// the returned value here to be invalidated. Use with caution!
-            ///
-            /// Additionally the returned object can be safely mutated,
-            /// the changes are guaranteed to be reflected in the input array.
-            pub unsafe fn view_mut_raw(ptr: *mut $ty, length: usize) -> $name {
-                let buf = wasm_bindgen::memory();
-                let mem = buf.unchecked_ref::<WebAssembly::Memory>();
-                $name::new_with_byte_offset_and_length(
-                    &mem.buffer(),
-                    ptr as u32,
-                    length as u32
-                )
-            }
+        fn from_len(len: usize) -> Self {
+            len.min(u16::MAX as usize) as u16
+        }
+    }
 
+    impl LenPrefix for u32 {
+        const SIZE: usize = 4;
 
-            /// Copy the contents of this JS typed array into the destination
-            /// Rust pointer.
-            ///
-            /// This function will efficiently copy the memory from a typed
-            /// array into this Wasm module's own linear memory, initializing
-            /// the memory destination provided.
-            ///
-            /// # Safety
-            ///
-            /// This function requires `dst` to point to a buffer
-            /// large enough to fit this array's contents.
-            pub unsafe fn raw_copy_to_ptr(&self, dst: *mut $ty) {
-                let buf = wasm_bindgen::memory();
-                let mem = buf.unchecked_ref::<WebAssembly::Memory>();
-                let all_wasm_memory = $name::new(&mem.buffer());
-                let offset = dst as usize / mem::size_of::<$ty>();
-                all_wasm_memory.set(self, offset as u32);
-            }
+        fn read(view: &DataView, pos: usize, little_endian: bool) -> Self {
+            view.get_uint32_endian(pos, little_endian)
+        }
 
-            /// Copy the contents of this JS typed array into the destination
-            /// Rust slice.
-            ///
-            /// This function will efficiently copy the memory from a typed
-            /// array into this Wasm module's own linear memory, initializing
-            /// the memory destination provided.
-            ///
-            /// # Panics
-            ///
-            /// This function will panic if this typed array's length is
-            /// different than the length of the provided `dst` array.
-            pub fn copy_to(&self, dst: &mut [$ty]) {
-                core::assert_eq!(self.length() as usize, dst.len());
-                unsafe { self.raw_copy_to_ptr(dst.as_mut_ptr()); }
-            }
+        fn write(self, view: &DataView, pos: usize, little_endian: bool) {
+            view.set_uint32_endian(pos, self, little_endian);
+        }
 
-            /// Copy the contents of the source Rust slice into this
-            /// JS typed array.
-            ///
-            /// This function will efficiently copy the memory from within
-            /// the Wasm module's own linear memory to this typed array.
-            ///
-            /// # Panics
-            ///
-            /// This function will panic if this typed array's length is
-            /// different than the length of the provided `src` array.
-            pub fn copy_from(&self, src: &[$ty]) {
-                core::assert_eq!(self.length() as usize, src.len());
-                // This is safe because the `set` function copies from its TypedArray argument
-                unsafe { self.set(&$name::view(src), 0) }
-            }
+        fn to_len(self) -> usize {
+            self as usize
+        }
 
-            /// Efficiently copies the contents of this JS typed array into a new Vec.
-            pub fn to_vec(&self) -> Vec<$ty> {
-                let mut output = Vec::with_capacity(self.length() as usize);
-                unsafe {
-                    self.raw_copy_to_ptr(output.as_mut_ptr());
-                    output.set_len(self.length() as usize);
-                }
-                output
-            }
+        fn from_len(len: usize) -> Self {
+            len.min(u32::MAX as usize) as u32
         }
+    }
 
-        impl<'a> From<&'a [$ty]> for $name {
-            #[inline]
-            fn from(slice: &'a [$ty]) -> $name {
-                // This is safe because the `new` function makes a copy if its argument is a TypedArray
-                unsafe { $name::new(&$name::view(slice)) }
-            }
+    /// A read/write cursor over a [`DataView`], tracking a current byte
+    /// offset so callers reading or writing a sequence of fields don't
+    /// have to thread an offset through every call by hand.
+    pub struct Cursor<'a> {
+        view: &'a DataView,
+        pos: usize,
+    }
+
+    impl<'a> Cursor<'a> {
+        /// Wraps `view`, starting at byte offset 0.
+        pub fn new(view: &'a DataView) -> Self {
+            Cursor { view, pos: 0 }
         }
 
-        impl Default for $name {
-            fn default() -> Self {
-                Self::new(&JsValue::UNDEFINED.unchecked_into())
-            }
+        /// The cursor's current byte offset into the view.
+        pub fn position(&self) -> usize {
+            self.pos
         }
-    )*);
-}
 
-arrays! {
-    /// `Int8Array()`
-    /// https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Int8Array
-    Int8Array: i8,
+        /// Moves the cursor to byte offset `pos`. Out-of-range positions
+        /// aren't rejected here -- the next read or write fails on its own
+        /// if `pos` turns out not to leave enough room.
+        pub fn set_position(&mut self, pos: usize) {
+            self.pos = pos;
+        }
 
-    /// `Int16Array()`
-    /// https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Int16Array
-    Int16Array: i16,
+        /// The number of bytes left between the cursor and the end of the
+        /// view.
+        pub fn remaining(&self) -> usize {
+            self.view.byte_length().saturating_sub(self.pos)
+        }
 
-    /// `Int32Array()`
-    /// https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Int32Array
-    Int32Array: i32,
+        /// A `Uint8Array` view of `len` bytes of the underlying buffer,
+        /// starting at the cursor, used to bulk-copy bytes in or out in a
+        /// single call instead of one `DataView` get/set per byte.
+        fn bytes_view(&self, len: usize) -> Uint8Array {
+            Uint8Array::new_with_byte_offset_and_length(
+                &self.view.buffer(),
+                (self.view.byte_offset() + self.pos) as u32,
+                len as u32,
+            )
+        }
 
-    /// `Uint8Array()`
-    /// https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Uint8Array
-    Uint8Array: u8,
+        /// Reads exactly `len` bytes starting at the cursor, bulk-copying
+        /// them out of the view once and validating them as UTF-8 in Rust,
+        /// then advances the cursor past them. Leaves the cursor
+        /// unmoved on error.
+        pub fn read_utf8(&mut self, len: usize) -> Result<String, Utf8OrBoundsError> {
+            let remaining = self.remaining();
+            if len > remaining {
+                return Err(Utf8OrBoundsError::OutOfBounds {
+                    position: self.pos,
+                    requested: len,
+                    remaining,
+                });
+            }
 
-    /// `Uint8ClampedArray()`
-    /// https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Uint8ClampedArray
-    Uint8ClampedArray: u8,
+            let bytes = self.bytes_view(len).to_vec();
+            let s = str::from_utf8(&bytes).map_err(|e| Utf8OrBoundsError::InvalidUtf8 {
+                valid_up_to: self.pos + e.valid_up_to(),
+            })?;
+            let owned = String::from(s);
+            self.pos += len;
+            Ok(owned)
+        }
 
-    /// `Uint16Array()`
-    /// https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Uint16Array
-    Uint16Array: u16,
+        /// Scans forward from the cursor for a `0` byte, decodes
+        /// everything before it as UTF-8, and advances the cursor past the
+        /// terminator (consuming it). Errors, without moving the cursor,
+        /// if no `0` byte appears before the end of the view.
+        pub fn read_utf8_nul(&mut self) -> Result<String, Utf8OrBoundsError> {
+            let remaining = self.remaining();
+            let scan = self.bytes_view(remaining).to_vec();
+            let nul_index = scan
+                .iter()
+                .position(|&byte| byte == 0)
+                .ok_or(Utf8OrBoundsError::MissingTerminator)?;
+
+            let s = str::from_utf8(&scan[..nul_index]).map_err(|e| Utf8OrBoundsError::InvalidUtf8 {
+                valid_up_to: self.pos + e.valid_up_to(),
+            })?;
+            let owned = String::from(s);
+            self.pos += nul_index + 1;
+            Ok(owned)
+        }
 
-    /// `Uint32Array()`
-    /// https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Uint32Array
-    Uint32Array: u32,
+        /// Writes `s`'s UTF-8 bytes starting at the cursor, bulk-copying
+        /// them into the view's buffer in a single call, then advances the
+        /// cursor past them.
+        ///
+        /// Errors, without writing or moving the cursor, if `s` doesn't fit
+        /// in the space remaining -- the underlying `Uint8Array` view isn't
+        /// bounds-checked on the JS side, so skipping this check would
+        /// otherwise let an uncaught `RangeError` through instead of a
+        /// normal `Result`.
+        pub fn write_utf8(&mut self, s: &str) -> Result<(), Utf8OrBoundsError> {
+            let bytes = s.as_bytes();
+            let remaining = self.remaining();
+            if bytes.len() > remaining {
+                return Err(Utf8OrBoundsError::OutOfBounds {
+                    position: self.pos,
+                    requested: bytes.len(),
+                    remaining,
+                });
+            }
+            self.bytes_view(bytes.len()).copy_from(bytes);
+            self.pos += bytes.len();
+            Ok(())
+        }
 
-    /// `Float32Array()`
-    /// https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Float32Array
-    Float32Array: f32,
+        /// Like [`Cursor::write_utf8`], followed by a trailing `0` byte.
+        /// Checks up front that both `s` and the terminator fit, so the
+        /// write is all-or-nothing.
+        pub fn write_utf8_nul(&mut self, s: &str) -> Result<(), Utf8OrBoundsError> {
+            let remaining = self.remaining();
+            let needed = s.len() + 1;
+            if needed > remaining {
+                return Err(Utf8OrBoundsError::OutOfBounds {
+                    position: self.pos,
+                    requested: needed,
+                    remaining,
+                });
+            }
+            self.write_utf8(s)?;
+            self.view.set_uint8(self.pos, 0);
+            self.pos += 1;
+            Ok(())
+        }
 
-    /// `Float64Array()`
-    /// https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Float64Array
-    Float64Array: f64,
+        /// Reads a `U`-sized length prefix (in the given endianness)
+        /// followed by that many bytes of UTF-8 text, advancing the cursor
+        /// past both.
+        pub fn read_len_prefixed_utf8<U: LenPrefix>(
+            &mut self,
+            little_endian: bool,
+        ) -> Result<String, Utf8OrBoundsError> {
+            let remaining = self.remaining();
+            if U::SIZE > remaining {
+                return Err(Utf8OrBoundsError::OutOfBounds {
+                    position: self.pos,
+                    requested: U::SIZE,
+                    remaining,
+                });
+            }
 
-    /// `BigInt64Array()`
-    /// https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/BigInt64Array
-    BigInt64Array: i64,
+            let len = U::read(self.view, self.pos, little_endian).to_len();
+            self.pos += U::SIZE;
+            self.read_utf8(len)
+        }
 
-    /// `BigUint64Array()`
-    /// https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/BigUint64Array
-    BigUint64Array: u64,
+        /// Writes `s`'s byte length as a `U`-sized prefix (in the given
+        /// endianness) followed by `s`'s UTF-8 bytes, advancing the cursor
+        /// past both. Saturates the prefix (see [`LenPrefix::from_len`])
+        /// rather than panicking if `s` is longer than `U` can represent.
+        ///
+        /// Errors, without writing or moving the cursor, if the prefix and
+        /// `s` together don't fit in the space remaining.
+        pub fn write_len_prefixed_utf8<U: LenPrefix>(
+            &mut self,
+            s: &str,
+            little_endian: bool,
+        ) -> Result<(), Utf8OrBoundsError> {
+            let remaining = self.remaining();
+            let needed = U::SIZE + s.len();
+            if needed > remaining {
+                return Err(Utf8OrBoundsError::OutOfBounds {
+                    position: self.pos,
+                    requested: needed,
+                    remaining,
+                });
+            }
+            let len = U::from_len(s.len());
+            len.write(self.view, self.pos, little_endian);
+            self.pos += U::SIZE;
+            self.write_utf8(s)?;
+            Ok(())
+        }
+    }
 }
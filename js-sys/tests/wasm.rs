@@ -0,0 +1,1012 @@
+//! Run this test with: `wasm-pack test --node` (or `--chrome`/`--firefox`)
+//! from the `js-sys` crate directory.
+
+#![cfg(target_arch = "wasm32")]
+
+use js_sys::wasm_bindgen::{JsCast, JsValue};
+use js_sys::*;
+use wasm_bindgen_test::*;
+
+#[wasm_bindgen_test]
+fn array_iter_last_ignores_phantom_element_after_shrink() {
+    let array = Array::new();
+    array.push(&JsValue::from(1));
+    array.push(&JsValue::from(2));
+    array.push(&JsValue::from(3));
+
+    let mut iter = array.iter();
+    // Advance once so the iterator has already committed to a starting
+    // length, then shrink the array out from under it.
+    assert_eq!(iter.next().and_then(|v| v.as_f64()), Some(1.0));
+    array.set_length(1);
+
+    assert_eq!(iter.last(), None);
+}
+
+#[wasm_bindgen_test]
+fn array_iter_last_returns_final_element_when_untouched() {
+    let array = Array::of3(&JsValue::from(1), &JsValue::from(2), &JsValue::from(3));
+    let last = array.iter().last().and_then(|v| v.as_f64());
+    assert_eq!(last, Some(3.0));
+}
+
+#[wasm_bindgen_test]
+fn set_converting_wraps_like_js_for_every_non_bigint_array() {
+    let array = Int8Array::new_with_length(3);
+    array.set_converting(&[200i32, -200, 127], 0).unwrap();
+    assert_eq!(array.to_vec(), vec![-56, 56, 127]);
+}
+
+#[wasm_bindgen_test]
+fn set_converting_rejects_offset_past_the_end() {
+    let array = Uint8Array::new_with_length(2);
+    let err = array.set_converting(&[1u8, 2, 3], 0);
+    assert!(err.is_err());
+}
+
+#[wasm_bindgen_test]
+fn spsc_ring_round_trips_a_message() {
+    let ring = sync::SpscRing::with_capacity(64).unwrap();
+    ring.try_push(b"hello").unwrap();
+
+    let mut out = Vec::new();
+    let len = ring.try_pop(&mut out).unwrap();
+    assert_eq!(len, 5);
+    assert_eq!(out, b"hello");
+}
+
+#[wasm_bindgen_test]
+fn spsc_ring_reports_empty_and_full() {
+    let ring = sync::SpscRing::with_capacity(8).unwrap();
+
+    let mut out = Vec::new();
+    assert_eq!(ring.try_pop(&mut out), Err(sync::Empty));
+    assert_eq!(ring.try_push(&[0u8; 32]), Err(sync::Full));
+}
+
+#[wasm_bindgen_test]
+fn cursor_write_utf8_errors_instead_of_throwing_near_the_end() {
+    let buffer = ArrayBuffer::new(4);
+    let view = DataView::new(&buffer, 0, 4);
+    let mut cursor = dataview::Cursor::new(&view);
+
+    assert!(cursor.write_utf8("hello").is_err());
+    // The failed write must not have moved the cursor or touched the buffer.
+    assert_eq!(cursor.position(), 0);
+
+    cursor.write_utf8("ok!!").unwrap();
+    assert_eq!(cursor.position(), 4);
+}
+
+#[wasm_bindgen_test]
+fn cursor_write_utf8_nul_checks_room_for_the_terminator_too() {
+    let buffer = ArrayBuffer::new(4);
+    let view = DataView::new(&buffer, 0, 4);
+    let mut cursor = dataview::Cursor::new(&view);
+
+    // "abcd" alone fits, but leaves no room for the trailing nul.
+    assert!(cursor.write_utf8_nul("abcd").is_err());
+    assert_eq!(cursor.position(), 0);
+
+    cursor.write_utf8_nul("abc").unwrap();
+    assert_eq!(cursor.position(), 4);
+}
+
+#[wasm_bindgen_test]
+fn cursor_write_len_prefixed_utf8_checks_prefix_and_string_together() {
+    let buffer = ArrayBuffer::new(4);
+    let view = DataView::new(&buffer, 0, 4);
+    let mut cursor = dataview::Cursor::new(&view);
+
+    // 1-byte prefix + 4-byte string would need 5 bytes total.
+    assert!(cursor
+        .write_len_prefixed_utf8::<u8>("abcd", true)
+        .is_err());
+    assert_eq!(cursor.position(), 0);
+
+    cursor.write_len_prefixed_utf8::<u8>("abc", true).unwrap();
+    assert_eq!(cursor.position(), 4);
+}
+
+#[wasm_bindgen_test]
+fn merge_deep_allows_a_shared_subobject_reached_through_two_sibling_keys() {
+    let shared_a = Object::new();
+    Reflect::set(shared_a.as_ref(), &JsValue::from_str("x"), &JsValue::from(1)).unwrap();
+    let shared_b = Object::new();
+    Reflect::set(shared_b.as_ref(), &JsValue::from_str("x"), &JsValue::from(2)).unwrap();
+
+    let a = Object::new();
+    Reflect::set(a.as_ref(), &JsValue::from_str("p"), shared_a.as_ref()).unwrap();
+    Reflect::set(a.as_ref(), &JsValue::from_str("q"), shared_a.as_ref()).unwrap();
+
+    let b = Object::new();
+    Reflect::set(b.as_ref(), &JsValue::from_str("p"), shared_b.as_ref()).unwrap();
+    Reflect::set(b.as_ref(), &JsValue::from_str("q"), shared_b.as_ref()).unwrap();
+
+    // Not a cycle: `shared_a`/`shared_b` are just reachable twice, through
+    // sibling keys "p" and "q", not through themselves.
+    let merged = Object::merge_deep(&a, &b, &MergeOptions::new()).unwrap();
+    let p = Reflect::get(merged.as_ref(), &JsValue::from_str("p")).unwrap();
+    let x = Reflect::get(&p, &JsValue::from_str("x")).unwrap();
+    assert_eq!(x.as_f64(), Some(2.0));
+}
+
+#[wasm_bindgen_test]
+fn merge_deep_rejects_an_actual_cycle() {
+    let a = Object::new();
+    Reflect::set(a.as_ref(), &JsValue::from_str("self"), a.as_ref()).unwrap();
+    let b = Object::new();
+    Reflect::set(b.as_ref(), &JsValue::from_str("self"), b.as_ref()).unwrap();
+
+    assert!(Object::merge_deep(&a, &b, &MergeOptions::new()).is_err());
+}
+
+#[wasm_bindgen_test]
+fn spsc_ring_free_space_is_correct_for_non_power_of_two_capacity_wrap_around() {
+    // 100 isn't a power of two, so `tail.wrapping_sub(head) % capacity`
+    // doesn't line up with the true circular distance once `tail` wraps
+    // past `head` -- this sequence puts the ring in exactly that state:
+    // head ends up at 94 and tail at 78, with 15 bytes of true free space.
+    let ring = sync::SpscRing::with_capacity(100).unwrap();
+
+    ring.try_push(&[0u8; 90]).unwrap();
+    let mut out = Vec::new();
+    ring.try_pop(&mut out).unwrap();
+    out.clear();
+    ring.try_push(&[0u8; 80]).unwrap();
+
+    // True free space is 15 bytes; a push needing 16 (4-byte frame prefix +
+    // 12-byte payload) must fail rather than be silently accepted and
+    // overwrite bytes the consumer hasn't read yet.
+    assert_eq!(ring.try_push(&[0u8; 12]), Err(sync::Full));
+    // But one that needs exactly the 15 bytes available must still succeed.
+    ring.try_push(&[0u8; 11]).unwrap();
+}
+
+#[wasm_bindgen_test]
+fn array_insert_at_start_middle_and_end() {
+    let array = Array::of3(&JsValue::from(1), &JsValue::from(2), &JsValue::from(3));
+
+    array.insert(0, &JsValue::from(0));
+    array.insert(2, &JsValue::from_str("mid"));
+    array.insert(array.length(), &JsValue::from(99));
+
+    assert_eq!(array.length(), 6);
+    assert_eq!(array.get(0).as_f64(), Some(0.0));
+    assert_eq!(array.get(2).as_string(), Some("mid".to_string()));
+    assert_eq!(array.get(5).as_f64(), Some(99.0));
+}
+
+#[wasm_bindgen_test]
+fn array_remove_from_a_one_element_array() {
+    let array = Array::of1(&JsValue::from(42));
+    let removed = array.remove(0);
+    assert_eq!(removed.and_then(|v| v.as_f64()), Some(42.0));
+    assert_eq!(array.length(), 0);
+    assert_eq!(array.remove(0), None);
+}
+
+#[wasm_bindgen_test]
+fn array_swap_remove_preserves_the_multiset() {
+    let array = Array::of4(
+        &JsValue::from(1),
+        &JsValue::from(2),
+        &JsValue::from(3),
+        &JsValue::from(4),
+    );
+    let removed = array.swap_remove(1);
+    assert_eq!(removed.and_then(|v| v.as_f64()), Some(2.0));
+    assert_eq!(array.length(), 3);
+
+    let mut remaining: Vec<i64> = array
+        .iter()
+        .map(|v| v.as_f64().unwrap() as i64)
+        .collect();
+    remaining.sort();
+    assert_eq!(remaining, vec![1, 3, 4]);
+}
+
+#[wasm_bindgen_test]
+fn array_eq_f64_slice_and_eq_str_slice() {
+    let numbers = Array::of3(&JsValue::from(1.0), &JsValue::from(2.0), &JsValue::from(3.0));
+    assert!(numbers.eq_f64_slice(&[1.0, 2.0, 3.0]));
+    assert!(!numbers.eq_f64_slice(&[1.0, 2.0]));
+    assert!(!numbers.eq_f64_slice(&[1.0, 2.0, 4.0]));
+
+    let nan_array = Array::of1(&JsValue::from(f64::NAN));
+    assert!(!nan_array.eq_f64_slice(&[f64::NAN]));
+
+    let strings = Array::of2(&JsValue::from_str("a"), &JsValue::from_str("b"));
+    assert!(strings.eq_str_slice(&["a", "b"]));
+    assert!(!strings.eq_str_slice(&["a", "c"]));
+    assert!(!strings.eq_str_slice(&["a"]));
+}
+
+#[wasm_bindgen_test]
+fn array_eq_by_short_circuits_on_length_mismatch() {
+    let a = Array::of2(&JsValue::from(1), &JsValue::from(2));
+    let b = Array::of3(&JsValue::from(1), &JsValue::from(2), &JsValue::from(3));
+    assert!(!a.eq_by(&b, &mut |x, y| x.as_f64() == y.as_f64()));
+
+    let c = Array::of2(&JsValue::from(1), &JsValue::from(99));
+    assert!(!a.eq_by(&c, &mut |x, y| x.as_f64() == y.as_f64()));
+
+    let d = Array::of2(&JsValue::from(1), &JsValue::from(2));
+    assert!(a.eq_by(&d, &mut |x, y| x.as_f64() == y.as_f64()));
+}
+
+#[wasm_bindgen_test]
+fn array_partial_eq_impls_against_rust_slices() {
+    let numbers = Array::of2(&JsValue::from(1.0), &JsValue::from(2.0));
+    assert_eq!(numbers, vec![1.0, 2.0]);
+    assert_eq!(&numbers, &[1.0, 2.0][..]);
+
+    let strings = Array::of2(&JsValue::from_str("a"), &JsValue::from_str("b"));
+    assert_eq!(&strings, &["a", "b"][..]);
+}
+
+#[wasm_bindgen_test]
+fn array_iter_rev_and_to_vec_reversed_match_to_reversed() {
+    let array = Array::of3(&JsValue::from(1), &JsValue::from(2), &JsValue::from(3));
+
+    let via_iter: Vec<f64> = array.iter_rev().map(|v| v.as_f64().unwrap()).collect();
+    let via_helper: Vec<f64> = array
+        .to_vec_reversed()
+        .into_iter()
+        .map(|v| v.as_f64().unwrap())
+        .collect();
+    let via_js: Vec<f64> = array
+        .shallow_copy()
+        .reverse()
+        .to_vec()
+        .into_iter()
+        .map(|v| v.as_f64().unwrap())
+        .collect();
+
+    assert_eq!(via_iter, vec![3.0, 2.0, 1.0]);
+    assert_eq!(via_helper, via_js);
+}
+
+#[wasm_bindgen_test]
+fn array_first_checked_and_last_checked_on_empty_arrays() {
+    let empty = Array::new();
+    assert_eq!(empty.first_checked(), None);
+    assert_eq!(empty.last_checked(), None);
+
+    let one = Array::of1(&JsValue::from(7));
+    assert_eq!(one.first_checked().and_then(|v| v.as_f64()), Some(7.0));
+    assert_eq!(one.last_checked().and_then(|v| v.as_f64()), Some(7.0));
+}
+
+#[wasm_bindgen_test]
+fn array_get_at_negative_index_on_empty_array() {
+    let empty = Array::new();
+    assert_eq!(empty.get_at(-1), None);
+    assert_eq!(empty.get_at(0u32), None);
+}
+
+#[wasm_bindgen_test]
+fn array_get_at_matches_js_at_for_end_relative_indices() {
+    let array = Array::of3(&JsValue::from(10), &JsValue::from(20), &JsValue::from(30));
+
+    assert_eq!(array.get_at(-1).and_then(|v| v.as_f64()), array.at(-1).as_f64());
+    assert_eq!(array.get_at(-3).and_then(|v| v.as_f64()), array.at(-3).as_f64());
+    // -(len+1) is out of bounds.
+    assert_eq!(array.get_at(-4), None);
+    assert!(array.at(-4).is_undefined());
+}
+
+#[wasm_bindgen_test]
+fn array_slice_idx_end_relative_slicing() {
+    let array = Array::of4(
+        &JsValue::from(1),
+        &JsValue::from(2),
+        &JsValue::from(3),
+        &JsValue::from(4),
+    );
+
+    let middle = array.slice_idx(1, -1);
+    assert_eq!(middle.to_vec().len(), 2);
+    assert_eq!(middle.get(0).as_f64(), Some(2.0));
+    assert_eq!(middle.get(1).as_f64(), Some(3.0));
+
+    let all = array.slice_idx(0u32, JsIndex::FromEnd(0));
+    assert_eq!(all.length(), 4);
+}
+
+#[wasm_bindgen_test]
+fn array_join_with_custom_formatter_for_objects() {
+    let obj = Object::new();
+    Reflect::set(obj.as_ref(), &JsValue::from_str("name"), &JsValue::from_str("x")).unwrap();
+    let array = Array::of2(obj.as_ref(), &JsValue::from(1));
+
+    let joined = array.join_with(", ", &mut |v| {
+        if let Some(o) = v.dyn_ref::<Object>() {
+            let name = Reflect::get(o.as_ref(), &JsValue::from_str("name")).unwrap();
+            name.as_string().unwrap_or_default()
+        } else {
+            v.as_f64().map(|n| n.to_string()).unwrap_or_default()
+        }
+    });
+    assert_eq!(joined, "x, 1");
+}
+
+#[wasm_bindgen_test]
+fn array_join_with_on_empty_and_single_element_arrays() {
+    let empty = Array::new();
+    assert_eq!(empty.join_with(",", &mut |v| v.as_f64().unwrap().to_string()), "");
+
+    let one = Array::of1(&JsValue::from(5));
+    assert_eq!(one.join_with(",", &mut |v| v.as_f64().unwrap().to_string()), "5");
+}
+
+#[wasm_bindgen_test]
+fn array_join_display_falls_back_to_error_marker_on_throwing_to_string() {
+    let thrower = Object::new();
+    let to_string_key = JsValue::from_str("toString");
+    let throwing_fn = Function::new_no_args("throw new Error('boom')");
+    Reflect::set(thrower.as_ref(), &to_string_key, throwing_fn.as_ref()).unwrap();
+
+    let array = Array::of1(thrower.as_ref());
+    assert_eq!(array.join_display(","), "<error>");
+}
+
+#[wasm_bindgen_test]
+fn array_join_numbers_rounds_to_precision() {
+    let array = Array::of2(&JsValue::from(1.005), &JsValue::from(2.0));
+    assert_eq!(array.join_numbers(",", Some(1)), "1.0,2.0");
+    assert_eq!(array.join_numbers(",", None), "1.005,2");
+}
+
+#[wasm_bindgen_test]
+fn array_flatten_preserves_order_and_skips_holes() {
+    let inner_a = Array::of2(&JsValue::from(1), &JsValue::from(2));
+    let inner_b = Array::new_with_length(2); // a hole at every index
+    let inner_c = Array::of1(&JsValue::from(3));
+    let outer = Array::of3(inner_a.as_ref(), inner_b.as_ref(), inner_c.as_ref());
+
+    let flat = outer.flatten();
+    let values: Vec<f64> = flat.to_vec().into_iter().filter_map(|v| v.as_f64()).collect();
+    assert_eq!(values, vec![1.0, 2.0, 3.0]);
+    // `flat` skips holes per spec, so the two holes from `inner_b` don't
+    // appear at all, not even as `undefined`.
+    assert_eq!(flat.length(), 3);
+}
+
+#[wasm_bindgen_test]
+fn array_flatten_of_empty_inner_arrays() {
+    let outer = Array::of2(Array::new().as_ref(), Array::new().as_ref());
+    assert_eq!(outer.flatten().length(), 0);
+    assert_eq!(outer.concat_inner().length(), 0);
+}
+
+#[wasm_bindgen_test]
+fn array_flat_map_typed_with_variable_length_results() {
+    let array = Array::of3(&JsValue::from(1), &JsValue::from(2), &JsValue::from(3));
+
+    let result = Array::flat_map_typed(&array, &mut |v, _index, _array| {
+        let n = v.as_f64().unwrap() as u32;
+        let out = Array::new();
+        for _ in 0..n {
+            out.push(&v);
+        }
+        out
+    });
+
+    let values: Vec<f64> = result.to_vec().into_iter().map(|v| v.as_f64().unwrap()).collect();
+    assert_eq!(values, vec![1.0, 2.0, 2.0, 3.0, 3.0, 3.0]);
+}
+
+#[wasm_bindgen_test]
+fn uint8array_starts_with_detects_a_magic_header() {
+    let array = Uint8Array::from(&[0x89, 0x50, 0x4E, 0x47, 0x0D][..]);
+    assert!(array.starts_with(&[0x89, 0x50, 0x4E, 0x47]));
+    assert!(!array.starts_with(&[0x89, 0x50, 0x4E, 0x48]));
+    // Prefix longer than the array itself can never match.
+    assert!(!array.starts_with(&[0x89, 0x50, 0x4E, 0x47, 0x0D, 0xFF]));
+}
+
+#[wasm_bindgen_test]
+fn uint8array_ends_with_and_eq_slice() {
+    let array = Uint8Array::from(&[1u8, 2, 3, 4][..]);
+    assert!(array.ends_with(&[3, 4]));
+    assert!(!array.ends_with(&[3, 5]));
+    assert!(array.eq_slice(&[1, 2, 3, 4]));
+    assert!(!array.eq_slice(&[1, 2, 3]));
+}
+
+#[wasm_bindgen_test]
+fn uint8array_find_subslice_at_the_end_and_with_empty_needle() {
+    let array = Uint8Array::from(&[1u8, 2, 3, 4, 5][..]);
+    assert_eq!(array.find_subslice(&[4, 5]), Some(3));
+    assert_eq!(array.find_subslice(&[9]), None);
+    assert_eq!(array.find_subslice(&[]), Some(0));
+}
+
+#[wasm_bindgen_test]
+fn is_nullish_and_is_truthy_cover_the_js_truthiness_table() {
+    assert!(is_nullish(&JsValue::NULL));
+    assert!(is_nullish(&JsValue::UNDEFINED));
+    assert!(!is_nullish(&JsValue::from(0)));
+    assert!(!is_nullish(&JsValue::from_str("")));
+
+    assert!(!JsValue::NULL.is_truthy());
+    assert!(!JsValue::UNDEFINED.is_truthy());
+    assert!(!JsValue::from(0).is_truthy());
+    assert!(!JsValue::from(-0.0).is_truthy());
+    assert!(!JsValue::from(f64::NAN).is_truthy());
+    assert!(!JsValue::from_str("").is_truthy());
+    assert!(!JsValue::from_bool(false).is_truthy());
+
+    assert!(JsValue::from(1).is_truthy());
+    assert!(JsValue::from_str("0").is_truthy());
+    assert!(JsValue::from_bool(true).is_truthy());
+    assert!(JsValue::from(Object::new()).is_truthy());
+}
+
+#[wasm_bindgen_test]
+fn nullish_coalesce_picks_b_only_when_a_is_nullish() {
+    let zero = JsValue::from(0);
+    let fallback = JsValue::from_str("fallback");
+    assert_eq!(nullish_coalesce(&zero, &fallback).as_f64(), Some(0.0));
+
+    let null = JsValue::NULL;
+    assert_eq!(
+        nullish_coalesce(&null, &fallback).as_string(),
+        Some("fallback".to_string())
+    );
+}
+
+#[wasm_bindgen_test]
+fn array_compact_and_compact_falsy() {
+    let array = Array::of5(
+        &JsValue::NULL,
+        &JsValue::UNDEFINED,
+        &JsValue::from(0),
+        &JsValue::from_str(""),
+        &JsValue::from(1),
+    );
+    array.push(&JsValue::from_bool(true));
+
+    let compacted: Vec<f64> = array.compact().to_vec().into_iter().filter_map(|v| v.as_f64()).collect();
+    // `compact` only drops null/undefined, so 0 survives.
+    assert_eq!(array.compact().length(), 4);
+    assert_eq!(compacted, vec![0.0, 1.0]);
+
+    // `compact_falsy` also drops 0 and "".
+    assert_eq!(array.compact_falsy().length(), 2);
+}
+
+#[wasm_bindgen_test]
+fn map_invert_keeps_only_the_last_key_on_a_collision() {
+    let map = Map::new();
+    map.set(&JsValue::from_str("a"), &JsValue::from(1));
+    map.set(&JsValue::from_str("b"), &JsValue::from(1));
+    map.set(&JsValue::from_str("c"), &JsValue::from(2));
+
+    let inverted = map.invert();
+    assert_eq!(inverted.size(), 2);
+    assert_eq!(
+        inverted.get(&JsValue::from(1)).as_string(),
+        Some("b".to_string())
+    );
+    assert_eq!(
+        inverted.get(&JsValue::from(2)).as_string(),
+        Some("c".to_string())
+    );
+}
+
+#[wasm_bindgen_test]
+fn map_invert_multi_collects_every_colliding_key_in_order() {
+    let map = Map::new();
+    map.set(&JsValue::from_str("a"), &JsValue::from(1));
+    map.set(&JsValue::from_str("b"), &JsValue::from(1));
+    map.set(&JsValue::from_str("c"), &JsValue::from(2));
+
+    let inverted = map.invert_multi();
+    let keys_for_one: Array = inverted.get(&JsValue::from(1)).unchecked_into();
+    assert_eq!(
+        keys_for_one.to_vec().iter().filter_map(|v| v.as_string()).collect::<Vec<_>>(),
+        vec!["a".to_string(), "b".to_string()]
+    );
+
+    let keys_for_two: Array = inverted.get(&JsValue::from(2)).unchecked_into();
+    assert_eq!(keys_for_two.length(), 1);
+}
+
+#[wasm_bindgen_test]
+fn array_key_by_uses_the_closure_and_last_one_wins_on_collision() {
+    let array = Array::of3(
+        &JsValue::from(1),
+        &JsValue::from(2),
+        &JsValue::from(3),
+    );
+
+    let grouped = array.key_by(&mut |v| {
+        let n = v.as_f64().unwrap();
+        JsValue::from_bool(n as i64 % 2 == 0)
+    });
+
+    assert_eq!(grouped.size(), 2);
+    // Odd (1, then 3) collide on key `false`; 3 wins as the later element.
+    assert_eq!(grouped.get(&JsValue::from_bool(false)).as_f64(), Some(3.0));
+    assert_eq!(grouped.get(&JsValue::from_bool(true)).as_f64(), Some(2.0));
+}
+
+#[wasm_bindgen_test]
+fn array_key_by_prop_groups_missing_properties_under_undefined() {
+    let with_id = Object::new();
+    Reflect::set(with_id.as_ref(), &JsValue::from_str("id"), &JsValue::from_str("x")).unwrap();
+    let without_id = Object::new();
+
+    let array = Array::of2(with_id.as_ref(), without_id.as_ref());
+    let grouped = array.key_by_prop("id").unwrap();
+
+    assert_eq!(grouped.size(), 2);
+    assert!(Object::is(&grouped.get(&JsValue::from_str("x")), with_id.as_ref()));
+    assert!(Object::is(&grouped.get(&JsValue::UNDEFINED), without_id.as_ref()));
+}
+
+#[wasm_bindgen_test]
+fn array_from_fn_builds_every_slot_with_no_holes() {
+    let empty = Array::from_fn(0, |i| JsValue::from(i));
+    assert_eq!(empty.length(), 0);
+
+    let array = Array::from_fn(5, |i| JsValue::from(i * 2));
+    assert_eq!(array.length(), 5);
+    assert_eq!(
+        array.to_vec().iter().filter_map(|v| v.as_f64()).collect::<Vec<_>>(),
+        vec![0.0, 2.0, 4.0, 6.0, 8.0]
+    );
+}
+
+#[wasm_bindgen_test]
+fn array_resize_with_shrinks_then_grows() {
+    let array = Array::from_fn(5, |i| JsValue::from(i));
+
+    array.resize_with(2, |i| JsValue::from(i));
+    assert_eq!(array.length(), 2);
+    assert_eq!(array.get(0).as_f64(), Some(0.0));
+    assert_eq!(array.get(1).as_f64(), Some(1.0));
+
+    array.resize_with(4, |i| JsValue::from(100 + i));
+    assert_eq!(array.length(), 4);
+    assert_eq!(array.get(2).as_f64(), Some(102.0));
+    assert_eq!(array.get(3).as_f64(), Some(103.0));
+}
+
+#[wasm_bindgen_test]
+fn array_repeat_shares_the_same_object_handle_across_every_slot() {
+    let shared = Object::new();
+    Reflect::set(shared.as_ref(), &JsValue::from_str("x"), &JsValue::from(1)).unwrap();
+
+    let array = Array::repeat(shared.as_ref(), 3);
+    assert_eq!(array.length(), 3);
+
+    let first: Object = array.get(0).unchecked_into();
+    Reflect::set(first.as_ref(), &JsValue::from_str("x"), &JsValue::from(2)).unwrap();
+
+    // Every slot is the *same* object, so mutating one mutates them all.
+    let second: Object = array.get(1).unchecked_into();
+    let x = Reflect::get(second.as_ref(), &JsValue::from_str("x")).unwrap();
+    assert_eq!(x.as_f64(), Some(2.0));
+}
+
+#[wasm_bindgen_test]
+fn typed_array_find_index_of_and_find_last_index_of_hide_the_js_sentinel() {
+    let array = Uint8Array::from(&[10u8, 20, 30, 20, 10][..]);
+
+    assert_eq!(array.find_index_of(20, 0), Some(1));
+    assert_eq!(array.find_last_index_of(20, -1), Some(3));
+    assert_eq!(array.find_index_of(99, 0), None);
+    assert_eq!(array.find_last_index_of(99, -1), None);
+
+    // A negative `from_index` counts back from the end, same as JS.
+    assert_eq!(array.find_index_of(10, -2), Some(4));
+}
+
+#[wasm_bindgen_test]
+fn float64array_includes_finds_nan_but_index_of_does_not() {
+    let array = Float64Array::from(&[1.0, f64::NAN, 3.0][..]);
+
+    // `includes` uses SameValueZero, so it treats NaN as findable.
+    assert!(array.includes(f64::NAN, 0));
+    // `index_of` uses strict equality, under which NaN is never equal to
+    // anything, including itself.
+    assert_eq!(array.find_index_of(f64::NAN, 0), None);
+
+    assert!(array.includes(3.0, 0));
+    assert_eq!(array.find_index_of(3.0, 0), Some(2));
+}
+
+#[wasm_bindgen_test]
+fn typed_array_join_uses_the_given_separator() {
+    let array = Uint8Array::from(&[1u8, 2, 3][..]);
+    assert_eq!(String::from(array.join(", ")), "1, 2, 3".to_string());
+}
+
+#[wasm_bindgen_test]
+fn array_to_sorted_by_cached_key_is_stable_for_equal_keys_and_leaves_self_untouched() {
+    let make_pair = |label: &str, key: i32| {
+        let obj = Object::new();
+        Reflect::set(obj.as_ref(), &JsValue::from_str("label"), &JsValue::from_str(label)).unwrap();
+        Reflect::set(obj.as_ref(), &JsValue::from_str("key"), &JsValue::from(key)).unwrap();
+        obj
+    };
+
+    let a = make_pair("a", 1);
+    let b = make_pair("b", 0);
+    let c = make_pair("c", 1);
+    let array = Array::of3(a.as_ref(), b.as_ref(), c.as_ref());
+
+    let sorted = array.to_sorted_by_cached_key(&mut |v| {
+        Reflect::get(&v, &JsValue::from_str("key")).unwrap().as_f64().unwrap() as i32
+    });
+
+    let labels: Vec<String> = sorted
+        .to_vec()
+        .into_iter()
+        .map(|v| Reflect::get(&v, &JsValue::from_str("label")).unwrap().as_string().unwrap())
+        .collect();
+    // "a" and "c" share key 1; stability keeps "a" before "c".
+    assert_eq!(labels, vec!["b".to_string(), "a".to_string(), "c".to_string()]);
+
+    // `self` is untouched -- still in its original order.
+    assert!(Object::is(&array.get(0), a.as_ref()));
+}
+
+#[wasm_bindgen_test]
+fn array_sort_numbers_puts_nan_last_and_sorts_the_rest() {
+    let array = Array::of5(
+        &JsValue::from(3.0),
+        &JsValue::from(f64::NAN),
+        &JsValue::from(1.0),
+        &JsValue::from(f64::NAN),
+        &JsValue::from(2.0),
+    );
+
+    let sorted = array.sort_numbers();
+    let values = sorted.to_vec();
+    assert_eq!(values[0].as_f64(), Some(1.0));
+    assert_eq!(values[1].as_f64(), Some(2.0));
+    assert_eq!(values[2].as_f64(), Some(3.0));
+    assert!(values[3].as_f64().unwrap().is_nan());
+    assert!(values[4].as_f64().unwrap().is_nan());
+
+    // Sorts in place and returns `self`.
+    assert_eq!(array.get(0).as_f64(), Some(1.0));
+}
+
+#[wasm_bindgen_test]
+fn array_count_by_and_frequencies_treat_nan_as_a_single_bucket() {
+    let array = Array::of5(
+        &JsValue::from(f64::NAN),
+        &JsValue::from(1.0),
+        &JsValue::from(f64::NAN),
+        &JsValue::from(1.0),
+        &JsValue::from(2.0),
+    );
+
+    let freqs = array.frequencies();
+    assert_eq!(freqs.get(&JsValue::from(f64::NAN)).as_f64(), Some(2.0));
+    assert_eq!(freqs.get(&JsValue::from(1.0)).as_f64(), Some(2.0));
+    assert_eq!(freqs.get(&JsValue::from(2.0)).as_f64(), Some(1.0));
+
+    let counted = array.count_by(&mut |v| v);
+    assert_eq!(counted.get(&JsValue::from(f64::NAN)).as_f64(), Some(2.0));
+}
+
+#[wasm_bindgen_test]
+fn map_increment_starts_from_zero_on_a_missing_key() {
+    let map = Map::new();
+    assert_eq!(map.increment(&JsValue::from_str("a"), 5.0), 5.0);
+    assert_eq!(map.increment(&JsValue::from_str("a"), 3.0), 8.0);
+    assert_eq!(map.get(&JsValue::from_str("a")).as_f64(), Some(8.0));
+}
+
+#[wasm_bindgen_test]
+fn array_fold_and_rfold_agree_on_a_numeric_sum_but_differ_on_order() {
+    let array = Array::of3(&JsValue::from(1), &JsValue::from(2), &JsValue::from(3));
+
+    let sum = array.fold(0.0, |acc, v, _i| acc + v.as_f64().unwrap());
+    assert_eq!(sum, 6.0);
+
+    let concat = array.fold(String::new(), |mut acc, v, _i| {
+        acc.push_str(&v.as_f64().unwrap().to_string());
+        acc
+    });
+    let rconcat = array.rfold(String::new(), |mut acc, v, _i| {
+        acc.push_str(&v.as_f64().unwrap().to_string());
+        acc
+    });
+    assert_eq!(concat, "123".to_string());
+    assert_eq!(rconcat, "321".to_string());
+}
+
+#[wasm_bindgen_test]
+fn array_try_fold_stops_at_the_first_error() {
+    let array = Array::of3(&JsValue::from(1), &JsValue::from(-1), &JsValue::from(3));
+
+    let result: Result<f64, String> = array.try_fold(0.0, |acc, v, i| {
+        let n = v.as_f64().unwrap();
+        if n < 0.0 {
+            Err(format!("negative at {}", i))
+        } else {
+            Ok(acc + n)
+        }
+    });
+    assert_eq!(result, Err("negative at 1".to_string()));
+
+    let all_positive = Array::of3(&JsValue::from(1), &JsValue::from(2), &JsValue::from(3));
+    let ok: Result<f64, String> = all_positive.try_fold(0.0, |acc, v, _i| Ok(acc + v.as_f64().unwrap()));
+    assert_eq!(ok, Ok(6.0));
+}
+
+#[wasm_bindgen_test]
+fn object_is_empty_and_own_key_count_match_object_keys() {
+    let empty = Object::new();
+    assert!(Object::is_empty(&empty));
+    assert_eq!(Object::own_key_count(&empty), 0);
+    assert_eq!(Object::try_own_key_count(&empty), Ok(0));
+
+    let obj = Object::new();
+    Reflect::set(obj.as_ref(), &JsValue::from_str("a"), &JsValue::from(1)).unwrap();
+    Reflect::set(obj.as_ref(), &JsValue::from_str("b"), &JsValue::from(2)).unwrap();
+    assert!(!Object::is_empty(&obj));
+    assert_eq!(Object::own_key_count(&obj), 2);
+    assert_eq!(Object::try_own_key_count(&obj), Ok(2));
+}
+
+#[wasm_bindgen_test]
+fn map_and_set_is_empty_reflect_size() {
+    let map = Map::new();
+    assert!(map.is_empty());
+    map.set(&JsValue::from_str("a"), &JsValue::from(1));
+    assert!(!map.is_empty());
+
+    let set = Set::new(&JsValue::UNDEFINED);
+    assert!(set.is_empty());
+    set.add(&JsValue::from(1));
+    assert!(!set.is_empty());
+}
+
+#[wasm_bindgen_test]
+fn array_try_to_array_requires_an_exact_length_match() {
+    let array = Array::of3(&JsValue::from(1), &JsValue::from(2), &JsValue::from(3));
+
+    let exact: [JsValue; 3] = array.try_to_array().unwrap();
+    assert_eq!(exact[0].as_f64(), Some(1.0));
+    assert_eq!(exact[2].as_f64(), Some(3.0));
+
+    let err = array.try_to_array::<4>().unwrap_err();
+    assert_eq!(err.expected, 4);
+    assert_eq!(err.actual, 3);
+
+    let err = array.try_to_array::<2>().unwrap_err();
+    assert_eq!(err.expected, 2);
+    assert_eq!(err.actual, 3);
+
+    let empty = Array::new();
+    let zero: [JsValue; 0] = empty.try_to_array().unwrap();
+    assert_eq!(zero.len(), 0);
+}
+
+#[wasm_bindgen_test]
+fn array_try_to_array_prefix_ignores_extra_trailing_elements() {
+    let array = Array::of3(&JsValue::from(1), &JsValue::from(2), &JsValue::from(3));
+
+    let prefix: [JsValue; 2] = array.try_to_array_prefix().unwrap();
+    assert_eq!(prefix[0].as_f64(), Some(1.0));
+    assert_eq!(prefix[1].as_f64(), Some(2.0));
+
+    let err = array.try_to_array_prefix::<4>().unwrap_err();
+    assert_eq!(err.expected, 4);
+    assert_eq!(err.actual, 3);
+}
+
+#[wasm_bindgen_test]
+fn array_try_to_f64_array_checks_length_and_element_type() {
+    let numbers = Array::of3(&JsValue::from(1.0), &JsValue::from(2.0), &JsValue::from(3.0));
+    let ok: [f64; 3] = numbers.try_to_f64_array().unwrap();
+    assert_eq!(ok, [1.0, 2.0, 3.0]);
+
+    match numbers.try_to_f64_array::<2>() {
+        Err(LengthOrTypeError::Length { expected, actual }) => {
+            assert_eq!(expected, 2);
+            assert_eq!(actual, 3);
+        }
+        other => panic!("expected a Length error, got {:?}", other),
+    }
+
+    let mixed = Array::of3(&JsValue::from(1.0), &JsValue::from_str("nope"), &JsValue::from(3.0));
+    match mixed.try_to_f64_array::<3>() {
+        Err(LengthOrTypeError::NotANumber { index }) => assert_eq!(index, 1),
+        other => panic!("expected a NotANumber error, got {:?}", other),
+    }
+}
+
+#[wasm_bindgen_test]
+fn array_is_sorted_detects_an_out_of_order_pair_and_nan() {
+    let sorted = Array::of3(&JsValue::from(1.0), &JsValue::from(2.0), &JsValue::from(3.0));
+    assert!(sorted.is_sorted());
+
+    let unsorted_at_end = Array::of3(&JsValue::from(1.0), &JsValue::from(3.0), &JsValue::from(2.0));
+    assert!(!unsorted_at_end.is_sorted());
+
+    let single = Array::of1(&JsValue::from(1.0));
+    assert!(single.is_sorted());
+
+    let empty = Array::new();
+    assert!(empty.is_sorted());
+
+    let with_nan = Array::of3(&JsValue::from(1.0), &JsValue::from(f64::NAN), &JsValue::from(3.0));
+    assert!(!with_nan.is_sorted());
+}
+
+#[wasm_bindgen_test]
+fn array_is_sorted_by_uses_the_given_comparator() {
+    let descending = Array::of3(&JsValue::from(3.0), &JsValue::from(2.0), &JsValue::from(1.0));
+    assert!(descending.is_sorted_by(&mut |a, b| {
+        b.as_f64().unwrap().partial_cmp(&a.as_f64().unwrap()).unwrap()
+    }));
+    assert!(!descending.is_sorted_by(&mut |a, b| {
+        a.as_f64().unwrap().partial_cmp(&b.as_f64().unwrap()).unwrap()
+    }));
+}
+
+#[wasm_bindgen_test]
+fn sorted_array_new_rejects_unsorted_input() {
+    let sorted = Array::of3(&JsValue::from(1.0), &JsValue::from(2.0), &JsValue::from(3.0));
+    assert!(SortedArray::new(sorted).is_some());
+
+    let unsorted = Array::of3(&JsValue::from(2.0), &JsValue::from(1.0), &JsValue::from(3.0));
+    assert!(SortedArray::new(unsorted).is_none());
+}
+
+#[wasm_bindgen_test]
+fn sorted_array_binary_search_finds_or_locates_the_insertion_point() {
+    let array = Array::of5(
+        &JsValue::from(1.0),
+        &JsValue::from(3.0),
+        &JsValue::from(5.0),
+        &JsValue::from(7.0),
+        &JsValue::from(9.0),
+    );
+    let sorted = SortedArray::new(array).unwrap();
+
+    assert_eq!(sorted.binary_search(5.0), Ok(2));
+    assert_eq!(sorted.binary_search(4.0), Err(2));
+    assert_eq!(sorted.binary_search(0.0), Err(0));
+    assert_eq!(sorted.binary_search(10.0), Err(5));
+}
+
+#[wasm_bindgen_test]
+fn sorted_array_merge_with_interleaves_both_inputs_in_order() {
+    let a = SortedArray::new(Array::of3(&JsValue::from(1.0), &JsValue::from(4.0), &JsValue::from(7.0))).unwrap();
+    let b = SortedArray::new(Array::of3(&JsValue::from(2.0), &JsValue::from(4.0), &JsValue::from(8.0))).unwrap();
+
+    let merged = a.merge_with(&b);
+    assert!(merged.is_sorted());
+    assert_eq!(
+        merged.to_vec().iter().filter_map(|v| v.as_f64()).collect::<Vec<_>>(),
+        vec![1.0, 2.0, 4.0, 4.0, 7.0, 8.0]
+    );
+}
+
+#[wasm_bindgen_test]
+fn array_take_all_empties_self_and_is_observed_through_other_handles() {
+    let array = Array::of3(&JsValue::from(1), &JsValue::from(2), &JsValue::from(3));
+    let other_handle = array.clone();
+
+    let taken = array.take_all();
+    assert_eq!(
+        taken.to_vec().iter().filter_map(|v| v.as_f64()).collect::<Vec<_>>(),
+        vec![1.0, 2.0, 3.0]
+    );
+    assert_eq!(array.length(), 0);
+    // `other_handle` refers to the same underlying array.
+    assert_eq!(other_handle.length(), 0);
+}
+
+#[wasm_bindgen_test]
+fn array_replace_with_swaps_contents_and_leaves_other_untouched() {
+    let array = Array::of2(&JsValue::from(1), &JsValue::from(2));
+    let other = Array::of3(&JsValue::from(9), &JsValue::from(8), &JsValue::from(7));
+
+    let old = array.replace_with(&other);
+    assert_eq!(
+        old.to_vec().iter().filter_map(|v| v.as_f64()).collect::<Vec<_>>(),
+        vec![1.0, 2.0]
+    );
+    assert_eq!(
+        array.to_vec().iter().filter_map(|v| v.as_f64()).collect::<Vec<_>>(),
+        vec![9.0, 8.0, 7.0]
+    );
+    assert_eq!(other.length(), 3);
+}
+
+#[wasm_bindgen_test]
+fn array_truncate_never_grows() {
+    let array = Array::of3(&JsValue::from(1), &JsValue::from(2), &JsValue::from(3));
+
+    array.truncate(2);
+    assert_eq!(array.length(), 2);
+
+    // Already shorter than the requested length -- must not grow.
+    array.truncate(10);
+    assert_eq!(array.length(), 2);
+}
+
+fn to_f64_vec(array: &Array) -> Vec<f64> {
+    array.to_vec().into_iter().map(|v| v.as_f64().unwrap_or(f64::NAN)).collect()
+}
+
+#[wasm_bindgen_test]
+fn array_merge_sorted_interleaves_duplicates_and_stays_sorted() {
+    let a = Array::of3(&JsValue::from(1.0), &JsValue::from(3.0), &JsValue::from(5.0));
+    let b = Array::of3(&JsValue::from(3.0), &JsValue::from(4.0), &JsValue::from(6.0));
+
+    let merged = a.merge_sorted(&b);
+    assert!(merged.is_sorted());
+    assert_eq!(to_f64_vec(&merged), vec![1.0, 3.0, 3.0, 4.0, 5.0, 6.0]);
+
+    let empty = Array::new();
+    assert_eq!(to_f64_vec(&a.merge_sorted(&empty)), to_f64_vec(&a));
+    assert_eq!(to_f64_vec(&empty.merge_sorted(&empty)), Vec::<f64>::new());
+}
+
+#[wasm_bindgen_test]
+fn array_merge_sorted_by_uses_the_given_comparator() {
+    let a = Array::of2(&JsValue::from_str("b"), &JsValue::from_str("d"));
+    let b = Array::of2(&JsValue::from_str("a"), &JsValue::from_str("c"));
+
+    let merged = a.merge_sorted_by(&b, &mut |x, y| x.as_string().cmp(&y.as_string()));
+    let strings: Vec<String> = merged.to_vec().into_iter().filter_map(|v| v.as_string()).collect();
+    assert_eq!(strings, vec!["a", "b", "c", "d"].into_iter().map(String::from).collect::<Vec<_>>());
+}
+
+#[wasm_bindgen_test]
+fn array_intersect_sorted_keeps_one_per_matching_pair() {
+    let a = Array::of4(&JsValue::from(1.0), &JsValue::from(2.0), &JsValue::from(2.0), &JsValue::from(3.0));
+    let b = Array::of2(&JsValue::from(2.0), &JsValue::from(3.0));
+
+    let result = a.intersect_sorted(&b);
+    // 2 is matched once (`b` has one 2), so only one of the two 2s in `a` survives.
+    assert_eq!(to_f64_vec(&result), vec![2.0, 3.0]);
+
+    let empty = Array::new();
+    assert_eq!(to_f64_vec(&a.intersect_sorted(&empty)), Vec::<f64>::new());
+}
+
+#[wasm_bindgen_test]
+fn array_union_sorted_dedupes_across_and_within_both_inputs() {
+    let a = Array::of3(&JsValue::from(1.0), &JsValue::from(2.0), &JsValue::from(2.0));
+    let b = Array::of2(&JsValue::from(2.0), &JsValue::from(3.0));
+
+    let result = a.union_sorted(&b);
+    assert!(result.is_sorted());
+    assert_eq!(to_f64_vec(&result), vec![1.0, 2.0, 3.0]);
+}
+
+#[wasm_bindgen_test]
+fn array_diff_sorted_drops_one_match_per_element_in_other() {
+    let a = Array::of4(&JsValue::from(1.0), &JsValue::from(2.0), &JsValue::from(2.0), &JsValue::from(3.0));
+    let b = Array::of1(&JsValue::from(2.0));
+
+    let result = a.diff_sorted(&b);
+    // Only one of the two 2s is dropped, since `b` has just one.
+    assert_eq!(to_f64_vec(&result), vec![1.0, 2.0, 3.0]);
+
+    let empty = Array::new();
+    assert_eq!(to_f64_vec(&a.diff_sorted(&empty)), to_f64_vec(&a));
+}
+
+#[wasm_bindgen_test]
+fn array_sorted_set_ops_treat_nan_as_matching_itself() {
+    let a = Array::of2(&JsValue::from(1.0), &JsValue::from(f64::NAN));
+    let b = Array::of1(&JsValue::from(f64::NAN));
+
+    let intersected = a.intersect_sorted(&b);
+    assert_eq!(intersected.length(), 1);
+    assert!(intersected.get(0).as_f64().unwrap().is_nan());
+}